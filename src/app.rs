@@ -1,16 +1,20 @@
 use crate::{
     backup,
-    config::{AppConfig, GameConfig},
+    bg3::{LarianDirSource, ScriptExtenderInfo, FLATPAK_STEAM_DIR},
+    config::{self, glob_match, AppConfig, GameConfig},
     deploy,
     game::{self, GameId},
     importer,
     library::{
         is_sigillink_ranking_profile, library_mod_root, normalize_label, normalize_times,
-        path_times, resolve_times, FileOverride, InstallTarget, Library, ModEntry, ModSource,
-        Profile, ProfileEntry, SigilLinkRankMeta, TargetKind, TargetOverride,
-        SIGILLINK_RANKING_PROFILE,
+        path_times, resolve_times, run_schema_migrations, schema_version_of, EnsureModsReport,
+        FileOverride, InstallTarget, Library, ModDiskUsage, ModEntry, ModSource, OrderRule,
+        PakInfo, Profile, ProfileEntry, SchemaMigration, SigilLinkRankMeta, SigilLinkRankSnapshot,
+        TargetKind, TargetOverride, LIBRARY_SCHEMA_VERSION, SIGILLINK_RANKING_PROFILE,
     },
-    metadata, native_pak, sigillink, smart_rank, update,
+    metadata, native_pak,
+    palette::{self, PaletteAction, PaletteEntry},
+    sigillink, smart_rank, support, trash, update,
 };
 use anyhow::{Context, Result};
 use arboard::Clipboard;
@@ -18,12 +22,13 @@ use directories::BaseDirs;
 use serde::{Deserialize, Serialize};
 use std::{
     cmp::Ordering,
-    collections::{HashMap, HashSet, VecDeque},
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
     fs,
     io::{self, Write},
     path::{Path, PathBuf},
     process::{Command, Stdio},
     sync::{
+        atomic::{AtomicBool, Ordering as AtomicOrdering},
         mpsc::{self, Receiver, Sender, TryRecvError},
         Arc, Mutex,
     },
@@ -38,29 +43,43 @@ use blake3::Hasher;
 use std::os::unix::fs::MetadataExt;
 
 const SEARCH_DEBOUNCE_MS: u64 = 250;
+const IMPORT_RATE_WINDOW_MS: u128 = 5000;
 const HOTKEY_DEBOUNCE_MS: u64 = 200;
 const HOTKEY_FADE_MS: u64 = 200;
 const SIGILLINK_AUTO_RANK_DEBOUNCE_SECS: u64 = 5;
 const METADATA_CACHE_VERSION: u32 = 2;
-const SMART_RANK_DEBOUNCE_MS: u64 = 600;
+const METADATA_SAVE_EVERY: usize = 25;
+const METADATA_SAVE_DEBOUNCE_MS: u64 = 2000;
 const SMART_RANK_CACHE_SAVE_DEBOUNCE_MS: u64 = 400;
 const SMART_RANK_CACHE_VERSION: u32 = 2;
+const PALETTE_RECENT_LIMIT: usize = 8;
+const DEV_WATCH_POLL_MS: u64 = 1000;
+const MOD_LIST_MISSING_CONFIRM_PERCENT: usize = 25;
+const MOD_LIST_MISSING_CONFIRM_MIN: usize = 3;
+const BACKGROUND_CONFLICT_SCAN_MIN_SECS: u64 = 5;
+const DEV_WATCH_DEBOUNCE_MS: u64 = 1500;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ExportKind {
     ModList,
     Modsettings,
+    UuidList,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InputPurpose {
     ImportPath,
+    AdoptDevFolder,
     CreateProfile,
     RenameProfile {
         original: String,
     },
+    RenameMod {
+        id: String,
+    },
     DuplicateProfile {
         source: String,
+        enabled_only: bool,
     },
     #[allow(dead_code)]
     ExportProfile {
@@ -70,6 +89,8 @@ pub enum InputPurpose {
     #[allow(dead_code)]
     ImportProfile,
     FilterMods,
+    SearchLog,
+    MoveToSlot,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -107,6 +128,9 @@ pub enum PathBrowserPurpose {
         kind: ExportKind,
     },
     ExportLog,
+    ExportDiagnostics,
+    StageDeploy,
+    ImportSettings,
     SigilLinkCache {
         action: SigilLinkCacheAction,
         require_dev: Option<u64>,
@@ -163,6 +187,7 @@ pub struct DialogToggle {
 pub enum DialogKind {
     Overwrite,
     Similar,
+    Identical,
     Unrecognized {
         path: PathBuf,
         label: String,
@@ -194,13 +219,37 @@ pub enum DialogKind {
     SigilLinkRelocation {
         target_root: PathBuf,
     },
+    CancelDeploy,
+    AdoptNativeMod {
+        id: String,
+        name: String,
+    },
+    ClearFileOverrides {
+        profile: String,
+    },
     MoveBlocked {
         resume_move_mode: bool,
         clear_filter: bool,
     },
+    PendingMove {
+        target: PendingMoveTarget,
+    },
+    DuplicateProfile {
+        source: String,
+    },
+    FreezeSort,
+    ResetProfileOrder,
     CancelImport,
+    SupportBundle,
+    ExportSettings,
+    RepairModsettings,
+    GameUpdated {
+        build: String,
+    },
     OverrideDependencies,
-    ImportSummary,
+    ImportSummary {
+        report: String,
+    },
     CopyDependencySearchLink {
         link: String,
     },
@@ -208,6 +257,7 @@ pub enum DialogKind {
     SigilLinkOnboarding,
     SigilLinkRankPrompt,
     SigilLinkClearPins,
+    SigilLinkCacheGc,
     SigilLinkPinNotice,
     #[allow(dead_code)]
     EnableAllVisible,
@@ -217,6 +267,15 @@ pub enum DialogKind {
     InvertVisible,
 }
 
+#[derive(Debug, Clone)]
+pub enum PendingMoveTarget {
+    Focus(Focus),
+    Profile(String),
+    Game(GameId),
+    Palette(PaletteAction),
+    Quit,
+}
+
 #[derive(Debug, Clone)]
 pub struct Dialog {
     pub title: String,
@@ -298,6 +357,14 @@ pub struct OverrideCandidatePicker {
     pub selected: usize,
 }
 
+#[derive(Debug, Clone)]
+pub struct ModListMatchPicker {
+    pub entry_index: usize,
+    pub label: String,
+    pub items: Vec<OverrideCandidateItem>,
+    pub selected: usize,
+}
+
 impl DependencyItem {
     pub fn is_override_action(&self) -> bool {
         matches!(self.kind, DependencyItemKind::OverrideAction)
@@ -364,6 +431,31 @@ pub struct DuplicateModInfo {
 pub struct LogEntry {
     pub level: LogLevel,
     pub message: String,
+    pub timestamp: time::OffsetDateTime,
+}
+
+impl LogEntry {
+    pub fn time_label(&self) -> String {
+        format!(
+            "{:02}:{:02}:{:02}",
+            self.timestamp.hour(),
+            self.timestamp.minute(),
+            self.timestamp.second()
+        )
+    }
+
+    pub fn timestamp_label(&self) -> String {
+        rfc3339_label(self.timestamp)
+    }
+
+    fn line(&self) -> String {
+        format!(
+            "[{}] [{}] {}",
+            self.timestamp_label(),
+            log_level_label(self.level),
+            self.message
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -373,6 +465,48 @@ pub enum LogLevel {
     Error,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFilter {
+    #[default]
+    All,
+    Warnings,
+    Errors,
+}
+
+impl LogFilter {
+    pub fn allows(self, level: LogLevel) -> bool {
+        match self {
+            LogFilter::All => true,
+            LogFilter::Warnings => matches!(level, LogLevel::Warn | LogLevel::Error),
+            LogFilter::Errors => level == LogLevel::Error,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LogFilter::All => "All",
+            LogFilter::Warnings => "Warnings + Errors",
+            LogFilter::Errors => "Errors",
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            LogFilter::All => "Log",
+            LogFilter::Warnings => "Log (Warn+Error)",
+            LogFilter::Errors => "Log (Errors)",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            LogFilter::All => LogFilter::Warnings,
+            LogFilter::Warnings => LogFilter::Errors,
+            LogFilter::Errors => LogFilter::All,
+        }
+    }
+}
+
 fn duplicate_source_label(source: ModSource) -> &'static str {
     match source {
         ModSource::Managed => "Managed",
@@ -409,17 +543,31 @@ enum ImportMessage {
     Completed {
         path: PathBuf,
         result: importer::ImportResult,
+        pak_hashes: Vec<LibraryPakHash>,
     },
     Failed {
         path: PathBuf,
         error: String,
     },
+    ModListDownloaded {
+        path: PathBuf,
+        file: importer::DownloadedFile,
+    },
     ApplyCompleted(ImportApplyOutcome),
 }
 
+/// Hash for an unhashed library pak, computed by the import worker for duplicate detection.
+struct LibraryPakHash {
+    mod_id: String,
+    file: String,
+    hash: String,
+}
+
 enum DeployMessage {
     Completed { report: deploy::DeployReport },
+    Staged { report: deploy::DeployReport },
     SigilLinkRelocation { error: String, target_root: PathBuf },
+    Cancelled,
     Failed { error: String },
 }
 
@@ -429,6 +577,46 @@ struct MetadataUpdate {
     created_at: Option<i64>,
     modified_at: Option<i64>,
     dependencies: Vec<String>,
+    pak_hashes: Vec<(String, String)>,
+    min_game_version: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportOutcomeKind {
+    Imported,
+    Replaced,
+    Linked,
+    Skipped,
+    Failed,
+}
+
+impl ImportOutcomeKind {
+    fn marker(self) -> &'static str {
+        match self {
+            ImportOutcomeKind::Imported => "+",
+            ImportOutcomeKind::Replaced => "~",
+            ImportOutcomeKind::Linked => "=",
+            ImportOutcomeKind::Skipped => "-",
+            ImportOutcomeKind::Failed => "!",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ImportOutcomeKind::Imported => "imported",
+            ImportOutcomeKind::Replaced => "replaced",
+            ImportOutcomeKind::Linked => "linked",
+            ImportOutcomeKind::Skipped => "skipped",
+            ImportOutcomeKind::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ImportOutcome {
+    name: String,
+    kind: ImportOutcomeKind,
+    detail: Option<String>,
 }
 
 struct ImportApplyOutcome {
@@ -445,6 +633,7 @@ enum MetadataMessage {
         total: usize,
     },
     Completed,
+    Cancelled,
     Failed {
         error: String,
     },
@@ -454,15 +643,79 @@ enum MissingPakMessage {
     Completed(Vec<SigilLinkMissingItem>),
 }
 
+enum DevWatchMessage {
+    Changed(String),
+}
+
+struct CacheRestageJob {
+    mod_id: String,
+    source: String,
+    pak_hashes: Vec<String>,
+}
+
+struct CacheRestageResult {
+    mod_id: String,
+    result: std::result::Result<Vec<InstallTarget>, String>,
+}
+
+enum DiskUsageMessage {
+    Completed(Vec<(String, ModDiskUsage)>),
+}
+
 enum ConflictMessage {
     Completed {
         conflicts: Vec<deploy::ConflictEntry>,
+        rules_rev: u64,
+    },
+    Failed {
+        error: String,
+    },
+}
+
+enum ToggleImpactMessage {
+    Completed {
+        mod_id: String,
+        changes: Vec<ToggleImpactChange>,
     },
     Failed {
+        mod_id: String,
         error: String,
     },
 }
 
+#[derive(Debug, Clone)]
+struct CacheVerifyJob {
+    mod_id: String,
+    mod_name: String,
+    targets: Vec<InstallTarget>,
+    source: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct CacheVerifyIssue {
+    mod_name: String,
+    path: String,
+    problem: String,
+}
+
+#[derive(Debug, Clone, Default)]
+struct CacheVerifyReport {
+    checked: usize,
+    issues: Vec<CacheVerifyIssue>,
+    repaired: Vec<CacheVerifyIssue>,
+    unverified: Vec<CacheVerifyIssue>,
+}
+
+#[derive(Debug, Clone)]
+struct CacheVerifyDamage {
+    kind: Option<TargetKind>,
+    relative_path: String,
+    dest: PathBuf,
+    size: u64,
+    hash: Option<String>,
+    problem: &'static str,
+}
+
 enum UpdateMessage {
     Completed(update::UpdateResult),
     Failed { error: String },
@@ -471,6 +724,7 @@ enum UpdateMessage {
 #[derive(Debug, Clone)]
 enum DuplicateKind {
     Exact,
+    Identical,
     Similar {
         new_label: String,
         existing_label: String,
@@ -486,6 +740,7 @@ struct DuplicateDecision {
     existing_label: String,
     kind: DuplicateKind,
     default_overwrite: Option<bool>,
+    hash_mismatch: Option<(String, String)>,
 }
 
 #[derive(Debug, Clone)]
@@ -512,6 +767,8 @@ struct ProfileExport {
     entries: Vec<ProfileExportEntry>,
     #[serde(default)]
     file_overrides: Vec<FileOverride>,
+    #[serde(default)]
+    last_deployed_at: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -530,8 +787,18 @@ struct ModListImport {
     warnings: Vec<String>,
 }
 
+const MODLIST_MIGRATIONS: &[SchemaMigration] = &[migrate_modlist_v0_to_v1];
+const MODLIST_SCHEMA_VERSION: u32 = MODLIST_MIGRATIONS.len() as u32;
+
 fn default_modlist_schema_version() -> u32 {
-    1
+    MODLIST_SCHEMA_VERSION
+}
+
+fn migrate_modlist_v0_to_v1(value: serde_json::Value) -> Result<serde_json::Value> {
+    if !value.is_object() {
+        return Err(anyhow::anyhow!("mod list export is not an object"));
+    }
+    Ok(value)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -572,6 +839,7 @@ pub enum ModSortColumn {
     Target,
     Created,
     Added,
+    Size,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -600,6 +868,7 @@ impl ModSort {
             ModSortColumn::Target => "Target",
             ModSortColumn::Created => "Created",
             ModSortColumn::Added => "Added",
+            ModSortColumn::Size => "Size",
         }
     }
 
@@ -622,7 +891,7 @@ impl ModSort {
     }
 }
 
-const MOD_SORT_COLUMNS: [ModSortColumn; 8] = [
+const MOD_SORT_COLUMNS: [ModSortColumn; 9] = [
     ModSortColumn::Enabled,
     ModSortColumn::Order,
     ModSortColumn::Native,
@@ -630,6 +899,7 @@ const MOD_SORT_COLUMNS: [ModSortColumn; 8] = [
     ModSortColumn::Name,
     ModSortColumn::Created,
     ModSortColumn::Added,
+    ModSortColumn::Size,
     ModSortColumn::Target,
 ];
 
@@ -666,6 +936,9 @@ pub struct App {
     pub whats_new_open: bool,
     pub whats_new_scroll: usize,
     pub paths_overlay_open: bool,
+    pub paths_overlay_selected: usize,
+    pub script_extender: Option<ScriptExtenderInfo>,
+    script_extender_warned: bool,
     pub should_quit: bool,
     pub move_mode: bool,
     pub move_origin_id: Option<String>,
@@ -676,6 +949,9 @@ pub struct App {
     pub dialog: Option<Dialog>,
     pub logs: Vec<LogEntry>,
     pub log_scroll: usize,
+    pub log_filter: LogFilter,
+    pub log_search: String,
+    pub log_search_match: Option<usize>,
     pub move_dirty: bool,
     pub focus: Focus,
     pub hotkey_focus: Focus,
@@ -684,6 +960,8 @@ pub struct App {
     clipboard: Option<Clipboard>,
     pub mod_filter: String,
     mod_filter_snapshot: Option<String>,
+    pub recent_filter: bool,
+    pub starred_filter: bool,
     pub mod_sort: ModSort,
     pub settings_menu: Option<SettingsMenu>,
     settings_menu_last_selected: usize,
@@ -692,10 +970,25 @@ pub struct App {
     pub update_status: UpdateStatus,
     pub smart_rank_preview: Option<SmartRankPreview>,
     pub smart_rank_scroll: usize,
+    pub compatibility_view: Option<CompatibilityView>,
+    pub command_palette: Option<CommandPalette>,
+    palette_recent: Vec<String>,
+    pub conflict_rules_view: Option<ConflictRulesView>,
+    pub trash_view: Option<TrashView>,
+    pub rank_history_view: Option<RankHistoryView>,
+    pub targets_view: Option<TargetsView>,
+    pub load_order_check_view: Option<LoadOrderCheckView>,
+    pub toggle_impact_view: Option<ToggleImpactView>,
+    pub order_rules_view: Option<OrderRulesView>,
+    pub setup_wizard: Option<SetupWizard>,
+    pub uuid_collision_view: Option<UuidCollisionView>,
+    uuid_collision_notes: Vec<String>,
     pub smart_rank_view: SmartRankView,
     pub mod_list_preview: Option<ModListPreview>,
+    pub mod_list_picker: Option<ModListMatchPicker>,
     pub mod_list_scroll: usize,
     sigillink_force_preview: bool,
+    sigillink_rank_scope: Option<HashSet<String>>,
     sigillink_preview_notice: Option<String>,
     sigillink_rank_pending_import: bool,
     sigillink_rank_debounce_until: Option<Instant>,
@@ -730,10 +1023,29 @@ pub struct App {
     metadata_total: usize,
     metadata_processed_ids: HashSet<String>,
     metadata_dirty: bool,
+    metadata_unsaved: usize,
+    metadata_last_saved: Option<Instant>,
+    metadata_targeted: bool,
+    metadata_cancel: Arc<AtomicBool>,
+    deploy_cancel: Arc<AtomicBool>,
     missing_pak_tx: Sender<MissingPakMessage>,
     missing_pak_rx: Receiver<MissingPakMessage>,
     missing_pak_active: bool,
     missing_pak_pending: bool,
+    automation_paused: bool,
+    automation_deploy_deferred: bool,
+    automation_scan_deferred: bool,
+    automation_metadata_deferred: bool,
+    dev_watch_tx: Sender<DevWatchMessage>,
+    dev_watch_rx: Receiver<DevWatchMessage>,
+    dev_watch_targets: Arc<Mutex<Vec<(String, PathBuf)>>>,
+    dev_watch_started: bool,
+    dev_watch_changed: HashSet<String>,
+    dev_watch_last_change: Option<Instant>,
+    disk_usage_tx: Sender<DiskUsageMessage>,
+    disk_usage_rx: Receiver<DiskUsageMessage>,
+    disk_usage_active: bool,
+    disk_usage_pending: bool,
     update_tx: Sender<UpdateMessage>,
     update_rx: Receiver<UpdateMessage>,
     update_active: bool,
@@ -745,6 +1057,7 @@ pub struct App {
     hotkey_transition_at: Option<Instant>,
     hotkey_fade_until: Option<Instant>,
     import_queue: VecDeque<PathBuf>,
+    import_dev_paths: HashSet<PathBuf>,
     import_active: Option<PathBuf>,
     import_apply_active: bool,
     import_tx: Sender<ImportMessage>,
@@ -765,24 +1078,56 @@ pub struct App {
     pak_meta_cache: Arc<metadata::PakMetaCache>,
     pending_delete_mod: Option<(String, String)>,
     import_failures: Vec<importer::ImportFailure>,
+    import_outcomes: Vec<ImportOutcome>,
+    import_replacing: HashMap<String, String>,
     import_progress: Option<importer::ImportProgress>,
+    import_rate_samples: VecDeque<(Instant, u64)>,
+    import_cancel: importer::CancelFlag,
     import_summary_pending: bool,
     deploy_active: bool,
+    deploy_manual: bool,
     deploy_pending: bool,
+    deploy_due: Option<Instant>,
     deploy_reason: Option<String>,
     deploy_backup: bool,
+    deploy_skip_loose: bool,
+    deploy_staging: Option<PathBuf>,
+    deploy_profile: Option<String>,
+    deployed_state_hash: Option<String>,
+    deploy_state_dirty: bool,
+    pub deploy_out_of_date: bool,
     deploy_tx: Sender<DeployMessage>,
     deploy_rx: Receiver<DeployMessage>,
     conflict_active: bool,
     conflict_pending: bool,
+    conflict_background: bool,
+    conflict_last_activity: Option<Instant>,
     conflict_tx: Sender<ConflictMessage>,
     conflict_rx: Receiver<ConflictMessage>,
+    toggle_impact_tx: Sender<ToggleImpactMessage>,
+    toggle_impact_rx: Receiver<ToggleImpactMessage>,
+    cache_verify_active: bool,
+    cache_verify_tx: Sender<CacheVerifyReport>,
+    cache_verify_rx: Receiver<CacheVerifyReport>,
+    cache_size: Option<u64>,
+    cache_size_active: bool,
+    cache_size_tx: Sender<(PathBuf, u64)>,
+    cache_size_rx: Receiver<(PathBuf, u64)>,
+    cache_fs_warning: Option<String>,
+    cache_fs_split: Vec<&'static str>,
+    cache_restage_active: bool,
+    cache_restage_tx: Sender<Vec<CacheRestageResult>>,
+    cache_restage_rx: Receiver<Vec<CacheRestageResult>>,
     log_path: PathBuf,
     duplicate_queue: VecDeque<DuplicateDecision>,
     pending_duplicate: Option<DuplicateDecision>,
     duplicate_apply_all: Option<bool>,
     approved_imports: Vec<importer::ImportMod>,
     pub conflicts: Vec<deploy::ConflictEntry>,
+    pub ignored_conflicts: Vec<deploy::ConflictEntry>,
+    pub show_ignored_conflicts: bool,
+    conflict_rules_rev: u64,
+    conflict_rule_counts: Vec<usize>,
     pub conflict_selected: usize,
     pub override_swap: Option<OverrideSwap>,
     pub pending_overrides: HashMap<usize, PendingOverride>,
@@ -848,6 +1193,7 @@ pub enum SmartRankMode {
     Auto,
     Preview,
     Warmup,
+    Report,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -868,6 +1214,135 @@ pub enum CliVerbosity {
 pub struct CliImportOptions {
     pub deploy: bool,
     pub verbosity: CliVerbosity,
+    pub json: bool,
+    pub on_duplicate: CliDuplicatePolicy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CliExitCode {
+    Failure = 1,
+    Usage = 2,
+    PathsNotReady = 3,
+    ImportFailed = 4,
+    DeployFailed = 5,
+    UuidCollision = 6,
+}
+
+impl std::fmt::Display for CliExitCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            CliExitCode::Failure => "failed",
+            CliExitCode::Usage => "invalid arguments",
+            CliExitCode::PathsNotReady => "game paths not ready",
+            CliExitCode::ImportFailed => "import failed",
+            CliExitCode::DeployFailed => "deploy failed",
+            CliExitCode::UuidCollision => "pak UUID collision",
+        };
+        write!(f, "{label}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CliDuplicatePolicy {
+    Prompt,
+    Skip,
+    Overwrite,
+    Newer,
+}
+
+impl CliDuplicatePolicy {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "prompt" | "ask" => Some(Self::Prompt),
+            "skip" | "keep" => Some(Self::Skip),
+            "overwrite" | "replace" => Some(Self::Overwrite),
+            "newer" | "default" => Some(Self::Newer),
+            _ => None,
+        }
+    }
+
+    fn resolve(self, default_overwrite: Option<bool>) -> Option<bool> {
+        match self {
+            Self::Prompt => None,
+            Self::Skip => Some(false),
+            Self::Overwrite => Some(true),
+            Self::Newer => Some(default_overwrite.unwrap_or(false)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct CliImportSummary {
+    imported: usize,
+    sources: Vec<CliImportSourceResult>,
+    failures: Vec<CliImportFailureEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deploy: Option<CliImportDeploySummary>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct CliImportSourceResult {
+    input: String,
+    source: String,
+    status: &'static str,
+    imported: usize,
+    mods: Vec<String>,
+    failures: usize,
+    elapsed_ms: u128,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct CliImportFailureEntry {
+    source: String,
+    reason: String,
+    error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct CliImportDeploySummary {
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+    pak_count: usize,
+    loose_count: usize,
+    file_count: usize,
+    warnings: Vec<String>,
+}
+
+impl CliImportSourceResult {
+    fn failed(input: &str, source: String) -> Self {
+        Self {
+            input: input.to_string(),
+            source,
+            status: "failed",
+            imported: 0,
+            mods: Vec::new(),
+            failures: 1,
+            elapsed_ms: 0,
+        }
+    }
+}
+
+impl CliImportDeploySummary {
+    fn skipped(reason: &str) -> Self {
+        Self {
+            status: "skipped",
+            reason: Some(reason.to_string()),
+            pak_count: 0,
+            loose_count: 0,
+            file_count: 0,
+            warnings: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CliRankReport {
+    pub profile: String,
+    pub total: usize,
+    pub moves: Vec<SmartRankMove>,
+    pub warnings: Vec<String>,
+    pub applied: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -879,18 +1354,198 @@ pub struct SmartRankPreview {
     pub explain: smart_rank::SmartRankExplain,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct CommandPalette {
+    pub query: String,
+    pub selected: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct UuidCollisionView {
+    pub collisions: Vec<deploy::UuidCollision>,
+    pub selected: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct SetupWizard {
+    pub step: usize,
+    pub game_root: PathBuf,
+    pub larian_dir: PathBuf,
+    pub downloads_dir: PathBuf,
+}
+
+pub const SETUP_WIZARD_STEPS: [SetupStep; 3] = [
+    SetupStep::GameRoot,
+    SetupStep::LarianDir,
+    SetupStep::DownloadsDir,
+];
+
+impl SetupWizard {
+    pub fn current_step(&self) -> Option<SetupStep> {
+        SETUP_WIZARD_STEPS.get(self.step).copied()
+    }
+
+    pub fn path(&self, step: SetupStep) -> &PathBuf {
+        match step {
+            SetupStep::GameRoot => &self.game_root,
+            SetupStep::LarianDir => &self.larian_dir,
+            SetupStep::DownloadsDir => &self.downloads_dir,
+        }
+    }
+
+    fn path_mut(&mut self, step: SetupStep) -> &mut PathBuf {
+        match step {
+            SetupStep::GameRoot => &mut self.game_root,
+            SetupStep::LarianDir => &mut self.larian_dir,
+            SetupStep::DownloadsDir => &mut self.downloads_dir,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TrashView {
+    pub entries: Vec<trash::TrashEntry>,
+    pub selected: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RankHistoryView {
+    pub selected: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct TargetsView {
+    pub mod_id: String,
+    pub scroll: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct TargetDetail {
+    pub kind: TargetKind,
+    pub label: String,
+    pub source: PathBuf,
+    pub source_exists: bool,
+    pub dest: Option<PathBuf>,
+    pub enabled: bool,
+    pub overridden: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct OrderRulesView {
+    pub selected: usize,
+    pub adding: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct DependencyEnableScan {
+    present: HashSet<String>,
+    missing: Vec<String>,
+    missing_mod_ids: HashSet<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ConflictRulesView {
+    pub selected: usize,
+    pub adding: Option<String>,
+    pub adding_matches: usize,
+    pub test_result: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadOrderIssueKind {
+    BaseGame,
+    Dependency,
+    Patch,
+    Framework,
+    Rule,
+}
+
+impl LoadOrderIssueKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            LoadOrderIssueKind::BaseGame => "base game",
+            LoadOrderIssueKind::Dependency => "dependency",
+            LoadOrderIssueKind::Patch => "patch",
+            LoadOrderIssueKind::Framework => "framework",
+            LoadOrderIssueKind::Rule => "rule",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LoadOrderIssue {
+    pub kind: LoadOrderIssueKind,
+    pub mod_id: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct LoadOrderCheckView {
+    pub issues: Vec<LoadOrderIssue>,
+    pub checked: usize,
+    pub selected: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ToggleImpactKind {
+    Introduced,
+    Resolved,
+    WinnerChanged,
+}
+
+impl ToggleImpactKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            ToggleImpactKind::Introduced => "New",
+            ToggleImpactKind::Resolved => "Resolved",
+            ToggleImpactKind::WinnerChanged => "Winner",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ToggleImpactChange {
+    pub kind: ToggleImpactKind,
+    pub target: TargetKind,
+    pub path: String,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ToggleImpactView {
+    pub mod_id: String,
+    pub mod_name: String,
+    pub enable: bool,
+    pub loading: bool,
+    pub error: Option<String>,
+    pub changes: Vec<ToggleImpactChange>,
+    pub selected: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompatibilityView {
+    pub report: smart_rank::CompatibilityReport,
+    pub selected: usize,
+    pub expanded: HashSet<usize>,
+    pub scroll: usize,
+}
+
 #[derive(Debug, Clone)]
 pub struct ModListEntry {
     pub id: String,
     pub name: String,
     pub enabled: bool,
+    pub uuid: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ModListMatchMethod {
     Id,
+    Uuid,
     Name,
     Label,
+    Picked,
 }
 
 #[derive(Debug, Clone)]
@@ -904,7 +1559,7 @@ pub enum ModListMatchOutcome {
     },
     Missing,
     Ambiguous {
-        candidates: Vec<String>,
+        candidates: Vec<OverrideCandidateItem>,
         #[allow(dead_code)]
         method: ModListMatchMethod,
     },
@@ -934,6 +1589,12 @@ pub enum ModListOverrideMode {
     Replace,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModListMissingMode {
+    Placeholder,
+    Block,
+}
+
 #[derive(Debug, Clone)]
 pub struct ModListPreview {
     pub source_label: String,
@@ -944,6 +1605,9 @@ pub struct ModListPreview {
     pub destination: ModListDestination,
     pub mode: ModListApplyMode,
     pub override_mode: ModListOverrideMode,
+    pub missing_mode: ModListMissingMode,
+    pub missing_confirm: bool,
+    pub missing_only: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1016,6 +1680,7 @@ pub struct NativeSyncDelta {
     pub added: Vec<ModEntry>,
     pub updated_native_files: usize,
     pub adopted_native: usize,
+    pub ignored_native: usize,
     pub modsettings_exists: bool,
     pub modsettings_hash: Option<String>,
     pub enabled_set: HashSet<String>,
@@ -1054,15 +1719,23 @@ impl App {
             // Retry auto-detect when stored paths are missing or stale.
             if let Ok(paths) = game::detect_paths(game_id, None, None) {
                 config.game_root = paths.game_root;
-                config.larian_dir = paths.larian_dir;
+                config.set_larian_dir(paths.larian_dir);
                 let _ = config.save();
             } else {
                 setup_error = Some(err.to_string());
             }
         }
+        if config.larian_dir_source.is_none() {
+            config.set_larian_dir(config.larian_dir.clone());
+        }
+        config.refresh_game_version();
 
         let mut library = Library::load_or_create(&config.data_dir)?;
-        library.ensure_mods_in_profiles();
+        let duplicate_profile_entries = library.dedup_profile_orders();
+        let stale_profile_entries = library.ensure_mods_in_profiles().placeholders;
+        if stale_profile_entries > 0 {
+            library.label_placeholders(&removed_mod_names(&config.sigillink_cache_root()));
+        }
         if !library
             .profiles
             .iter()
@@ -1098,10 +1771,16 @@ impl App {
         let (import_tx, import_rx) = mpsc::channel();
         let (deploy_tx, deploy_rx) = mpsc::channel();
         let (conflict_tx, conflict_rx) = mpsc::channel();
+        let (toggle_impact_tx, toggle_impact_rx) = mpsc::channel();
+        let (cache_verify_tx, cache_verify_rx) = mpsc::channel();
+        let (cache_size_tx, cache_size_rx) = mpsc::channel();
+        let (cache_restage_tx, cache_restage_rx) = mpsc::channel();
         let (smart_rank_tx, smart_rank_rx) = mpsc::channel();
         let (native_sync_tx, native_sync_rx) = mpsc::channel();
         let (metadata_tx, metadata_rx) = mpsc::channel();
         let (missing_pak_tx, missing_pak_rx) = mpsc::channel();
+        let (dev_watch_tx, dev_watch_rx) = mpsc::channel();
+        let (disk_usage_tx, disk_usage_rx) = mpsc::channel();
         let (update_tx, update_rx) = mpsc::channel();
         let log_path = config.data_dir.join("sigilsmith.log");
 
@@ -1126,6 +1805,9 @@ impl App {
             whats_new_open: false,
             whats_new_scroll: 0,
             paths_overlay_open: false,
+            paths_overlay_selected: 0,
+            script_extender: None,
+            script_extender_warned: false,
             should_quit: false,
             move_mode: false,
             move_origin_id: None,
@@ -1136,6 +1818,9 @@ impl App {
             dialog: None,
             logs: Vec::new(),
             log_scroll: 0,
+            log_filter: LogFilter::default(),
+            log_search: String::new(),
+            log_search_match: None,
             move_dirty: false,
             focus: Focus::Mods,
             hotkey_focus: Focus::Mods,
@@ -1144,6 +1829,8 @@ impl App {
             clipboard: Clipboard::new().ok(),
             mod_filter: String::new(),
             mod_filter_snapshot: None,
+            recent_filter: false,
+            starred_filter: false,
             mod_sort: ModSort::default(),
             settings_menu: None,
             settings_menu_last_selected: 0,
@@ -1153,9 +1840,24 @@ impl App {
             smart_rank_preview: None,
             smart_rank_scroll: 0,
             smart_rank_view: SmartRankView::Changes,
+            compatibility_view: None,
+            command_palette: None,
+            conflict_rules_view: None,
+            trash_view: None,
+            rank_history_view: None,
+            targets_view: None,
+            load_order_check_view: None,
+            toggle_impact_view: None,
+            order_rules_view: None,
+            setup_wizard: None,
+            uuid_collision_view: None,
+            uuid_collision_notes: Vec::new(),
+            palette_recent: Vec::new(),
             mod_list_preview: None,
+            mod_list_picker: None,
             mod_list_scroll: 0,
             sigillink_force_preview: false,
+            sigillink_rank_scope: None,
             sigillink_preview_notice: None,
             sigillink_rank_pending_import: false,
             sigillink_rank_debounce_until: None,
@@ -1190,10 +1892,29 @@ impl App {
             metadata_total: 0,
             metadata_processed_ids: HashSet::new(),
             metadata_dirty: false,
+            metadata_unsaved: 0,
+            metadata_last_saved: None,
+            metadata_targeted: false,
+            metadata_cancel: Arc::new(AtomicBool::new(false)),
+            deploy_cancel: Arc::new(AtomicBool::new(false)),
             missing_pak_tx,
             missing_pak_rx,
             missing_pak_active: false,
             missing_pak_pending: matches!(mode, StartupMode::Ui),
+            automation_paused: false,
+            automation_deploy_deferred: false,
+            automation_scan_deferred: false,
+            automation_metadata_deferred: false,
+            dev_watch_tx,
+            dev_watch_rx,
+            dev_watch_targets: Arc::new(Mutex::new(Vec::new())),
+            dev_watch_started: false,
+            dev_watch_changed: HashSet::new(),
+            dev_watch_last_change: None,
+            disk_usage_tx,
+            disk_usage_rx,
+            disk_usage_active: false,
+            disk_usage_pending: false,
             update_tx,
             update_rx,
             update_active: false,
@@ -1205,6 +1926,7 @@ impl App {
             hotkey_transition_at: None,
             hotkey_fade_until: None,
             import_queue: VecDeque::new(),
+            import_dev_paths: HashSet::new(),
             import_active: None,
             import_apply_active: false,
             import_tx,
@@ -1225,24 +1947,56 @@ impl App {
             pak_meta_cache: Arc::new(metadata::PakMetaCache::new()),
             pending_delete_mod: None,
             import_failures: Vec::new(),
+            import_outcomes: Vec::new(),
+            import_replacing: HashMap::new(),
             import_progress: None,
+            import_rate_samples: VecDeque::new(),
+            import_cancel: Arc::new(AtomicBool::new(false)),
             import_summary_pending: false,
             deploy_active: false,
+            deploy_manual: false,
             deploy_pending: false,
+            deploy_due: None,
             deploy_reason: None,
             deploy_backup: true,
+            deploy_skip_loose: false,
+            deploy_staging: None,
+            deploy_profile: None,
+            deployed_state_hash: None,
+            deploy_state_dirty: true,
+            deploy_out_of_date: false,
             deploy_tx,
             deploy_rx,
             conflict_active: false,
             conflict_pending: false,
+            conflict_background: false,
+            conflict_last_activity: None,
             conflict_tx,
             conflict_rx,
+            toggle_impact_tx,
+            toggle_impact_rx,
+            cache_verify_active: false,
+            cache_verify_tx,
+            cache_verify_rx,
+            cache_size: None,
+            cache_size_active: false,
+            cache_size_tx,
+            cache_size_rx,
+            cache_fs_warning: None,
+            cache_fs_split: Vec::new(),
+            cache_restage_active: false,
+            cache_restage_tx,
+            cache_restage_rx,
             log_path,
             duplicate_queue: VecDeque::new(),
             pending_duplicate: None,
             duplicate_apply_all: None,
             approved_imports: Vec::new(),
             conflicts: Vec::new(),
+            ignored_conflicts: Vec::new(),
+            show_ignored_conflicts: false,
+            conflict_rules_rev: 0,
+            conflict_rule_counts: Vec::new(),
             conflict_selected: 0,
             override_swap: None,
             pending_overrides: HashMap::new(),
@@ -1274,6 +2028,9 @@ impl App {
                 app.config.game_root.display(),
                 app.config.larian_dir.display()
             ));
+            if let Some(source) = app.config.larian_dir_source {
+                app.log_info(format!("Larian data dir source: {}", source.label()));
+            }
             app.set_toast(
                 "Paths detected: BG3 + Larian data",
                 ToastLevel::Info,
@@ -1292,8 +2049,29 @@ impl App {
                     );
                 }
             }
+            app.check_game_updated(matches!(mode, StartupMode::Ui));
+            app.check_cache_filesystems();
+            app.log_profile_duplicates(&duplicate_profile_entries);
+            if stale_profile_entries > 0 {
+                app.log_warn(format!(
+                    "Profiles referenced {stale_profile_entries} removed mod(s); kept as missing placeholders"
+                ));
+            }
+            app.deployed_state_hash =
+                deploy::load_last_report(&app.config.data_dir).and_then(|report| report.state_hash);
+            app.refresh_script_extender();
+            if let Some(info) = &app.script_extender {
+                app.log_info(format!(
+                    "Script Extender detected: {}",
+                    script_extender_label(info)
+                ));
+            }
+        }
+        if matches!(mode, StartupMode::Ui) && !app.app_config.setup_completed {
+            app.open_setup_wizard();
+        } else {
+            app.ensure_setup();
         }
-        app.ensure_setup();
         if matches!(mode, StartupMode::Cli) {
             app.finish_startup();
         }
@@ -1303,7 +2081,92 @@ impl App {
         Ok(app)
     }
 
-    pub fn finish_startup(&mut self) {
+    pub fn cache_filesystem_split(&self) -> &[&'static str] {
+        &self.cache_fs_split
+    }
+
+    fn detect_cache_filesystem_split(&self) -> Vec<&'static str> {
+        let Ok(paths) = game::detect_paths(
+            self.game_id,
+            Some(&self.config.game_root),
+            Some(&self.config.larian_dir),
+        ) else {
+            return Vec::new();
+        };
+        let Some(cache_dev) = path_dev_id_or_parent(&self.config.sigillink_cache_root()) else {
+            return Vec::new();
+        };
+        [
+            ("game Data folder", paths.data_dir),
+            ("Larian Mods folder", paths.larian_mods_dir),
+        ]
+        .into_iter()
+        .filter(|(_, path)| path_dev_id_or_parent(path).is_some_and(|dev| dev != cache_dev))
+        .map(|(label, _)| label)
+        .collect()
+    }
+
+    fn check_cache_filesystems(&mut self) {
+        let split = self.detect_cache_filesystem_split();
+        self.cache_fs_split = split.clone();
+        if split.is_empty() {
+            self.cache_fs_warning = None;
+            return;
+        }
+        let message = format!(
+            "SigiLink cache ({}) is on a different filesystem than the {}: deploys use symlinks instead of hardlinks, so that drive must stay mounted",
+            self.config.sigillink_cache_root().display(),
+            split.join(" and ")
+        );
+        if self.cache_fs_warning.as_deref() == Some(message.as_str()) {
+            return;
+        }
+        self.log_warn(message.clone());
+        self.set_toast(
+            "SigiLink cache is on another drive: deploys will symlink (see log)",
+            ToastLevel::Warn,
+            Duration::from_secs(4),
+        );
+        self.cache_fs_warning = Some(message);
+    }
+
+    fn check_game_updated(&mut self, prompt: bool) {
+        let Some(deployed) =
+            deploy::load_last_report(&self.config.data_dir).and_then(|report| report.game_build)
+        else {
+            return;
+        };
+        let Some(current) = game::detect_game_build(self.game_id, &self.config.game_root) else {
+            return;
+        };
+        if deployed == current
+            || self.config.game_update_acknowledged.as_deref() == Some(current.as_str())
+        {
+            return;
+        }
+        self.log_warn(format!(
+            "Game updated since last deploy ({deployed} -> {current}); mods or Script Extender may need reinstalling"
+        ));
+        if !prompt || self.dialog.is_some() {
+            return;
+        }
+        self.open_dialog(Dialog {
+            title: "Game updated".to_string(),
+            message: format!(
+                "{} changed since the last deploy:\n{deployed} -> {current}\n\nUpdates can reset modsettings.lsx and break mods or Script Extender, which may need reinstalling. Redeploy now?",
+                self.game_id.display_name()
+            ),
+            yes_label: "Redeploy".to_string(),
+            no_label: "Later".to_string(),
+            choice: DialogChoice::Yes,
+            kind: DialogKind::GameUpdated { build: current },
+            toggle: None,
+            toggle_alt: None,
+            scroll: 0,
+        });
+    }
+
+    pub fn finish_startup(&mut self) {
         if !self.startup_pending {
             return;
         }
@@ -1321,6 +2184,12 @@ impl App {
         if self.normalize_mod_sources() {
             let _ = self.library.save(&self.config.data_dir);
         }
+        self.purge_expired_trash();
+        if matches!(self.startup_mode, StartupMode::Ui) {
+            self.sweep_disabled_mod_cache();
+            self.sync_dev_watch();
+            self.queue_disk_usage_scan();
+        }
         self.maybe_start_metadata_refresh();
         self.queue_conflict_scan("startup");
         self.start_update_check();
@@ -1392,12 +2261,17 @@ impl App {
         };
         let mod_map = self.library.index_by_id();
         let filter = self.mod_filter_normalized();
+        let recent_cutoff = self.recent_filter.then(|| self.recent_added_cutoff());
+        let starred_only = self.starred_filter;
         let mut indices: Vec<usize> = profile
             .order
             .iter()
             .enumerate()
             .filter_map(|(index, entry)| {
                 if let Some(label) = entry.missing_label.as_deref() {
+                    if recent_cutoff.is_some() || starred_only {
+                        return None;
+                    }
                     if let Some(filter) = filter.as_deref() {
                         let label_match = label.to_lowercase().contains(filter);
                         let id_match = entry.id.to_lowercase().contains(filter);
@@ -1413,6 +2287,14 @@ impl App {
                         return None;
                     }
                 }
+                if let Some(cutoff) = recent_cutoff {
+                    if mod_entry.added_at < cutoff {
+                        return None;
+                    }
+                }
+                if starred_only && !self.library.starred.contains(&entry.id) {
+                    return None;
+                }
                 Some(index)
             })
             .collect();
@@ -1459,7 +2341,162 @@ impl App {
     }
 
     pub fn mod_filter_active(&self) -> bool {
-        !self.mod_filter.trim().is_empty()
+        !self.mod_filter.trim().is_empty() || self.recent_filter || self.starred_filter
+    }
+
+    fn recent_added_cutoff(&self) -> i64 {
+        let window = self.app_config.recent_added_hours.saturating_mul(3600) as i64;
+        now_timestamp().saturating_sub(window)
+    }
+
+    pub fn mod_is_new(&self, mod_entry: &ModEntry) -> bool {
+        mod_entry.added_at > self.library.new_mods_seen_at
+            && mod_entry.added_at >= self.recent_added_cutoff()
+    }
+
+    pub fn recent_added_label(&self) -> String {
+        let hours = self.app_config.recent_added_hours;
+        if hours >= 24 && hours.is_multiple_of(24) {
+            format!("{}d", hours / 24)
+        } else {
+            format!("{hours}h")
+        }
+    }
+
+    pub fn toggle_recent_filter(&mut self) {
+        let current_id = self.selected_profile_id();
+        self.recent_filter = !self.recent_filter;
+        self.move_mode = false;
+        self.reselect_mod_by_id(current_id);
+        self.status = if self.recent_filter {
+            format!(
+                "Showing mods added in the last {}",
+                self.recent_added_label()
+            )
+        } else {
+            "Recently added filter cleared".to_string()
+        };
+    }
+
+    pub fn toggle_starred_filter(&mut self) {
+        let current_id = self.selected_profile_id();
+        self.starred_filter = !self.starred_filter;
+        self.move_mode = false;
+        self.reselect_mod_by_id(current_id);
+        self.status = if self.starred_filter {
+            format!("Showing starred mods ({})", self.library.starred.len())
+        } else {
+            "Starred filter cleared".to_string()
+        };
+    }
+
+    pub fn mod_is_starred(&self, mod_id: &str) -> bool {
+        self.library.starred.contains(mod_id)
+    }
+
+    pub fn toggle_star_selected(&mut self) {
+        let Some(id) = self.selected_profile_id() else {
+            return;
+        };
+        let Some(name) = self
+            .library
+            .mods
+            .iter()
+            .find(|mod_entry| mod_entry.id == id)
+            .map(|mod_entry| mod_entry.display_name())
+        else {
+            return;
+        };
+        let starred = if self.library.starred.remove(&id) {
+            false
+        } else {
+            self.library.starred.insert(id.clone());
+            true
+        };
+        if self.allow_persistence() {
+            if let Err(err) = self.library.save(&self.config.data_dir) {
+                self.log_warn(format!("Library save failed: {err}"));
+            }
+        }
+        if self.starred_filter && !starred {
+            self.reselect_mod_by_id(Some(id));
+        }
+        self.status = if starred {
+            format!("Starred: {name}")
+        } else {
+            format!("Unstarred: {name}")
+        };
+    }
+
+    pub fn acknowledge_new_mods(&mut self) {
+        let now = now_timestamp();
+        let count = self
+            .library
+            .mods
+            .iter()
+            .filter(|mod_entry| self.mod_is_new(mod_entry))
+            .count();
+        if count == 0 {
+            self.status = "No new mods to acknowledge".to_string();
+            return;
+        }
+        self.library.new_mods_seen_at = now;
+        if self.allow_persistence() {
+            if let Err(err) = self.library.save(&self.config.data_dir) {
+                self.log_warn(format!("Library save failed: {err}"));
+            }
+        }
+        self.status = format!("Acknowledged {count} new mod(s)");
+    }
+
+    pub fn cycle_recent_added_hours(&mut self) -> Result<()> {
+        let options: [u64; 6] = [1, 6, 12, 24, 72, 168];
+        let current = self.app_config.recent_added_hours;
+        let next = options
+            .iter()
+            .copied()
+            .find(|hours| *hours > current)
+            .unwrap_or(options[0]);
+        self.app_config.recent_added_hours = next;
+        self.app_config.save()?;
+        if self.recent_filter {
+            let current_id = self.selected_profile_id();
+            self.reselect_mod_by_id(current_id);
+        }
+        self.status = format!("Recently added window: {}", self.recent_added_label());
+        Ok(())
+    }
+
+    pub fn auto_deploy_debounce_label(&self) -> String {
+        debounce_label(self.app_config.auto_deploy_debounce_ms)
+    }
+
+    pub fn smart_rank_debounce_label(&self) -> String {
+        debounce_label(self.app_config.smart_rank_debounce_ms)
+    }
+
+    pub fn cycle_auto_deploy_debounce(&mut self) -> Result<()> {
+        let options: [u64; 6] = [0, 250, 500, 1000, 2000, 5000];
+        self.app_config.auto_deploy_debounce_ms =
+            next_debounce_option(&options, self.app_config.auto_deploy_debounce_ms);
+        self.app_config.save()?;
+        self.status = format!(
+            "Auto deploy debounce: {}",
+            self.auto_deploy_debounce_label()
+        );
+        Ok(())
+    }
+
+    pub fn cycle_smart_rank_debounce(&mut self) -> Result<()> {
+        let options: [u64; 6] = [200, 400, 600, 1000, 2000, 5000];
+        self.app_config.smart_rank_debounce_ms =
+            next_debounce_option(&options, self.app_config.smart_rank_debounce_ms);
+        self.app_config.save()?;
+        self.status = format!(
+            "SigiLink refresh debounce: {}",
+            self.smart_rank_debounce_label()
+        );
+        Ok(())
     }
 
     fn mod_filter_normalized(&self) -> Option<String> {
@@ -1515,6 +2552,7 @@ impl App {
             "created" => Some(ModSortColumn::Created),
             "added" => Some(ModSortColumn::Added),
             "target" => Some(ModSortColumn::Target),
+            "size" => Some(ModSortColumn::Size),
             _ => None,
         }
     }
@@ -1530,6 +2568,7 @@ impl App {
                 ModSortColumn::Target => "Target",
                 ModSortColumn::Created => "Created",
                 ModSortColumn::Added => "Added",
+                ModSortColumn::Size => "Size",
             };
             return label.to_string();
         }
@@ -1558,7 +2597,7 @@ impl App {
     }
 
     pub fn cycle_default_sort_column(&mut self) -> Result<()> {
-        let options: [Option<ModSortColumn>; 9] = [
+        let options: [Option<ModSortColumn>; 10] = [
             None,
             Some(ModSortColumn::Enabled),
             Some(ModSortColumn::Order),
@@ -1567,6 +2606,7 @@ impl App {
             Some(ModSortColumn::Name),
             Some(ModSortColumn::Created),
             Some(ModSortColumn::Added),
+            Some(ModSortColumn::Size),
             Some(ModSortColumn::Target),
         ];
         let current = self.default_sort_column_value();
@@ -1585,6 +2625,7 @@ impl App {
             ModSortColumn::Created => "created".to_string(),
             ModSortColumn::Added => "added".to_string(),
             ModSortColumn::Target => "target".to_string(),
+            ModSortColumn::Size => "size".to_string(),
         });
         self.app_config.save()?;
         self.apply_default_sort();
@@ -1660,6 +2701,7 @@ impl App {
         self.settings_menu = Some(SettingsMenu { selected });
         self.settings_menu_return = false;
         self.start_update_check();
+        self.request_cache_size();
     }
 
     pub fn close_settings_menu(&mut self) {
@@ -1741,12 +2783,204 @@ impl App {
 
     pub fn open_paths_overlay(&mut self) {
         self.paths_overlay_open = true;
+        self.paths_overlay_selected = 0;
+        self.refresh_script_extender();
+        self.request_cache_size();
+        self.check_cache_filesystems();
+    }
+
+    pub fn request_cache_size(&mut self) {
+        if self.cache_size_active {
+            return;
+        }
+        self.cache_size_active = true;
+        let root = self.config.sigillink_cache_root();
+        let tx = self.cache_size_tx.clone();
+        // The cache root defaults to the data dir, so only count the cache's own folders.
+        let dirs = [
+            self.config.sigillink_mods_root(),
+            self.config.sigillink_index_root(),
+            self.config.sigillink_temp_root(),
+            trash::trash_root(&root),
+        ];
+        thread::spawn(move || {
+            let bytes = dirs.iter().map(|dir| cache_entry_bytes(dir)).sum();
+            let _ = tx.send((root, bytes));
+        });
+    }
+
+    fn poll_cache_size(&mut self) {
+        while let Ok((root, bytes)) = self.cache_size_rx.try_recv() {
+            self.cache_size_active = false;
+            self.cache_size = (root == self.config.sigillink_cache_root()).then_some(bytes);
+        }
+    }
+
+    pub fn cache_size_label(&self) -> String {
+        match self.cache_size {
+            Some(bytes) if self.cache_size_active => {
+                format!("{} (updating)", importer::format_bytes(bytes))
+            }
+            Some(bytes) => importer::format_bytes(bytes),
+            None if self.cache_size_active => "calculating...".to_string(),
+            None => "unknown".to_string(),
+        }
+    }
+
+    pub fn refresh_script_extender(&mut self) {
+        self.script_extender = if self.config.game_root.as_os_str().is_empty() {
+            None
+        } else {
+            game::detect_script_extender(self.game_id, &self.config.game_root)
+        };
+    }
+
+    pub fn script_extender_status(&self) -> String {
+        match &self.script_extender {
+            Some(info) => script_extender_label(info),
+            None => "not detected".to_string(),
+        }
+    }
+
+    pub fn mods_requiring_script_extender(&self) -> Vec<String> {
+        let Some(profile) = self.library.active_profile() else {
+            return Vec::new();
+        };
+        let mod_cache = self.smart_rank_cache.as_ref().map(|cache| &cache.mod_cache);
+        let mod_map = self.library.index_by_id();
+        profile
+            .order
+            .iter()
+            .filter(|entry| entry.enabled)
+            .filter_map(|entry| mod_map.get(&entry.id))
+            .filter(|mod_entry| {
+                let by_dependency = mod_entry.dependencies.iter().any(|dep| {
+                    let key = normalize_label(dep);
+                    key.contains("scriptextender") || key.contains("bg3se")
+                });
+                let by_files = mod_cache
+                    .and_then(|cache| cache.mods.get(&mod_entry.id))
+                    .is_some_and(|cached| {
+                        cached
+                            .file_paths
+                            .iter()
+                            .any(|path| path.to_ascii_lowercase().contains("scriptextender/"))
+                    });
+                by_dependency || by_files
+            })
+            .map(|mod_entry| mod_entry.display_name())
+            .collect()
+    }
+
+    fn check_script_extender_requirement(&mut self) {
+        self.refresh_script_extender();
+        if self.script_extender.is_some() {
+            self.script_extender_warned = false;
+            return;
+        }
+        let required = self.mods_requiring_script_extender();
+        if required.is_empty() || self.script_extender_warned {
+            return;
+        }
+        self.script_extender_warned = true;
+        let preview = required
+            .iter()
+            .take(3)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(", ");
+        let more = required.len().saturating_sub(3);
+        let suffix = if more > 0 {
+            format!(" and {more} more")
+        } else {
+            String::new()
+        };
+        self.log_warn(format!(
+            "Script Extender not found in bin/ but {} enabled mod(s) use it: {preview}{suffix}",
+            required.len()
+        ));
+        self.set_toast(
+            "Script Extender missing: some enabled mods need it",
+            ToastLevel::Warn,
+            Duration::from_secs(4),
+        );
     }
 
     pub fn close_paths_overlay(&mut self) {
         self.paths_overlay_open = false;
     }
 
+    pub fn paths_overlay_entries(&self) -> Vec<(&'static str, Option<PathBuf>)> {
+        let set = |path: &PathBuf| (!path.as_os_str().is_empty()).then(|| path.clone());
+        let mut entries = vec![
+            ("Root", set(&self.config.game_root)),
+            (
+                match self.config.larian_dir_source {
+                    Some(LarianDirSource::Proton) => "User (Proton)",
+                    _ => "User",
+                },
+                set(&self.config.larian_dir),
+            ),
+            ("Data", set(&self.config.data_dir)),
+            ("SigiLink Cache", Some(self.config.sigillink_cache_root())),
+            ("Config", Some(self.config.data_dir.join("config.json"))),
+        ];
+        if let Some(info) = &self.script_extender {
+            entries.push(("Script Extender", Some(info.loader.clone())));
+        }
+        entries
+    }
+
+    pub fn paths_overlay_move(&mut self, delta: isize) {
+        let len = self.paths_overlay_entries().len();
+        self.paths_overlay_selected = self
+            .paths_overlay_selected
+            .saturating_add_signed(delta)
+            .min(len.saturating_sub(1));
+    }
+
+    pub fn copy_selected_path(&mut self) {
+        let entries = self.paths_overlay_entries();
+        let Some((label, path)) = entries.get(self.paths_overlay_selected) else {
+            return;
+        };
+        let Some(path) = path else {
+            self.status = format!("{label} path not set");
+            self.set_toast(
+                &format!("{label} path not set"),
+                ToastLevel::Warn,
+                Duration::from_secs(2),
+            );
+            return;
+        };
+        if self.copy_to_clipboard(&path.display().to_string()) {
+            self.status = format!("{label} path copied");
+            self.set_toast(
+                &format!("{label} path copied"),
+                ToastLevel::Info,
+                Duration::from_secs(2),
+            );
+        }
+    }
+
+    pub fn copy_all_paths(&mut self) {
+        let text = self
+            .paths_overlay_entries()
+            .into_iter()
+            .map(|(label, path)| {
+                let value = path
+                    .map(|path| path.display().to_string())
+                    .unwrap_or_else(|| "<not set>".to_string());
+                format!("{label}: {value}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        if self.copy_to_clipboard(&text) {
+            self.status = "Paths copied".to_string();
+            self.set_toast("Paths copied", ToastLevel::Info, Duration::from_secs(2));
+        }
+    }
+
     pub fn toggle_confirm_profile_delete(&mut self) -> Result<()> {
         self.app_config.confirm_profile_delete = !self.app_config.confirm_profile_delete;
         self.app_config.save()?;
@@ -1783,6 +3017,57 @@ impl App {
         Ok(())
     }
 
+    pub fn automation_paused(&self) -> bool {
+        self.automation_paused
+    }
+
+    pub fn toggle_automation_pause(&mut self) {
+        self.automation_paused = !self.automation_paused;
+        if self.automation_paused {
+            let auto_pending = self.deploy_pending
+                && self
+                    .deploy_reason
+                    .as_deref()
+                    .is_some_and(|reason| reason.starts_with("auto:"));
+            if auto_pending {
+                self.deploy_pending = false;
+                self.deploy_due = None;
+                self.deploy_reason = None;
+                self.automation_deploy_deferred = true;
+            }
+            self.status =
+                "Automation paused: auto deploy, scans, and auto-rank on hold".to_string();
+            self.log_info("Automation paused".to_string());
+            self.set_toast(
+                "Automation paused",
+                ToastLevel::Warn,
+                Duration::from_secs(2),
+            );
+            return;
+        }
+        self.log_info("Automation resumed".to_string());
+        if std::mem::take(&mut self.automation_metadata_deferred) && !self.metadata_active {
+            self.maybe_start_metadata_refresh();
+        }
+        let deploy = std::mem::take(&mut self.automation_deploy_deferred);
+        if deploy && self.app_config.auto_deploy_enabled {
+            self.queue_deploy("auto: automation resumed");
+        }
+        if std::mem::take(&mut self.automation_scan_deferred) || deploy {
+            self.queue_conflict_scan("automation resumed");
+        }
+        self.status = if deploy && self.deploy_pending {
+            "Automation resumed: deploying queued changes".to_string()
+        } else {
+            "Automation resumed".to_string()
+        };
+        self.set_toast(
+            "Automation resumed",
+            ToastLevel::Info,
+            Duration::from_secs(2),
+        );
+    }
+
     pub fn toggle_enable_mods_after_import(&mut self) -> Result<()> {
         self.app_config.enable_mods_after_import = !self.app_config.enable_mods_after_import;
         self.app_config.save()?;
@@ -1795,6 +3080,30 @@ impl App {
         Ok(())
     }
 
+    pub fn toggle_enable_import_dependencies(&mut self) -> Result<()> {
+        self.app_config.enable_import_dependencies = !self.app_config.enable_import_dependencies;
+        self.app_config.save()?;
+        let state = if self.app_config.enable_import_dependencies {
+            "enabled"
+        } else {
+            "disabled"
+        };
+        self.status = format!("Enable import dependencies {state}");
+        Ok(())
+    }
+
+    pub fn toggle_quarantine_removed_mods(&mut self) -> Result<()> {
+        self.app_config.quarantine_removed_mods = !self.app_config.quarantine_removed_mods;
+        self.app_config.save()?;
+        let state = if self.app_config.quarantine_removed_mods {
+            "enabled"
+        } else {
+            "disabled"
+        };
+        self.status = format!("Quarantine removed mod files {state}");
+        Ok(())
+    }
+
     pub fn toggle_delete_mod_files_on_remove(&mut self) -> Result<()> {
         self.app_config.delete_mod_files_on_remove = !self.app_config.delete_mod_files_on_remove;
         self.app_config.save()?;
@@ -1892,7 +3201,91 @@ impl App {
         self.open_smart_rank_preview();
     }
 
-    #[allow(dead_code)]
+    pub fn run_sigillink_ranking_scoped(&mut self) {
+        let Some(profile) = self.library.active_profile() else {
+            self.status = "SigiLink scoped ranking: no profile".to_string();
+            return;
+        };
+        let ids: Vec<String> = if self.mod_filter_active() {
+            self.visible_profile_indices()
+                .into_iter()
+                .filter_map(|index| profile.order.get(index).map(|entry| entry.id.clone()))
+                .collect()
+        } else {
+            self.selected_profile_id().into_iter().collect()
+        };
+        let pinned = self.app_config.sigillink_ranking_enabled;
+        let scope: HashSet<String> = ids
+            .into_iter()
+            .filter(|id| !pinned || !profile.sigillink_pins.contains_key(id))
+            .filter(|id| !self.library.sigillink_excluded.contains(id))
+            .collect();
+        if scope.is_empty() {
+            self.status = "SigiLink scoped ranking: no unpinned mods selected".to_string();
+            return;
+        }
+        if scope.len() == profile.order.len() {
+            self.run_sigillink_ranking_solo();
+            return;
+        }
+        let count = scope.len();
+        self.sigillink_rank_pending_import = false;
+        self.sigillink_rank_debounce_until = None;
+        self.sigillink_force_preview = true;
+        self.sigillink_preview_notice = Some(format!(
+            "SigiLink Intelligent Ranking: {count} selected mod(s)"
+        ));
+        self.sigillink_rank_scope = Some(scope);
+        self.open_smart_rank_preview();
+        if !self.smart_rank_active {
+            self.sigillink_rank_scope = None;
+        }
+    }
+
+    fn merge_scoped_rank(
+        current: &[ProfileEntry],
+        ranked: &[ProfileEntry],
+        scope: &HashSet<String>,
+    ) -> Vec<ProfileEntry> {
+        let by_id: HashMap<&str, &ProfileEntry> = current
+            .iter()
+            .map(|entry| (entry.id.as_str(), entry))
+            .collect();
+        let mut leading = Vec::new();
+        let mut following: HashMap<&str, Vec<&ProfileEntry>> = HashMap::new();
+        let mut anchor: Option<&str> = None;
+        for entry in ranked {
+            if !scope.contains(&entry.id) {
+                anchor = Some(entry.id.as_str());
+                continue;
+            }
+            let Some(current_entry) = by_id.get(entry.id.as_str()).copied() else {
+                continue;
+            };
+            match anchor {
+                Some(anchor) => following.entry(anchor).or_default().push(current_entry),
+                None => leading.push(current_entry),
+            }
+        }
+        let mut merged: Vec<ProfileEntry> = leading.into_iter().cloned().collect();
+        for entry in current {
+            if scope.contains(&entry.id) {
+                continue;
+            }
+            merged.push(entry.clone());
+            if let Some(items) = following.remove(entry.id.as_str()) {
+                merged.extend(items.into_iter().cloned());
+            }
+        }
+        for entry in current {
+            if scope.contains(&entry.id) && !merged.iter().any(|item| item.id == entry.id) {
+                merged.push(entry.clone());
+            }
+        }
+        merged
+    }
+
+    #[allow(dead_code)]
     pub(crate) fn clear_system_caches(&mut self) {
         self.clear_framework_caches();
         self.clear_sigillink_caches();
@@ -1941,6 +3334,224 @@ impl App {
         );
     }
 
+    pub fn verify_sigillink_cache(&mut self) {
+        if self.cache_verify_active {
+            self.status = "SigiLink cache check already running".to_string();
+            return;
+        }
+        if self.is_busy() {
+            self.status = "SigiLink cache check blocked: busy".to_string();
+            return;
+        }
+        // Live-linked dev folders change under us, so only verify copied payloads.
+        let jobs: Vec<CacheVerifyJob> = self
+            .library
+            .mods
+            .iter()
+            .filter(|mod_entry| {
+                !mod_entry.is_native() && mod_entry.dev_path.is_none() && !mod_entry.cache_evicted
+            })
+            .map(|mod_entry| CacheVerifyJob {
+                mod_id: mod_entry.id.clone(),
+                mod_name: mod_entry.display_name(),
+                targets: mod_entry.targets.clone(),
+                source: mod_entry.source_path.clone(),
+            })
+            .collect();
+        if jobs.is_empty() {
+            self.status = "SigiLink cache check: no managed mods".to_string();
+            return;
+        }
+        let cache_root = self.config.sigillink_cache_root();
+        let tx = self.cache_verify_tx.clone();
+        let count = jobs.len();
+        thread::spawn(move || {
+            let _ = tx.send(scan_cache_integrity(&cache_root, &jobs));
+        });
+        self.cache_verify_active = true;
+        self.status = format!("Verifying SigiLink cache ({count} mod(s))...");
+        self.log_info(format!("SigiLink cache check started: {count} mod(s)"));
+    }
+
+    fn poll_cache_verify(&mut self) {
+        loop {
+            match self.cache_verify_rx.try_recv() {
+                Ok(report) => self.handle_cache_verify_report(report),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => break,
+            }
+        }
+    }
+
+    fn handle_cache_verify_report(&mut self, report: CacheVerifyReport) {
+        self.cache_verify_active = false;
+        for issue in &report.unverified {
+            self.log_info(format!(
+                "SigiLink cache: {} / {}: unverified, {} (reimport the mod to record one)",
+                issue.mod_name, issue.path, issue.problem
+            ));
+        }
+        for issue in &report.repaired {
+            self.log_info(format!(
+                "SigiLink cache repaired: {} / {} ({})",
+                issue.mod_name, issue.path, issue.problem
+            ));
+        }
+        for issue in &report.issues {
+            self.log_warn(format!(
+                "SigiLink cache: {} / {}: {} (reimport the mod to repair)",
+                issue.mod_name, issue.path, issue.problem
+            ));
+        }
+        let repaired = report.repaired.len();
+        let broken = report.issues.len();
+        let unverified = report.unverified.len();
+        self.status = if broken == 0 && repaired == 0 && unverified == 0 {
+            format!("SigiLink cache intact ({} file(s) checked)", report.checked)
+        } else {
+            format!(
+                "SigiLink cache: {broken} problem(s), {repaired} repaired, {unverified} unverified ({} file(s) checked)",
+                report.checked
+            )
+        };
+        self.log_info(self.status.clone());
+        let level = if broken > 0 {
+            ToastLevel::Warn
+        } else {
+            ToastLevel::Info
+        };
+        self.set_toast(&self.status.clone(), level, Duration::from_secs(3));
+        if repaired > 0 {
+            self.queue_auto_deploy("cache repaired");
+        }
+    }
+
+    fn sigillink_cache_orphans(&self) -> Vec<PathBuf> {
+        let known: HashSet<&str> = self
+            .library
+            .mods
+            .iter()
+            .map(|mod_entry| mod_entry.id.as_str())
+            .collect();
+        let cache_root = self.config.sigillink_cache_root();
+        let mut orphans = Vec::new();
+        if let Ok(read_dir) = fs::read_dir(library_mod_root(&cache_root)) {
+            for path in read_dir
+                .filter_map(|item| item.ok())
+                .map(|item| item.path())
+            {
+                let name = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .unwrap_or("");
+                if path.is_dir() && !known.contains(name) {
+                    orphans.push(path);
+                }
+            }
+        }
+        if let Ok(read_dir) = fs::read_dir(sigillink::sigillink_root(&cache_root)) {
+            for path in read_dir
+                .filter_map(|item| item.ok())
+                .map(|item| item.path())
+            {
+                if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                    continue;
+                }
+                let stem = path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .unwrap_or("");
+                if !known.contains(stem) {
+                    orphans.push(path);
+                }
+            }
+        }
+        orphans.sort();
+        orphans
+    }
+
+    pub fn prompt_sigillink_cache_gc(&mut self) {
+        if self.dialog.is_some() {
+            return;
+        }
+        if self.is_busy() || self.cache_verify_active {
+            self.status = "SigiLink cache cleanup blocked: busy".to_string();
+            return;
+        }
+        let orphans = self.sigillink_cache_orphans();
+        if orphans.is_empty() {
+            self.status = "SigiLink cache has no orphaned entries".to_string();
+            self.set_toast(
+                "SigiLink cache has no orphaned entries",
+                ToastLevel::Info,
+                Duration::from_secs(2),
+            );
+            return;
+        }
+        let folders = orphans.iter().filter(|path| path.is_dir()).count();
+        let indexes = orphans.len() - folders;
+        let bytes: u64 = orphans.iter().map(|path| cache_entry_bytes(path)).sum();
+        self.log_info(format!(
+            "SigiLink cache cleanup dry run: {folders} mod folder(s), {indexes} index file(s), {}",
+            importer::format_bytes(bytes)
+        ));
+        self.open_dialog(Dialog {
+            title: "Clean up SigiLink cache?".to_string(),
+            message: format!(
+                "Found {} orphaned cache entr(ies) not referenced by any mod:\n\
+{folders} mod folder(s), {indexes} index file(s), {} reclaimable.\n\
+Removed mods kept in the trash are not touched.",
+                orphans.len(),
+                importer::format_bytes(bytes)
+            ),
+            yes_label: "Remove".to_string(),
+            no_label: "Cancel".to_string(),
+            choice: DialogChoice::No,
+            kind: DialogKind::SigilLinkCacheGc,
+            toggle: None,
+            toggle_alt: None,
+            scroll: 0,
+        });
+    }
+
+    fn collect_sigillink_cache_garbage(&mut self) {
+        if self.is_busy() || self.cache_verify_active {
+            self.status = "SigiLink cache cleanup blocked: busy".to_string();
+            return;
+        }
+        // Recheck rather than trusting the dry run; the library may have changed meanwhile.
+        let mut removed = 0usize;
+        let mut bytes = 0u64;
+        for path in self.sigillink_cache_orphans() {
+            let size = cache_entry_bytes(&path);
+            let result = if path.is_dir() {
+                fs::remove_dir_all(&path)
+            } else {
+                fs::remove_file(&path)
+            };
+            match result {
+                Ok(()) => {
+                    removed += 1;
+                    bytes += size;
+                }
+                Err(err) => self.log_warn(format!(
+                    "SigiLink cache cleanup failed for {}: {err}",
+                    path.display()
+                )),
+            }
+        }
+        self.status = format!(
+            "Removed {removed} orphaned cache entr(ies), reclaimed {}",
+            importer::format_bytes(bytes)
+        );
+        self.log_info(self.status.clone());
+        self.set_toast(
+            &self.status.clone(),
+            ToastLevel::Info,
+            Duration::from_secs(3),
+        );
+    }
+
     pub fn open_smart_rank_preview(&mut self) {
         if self.smart_rank_active {
             self.status = "SigiLink Intelligent Ranking already running".to_string();
@@ -1977,1522 +3588,4517 @@ impl App {
         );
     }
 
-    fn start_sigillink_auto_rank(&mut self) {
+    pub fn open_compatibility_report(&mut self) {
         if self.smart_rank_active {
-            self.status = "SigiLink Intelligent Ranking already running".to_string();
+            self.status = "Compatibility report blocked: SigiLink scan running".to_string();
             return;
         }
         if self.is_busy() {
-            self.status = "SigiLink Intelligent Ranking blocked: busy".to_string();
-            self.log_warn("SigiLink Intelligent Ranking blocked: busy".to_string());
-            return;
-        }
-        if !self.paths_ready() {
-            self.status = "SigiLink Intelligent Ranking blocked: paths not set".to_string();
-            self.log_warn("SigiLink Intelligent Ranking blocked: paths not set".to_string());
-            return;
-        }
-
-        if self.app_config.sigillink_ranking_enabled {
-            let inputs_hash = self.sigillink_inputs_hash();
-            if let (Some(next), Some(prev)) =
-                (inputs_hash.as_deref(), self.sigillink_last_inputs_hash())
-            {
-                if next == prev {
-                    self.status = "SigiLink Intelligent Ranking: up to date".to_string();
-                    return;
-                }
-            }
-        }
-
-        self.sigillink_force_preview = false;
-        let mode = if self.app_config.sigillink_auto_preview {
-            self.sigillink_preview_notice =
-                Some("SigiLink Intelligent Ranking: Auto-rank".to_string());
-            SmartRankMode::Auto
-        } else {
-            self.sigillink_preview_notice =
-                Some("SigiLink Intelligent Ranking: Auto-rank".to_string());
-            SmartRankMode::Preview
-        };
-
-        self.start_smart_rank_scan(mode, smart_rank::SmartRankRefreshMode::Incremental);
-    }
-
-    #[allow(dead_code)]
-    pub fn clear_smart_rank_cache(&mut self) {
-        self.smart_rank_cache = None;
-        self.clear_smart_rank_cache_file();
-        self.smart_rank_refresh_at = None;
-        if self.smart_rank_active {
-            self.status = "SigiLink ranking cache cleared; warming up after scan".to_string();
-            self.schedule_smart_rank_refresh(
-                smart_rank::SmartRankRefreshMode::Full,
-                "cache cleared",
-                false,
-            );
+            self.status = "Compatibility report blocked: busy".to_string();
             return;
         }
         if !self.paths_ready() {
-            self.status = "SigiLink ranking cache cleared".to_string();
+            self.status = "Compatibility report blocked: paths not set".to_string();
             return;
         }
-        self.status = "SigiLink ranking cache cleared; warming up".to_string();
         self.start_smart_rank_scan(
-            SmartRankMode::Warmup,
-            smart_rank::SmartRankRefreshMode::Full,
+            SmartRankMode::Report,
+            smart_rank::SmartRankRefreshMode::Incremental,
         );
     }
 
-    pub fn sigillink_ranking_enabled(&self) -> bool {
-        self.app_config.sigillink_ranking_enabled
+    fn show_compatibility_report(&mut self, cache: &smart_rank::SmartRankCacheData) {
+        match smart_rank::build_compatibility_report(&self.library, cache) {
+            Ok(report) => {
+                let count = report.entries.len();
+                self.log_info(format!(
+                    "Compatibility report: {count} mod(s) overlapping, {} scanned",
+                    report.scanned
+                ));
+                self.status = format!("Compatibility report: {count} mod(s) overlapping");
+                self.compatibility_view = Some(CompatibilityView {
+                    report,
+                    selected: 0,
+                    expanded: HashSet::new(),
+                    scroll: 0,
+                });
+            }
+            Err(err) => {
+                self.status = format!("Compatibility report failed: {err}");
+                self.log_error(format!("Compatibility report failed: {err}"));
+            }
+        }
     }
 
-    pub fn sigillink_pin_count(&self) -> usize {
-        self.library
-            .active_profile()
-            .map(|profile| profile.sigillink_pins.len())
-            .unwrap_or(0)
+    pub fn close_compatibility_report(&mut self) {
+        self.compatibility_view = None;
     }
 
-    pub fn sigillink_is_pinned(&self, mod_id: &str) -> bool {
-        self.library
+    pub fn open_load_order_check(&mut self) {
+        let checked = self
+            .library
             .active_profile()
-            .and_then(|profile| profile.sigillink_pins.get(mod_id))
-            .is_some()
+            .map(|profile| profile.order.iter().filter(|entry| entry.enabled).count())
+            .unwrap_or(0);
+        let issues = self.load_order_issues();
+        if issues.is_empty() {
+            self.status = format!("Load order check: no problems found ({checked} enabled)");
+            self.log_info(format!(
+                "Load order check: no problems found in {checked} enabled mod(s)"
+            ));
+        } else {
+            self.status = format!("Load order check: {} warning(s)", issues.len());
+            self.log_warn(format!(
+                "Load order check: {} warning(s) in {checked} enabled mod(s)",
+                issues.len()
+            ));
+        }
+        self.load_order_check_view = Some(LoadOrderCheckView {
+            issues,
+            checked,
+            selected: 0,
+        });
     }
 
-    pub fn sigillink_rank_meta(&self) -> SigilLinkRankMeta {
-        self.library
-            .profiles
-            .iter()
-            .find(|profile| is_sigillink_ranking_profile(&profile.name))
-            .map(|profile| profile.sigillink_meta.clone())
-            .unwrap_or_default()
+    pub fn close_load_order_check(&mut self) {
+        self.load_order_check_view = None;
     }
 
-    pub fn sigillink_preview_notice(&self) -> Option<&str> {
-        self.sigillink_preview_notice.as_deref()
+    pub fn load_order_check_move(&mut self, delta: isize) {
+        let Some(view) = &mut self.load_order_check_view else {
+            return;
+        };
+        let len = view.issues.len();
+        if len == 0 {
+            view.selected = 0;
+            return;
+        }
+        view.selected = (view.selected as isize + delta).clamp(0, len as isize - 1) as usize;
     }
 
-    fn sigillink_last_inputs_hash(&self) -> Option<&str> {
-        self.library
-            .profiles
-            .iter()
-            .find(|profile| is_sigillink_ranking_profile(&profile.name))
-            .and_then(|profile| profile.sigillink_meta.last_inputs_hash.as_deref())
+    pub fn load_order_check_jump(&mut self) {
+        let Some(mod_id) = self
+            .load_order_check_view
+            .as_ref()
+            .and_then(|view| view.issues.get(view.selected))
+            .and_then(|issue| issue.mod_id.clone())
+        else {
+            return;
+        };
+        self.load_order_check_view = None;
+        self.focus_mods();
+        self.reselect_mod_by_id(Some(mod_id));
     }
 
-    fn sigillink_ranking_profile_mut(&mut self) -> Option<&mut Profile> {
-        self.library
-            .profiles
-            .iter_mut()
-            .find(|profile| is_sigillink_ranking_profile(&profile.name))
-    }
-
-    fn sigillink_inputs_hash(&self) -> Option<String> {
-        let Some(profile) = self.library.active_profile() else {
-            return None;
-        };
-        let mut hasher = Hasher::new();
-        hasher.update(profile.name.as_bytes());
-        for entry in &profile.order {
-            hasher.update(entry.id.as_bytes());
-            hasher.update(&[entry.enabled as u8]);
-        }
-        let mut pins: Vec<(&String, &usize)> = profile.sigillink_pins.iter().collect();
-        pins.sort_by(|(id_a, idx_a), (id_b, idx_b)| idx_a.cmp(idx_b).then_with(|| id_a.cmp(id_b)));
-        for (id, index) in pins {
-            hasher.update(id.as_bytes());
-            hasher.update(&index.to_le_bytes());
+    pub fn open_toggle_impact(&mut self) {
+        if !self.paths_ready() {
+            self.status = "Game paths not set: open Menu (Esc) to configure".to_string();
+            return;
         }
-        Some(hasher.finalize().to_hex().to_string())
-    }
-
-    fn update_sigillink_inputs_hash(&mut self) {
-        let Some(hash) = self.sigillink_inputs_hash() else {
+        let Some(id) = self.selected_profile_id() else {
+            self.status = "No mod selected".to_string();
             return;
         };
-        let Some(profile) = self.sigillink_ranking_profile_mut() else {
+        let Some(entry) = self
+            .library
+            .active_profile()
+            .and_then(|profile| profile.order.iter().find(|entry| entry.id == id))
+        else {
             return;
         };
-        if profile.sigillink_meta.last_inputs_hash.as_deref() == Some(hash.as_str()) {
+        if entry.missing_label.is_some() {
+            self.status = "Missing mod file".to_string();
             return;
         }
-        profile.sigillink_meta.last_inputs_hash = Some(hash);
-        if self.allow_persistence() {
-            let _ = self.library.save(&self.config.data_dir);
-        }
-    }
+        let enable = !entry.enabled;
+        let mod_name = self
+            .library
+            .mods
+            .iter()
+            .find(|mod_entry| mod_entry.id == id)
+            .map(|mod_entry| mod_entry.display_name())
+            .unwrap_or_else(|| id.clone());
 
-    fn apply_sigillink_pins(
-        order: Vec<ProfileEntry>,
-        pins: &HashMap<String, usize>,
-    ) -> Vec<ProfileEntry> {
-        if pins.is_empty() {
-            return order;
-        }
-        let mut base = Vec::with_capacity(order.len());
-        let mut pinned: Vec<(usize, usize, ProfileEntry)> = Vec::new();
-        for (index, entry) in order.into_iter().enumerate() {
-            if let Some(pin_index) = pins.get(&entry.id).copied() {
-                pinned.push((pin_index, index, entry));
-            } else {
-                base.push(entry);
+        let mut hypothetical = self.library.clone();
+        if let Some(profile) = hypothetical.active_profile_mut() {
+            for entry in &mut profile.order {
+                if entry.id == id {
+                    entry.enabled = enable;
+                }
             }
         }
-        pinned.sort_by(|(pin_a, idx_a, _), (pin_b, idx_b, _)| {
-            pin_a.cmp(pin_b).then_with(|| idx_b.cmp(idx_a))
+        // A stale override list would skew the delta, so rescan the baseline while one is due.
+        let baseline = if self.conflict_pending || self.conflict_active {
+            None
+        } else {
+            Some(
+                self.conflicts
+                    .iter()
+                    .chain(self.ignored_conflicts.iter())
+                    .cloned()
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let tx = self.toggle_impact_tx.clone();
+        let config = self.config.clone();
+        let library = self.library.clone();
+        let mod_id = id.clone();
+        thread::spawn(move || {
+            let message = match scan_toggle_impact(&config, &library, &hypothetical, baseline) {
+                Ok(changes) => ToggleImpactMessage::Completed { mod_id, changes },
+                Err(err) => ToggleImpactMessage::Failed {
+                    mod_id,
+                    error: err.to_string(),
+                },
+            };
+            let _ = tx.send(message);
         });
-        for (pin_index, _, entry) in pinned {
-            let insert_at = pin_index.min(base.len());
-            base.insert(insert_at, entry);
-        }
-        base
-    }
 
-    fn set_sigillink_pin(&mut self, mod_id: &str, index: usize) {
-        let Some(profile) = self.library.active_profile_mut() else {
-            return;
-        };
-        profile.sigillink_pins.insert(mod_id.to_string(), index);
-        if self.allow_persistence() {
-            let _ = self.library.save(&self.config.data_dir);
-        }
+        let verb = if enable { "enable" } else { "disable" };
+        self.status = format!("Previewing {verb} impact: {mod_name}");
+        self.toggle_impact_view = Some(ToggleImpactView {
+            mod_id: id,
+            mod_name,
+            enable,
+            loading: true,
+            error: None,
+            changes: Vec::new(),
+            selected: 0,
+        });
     }
 
-    pub fn clear_sigillink_pin(&mut self, mod_id: &str) -> bool {
-        let Some(profile) = self.library.active_profile_mut() else {
-            return false;
-        };
-        let removed = profile.sigillink_pins.remove(mod_id).is_some();
-        if removed && self.allow_persistence() {
-            let _ = self.library.save(&self.config.data_dir);
-        }
-        removed
+    pub fn close_toggle_impact(&mut self) {
+        self.toggle_impact_view = None;
     }
 
-    fn clear_all_sigillink_pins(&mut self) {
-        let Some(profile) = self.library.active_profile_mut() else {
+    pub fn toggle_impact_move(&mut self, delta: isize) {
+        let Some(view) = &mut self.toggle_impact_view else {
             return;
         };
-        if profile.sigillink_pins.is_empty() {
-            self.status = "SigiLink pins already reset".to_string();
+        let len = view.changes.len();
+        if len == 0 {
+            view.selected = 0;
             return;
         }
-        profile.sigillink_pins.clear();
-        if self.allow_persistence() {
-            let _ = self.library.save(&self.config.data_dir);
-        }
-        self.status = "SigiLink pins reset".to_string();
-        self.set_toast(
-            "SigiLink pins reset",
-            ToastLevel::Info,
-            Duration::from_secs(2),
-        );
+        view.selected = (view.selected as isize + delta).clamp(0, len as isize - 1) as usize;
     }
 
-    pub fn prompt_clear_sigillink_pins(&mut self) {
-        if self.dialog.is_some() {
+    pub fn toggle_impact_apply(&mut self) {
+        let Some(view) = self.toggle_impact_view.take() else {
+            return;
+        };
+        self.focus_mods();
+        self.reselect_mod_by_id(Some(view.mod_id.clone()));
+        if self.selected_profile_id().as_deref() != Some(view.mod_id.as_str()) {
+            self.status = format!("{} is not visible in the mod list", view.mod_name);
             return;
         }
-        if self.sigillink_pin_count() == 0 {
-            self.status = "SigiLink pins already reset".to_string();
-            self.set_toast(
-                "SigiLink pins already reset",
-                ToastLevel::Info,
-                Duration::from_secs(2),
-            );
+        let enabled = self
+            .library
+            .active_profile()
+            .and_then(|profile| profile.order.iter().find(|entry| entry.id == view.mod_id))
+            .map(|entry| entry.enabled);
+        if enabled == Some(view.enable) {
             return;
         }
-        self.open_dialog(Dialog {
-            title: "Reset all SigiLink pins?".to_string(),
-            message: "This will clear manual SigiLink pins in the current profile.".to_string(),
-            yes_label: "Reset".to_string(),
-            no_label: "Cancel".to_string(),
-            choice: DialogChoice::No,
-            kind: DialogKind::SigilLinkClearPins,
-            toggle: None,
-            toggle_alt: None,
-            scroll: 0,
-        });
+        self.toggle_selected();
     }
 
-    fn maybe_prompt_sigillink_pin_notice(&mut self, mod_id: &str) {
-        if self.dialog.is_some() || self.app_config.sigillink_pin_notice_dismissed {
-            return;
+    fn poll_toggle_impact(&mut self) {
+        loop {
+            match self.toggle_impact_rx.try_recv() {
+                Ok(message) => self.handle_toggle_impact_message(message),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => break,
+            }
         }
-        let name = self
-            .library
-            .mods
-            .iter()
-            .find(|entry| entry.id == mod_id)
-            .map(|entry| entry.display_name())
-            .unwrap_or_else(|| "Selected mod".to_string());
-        let message = format!(
-            "\"{name}\" is now unlinked from SigiLink auto ranking.\n\
-SigiLink will not move it automatically, but other mods may shift around it.\n\
-Use Ctrl+R to reset this mod or F12 to reset all pins."
-        );
-        self.open_dialog(Dialog {
-            title: "SigiLink Manual Pin".to_string(),
-            message,
-            yes_label: "OK".to_string(),
-            no_label: "Cancel".to_string(),
-            choice: DialogChoice::Yes,
-            kind: DialogKind::SigilLinkPinNotice,
-            toggle: Some(DialogToggle {
-                label: "Don't show again".to_string(),
-                checked: false,
-            }),
-            toggle_alt: None,
-            scroll: 0,
-        });
     }
 
-    fn request_sigillink_auto_rank(&mut self) {
-        if !self.app_config.sigillink_ranking_enabled {
+    fn handle_toggle_impact_message(&mut self, message: ToggleImpactMessage) {
+        let Some(view) = &mut self.toggle_impact_view else {
             return;
+        };
+        match message {
+            ToggleImpactMessage::Completed { mod_id, changes } if mod_id == view.mod_id => {
+                let count = |kind| changes.iter().filter(|change| change.kind == kind).count();
+                self.status = format!(
+                    "Toggle preview: {} new, {} resolved, {} winner change(s)",
+                    count(ToggleImpactKind::Introduced),
+                    count(ToggleImpactKind::Resolved),
+                    count(ToggleImpactKind::WinnerChanged)
+                );
+                view.loading = false;
+                view.changes = changes;
+                view.selected = 0;
+            }
+            ToggleImpactMessage::Failed { mod_id, error } if mod_id == view.mod_id => {
+                self.status = format!("Toggle preview failed: {error}");
+                view.loading = false;
+                view.error = Some(error);
+            }
+            _ => {}
         }
-        self.sigillink_rank_pending_import = true;
-        self.sigillink_rank_debounce_until =
-            Some(Instant::now() + Duration::from_secs(SIGILLINK_AUTO_RANK_DEBOUNCE_SECS));
-        self.maybe_start_sigillink_rank_pending();
     }
 
-    fn compute_missing_dependency_blocks(&self) -> HashSet<String> {
-        if !self.dependency_cache_ready {
-            return HashSet::new();
-        }
+    pub fn load_order_issues(&self) -> Vec<LoadOrderIssue> {
+        let Some(profile) = self.library.active_profile() else {
+            return Vec::new();
+        };
+        let mod_map = self.library.index_by_id();
+        let enabled: Vec<&ModEntry> = profile
+            .order
+            .iter()
+            .filter(|entry| entry.enabled)
+            .filter_map(|entry| mod_map.get(&entry.id))
+            .collect();
+        let positions: HashMap<&str, usize> = enabled
+            .iter()
+            .enumerate()
+            .map(|(index, mod_entry)| (mod_entry.id.as_str(), index))
+            .collect();
         let lookup = DependencyLookup::new(&self.library.mods);
-        let mut missing = HashSet::new();
-        for mod_entry in &self.library.mods {
-            if self.missing_dependency_count_for_mod(mod_entry, &lookup) > 0 {
-                missing.insert(mod_entry.id.clone());
+        let mut issues = Vec::new();
+        let mut non_framework_before = 0usize;
+
+        for (index, mod_entry) in enabled.iter().enumerate() {
+            let name = mod_entry.display_name();
+            if metadata::is_base_dependency_uuid(&mod_entry.id)
+                || metadata::is_base_dependency_label(&mod_entry.name)
+            {
+                issues.push(LoadOrderIssue {
+                    kind: LoadOrderIssueKind::BaseGame,
+                    mod_id: Some(mod_entry.id.clone()),
+                    message: format!(
+                        "{name} is a base game module; the game loads it itself, so remove it from the order"
+                    ),
+                });
+                continue;
+            }
+
+            let mut deps = mod_entry.dependencies.clone();
+            filter_ignored_dependencies(&mut deps);
+            let mut late: Vec<String> = Vec::new();
+            for dep in &deps {
+                let resolved = lookup.resolve_ids(dep);
+                if dependency_is_self_alias(dep, mod_entry, &resolved) {
+                    continue;
+                }
+                for id in resolved {
+                    let after = positions
+                        .get(id.as_str())
+                        .is_some_and(|position| *position > index);
+                    if after && id != mod_entry.id && !late.contains(&id) {
+                        late.push(id);
+                    }
+                }
+            }
+            for id in &late {
+                let dep_name = mod_map
+                    .get(id)
+                    .map(|dep| dep.display_name())
+                    .unwrap_or_else(|| id.clone());
+                issues.push(LoadOrderIssue {
+                    kind: LoadOrderIssueKind::Dependency,
+                    mod_id: Some(mod_entry.id.clone()),
+                    message: format!("{name} loads before its dependency {dep_name}"),
+                });
+            }
+
+            // Heuristics look at the real mod name; custom names are display-only.
+            let original_name = mod_entry.original_display_name();
+            if is_patch_label(&original_name) {
+                let patch_key = normalize_label(&original_name);
+                for target in enabled.iter().skip(index + 1) {
+                    let target_name = target.original_display_name();
+                    if late.contains(&target.id) || is_patch_label(&target_name) {
+                        continue;
+                    }
+                    let target_key = normalize_label(&target_name);
+                    if target_key.len() >= 5 && patch_key.contains(&target_key) {
+                        issues.push(LoadOrderIssue {
+                            kind: LoadOrderIssueKind::Patch,
+                            mod_id: Some(mod_entry.id.clone()),
+                            message: format!(
+                                "{name} looks like a patch for {} but loads before it",
+                                target.display_name()
+                            ),
+                        });
+                    }
+                }
+            }
+
+            if is_framework_label(&original_name) {
+                if non_framework_before > 0 {
+                    issues.push(LoadOrderIssue {
+                        kind: LoadOrderIssueKind::Framework,
+                        mod_id: Some(mod_entry.id.clone()),
+                        message: format!(
+                            "{name} is a framework but loads after {non_framework_before} mod(s); frameworks usually go first"
+                        ),
+                    });
+                }
+            } else {
+                non_framework_before += 1;
             }
         }
-        missing
-    }
 
-    fn refresh_dependency_blocks(&mut self) -> HashSet<String> {
-        let missing = self.compute_missing_dependency_blocks();
-        if missing != self.library.dependency_blocks {
-            self.library.dependency_blocks = missing.clone();
-            let _ = self.library.save(&self.config.data_dir);
+        let tags_by_id: HashMap<String, Vec<String>> = self
+            .smart_rank_cache
+            .as_ref()
+            .map(|cache| {
+                cache
+                    .mod_cache
+                    .mods
+                    .iter()
+                    .map(|(id, cached)| (id.clone(), cached.tags.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        for violation in smart_rank::order_rule_violations(
+            &profile.order_rules,
+            &profile.order,
+            &mod_map,
+            &tags_by_id,
+        ) {
+            issues.push(LoadOrderIssue {
+                kind: LoadOrderIssueKind::Rule,
+                mod_id: None,
+                message: format!("Ordering rule not satisfied: {violation}"),
+            });
         }
-        missing
+        issues
     }
 
-    fn run_startup_dependency_check(&mut self) {
-        if !self.startup_dependency_check_pending {
+    pub fn compatibility_move(&mut self, delta: isize) {
+        let Some(view) = &mut self.compatibility_view else {
             return;
-        }
-        if !self.dependency_cache_ready {
+        };
+        let len = view.report.entries.len();
+        if len == 0 {
             return;
         }
-        self.startup_dependency_check_pending = false;
-        let missing_blocks = self.refresh_dependency_blocks();
-        let Some(profile) = self.library.active_profile() else {
+        let next = (view.selected as isize + delta).clamp(0, len as isize - 1);
+        view.selected = next as usize;
+    }
+
+    pub fn compatibility_toggle_expanded(&mut self) {
+        let Some(view) = &mut self.compatibility_view else {
             return;
         };
-        let mut to_disable = Vec::new();
-        let mut disabled_names = Vec::new();
-        for entry in profile.order.iter().filter(|entry| entry.enabled) {
-            if !entry.enabled {
-                continue;
-            }
-            let Some(mod_entry) = self
-                .library
-                .mods
-                .iter()
-                .find(|mod_entry| mod_entry.id == entry.id)
-            else {
-                continue;
-            };
-            if missing_blocks.contains(&mod_entry.id) {
-                to_disable.push(entry.id.clone());
-                disabled_names.push(mod_entry.display_name());
-            }
-        }
-        if to_disable.is_empty() {
-            return;
-        }
-        let changed = self.set_mods_enabled_in_active(&to_disable, false);
-        if changed == 0 {
+        if view.report.entries.is_empty() {
             return;
         }
-        self.status = format!("Startup: disabled {changed} mod(s) missing dependencies");
-        self.log_warn(format!(
-            "Startup: disabled {changed} mod(s) missing dependencies"
-        ));
-        self.schedule_smart_rank_refresh(
-            smart_rank::SmartRankRefreshMode::Incremental,
-            "startup dependency disable",
-            true,
-        );
-        self.queue_auto_deploy("startup dependency disable");
-        if self.app_config.show_startup_dependency_notice {
-            self.prompt_startup_dependency_notice(disabled_names);
+        if !view.expanded.remove(&view.selected) {
+            view.expanded.insert(view.selected);
         }
     }
 
-    fn maybe_start_metadata_refresh(&mut self) {
-        if self.metadata_cache_valid() {
-            self.log_info("Metadata cache valid; skipping refresh".to_string());
-            self.prime_dependency_cache_from_library();
-            self.run_startup_dependency_check();
-            self.schedule_smart_rank_warmup();
+    pub fn export_compatibility_report(&mut self) {
+        let Some(view) = &self.compatibility_view else {
             return;
+        };
+        let report = view.report.clone();
+        let safe_profile = Self::sanitize_filename_component(&report.profile);
+        let profile_part = if safe_profile.is_empty() {
+            "profile".to_string()
+        } else {
+            safe_profile
+        };
+        let base = self.export_dir().join(format!(
+            "compatibility-{}-{}-{}",
+            self.game_id.as_str(),
+            profile_part,
+            self.export_timestamp()
+        ));
+        let text_path = base.with_extension("txt");
+        let json_path = base.with_extension("json");
+        let result = serde_json::to_string_pretty(&report)
+            .context("serialize compatibility report")
+            .and_then(|json| Self::write_atomic_text(&json_path, &json))
+            .and_then(|_| Self::write_atomic_text(&text_path, &compatibility_report_text(&report)));
+        match result {
+            Ok(()) => {
+                self.status = format!("Compatibility report exported: {}", text_path.display());
+                self.log_info(format!(
+                    "Compatibility report exported: {} (+ .json)",
+                    text_path.display()
+                ));
+                self.set_toast(
+                    "Compatibility report exported",
+                    ToastLevel::Info,
+                    Duration::from_secs(2),
+                );
+            }
+            Err(err) => {
+                self.status = format!("Compatibility export failed: {err}");
+                self.log_error(format!("Compatibility export failed: {err}"));
+            }
         }
-        self.start_metadata_refresh();
     }
 
-    fn metadata_cache_valid(&self) -> bool {
-        if self.library.metadata_cache_version != METADATA_CACHE_VERSION {
-            return false;
-        }
-        let Some(expected) = self.library.metadata_cache_key.as_deref() else {
-            return false;
-        };
-        expected == self.metadata_cache_key()
+    pub fn open_command_palette(&mut self) {
+        self.move_mode = false;
+        self.command_palette = Some(CommandPalette::default());
     }
 
-    fn metadata_cache_key(&self) -> String {
-        let mut hasher = Hasher::new();
-        hasher.update(b"metadata-cache-v1");
-        let mut mods: Vec<&ModEntry> = self.library.mods.iter().collect();
-        mods.sort_by(|a, b| a.id.cmp(&b.id));
-        for mod_entry in mods {
-            hasher.update(mod_entry.id.as_bytes());
-            hasher.update(mod_entry.name.as_bytes());
-            if let Some(label) = mod_entry.source_label.as_deref() {
-                hasher.update(label.as_bytes());
-            }
-            let source_tag = match mod_entry.source {
-                ModSource::Managed => 0u8,
-                ModSource::Native => 1u8,
-            };
-            hasher.update(&[source_tag]);
-            let mut targets: Vec<String> = Vec::new();
-            for target in &mod_entry.targets {
-                let key = match target {
-                    InstallTarget::Pak { file, info } => {
-                        format!("pak|{}|{}|{}", file, info.uuid, info.folder)
-                    }
-                    InstallTarget::Generated { dir } => format!("gen|{dir}"),
-                    InstallTarget::Data { dir } => format!("data|{dir}"),
-                    InstallTarget::Bin { dir } => format!("bin|{dir}"),
-                };
-                targets.push(key);
-            }
-            targets.sort();
-            for target in targets {
-                hasher.update(target.as_bytes());
-            }
+    pub fn close_command_palette(&mut self) {
+        self.command_palette = None;
+    }
+
+    pub fn command_palette_input(&mut self, ch: char) {
+        if let Some(palette) = &mut self.command_palette {
+            palette.query.push(ch);
+            palette.selected = 0;
         }
-        hasher.finalize().to_hex().to_string()
     }
 
-    fn prime_dependency_cache_from_library(&mut self) {
-        self.dependency_cache.clear();
-        for mod_entry in &self.library.mods {
-            if !mod_entry.dependencies.is_empty() {
-                self.dependency_cache
-                    .insert(mod_entry.id.clone(), mod_entry.dependencies.clone());
-            } else {
-                self.dependency_cache
-                    .insert(mod_entry.id.clone(), Vec::new());
-            }
+    pub fn command_palette_backspace(&mut self) {
+        if let Some(palette) = &mut self.command_palette {
+            palette.query.pop();
+            palette.selected = 0;
         }
-        self.dependency_cache_ready = true;
-        self.refresh_dependency_blocks();
     }
 
-    fn schedule_smart_rank_warmup(&mut self) {
-        if !self.paths_ready() {
+    pub fn command_palette_move(&mut self, delta: isize) {
+        let len = self.command_palette_entries().len();
+        let Some(palette) = &mut self.command_palette else {
+            return;
+        };
+        if len == 0 {
+            palette.selected = 0;
             return;
         }
-        if self.smart_rank_cache.is_none() {
-            self.load_smart_rank_cache();
+        let next = (palette.selected as isize + delta).clamp(0, len as isize - 1);
+        palette.selected = next as usize;
+    }
+
+    fn palette_actions(&self) -> Vec<PaletteAction> {
+        let mut actions = vec![
+            PaletteAction::Deploy,
+            PaletteAction::DeployLoadOrder,
+            PaletteAction::RepairModsettings,
+            PaletteAction::StageDeploy,
+            PaletteAction::Import,
+            PaletteAction::AdoptDevFolder,
+            PaletteAction::ToggleDevWatch,
+            PaletteAction::RenameMod,
+            PaletteAction::ShowInstallTargets,
+            PaletteAction::ToggleAllTargets,
+            PaletteAction::SigilLinkRank,
+            PaletteAction::SigilLinkRankScoped,
+            PaletteAction::CompatibilityReport,
+            PaletteAction::CheckLoadOrder,
+            PaletteAction::PreviewToggleImpact,
+            PaletteAction::ExportMenu,
+            PaletteAction::CopyModsettings,
+            PaletteAction::ImportModList,
+            PaletteAction::ToggleAutoDeploy,
+            PaletteAction::ToggleAutomationPause,
+            PaletteAction::ToggleSigilLinkRanking,
+            PaletteAction::VerifySigilLinkCache,
+            PaletteAction::CleanSigilLinkCache,
+            PaletteAction::RestageMissingMods,
+            PaletteAction::ClearFileOverrides,
+            PaletteAction::ConflictRules,
+            PaletteAction::OrderRules,
+            PaletteAction::RankHistory,
+            PaletteAction::RevertLastRank,
+            PaletteAction::ToggleSigilLinkExclusion,
+            PaletteAction::ClearFilter,
+            PaletteAction::ToggleRecentFilter,
+            PaletteAction::ToggleStarredFilter,
+            PaletteAction::ToggleStar,
+            PaletteAction::RecomputeModSizes,
+            PaletteAction::RefreshVisibleMetadata,
+            PaletteAction::AdoptNative,
+            PaletteAction::RestoreRemovedMods,
+            PaletteAction::FreezeSort,
+            PaletteAction::ResetProfileOrder,
+            PaletteAction::NormalizeProfiles,
+            PaletteAction::Rollback,
+            PaletteAction::ShowPaths,
+            PaletteAction::OpenDataDir,
+            PaletteAction::OpenGameRoot,
+            PaletteAction::OpenLarianDir,
+            PaletteAction::SetupPaths,
+            PaletteAction::Settings,
+            PaletteAction::Help,
+            PaletteAction::WhatsNew,
+            PaletteAction::CheckUpdates,
+        ];
+        actions.extend(
+            self.library
+                .profiles
+                .iter()
+                .map(|profile| PaletteAction::SwitchProfile(profile.name.clone())),
+        );
+        actions.extend(
+            self.library
+                .profiles
+                .iter()
+                .filter(|profile| profile.name != self.library.active_profile)
+                .map(|profile| PaletteAction::DeployProfile(profile.name.clone())),
+        );
+        actions
+    }
+
+    fn palette_disabled_reason(&self, action: &PaletteAction) -> Option<String> {
+        if let PaletteAction::SwitchProfile(name) = action {
+            if *name == self.library.active_profile {
+                return Some("already active".to_string());
+            }
         }
-        let profile_key = self.smart_rank_profile_key();
-        let mut desired = smart_rank::SmartRankRefreshMode::Full;
-        if let Some(cache) = &self.smart_rank_cache {
-            if cache.version == SMART_RANK_CACHE_VERSION {
-                if cache.result.is_some()
-                    && cache.profile_key == profile_key
-                    && self.smart_rank_cache_ready(cache)
-                {
-                    self.smart_rank_refresh_pending = None;
-                    self.smart_rank_refresh_at = None;
-                    self.status = "SigiLink Intelligent Ranking warmup cached".to_string();
-                    self.log_info("SigiLink ranking warmup: cache hit".to_string());
-                    return;
-                }
-                if cache.profile_key == profile_key {
-                    desired = smart_rank::SmartRankRefreshMode::Incremental;
-                } else if cache.result.is_some() && self.smart_rank_cache_ready(cache) {
-                    desired = smart_rank::SmartRankRefreshMode::ReorderOnly;
-                } else {
-                    desired = smart_rank::SmartRankRefreshMode::Incremental;
-                }
+        if matches!(action, PaletteAction::FreezeSort) && self.mod_sort.is_order_default() {
+            return Some("already sorted by order".to_string());
+        }
+        if action.blocked_when_busy() {
+            if let Some(reason) = self.busy_reason() {
+                return Some(reason.to_string());
             }
         }
-        self.smart_rank_refresh_pending = Some(desired);
-        self.smart_rank_refresh_at = None;
+        None
     }
 
-    fn smart_rank_cache_ready(&self, cache: &SmartRankCache) -> bool {
-        Self::smart_rank_cache_ready_for(&self.library, cache)
+    pub fn busy_reason(&self) -> Option<&'static str> {
+        if self.startup_pending {
+            Some("startup in progress")
+        } else if self.native_sync_active {
+            Some("native mod sync running")
+        } else if self.import_active.is_some() || self.import_apply_active {
+            Some("import running")
+        } else if self.deploy_active || self.deploy_pending {
+            Some("deploy running")
+        } else if self.conflict_active || self.conflict_pending {
+            Some("override scan running")
+        } else if self.smart_rank_active {
+            Some("SigiLink scan running")
+        } else if self.metadata_active {
+            Some("metadata scan running")
+        } else {
+            None
+        }
     }
 
-    fn smart_rank_cache_ready_for(library: &Library, cache: &SmartRankCache) -> bool {
-        if cache.version != SMART_RANK_CACHE_VERSION {
-            return false;
+    pub fn active_task_labels(&self) -> Vec<String> {
+        let mut tasks = Vec::new();
+        if self.startup_pending {
+            tasks.push("Startup".to_string());
         }
-        if cache.result.is_none() {
-            return false;
+        if self.native_sync_active {
+            match &self.native_sync_progress {
+                Some(progress) if progress.total > 0 => tasks.push(format!(
+                    "Native sync {}/{}",
+                    progress.current, progress.total
+                )),
+                _ => tasks.push("Native sync".to_string()),
+            }
         }
-        let Some(profile) = library.active_profile() else {
-            return false;
-        };
-        for entry in profile.order.iter().filter(|entry| entry.enabled) {
-            let Some(mod_entry) = library
-                .mods
-                .iter()
-                .find(|mod_entry| mod_entry.id == entry.id)
-            else {
-                continue;
-            };
-            let key = smart_rank::mod_cache_key(mod_entry);
-            match cache.mod_cache.mods.get(&entry.id) {
-                Some(entry_cache) if entry_cache.key == key && entry_cache.has_data => {}
-                _ => return false,
+        if self.import_active.is_some() {
+            tasks.push("Import".to_string());
+        } else if self.import_apply_active {
+            tasks.push("Import apply".to_string());
+        }
+        if self.metadata_active {
+            if self.metadata_total > 0 {
+                tasks.push(format!(
+                    "Metadata scan {}/{}",
+                    self.metadata_processed, self.metadata_total
+                ));
+            } else {
+                tasks.push("Metadata scan".to_string());
             }
         }
-        true
+        if self.smart_rank_active {
+            match &self.smart_rank_progress {
+                Some(progress) if progress.total > 0 => tasks.push(format!(
+                    "SigiLink scan {}/{}",
+                    progress.scanned, progress.total
+                )),
+                _ => tasks.push("SigiLink scan".to_string()),
+            }
+        }
+        if self.deploy_active {
+            tasks.push("Deploying".to_string());
+        } else if self.deploy_pending {
+            tasks.push("Deploy pending".to_string());
+        }
+        if self.conflict_active {
+            tasks.push("Override scan".to_string());
+        } else if self.conflict_pending {
+            tasks.push("Override scan pending".to_string());
+        }
+        if self.disk_usage_active {
+            tasks.push("Size scan".to_string());
+        }
+        tasks
     }
 
-    #[allow(dead_code)]
-    fn smart_rank_cache_missing_ids(&self, cache: &SmartRankCache) -> Vec<String> {
-        Self::smart_rank_cache_missing_ids_for(&self.library, cache)
+    pub fn command_palette_entries(&self) -> Vec<PaletteEntry> {
+        let query = self
+            .command_palette
+            .as_ref()
+            .map(|palette| palette.query.as_str())
+            .unwrap_or("");
+        let mut scored: Vec<(i64, usize, PaletteEntry)> = self
+            .palette_actions()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, action)| {
+                let label = action.label();
+                let mut score = palette::fuzzy_score(query, &label)?;
+                let id = action.id();
+                if let Some(rank) = self.palette_recent.iter().position(|recent| *recent == id) {
+                    score += ((PALETTE_RECENT_LIMIT - rank) * 4) as i64;
+                }
+                let disabled = self.palette_disabled_reason(&action);
+                Some((
+                    score,
+                    index,
+                    PaletteEntry {
+                        action,
+                        label,
+                        disabled,
+                    },
+                ))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        scored.into_iter().map(|(_, _, entry)| entry).collect()
     }
 
-    #[allow(dead_code)]
-    fn smart_rank_cache_missing_ids_for(library: &Library, cache: &SmartRankCache) -> Vec<String> {
-        let mut missing = Vec::new();
-        let Some(profile) = library.active_profile() else {
-            return missing;
+    pub fn command_palette_execute(&mut self) -> Result<()> {
+        let Some(selected) = self
+            .command_palette
+            .as_ref()
+            .map(|palette| palette.selected)
+        else {
+            return Ok(());
         };
-        for entry in &profile.order {
-            let Some(mod_entry) = library
-                .mods
-                .iter()
-                .find(|mod_entry| mod_entry.id == entry.id)
-            else {
-                continue;
-            };
-            let key = smart_rank::mod_cache_key(mod_entry);
-            match cache.mod_cache.mods.get(&entry.id) {
-                Some(entry_cache) if entry_cache.key == key && entry_cache.has_data => {}
-                _ => missing.push(entry.id.clone()),
-            }
+        let Some(entry) = self.command_palette_entries().into_iter().nth(selected) else {
+            return Ok(());
+        };
+        if let Some(reason) = entry.disabled {
+            self.status = format!("{} unavailable: {reason}", entry.label);
+            self.set_toast(
+                &format!("{}: {reason}", entry.label),
+                ToastLevel::Warn,
+                Duration::from_secs(2),
+            );
+            return Ok(());
         }
-        missing
-    }
-
-    fn merge_smart_rank_refresh_kind(
-        &self,
-        current: smart_rank::SmartRankRefreshMode,
-        next: smart_rank::SmartRankRefreshMode,
-    ) -> smart_rank::SmartRankRefreshMode {
-        use smart_rank::SmartRankRefreshMode;
-        match (current, next) {
-            (SmartRankRefreshMode::Full, _) | (_, SmartRankRefreshMode::Full) => {
-                SmartRankRefreshMode::Full
+        self.close_command_palette();
+        let id = entry.action.id();
+        self.palette_recent.retain(|recent| *recent != id);
+        self.palette_recent.insert(0, id);
+        self.palette_recent.truncate(PALETTE_RECENT_LIMIT);
+        if self.prompt_pending_move(PendingMoveTarget::Palette(entry.action.clone())) {
+            return Ok(());
+        }
+        self.run_palette_action(entry.action)
+    }
+
+    fn run_palette_action(&mut self, action: PaletteAction) -> Result<()> {
+        match action {
+            PaletteAction::SwitchProfile(name) => self.set_active_profile(&name)?,
+            PaletteAction::DeployProfile(name) => self.deploy_profile(&name)?,
+            PaletteAction::Deploy => self.deploy()?,
+            PaletteAction::DeployLoadOrder => self.deploy_load_order_only()?,
+            PaletteAction::RepairModsettings => self.prompt_repair_modsettings(),
+            PaletteAction::StageDeploy => self.open_stage_deploy(),
+            PaletteAction::Rollback => self.rollback_last_backup()?,
+            PaletteAction::Import => self.enter_import_mode(),
+            PaletteAction::AdoptDevFolder => self.enter_adopt_dev_folder(),
+            PaletteAction::ToggleDevWatch => self.toggle_dev_watch_selected(),
+            PaletteAction::RenameMod => self.enter_rename_mod(),
+            PaletteAction::ShowInstallTargets => self.open_targets_view(),
+            PaletteAction::ToggleAllTargets => self.toggle_all_targets_selected(),
+            PaletteAction::SigilLinkRank => self.run_sigillink_ranking_solo(),
+            PaletteAction::SigilLinkRankScoped => self.run_sigillink_ranking_scoped(),
+            PaletteAction::CompatibilityReport => self.open_compatibility_report(),
+            PaletteAction::CheckLoadOrder => self.open_load_order_check(),
+            PaletteAction::PreviewToggleImpact => self.open_toggle_impact(),
+            PaletteAction::ExportMenu => {
+                let active = self.library.active_profile.clone();
+                self.open_export_menu(&active);
+            }
+            PaletteAction::CopyModsettings => {
+                let active = self.library.active_profile.clone();
+                if let Err(err) = self.export_modsettings_clipboard(&active) {
+                    self.status = format!("Export failed: {err}");
+                    self.log_error(format!("Export failed: {err}"));
+                }
             }
-            (SmartRankRefreshMode::Incremental, _) | (_, SmartRankRefreshMode::Incremental) => {
-                SmartRankRefreshMode::Incremental
+            PaletteAction::ImportModList => self.enter_import_profile(),
+            PaletteAction::ToggleAutoDeploy => self.toggle_auto_deploy()?,
+            PaletteAction::ToggleAutomationPause => self.toggle_automation_pause(),
+            PaletteAction::ToggleSigilLinkRanking => self.toggle_sigillink_ranking()?,
+            PaletteAction::ClearFileOverrides => self.prompt_clear_all_file_overrides(),
+            PaletteAction::VerifySigilLinkCache => self.verify_sigillink_cache(),
+            PaletteAction::CleanSigilLinkCache => self.prompt_sigillink_cache_gc(),
+            PaletteAction::RestageMissingMods => self.restage_missing_mods(),
+            PaletteAction::ConflictRules => self.open_conflict_rules(),
+            PaletteAction::OrderRules => self.open_order_rules(),
+            PaletteAction::RankHistory => self.open_rank_history(),
+            PaletteAction::RevertLastRank => self.revert_last_sigillink_rank(),
+            PaletteAction::ToggleSigilLinkExclusion => self.toggle_sigillink_exclusion_selected(),
+            PaletteAction::ClearFilter => self.clear_mod_filter(),
+            PaletteAction::ToggleRecentFilter => self.toggle_recent_filter(),
+            PaletteAction::ToggleStarredFilter => self.toggle_starred_filter(),
+            PaletteAction::ToggleStar => self.toggle_star_selected(),
+            PaletteAction::RecomputeModSizes => self.recompute_mod_sizes(),
+            PaletteAction::RefreshVisibleMetadata => self.refresh_visible_metadata(),
+            PaletteAction::AdoptNative => self.prompt_adopt_native_mod(),
+            PaletteAction::RestoreRemovedMods => self.open_trash_view(),
+            PaletteAction::FreezeSort => self.prompt_freeze_sort(),
+            PaletteAction::ResetProfileOrder => self.prompt_reset_profile_order(),
+            PaletteAction::NormalizeProfiles => self.normalize_all_profiles(),
+            PaletteAction::ShowPaths => self.open_paths_overlay(),
+            PaletteAction::OpenDataDir => self.open_data_dir(),
+            PaletteAction::OpenGameRoot => self.open_game_root(),
+            PaletteAction::OpenLarianDir => self.open_larian_dir(),
+            PaletteAction::SetupPaths => self.enter_setup_game_root(),
+            PaletteAction::Settings => self.open_settings_menu(),
+            PaletteAction::Help => self.toggle_help(),
+            PaletteAction::WhatsNew => self.open_whats_new(),
+            PaletteAction::CheckUpdates => {
+                if matches!(self.update_status, UpdateStatus::Available { .. }) {
+                    self.apply_ready_update();
+                } else {
+                    self.request_update_check();
+                }
             }
-            _ => SmartRankRefreshMode::ReorderOnly,
         }
+        Ok(())
     }
 
-    fn schedule_smart_rank_refresh(
-        &mut self,
-        kind: smart_rank::SmartRankRefreshMode,
-        reason: &str,
-        debounce: bool,
-    ) {
-        if matches!(kind, smart_rank::SmartRankRefreshMode::Full) {
-            self.smart_rank_cache = None;
-            self.clear_smart_rank_cache_file();
-        }
-        self.interrupt_smart_rank(reason);
-        let next = match self.smart_rank_refresh_pending.take() {
-            Some(current) => self.merge_smart_rank_refresh_kind(current, kind),
-            None => kind,
-        };
-        self.smart_rank_refresh_pending = Some(next);
-        if debounce {
-            self.smart_rank_refresh_at =
-                Some(Instant::now() + Duration::from_millis(SMART_RANK_DEBOUNCE_MS));
-        } else {
-            self.smart_rank_refresh_at = None;
+    fn start_sigillink_auto_rank(&mut self) {
+        if self.smart_rank_active {
+            self.status = "SigiLink Intelligent Ranking already running".to_string();
+            return;
+        }
+        if self.is_busy() {
+            self.status = "SigiLink Intelligent Ranking blocked: busy".to_string();
+            self.log_warn("SigiLink Intelligent Ranking blocked: busy".to_string());
+            return;
+        }
+        if !self.paths_ready() {
+            self.status = "SigiLink Intelligent Ranking blocked: paths not set".to_string();
+            self.log_warn("SigiLink Intelligent Ranking blocked: paths not set".to_string());
+            return;
         }
-    }
 
-    fn resolve_smart_rank_refresh_kind(
-        &self,
-        requested: smart_rank::SmartRankRefreshMode,
-    ) -> smart_rank::SmartRankRefreshMode {
-        if matches!(requested, smart_rank::SmartRankRefreshMode::Full) {
-            return requested;
+        if self.app_config.sigillink_ranking_enabled {
+            let inputs_hash = self.sigillink_inputs_hash();
+            if let (Some(next), Some(prev)) =
+                (inputs_hash.as_deref(), self.sigillink_last_inputs_hash())
+            {
+                if next == prev {
+                    self.status = "SigiLink Intelligent Ranking: up to date".to_string();
+                    return;
+                }
+            }
         }
-        let Some(cache) = &self.smart_rank_cache else {
-            return smart_rank::SmartRankRefreshMode::Full;
+
+        self.sigillink_force_preview = false;
+        let mode = if self.app_config.sigillink_auto_preview {
+            self.sigillink_preview_notice =
+                Some("SigiLink Intelligent Ranking: Auto-rank".to_string());
+            SmartRankMode::Auto
+        } else {
+            self.sigillink_preview_notice =
+                Some("SigiLink Intelligent Ranking: Auto-rank".to_string());
+            SmartRankMode::Preview
         };
-        if cache.version != SMART_RANK_CACHE_VERSION {
-            return smart_rank::SmartRankRefreshMode::Full;
+
+        self.start_smart_rank_scan(mode, smart_rank::SmartRankRefreshMode::Incremental);
+    }
+
+    #[allow(dead_code)]
+    pub fn clear_smart_rank_cache(&mut self) {
+        self.smart_rank_cache = None;
+        self.clear_smart_rank_cache_file();
+        self.smart_rank_refresh_at = None;
+        if self.smart_rank_active {
+            self.status = "SigiLink ranking cache cleared; warming up after scan".to_string();
+            self.schedule_smart_rank_refresh(
+                smart_rank::SmartRankRefreshMode::Full,
+                "cache cleared",
+                false,
+            );
+            return;
         }
-        if matches!(requested, smart_rank::SmartRankRefreshMode::ReorderOnly)
-            && !self.smart_rank_cache_ready(cache)
-        {
-            return smart_rank::SmartRankRefreshMode::Incremental;
+        if !self.paths_ready() {
+            self.status = "SigiLink ranking cache cleared".to_string();
+            return;
         }
-        requested
+        self.status = "SigiLink ranking cache cleared; warming up".to_string();
+        self.start_smart_rank_scan(
+            SmartRankMode::Warmup,
+            smart_rank::SmartRankRefreshMode::Full,
+        );
     }
 
-    fn prompt_startup_dependency_notice(&mut self, disabled: Vec<String>) {
-        if disabled.is_empty() || self.dialog.is_some() {
+    pub fn sigillink_ranking_enabled(&self) -> bool {
+        self.app_config.sigillink_ranking_enabled
+    }
+
+    pub fn sigillink_pin_count(&self) -> usize {
+        self.library
+            .active_profile()
+            .map(|profile| profile.sigillink_pins.len())
+            .unwrap_or(0)
+    }
+
+    pub fn file_override_count(&self) -> usize {
+        self.library
+            .active_profile()
+            .map(|profile| profile.file_overrides.len())
+            .unwrap_or(0)
+    }
+
+    pub fn sigillink_is_pinned(&self, mod_id: &str) -> bool {
+        self.library
+            .active_profile()
+            .and_then(|profile| profile.sigillink_pins.get(mod_id))
+            .is_some()
+    }
+
+    pub fn sigillink_is_excluded(&self, mod_id: &str) -> bool {
+        self.library.sigillink_excluded.contains(mod_id)
+    }
+
+    pub fn toggle_sigillink_exclusion_selected(&mut self) {
+        let Some(id) = self.selected_profile_id() else {
+            return;
+        };
+        let name = self
+            .library
+            .mods
+            .iter()
+            .find(|mod_entry| mod_entry.id == id)
+            .map(|mod_entry| mod_entry.display_name())
+            .unwrap_or_else(|| id.clone());
+        let excluded = if self.library.sigillink_excluded.remove(&id) {
+            false
+        } else {
+            self.library.sigillink_excluded.insert(id);
+            true
+        };
+        if let Err(err) = self.library.save(&self.config.data_dir) {
+            self.status = format!("SigiLink exclusion save failed: {err}");
+            self.log_error(format!("SigiLink exclusion save failed: {err}"));
             return;
         }
-        let total = disabled.len();
-        let mut lines = Vec::new();
-        lines.push(format!("Disabled {total} mod(s) missing dependencies."));
-        lines.push(String::new());
-        for (index, name) in disabled.iter().enumerate() {
-            lines.push(format!("{}. {name}", index + 1));
+        let message = if excluded {
+            format!("SigiLink: {name} excluded from ranking in all profiles")
+        } else {
+            format!("SigiLink: {name} ranked again")
+        };
+        self.status = message.clone();
+        self.log_info(message.clone());
+        self.set_toast(&message, ToastLevel::Info, Duration::from_secs(2));
+        if !excluded && self.app_config.sigillink_ranking_enabled {
+            self.request_sigillink_auto_rank();
         }
-        self.open_dialog(Dialog {
-            title: "Dependencies missing".to_string(),
-            message: lines.join("\n"),
-            yes_label: "OK".to_string(),
-            no_label: "Hide next time".to_string(),
-            choice: DialogChoice::Yes,
-            kind: DialogKind::StartupDependencyNotice,
-            toggle: None,
-            toggle_alt: None,
-            scroll: 0,
-        });
     }
 
-    fn interrupt_smart_rank(&mut self, reason: &str) {
-        if !self.smart_rank_active {
+    pub fn sigillink_rank_meta(&self) -> SigilLinkRankMeta {
+        self.library
+            .profiles
+            .iter()
+            .find(|profile| is_sigillink_ranking_profile(&profile.name))
+            .map(|profile| profile.sigillink_meta.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn sigillink_rank_history(&self) -> Vec<SigilLinkRankSnapshot> {
+        let active = self.library.active_profile.as_str();
+        self.sigillink_rank_meta()
+            .history
+            .into_iter()
+            .filter(|entry| entry.profile == active)
+            .collect()
+    }
+
+    pub fn open_rank_history(&mut self) {
+        if self.sigillink_rank_history().is_empty() {
+            self.status = "No SigiLink rankings recorded for this profile".to_string();
             return;
         }
-        self.smart_rank_interrupt = true;
-        self.smart_rank_progress = None;
-        self.status = "SigiLink Intelligent Ranking paused for changes".to_string();
-        self.log_info(format!("SigiLink ranking interrupted: {reason}"));
+        self.rank_history_view = Some(RankHistoryView::default());
     }
 
-    fn clear_smart_rank_scan_state(&mut self) {
-        self.smart_rank_active = false;
-        self.smart_rank_progress = None;
-        self.smart_rank_mode = None;
-        self.smart_rank_refresh_kind = None;
-        self.smart_rank_interrupt = false;
-        self.smart_rank_scan_active = None;
-        self.smart_rank_scan_profile_key = None;
+    pub fn close_rank_history(&mut self) {
+        self.rank_history_view = None;
     }
 
-    fn allow_persistence(&self) -> bool {
-        #[cfg(debug_assertions)]
-        {
-            return !self.debug_suppress_persistence;
-        }
-        #[cfg(not(debug_assertions))]
-        {
-            return true;
+    pub fn rank_history_move(&mut self, delta: isize) {
+        let len = self.sigillink_rank_history().len();
+        let Some(view) = &mut self.rank_history_view else {
+            return;
+        };
+        if len == 0 {
+            view.selected = 0;
+            return;
         }
+        let next = (view.selected as isize + delta).clamp(0, len as isize - 1);
+        view.selected = next as usize;
     }
 
-    fn smart_rank_scan_matches(&self, scan_id: u64) -> bool {
-        self.smart_rank_scan_active == Some(scan_id)
+    pub fn rank_history_restore(&mut self, ranked: bool) {
+        let Some(selected) = self.rank_history_view.as_ref().map(|view| view.selected) else {
+            return;
+        };
+        self.restore_rank_snapshot(selected, ranked);
     }
 
-    fn smart_rank_scan_profile_matches(&self, profile_key: &str) -> bool {
-        self.smart_rank_scan_profile_key.as_deref() == Some(profile_key)
+    pub fn revert_last_sigillink_rank(&mut self) {
+        if self.sigillink_rank_history().is_empty() {
+            self.status = "No SigiLink ranking to revert".to_string();
+            return;
+        }
+        self.restore_rank_snapshot(0, false);
     }
 
-    fn maybe_restart_smart_rank(&mut self) {
-        let Some(pending) = self.smart_rank_refresh_pending else {
+    fn restore_rank_snapshot(&mut self, index: usize, ranked: bool) {
+        if self.block_locked_profile("restore ranking") {
+            return;
+        }
+        let history = self.sigillink_rank_history();
+        let Some(snapshot) = history.get(index) else {
             return;
         };
-        if let Some(ready_at) = self.smart_rank_refresh_at {
-            if Instant::now() < ready_at {
+        let target = if ranked {
+            &snapshot.after
+        } else {
+            &snapshot.before
+        };
+        let when = format_snapshot_time(snapshot.ranked_at);
+        let previous_id = self.selected_profile_id();
+        let moved = {
+            let Some(profile) = self.library.active_profile_mut() else {
                 return;
+            };
+            let mut remaining: Vec<ProfileEntry> = profile.order.clone();
+            let mut restored = Vec::with_capacity(remaining.len());
+            for entry in target {
+                if let Some(pos) = remaining.iter().position(|item| item.id == entry.id) {
+                    restored.push(remaining.remove(pos));
+                }
             }
-        }
-        if self.smart_rank_active || self.is_busy() {
+            restored.extend(remaining);
+            let moved = restored
+                .iter()
+                .zip(profile.order.iter())
+                .filter(|(a, b)| a.id != b.id)
+                .count();
+            profile.order = restored;
+            moved
+        };
+        self.reselect_mod_by_id(previous_id);
+        let label = if ranked {
+            "ranked order"
+        } else {
+            "order before rank"
+        };
+        if let Err(err) = self.library.save(&self.config.data_dir) {
+            self.status = format!("Ranking restore save failed: {err}");
+            self.log_error(format!("Ranking restore save failed: {err}"));
             return;
         }
-        if !self.paths_ready() {
+        self.status = format!("Restored {label} from {when}: {moved} mod(s) moved");
+        self.log_info(format!(
+            "SigiLink history: restored {label} from {when} ({moved} moved)"
+        ));
+        if moved == 0 {
             return;
         }
-        self.smart_rank_refresh_pending = None;
-        self.smart_rank_refresh_at = None;
-        let refresh = self.resolve_smart_rank_refresh_kind(pending);
-        self.start_smart_rank_scan(SmartRankMode::Warmup, refresh);
+        self.update_sigillink_inputs_hash();
+        self.schedule_smart_rank_refresh(
+            smart_rank::SmartRankRefreshMode::ReorderOnly,
+            "order changed",
+            true,
+        );
+        self.queue_auto_deploy("order changed");
     }
 
-    fn start_smart_rank_scan(
-        &mut self,
-        mode: SmartRankMode,
-        refresh: smart_rank::SmartRankRefreshMode,
-    ) {
-        if self.smart_rank_active {
-            return;
+    pub fn sigillink_preview_notice(&self) -> Option<&str> {
+        self.sigillink_preview_notice.as_deref()
+    }
+
+    fn sigillink_last_inputs_hash(&self) -> Option<&str> {
+        self.library
+            .profiles
+            .iter()
+            .find(|profile| is_sigillink_ranking_profile(&profile.name))
+            .and_then(|profile| profile.sigillink_meta.last_inputs_hash.as_deref())
+    }
+
+    fn sigillink_ranking_profile_mut(&mut self) -> Option<&mut Profile> {
+        self.library
+            .profiles
+            .iter_mut()
+            .find(|profile| is_sigillink_ranking_profile(&profile.name))
+    }
+
+    fn sigillink_inputs_hash(&self) -> Option<String> {
+        let Some(profile) = self.library.active_profile() else {
+            return None;
+        };
+        let mut hasher = Hasher::new();
+        hasher.update(profile.name.as_bytes());
+        for entry in &profile.order {
+            hasher.update(entry.id.as_bytes());
+            hasher.update(&[entry.enabled as u8]);
         }
-        let profile_key = self.smart_rank_profile_key();
-        if let Some(cache) = &self.smart_rank_cache {
-            if cache.profile_key == profile_key && self.smart_rank_cache_ready(cache) {
-                if let Some(result) = cache.result.clone() {
-                    self.clear_smart_rank_scan_state();
-                    match mode {
-                        SmartRankMode::Auto | SmartRankMode::Preview => {
-                            self.finalize_smart_rank_preview(result, mode);
-                        }
-                        SmartRankMode::Warmup => {
-                            self.log_info("SigiLink ranking warmup: cache hit".to_string());
-                            self.status = "SigiLink Intelligent Ranking warmup cached".to_string();
-                        }
-                    }
-                    return;
-                }
-            }
-            self.log_info("SigiLink ranking cache miss (profile change)".to_string());
+        let mut pins: Vec<(&String, &usize)> = profile.sigillink_pins.iter().collect();
+        pins.sort_by(|(id_a, idx_a), (id_b, idx_b)| idx_a.cmp(idx_b).then_with(|| id_a.cmp(id_b)));
+        for (id, index) in pins {
+            hasher.update(id.as_bytes());
+            hasher.update(&index.to_le_bytes());
         }
-        self.smart_rank_scan_id = self.smart_rank_scan_id.wrapping_add(1);
-        let scan_id = self.smart_rank_scan_id;
-        self.smart_rank_scan_active = Some(scan_id);
-        self.smart_rank_scan_profile_key = Some(profile_key.clone());
-        self.smart_rank_mode = Some(mode);
-        self.smart_rank_active = true;
-        self.smart_rank_refresh_kind = Some(refresh);
-        self.smart_rank_progress = None;
-        self.smart_rank_view = SmartRankView::Changes;
-        self.smart_rank_scroll = 0;
-        self.status = match mode {
-            SmartRankMode::Auto | SmartRankMode::Preview => {
-                "SigiLink Intelligent Ranking: scanning...".to_string()
-            }
-            SmartRankMode::Warmup => "SigiLink Intelligent Ranking: warmup scan...".to_string(),
-        };
-        self.log_info("SigiLink ranking scan started".to_string());
-
-        let config = self.config.clone();
-        let library = self.library.clone();
-        let cache_data = self
-            .smart_rank_cache
-            .as_ref()
-            .map(|cache| cache.mod_cache.clone());
-        let tx = self.smart_rank_tx.clone();
-        thread::spawn(move || {
-            let result = smart_rank::smart_rank_profile_cached_with_progress(
-                &config,
-                &library,
-                cache_data.as_ref(),
-                refresh,
-                |progress| {
-                    let _ = tx.send(SmartRankMessage::Progress { scan_id, progress });
-                },
-            );
-            match result {
-                Ok(result) => {
-                    let _ = tx.send(SmartRankMessage::Finished {
-                        scan_id,
-                        computed: result,
-                    });
-                }
-                Err(err) => {
-                    let _ = tx.send(SmartRankMessage::Failed {
-                        scan_id,
-                        error: err.to_string(),
-                    });
-                }
+        if !profile.order_rules.is_empty() {
+            if let Ok(raw) = serde_json::to_vec(&profile.order_rules) {
+                hasher.update(&raw);
             }
-        });
+        }
+        let mut excluded: Vec<&String> = self.library.sigillink_excluded.iter().collect();
+        excluded.sort();
+        for id in excluded {
+            hasher.update(b"excluded:");
+            hasher.update(id.as_bytes());
+        }
+        Some(hasher.finalize().to_hex().to_string())
     }
 
-    fn finalize_smart_rank_preview(
-        &mut self,
-        result: smart_rank::SmartRankResult,
-        mode: SmartRankMode,
-    ) {
-        self.smart_rank_active = false;
-        self.smart_rank_progress = None;
-        self.smart_rank_mode = None;
-        self.smart_rank_refresh_kind = None;
-        self.smart_rank_interrupt = false;
-        self.smart_rank_scan_active = None;
-        self.smart_rank_scan_profile_key = None;
-
-        self.log_info(format!(
-            "SigiLink ranking scan: loose {}/{} pak {}/{} in {}ms (missing loose {}, pak {})",
-            result.report.scanned_loose,
-            result.report.enabled_loose,
-            result.report.scanned_pak,
-            result.report.enabled_pak,
-            result.report.elapsed_ms,
-            result.report.missing_loose,
-            result.report.missing_pak,
-        ));
-
-        let (current_order, pins) = {
-            let Some(profile) = self.library.active_profile() else {
-                self.status = "SigiLink Intelligent Ranking skipped: no profile".to_string();
-                self.sigillink_force_preview = false;
-                self.sigillink_preview_notice = None;
-                return;
-            };
-            if result.order.len() != profile.order.len() {
-                self.status = "SigiLink Intelligent Ranking skipped: incomplete order".to_string();
-                self.log_warn("SigiLink Intelligent Ranking skipped: incomplete order".to_string());
-                self.sigillink_force_preview = false;
-                self.sigillink_preview_notice = None;
-                return;
-            }
-            let pins = if self.app_config.sigillink_ranking_enabled {
-                profile.sigillink_pins.clone()
-            } else {
-                HashMap::new()
-            };
-            (profile.order.clone(), pins)
+    fn update_sigillink_inputs_hash(&mut self) {
+        let Some(hash) = self.sigillink_inputs_hash() else {
+            return;
         };
-
-        let proposed = if self.app_config.sigillink_ranking_enabled {
-            Self::apply_sigillink_pins(result.order, &pins)
-        } else {
-            result.order
+        let Some(profile) = self.sigillink_ranking_profile_mut() else {
+            return;
         };
-
-        let mod_map = self.library.index_by_id();
-        let mut current_index = HashMap::new();
-        for (index, entry) in current_order.iter().enumerate() {
-            current_index.insert(entry.id.clone(), index);
+        if profile.sigillink_meta.last_inputs_hash.as_deref() == Some(hash.as_str()) {
+            return;
         }
-        let mut proposed_index = HashMap::new();
-        for (index, entry) in proposed.iter().enumerate() {
-            proposed_index.insert(entry.id.clone(), index);
+        profile.sigillink_meta.last_inputs_hash = Some(hash);
+        if self.allow_persistence() {
+            let _ = self.library.save(&self.config.data_dir);
         }
-        let mut moves = Vec::new();
-        for (id, from) in current_index {
-            let Some(to) = proposed_index.get(&id).copied() else {
-                continue;
-            };
-            if from == to {
-                continue;
+    }
+
+    fn sigillink_effective_pins(
+        profile: &Profile,
+        excluded: &HashSet<String>,
+        respect_pins: bool,
+    ) -> HashMap<String, usize> {
+        let mut pins = if respect_pins {
+            profile.sigillink_pins.clone()
+        } else {
+            HashMap::new()
+        };
+        for (index, entry) in profile.order.iter().enumerate() {
+            if excluded.contains(&entry.id) {
+                pins.insert(entry.id.clone(), index);
             }
-            let (name, created_at, added_at) = if let Some(mod_entry) = mod_map.get(&id) {
-                (
-                    mod_entry.display_name(),
-                    mod_entry.created_at,
-                    mod_entry.added_at,
-                )
-            } else {
-                (id.clone(), None, 0)
-            };
-            moves.push(SmartRankMove {
-                name,
-                from,
-                to,
-                created_at,
-                added_at,
-            });
         }
-        moves.sort_by_key(|entry| entry.from);
-
-        let mut report = result.report;
-        report.moved = moves.len();
-        self.update_sigillink_inputs_hash();
-        let missing_items = self.refresh_sigillink_missing_paks();
+        pins
+    }
 
-        if moves.is_empty() {
-            for warning in result.warnings {
-                self.log_warn(warning);
-            }
-            let notice = self.sigillink_preview_notice.take();
-            self.sigillink_force_preview = false;
-            if let Some(notice) = notice {
-                self.status = format!("{notice} (no changes)");
+    fn apply_sigillink_pins(
+        order: Vec<ProfileEntry>,
+        pins: &HashMap<String, usize>,
+    ) -> Vec<ProfileEntry> {
+        if pins.is_empty() {
+            return order;
+        }
+        let mut base = Vec::with_capacity(order.len());
+        let mut pinned: Vec<(usize, usize, ProfileEntry)> = Vec::new();
+        for (index, entry) in order.into_iter().enumerate() {
+            if let Some(pin_index) = pins.get(&entry.id).copied() {
+                pinned.push((pin_index, index, entry));
             } else {
-                self.status = "SigiLink Intelligent Ranking: no changes".to_string();
+                base.push(entry);
             }
-            self.open_sigillink_missing_queue(SigilLinkMissingTrigger::Auto, missing_items);
-            return;
         }
+        pinned.sort_by(|(pin_a, idx_a, _), (pin_b, idx_b, _)| {
+            pin_a.cmp(pin_b).then_with(|| idx_b.cmp(idx_a))
+        });
+        for (pin_index, _, entry) in pinned {
+            let insert_at = pin_index.min(base.len());
+            base.insert(insert_at, entry);
+        }
+        base
+    }
 
-        let preview = SmartRankPreview {
-            proposed,
-            report,
-            moves,
-            warnings: result.warnings,
-            explain: result.explain,
+    fn set_sigillink_pin(&mut self, mod_id: &str, index: usize) {
+        let Some(profile) = self.library.active_profile_mut() else {
+            return;
         };
-        self.smart_rank_scroll = 0;
-        self.smart_rank_view = SmartRankView::Changes;
-        self.sigillink_force_preview = false;
-        match mode {
-            SmartRankMode::Auto => {
-                self.smart_rank_preview = Some(preview);
-                self.apply_smart_rank_preview();
-            }
-            SmartRankMode::Preview => {
-                self.smart_rank_preview = Some(preview);
-                self.status = "SigiLink Intelligent Ranking preview ready".to_string();
-            }
-            SmartRankMode::Warmup => {}
+        profile.sigillink_pins.insert(mod_id.to_string(), index);
+        if self.allow_persistence() {
+            let _ = self.library.save(&self.config.data_dir);
         }
     }
 
-    pub fn apply_smart_rank_preview(&mut self) {
-        let Some(preview) = self.smart_rank_preview.take() else {
-            return;
+    pub fn clear_sigillink_pin(&mut self, mod_id: &str) -> bool {
+        let Some(profile) = self.library.active_profile_mut() else {
+            return false;
         };
-        self.smart_rank_scroll = 0;
-        self.smart_rank_view = SmartRankView::Changes;
-        let Some(profile) = self.library.active_profile() else {
-            self.status = "SigiLink Intelligent Ranking skipped: no profile".to_string();
-            self.sigillink_preview_notice = None;
+        let removed = profile.sigillink_pins.remove(mod_id).is_some();
+        if removed && self.allow_persistence() {
+            let _ = self.library.save(&self.config.data_dir);
+        }
+        removed
+    }
+
+    fn clear_all_sigillink_pins(&mut self) {
+        let Some(profile) = self.library.active_profile_mut() else {
             return;
         };
-        if preview.proposed.len() != profile.order.len() {
-            self.status = "SigiLink Intelligent Ranking skipped: incomplete order".to_string();
-            self.sigillink_preview_notice = None;
+        if profile.sigillink_pins.is_empty() {
+            self.status = "SigiLink pins already reset".to_string();
             return;
         }
-        let proposed = preview.proposed.clone();
-        let moved = preview.report.moved;
-        let total = preview.report.total;
-        let missing = preview.report.missing;
-        let pins = self.sigillink_pin_count();
-        let file_overrides = profile.file_overrides.clone();
-        if let Some(profile) = self.library.active_profile_mut() {
-            profile.order = proposed.clone();
-        }
-        let inputs_hash = self.sigillink_inputs_hash();
-        if let Some(rank_profile) = self.sigillink_ranking_profile_mut() {
-            rank_profile.order = proposed.clone();
-            rank_profile.file_overrides = file_overrides;
-            rank_profile.sigillink_meta.last_ranked_at = Some(now_timestamp());
-            rank_profile.sigillink_meta.last_moves = moved;
-            rank_profile.sigillink_meta.last_pins = pins;
-            rank_profile.sigillink_meta.last_inputs_hash = inputs_hash;
+        profile.sigillink_pins.clear();
+        if self.allow_persistence() {
+            let _ = self.library.save(&self.config.data_dir);
         }
-        if let Err(err) = self.library.save(&self.config.data_dir) {
-            self.status = format!("SigiLink ranking save failed: {err}");
-            self.log_error(format!("SigiLink ranking save failed: {err}"));
+        self.status = "SigiLink pins reset".to_string();
+        self.set_toast(
+            "SigiLink pins reset",
+            ToastLevel::Info,
+            Duration::from_secs(2),
+        );
+    }
+
+    pub fn prompt_clear_sigillink_pins(&mut self) {
+        if self.dialog.is_some() {
             return;
         }
-        if missing > 0 {
-            self.status = format!(
-                "SigiLink Intelligent Ranking applied: {moved}/{total} (missing {missing})"
+        if self.sigillink_pin_count() == 0 {
+            self.status = "SigiLink pins already reset".to_string();
+            self.set_toast(
+                "SigiLink pins already reset",
+                ToastLevel::Info,
+                Duration::from_secs(2),
             );
-        } else {
-            self.status = format!("SigiLink Intelligent Ranking applied: {moved}/{total} mod(s)");
-        }
-        if preview.report.conflicts > 0 {
-            self.log_info(format!(
-                "SigiLink ranking analyzed {} conflict set(s)",
-                preview.report.conflicts
-            ));
-        }
-        for warning in preview.warnings {
-            self.log_warn(warning);
+            return;
         }
-        self.queue_auto_deploy("sigillink ranking");
-        self.sigillink_preview_notice = None;
-        self.maybe_prompt_sigillink_missing_paks(SigilLinkMissingTrigger::Auto);
+        self.open_dialog(Dialog {
+            title: "Reset all SigiLink pins?".to_string(),
+            message: "This will clear manual SigiLink pins in the current profile.".to_string(),
+            yes_label: "Reset".to_string(),
+            no_label: "Cancel".to_string(),
+            choice: DialogChoice::No,
+            kind: DialogKind::SigilLinkClearPins,
+            toggle: None,
+            toggle_alt: None,
+            scroll: 0,
+        });
     }
 
-    pub fn cancel_smart_rank_preview(&mut self) {
-        if self.smart_rank_preview.take().is_some() {
-            self.status = "SigiLink Intelligent Ranking canceled".to_string();
+    fn maybe_prompt_sigillink_pin_notice(&mut self, mod_id: &str) {
+        if self.dialog.is_some() || self.app_config.sigillink_pin_notice_dismissed {
+            return;
         }
-        self.smart_rank_scroll = 0;
-        self.smart_rank_view = SmartRankView::Changes;
-        self.sigillink_preview_notice = None;
-        self.sigillink_force_preview = false;
-        self.maybe_prompt_sigillink_missing_paks(SigilLinkMissingTrigger::Auto);
+        let name = self
+            .library
+            .mods
+            .iter()
+            .find(|entry| entry.id == mod_id)
+            .map(|entry| entry.display_name())
+            .unwrap_or_else(|| "Selected mod".to_string());
+        let message = format!(
+            "\"{name}\" is now unlinked from SigiLink auto ranking.\n\
+SigiLink will not move it automatically, but other mods may shift around it.\n\
+Use Ctrl+R to reset this mod or F12 to reset all pins."
+        );
+        self.open_dialog(Dialog {
+            title: "SigiLink Manual Pin".to_string(),
+            message,
+            yes_label: "OK".to_string(),
+            no_label: "Cancel".to_string(),
+            choice: DialogChoice::Yes,
+            kind: DialogKind::SigilLinkPinNotice,
+            toggle: Some(DialogToggle {
+                label: "Don't show again".to_string(),
+                checked: false,
+            }),
+            toggle_alt: None,
+            scroll: 0,
+        });
     }
 
-    pub fn conflicts_scanning(&self) -> bool {
-        self.conflict_active
+    fn request_sigillink_auto_rank(&mut self) {
+        if !self.app_config.sigillink_ranking_enabled {
+            return;
+        }
+        self.sigillink_rank_pending_import = true;
+        self.sigillink_rank_debounce_until =
+            Some(Instant::now() + Duration::from_secs(SIGILLINK_AUTO_RANK_DEBOUNCE_SECS));
+        self.maybe_start_sigillink_rank_pending();
     }
 
-    pub fn conflicts_pending(&self) -> bool {
-        self.conflict_pending
+    fn compute_missing_dependency_blocks(&self) -> HashSet<String> {
+        if !self.dependency_cache_ready {
+            return HashSet::new();
+        }
+        let lookup = DependencyLookup::new(&self.library.mods);
+        let mut missing = HashSet::new();
+        for mod_entry in &self.library.mods {
+            if self.missing_dependency_count_for_mod(mod_entry, &lookup) > 0 {
+                missing.insert(mod_entry.id.clone());
+            }
+        }
+        missing
     }
 
-    pub fn deploy_active(&self) -> bool {
-        self.deploy_active
+    fn refresh_dependency_blocks(&mut self) -> HashSet<String> {
+        let missing = self.compute_missing_dependency_blocks();
+        if missing != self.library.dependency_blocks {
+            self.library.dependency_blocks = missing.clone();
+            let _ = self.library.save(&self.config.data_dir);
+        }
+        missing
     }
 
-    pub fn deploy_pending(&self) -> bool {
-        self.deploy_pending
+    fn run_startup_dependency_check(&mut self) {
+        if !self.startup_dependency_check_pending {
+            return;
+        }
+        if !self.dependency_cache_ready {
+            return;
+        }
+        self.startup_dependency_check_pending = false;
+        let missing_blocks = self.refresh_dependency_blocks();
+        let Some(profile) = self.library.active_profile() else {
+            return;
+        };
+        let mut to_disable = Vec::new();
+        let mut disabled_names = Vec::new();
+        for entry in profile.order.iter().filter(|entry| entry.enabled) {
+            if !entry.enabled {
+                continue;
+            }
+            let Some(mod_entry) = self
+                .library
+                .mods
+                .iter()
+                .find(|mod_entry| mod_entry.id == entry.id)
+            else {
+                continue;
+            };
+            if missing_blocks.contains(&mod_entry.id) {
+                to_disable.push(entry.id.clone());
+                disabled_names.push(mod_entry.display_name());
+            }
+        }
+        if to_disable.is_empty() {
+            return;
+        }
+        let changed = self.set_mods_enabled_in_active(&to_disable, false);
+        if changed == 0 {
+            return;
+        }
+        self.status = format!("Startup: disabled {changed} mod(s) missing dependencies");
+        self.log_warn(format!(
+            "Startup: disabled {changed} mod(s) missing dependencies"
+        ));
+        self.schedule_smart_rank_refresh(
+            smart_rank::SmartRankRefreshMode::Incremental,
+            "startup dependency disable",
+            true,
+        );
+        self.queue_auto_deploy("startup dependency disable");
+        if self.app_config.show_startup_dependency_notice {
+            self.prompt_startup_dependency_notice(disabled_names);
+        }
     }
 
-    pub fn deploy_reason_contains(&self, needle: &str) -> bool {
-        self.deploy_reason
-            .as_deref()
-            .map(|reason| reason.contains(needle))
-            .unwrap_or(false)
+    fn maybe_start_metadata_refresh(&mut self) {
+        if self.metadata_cache_valid() {
+            self.log_info("Metadata cache valid; skipping refresh".to_string());
+            self.prime_dependency_cache_from_library();
+            self.run_startup_dependency_check();
+            self.schedule_smart_rank_warmup();
+            return;
+        }
+        if self.automation_paused {
+            self.automation_metadata_deferred = true;
+            self.log_info("Metadata refresh deferred: automation paused".to_string());
+            return;
+        }
+        self.start_metadata_refresh();
     }
 
-    pub fn is_busy(&self) -> bool {
-        self.startup_pending
-            || self.native_sync_active
-            || self.import_active.is_some()
-            || self.import_apply_active
-            || self.deploy_active
-            || self.deploy_pending
-            || self.conflict_active
-            || self.conflict_pending
-            || self.smart_rank_active
-            || self.metadata_active
+    fn metadata_cache_valid(&self) -> bool {
+        if self.library.metadata_cache_version != METADATA_CACHE_VERSION {
+            return false;
+        }
+        let Some(expected) = self.library.metadata_cache_key.as_deref() else {
+            return false;
+        };
+        expected == self.metadata_cache_key()
     }
 
-    pub fn startup_pending(&self) -> bool {
-        self.startup_pending
+    fn metadata_cache_key(&self) -> String {
+        let mut hasher = Hasher::new();
+        hasher.update(b"metadata-cache-v1");
+        let mut mods: Vec<&ModEntry> = self.library.mods.iter().collect();
+        mods.sort_by(|a, b| a.id.cmp(&b.id));
+        for mod_entry in mods {
+            hash_metadata_inputs(&mut hasher, mod_entry);
+        }
+        hasher.finalize().to_hex().to_string()
     }
 
-    #[allow(dead_code)]
-    pub fn override_swap_info(&self) -> Option<OverrideSwapInfo> {
-        if self.focus != Focus::Conflicts {
-            return None;
+    fn maybe_save_metadata_progress(&mut self) {
+        if !self.metadata_dirty || self.metadata_unsaved < METADATA_SAVE_EVERY {
+            return;
         }
-        if let Some(pending) = self.pending_overrides.get(&self.conflict_selected) {
-            return Some(OverrideSwapInfo {
-                from: pending.from.clone(),
-                to: pending.to.clone(),
-            });
+        if let Some(last_saved) = self.metadata_last_saved {
+            if last_saved.elapsed() < Duration::from_millis(METADATA_SAVE_DEBOUNCE_MS) {
+                return;
+            }
         }
-        let Some(info) = self.override_swap.as_ref() else {
-            return None;
-        };
-        Some(OverrideSwapInfo {
-            from: info.from.clone(),
-            to: info.to.clone(),
-        })
+        if self.allow_persistence() {
+            let _ = self.library.save(&self.config.data_dir);
+        }
+        self.metadata_dirty = false;
+        self.metadata_unsaved = 0;
+        self.metadata_last_saved = Some(Instant::now());
     }
 
-    pub fn explorer_items(&self) -> Vec<ExplorerItem> {
-        let mut items = Vec::new();
-
-        for game_id in game::supported_games() {
-            let expanded = self.explorer_game_expanded.contains(&game_id);
-            let active = self.game_id == game_id;
-            items.push(ExplorerItem {
-                kind: ExplorerItemKind::Game(game_id),
-                label: game_id.display_name().to_string(),
-                depth: 0,
-                active,
-                expanded,
-                disabled: false,
-                renaming: false,
-            });
-
-            if !expanded {
-                continue;
-            }
-
-            let profiles_expanded = self.explorer_profiles_expanded.contains(&game_id);
-            items.push(ExplorerItem {
-                kind: ExplorerItemKind::ProfilesHeader(game_id),
-                label: "Profiles".to_string(),
-                depth: 1,
-                active: false,
-                expanded: profiles_expanded,
-                disabled: !active,
-                renaming: false,
-            });
-
-            if !profiles_expanded {
-                continue;
-            }
-
-            if active {
-                for profile in &self.library.profiles {
-                    if is_sigillink_ranking_profile(&profile.name) {
-                        continue;
-                    }
-                    let mut label = profile.name.clone();
-                    let mut renaming = false;
-                    if let Some((original, buffer)) = self.rename_preview() {
-                        if original == profile.name {
-                            renaming = true;
-                            let trimmed = buffer.trim();
-                            label = if trimmed.is_empty() {
-                                "<new name>".to_string()
-                            } else {
-                                buffer
-                            };
-                        }
-                    }
-                    items.push(ExplorerItem {
-                        kind: ExplorerItemKind::Profile {
-                            name: profile.name.clone(),
-                        },
-                        label,
-                        depth: 2,
-                        active: profile.name == self.library.active_profile,
-                        expanded: false,
-                        disabled: false,
-                        renaming,
-                    });
-                }
-                items.push(ExplorerItem {
-                    kind: ExplorerItemKind::NewProfile(game_id),
-                    label: "New Profile...".to_string(),
-                    depth: 2,
-                    active: false,
-                    expanded: false,
-                    disabled: false,
-                    renaming: false,
-                });
+    fn prime_dependency_cache_from_library(&mut self) {
+        self.dependency_cache.clear();
+        for mod_entry in &self.library.mods {
+            if !mod_entry.dependencies.is_empty() {
+                self.dependency_cache
+                    .insert(mod_entry.id.clone(), mod_entry.dependencies.clone());
             } else {
-                items.push(ExplorerItem {
-                    kind: ExplorerItemKind::Info(game_id),
-                    label: "Select game to view profiles".to_string(),
-                    depth: 2,
-                    active: false,
-                    expanded: false,
-                    disabled: true,
-                    renaming: false,
-                });
+                self.dependency_cache
+                    .insert(mod_entry.id.clone(), Vec::new());
             }
         }
-
-        items
-    }
-
-    pub fn explorer_selected_item(&self) -> Option<ExplorerItem> {
-        let items = self.explorer_items();
-        items.get(self.explorer_selected).cloned()
+        self.dependency_cache_ready = true;
+        self.refresh_dependency_blocks();
     }
 
-    pub fn explorer_move_up(&mut self) {
-        if self.explorer_selected == 0 {
+    fn schedule_smart_rank_warmup(&mut self) {
+        if !self.paths_ready() {
             return;
         }
-        self.explorer_selected -= 1;
-    }
-
-    pub fn explorer_move_down(&mut self) {
-        let len = self.explorer_items().len();
-        if self.explorer_selected + 1 >= len {
-            return;
+        if self.smart_rank_cache.is_none() {
+            self.load_smart_rank_cache();
         }
-        self.explorer_selected += 1;
-    }
-
-    pub fn explorer_activate(&mut self) -> Result<()> {
-        let Some(item) = self.explorer_selected_item() else {
-            return Ok(());
-        };
-
-        match item.kind {
-            ExplorerItemKind::Game(game_id) => {
-                if game_id == self.game_id {
-                    self.toggle_game_expanded(game_id);
+        let profile_key = self.smart_rank_profile_key();
+        let mut desired = smart_rank::SmartRankRefreshMode::Full;
+        if let Some(cache) = &self.smart_rank_cache {
+            if cache.version == SMART_RANK_CACHE_VERSION {
+                if cache.result.is_some()
+                    && cache.profile_key == profile_key
+                    && self.smart_rank_cache_ready(cache)
+                {
+                    self.smart_rank_refresh_pending = None;
+                    self.smart_rank_refresh_at = None;
+                    self.status = "SigiLink Intelligent Ranking warmup cached".to_string();
+                    self.log_info("SigiLink ranking warmup: cache hit".to_string());
+                    return;
+                }
+                if cache.profile_key == profile_key {
+                    desired = smart_rank::SmartRankRefreshMode::Incremental;
+                } else if cache.result.is_some() && self.smart_rank_cache_ready(cache) {
+                    desired = smart_rank::SmartRankRefreshMode::ReorderOnly;
                 } else {
-                    self.set_active_game(game_id)?;
+                    desired = smart_rank::SmartRankRefreshMode::Incremental;
                 }
             }
-            ExplorerItemKind::ProfilesHeader(game_id) => {
-                self.toggle_profiles_expanded(game_id);
-            }
-            ExplorerItemKind::Profile { name, .. } => {
-                self.set_active_profile(&name)?;
-            }
-            ExplorerItemKind::NewProfile(_) => {
-                self.enter_create_profile();
-            }
-            ExplorerItemKind::Info(_) => {}
         }
+        self.smart_rank_refresh_pending = Some(desired);
+        self.smart_rank_refresh_at = None;
+    }
 
-        Ok(())
+    fn smart_rank_cache_ready(&self, cache: &SmartRankCache) -> bool {
+        Self::smart_rank_cache_ready_for(&self.library, cache)
+            && cache
+                .result
+                .as_ref()
+                .is_some_and(|result| result.weights == self.app_config.sigillink_weights)
     }
 
-    pub fn explorer_toggle_collapse(&mut self) {
-        let Some(item) = self.explorer_selected_item() else {
-            return;
+    fn smart_rank_cache_ready_for(library: &Library, cache: &SmartRankCache) -> bool {
+        if cache.version != SMART_RANK_CACHE_VERSION {
+            return false;
+        }
+        if cache.result.is_none() {
+            return false;
+        }
+        Self::smart_rank_mod_cache_warm_for(library, &cache.mod_cache)
+    }
+
+    fn smart_rank_mod_cache_warm_for(
+        library: &Library,
+        mod_cache: &smart_rank::SmartRankCacheData,
+    ) -> bool {
+        let Some(profile) = library.active_profile() else {
+            return false;
         };
-        match item.kind {
-            ExplorerItemKind::Game(game_id) => {
-                self.explorer_game_expanded.remove(&game_id);
-            }
-            ExplorerItemKind::ProfilesHeader(game_id) => {
-                self.explorer_profiles_expanded.remove(&game_id);
+        for entry in profile.order.iter().filter(|entry| entry.enabled) {
+            let Some(mod_entry) = library
+                .mods
+                .iter()
+                .find(|mod_entry| mod_entry.id == entry.id)
+            else {
+                continue;
+            };
+            let key = smart_rank::mod_cache_key(mod_entry);
+            match mod_cache.mods.get(&entry.id) {
+                Some(entry_cache) if entry_cache.key == key && entry_cache.has_data => {}
+                _ => return false,
             }
-            _ => {}
         }
+        true
     }
 
-    pub fn explorer_toggle_expand(&mut self) {
-        let Some(item) = self.explorer_selected_item() else {
-            return;
+    fn try_dry_smart_rank(&mut self, mode: SmartRankMode, profile_key: String) -> bool {
+        let Some(cache) = &self.smart_rank_cache else {
+            return false;
         };
-        match item.kind {
-            ExplorerItemKind::Game(game_id) => {
-                self.explorer_game_expanded.insert(game_id);
-            }
-            ExplorerItemKind::ProfilesHeader(game_id) => {
-                self.explorer_profiles_expanded.insert(game_id);
+        if cache.version != SMART_RANK_CACHE_VERSION
+            || !Self::smart_rank_mod_cache_warm_for(&self.library, &cache.mod_cache)
+        {
+            return false;
+        }
+        let mod_cache = cache.mod_cache.clone();
+        let computed = match smart_rank::smart_rank_profile_cached_with_progress(
+            &self.config,
+            &self.library,
+            Some(&mod_cache),
+            smart_rank::SmartRankRefreshMode::ReorderOnly,
+            self.app_config.sigillink_weights,
+            |_| {},
+        ) {
+            Ok(computed) => computed,
+            Err(err) => {
+                self.log_warn(format!("SigiLink dry rank failed, scanning instead: {err}"));
+                return false;
             }
-            _ => {}
+        };
+        if computed.scanned_mods > 0 {
+            return false;
         }
+        let result = computed.result;
+        self.log_info(format!(
+            "SigiLink ranking: dry rank from warm cache in {}ms",
+            result.report.elapsed_ms
+        ));
+        self.smart_rank_cache = Some(SmartRankCache {
+            version: SMART_RANK_CACHE_VERSION,
+            profile_key,
+            mod_cache: computed.cache,
+            result: Some(result.clone()),
+        });
+        self.maybe_save_smart_rank_cache(true);
+        self.clear_smart_rank_scan_state();
+        self.finalize_smart_rank_preview(result, mode);
+        true
     }
 
-    fn toggle_game_expanded(&mut self, game_id: GameId) {
-        if !self.explorer_game_expanded.insert(game_id) {
-            self.explorer_game_expanded.remove(&game_id);
-        }
+    #[allow(dead_code)]
+    fn smart_rank_cache_missing_ids(&self, cache: &SmartRankCache) -> Vec<String> {
+        Self::smart_rank_cache_missing_ids_for(&self.library, cache)
     }
 
-    fn toggle_profiles_expanded(&mut self, game_id: GameId) {
-        if !self.explorer_profiles_expanded.insert(game_id) {
-            self.explorer_profiles_expanded.remove(&game_id);
+    #[allow(dead_code)]
+    fn smart_rank_cache_missing_ids_for(library: &Library, cache: &SmartRankCache) -> Vec<String> {
+        let mut missing = Vec::new();
+        let Some(profile) = library.active_profile() else {
+            return missing;
+        };
+        for entry in &profile.order {
+            let Some(mod_entry) = library
+                .mods
+                .iter()
+                .find(|mod_entry| mod_entry.id == entry.id)
+            else {
+                continue;
+            };
+            let key = smart_rank::mod_cache_key(mod_entry);
+            match cache.mod_cache.mods.get(&entry.id) {
+                Some(entry_cache) if entry_cache.key == key && entry_cache.has_data => {}
+                _ => missing.push(entry.id.clone()),
+            }
         }
+        missing
     }
 
-    pub fn cycle_focus(&mut self) {
-        let next_focus = match self.focus {
-            Focus::Explorer => Focus::Mods,
-            Focus::Mods => Focus::Conflicts,
-            Focus::Conflicts => Focus::Log,
-            Focus::Log => Focus::Explorer,
-        };
-        self.set_focus(next_focus);
-        self.status = match self.focus {
-            Focus::Explorer => "Focus: explorer".to_string(),
-            Focus::Mods => "Focus: mod stack".to_string(),
-            Focus::Conflicts => "Focus: overrides".to_string(),
-            Focus::Log => "Focus: log".to_string(),
-        };
-    }
-
-    pub fn focus_mods(&mut self) {
-        if self.focus != Focus::Mods {
-            self.set_focus(Focus::Mods);
+    fn merge_smart_rank_refresh_kind(
+        &self,
+        current: smart_rank::SmartRankRefreshMode,
+        next: smart_rank::SmartRankRefreshMode,
+    ) -> smart_rank::SmartRankRefreshMode {
+        use smart_rank::SmartRankRefreshMode;
+        match (current, next) {
+            (SmartRankRefreshMode::Full, _) | (_, SmartRankRefreshMode::Full) => {
+                SmartRankRefreshMode::Full
+            }
+            (SmartRankRefreshMode::Incremental, _) | (_, SmartRankRefreshMode::Incremental) => {
+                SmartRankRefreshMode::Incremental
+            }
+            _ => SmartRankRefreshMode::ReorderOnly,
         }
     }
 
-    fn set_focus(&mut self, focus: Focus) {
-        if self.focus != focus {
-            self.hotkey_pending_focus = Some(focus);
-            self.hotkey_transition_at = Some(Instant::now());
-            self.hotkey_fade_until = None;
+    fn schedule_smart_rank_refresh(
+        &mut self,
+        kind: smart_rank::SmartRankRefreshMode,
+        reason: &str,
+        debounce: bool,
+    ) {
+        if matches!(kind, smart_rank::SmartRankRefreshMode::Full) {
+            self.smart_rank_cache = None;
+            self.clear_smart_rank_cache_file();
         }
-        self.focus = focus;
-        self.move_mode = false;
-    }
-
-    pub fn set_active_game(&mut self, game_id: GameId) -> Result<()> {
-        if game_id == self.game_id {
-            return Ok(());
+        self.interrupt_smart_rank(reason);
+        let next = match self.smart_rank_refresh_pending.take() {
+            Some(current) => self.merge_smart_rank_refresh_kind(current, kind),
+            None => kind,
+        };
+        self.smart_rank_refresh_pending = Some(next);
+        if debounce {
+            self.smart_rank_refresh_at = Some(
+                Instant::now() + Duration::from_millis(self.app_config.smart_rank_debounce_ms),
+            );
+        } else {
+            self.smart_rank_refresh_at = None;
         }
+    }
 
-        if self.import_active.is_some()
-            || self.import_apply_active
-            || self.deploy_active
-            || self.deploy_pending
-        {
-            self.status = "Game switch blocked: active tasks".to_string();
-            self.log_warn("Game switch blocked: active tasks".to_string());
-            return Ok(());
+    fn resolve_smart_rank_refresh_kind(
+        &self,
+        requested: smart_rank::SmartRankRefreshMode,
+    ) -> smart_rank::SmartRankRefreshMode {
+        if matches!(requested, smart_rank::SmartRankRefreshMode::Full) {
+            return requested;
         }
-        if self.conflict_active || self.conflict_pending {
-            self.status = "Game switch blocked: override scan running".to_string();
-            self.log_warn("Game switch blocked: override scan running".to_string());
-            return Ok(());
+        let Some(cache) = &self.smart_rank_cache else {
+            return smart_rank::SmartRankRefreshMode::Full;
+        };
+        if cache.version != SMART_RANK_CACHE_VERSION {
+            return smart_rank::SmartRankRefreshMode::Full;
         }
-
-        self.library.save(&self.config.data_dir)?;
-        self.config.save()?;
-
-        let mut config = GameConfig::load_or_create(game_id)?;
-        let mut library = Library::load_or_create(&config.data_dir)?;
-        library.ensure_mods_in_profiles();
-        if !config.active_profile.is_empty()
-            && library
-                .profiles
-                .iter()
-                .any(|profile| profile.name == config.active_profile)
+        if matches!(requested, smart_rank::SmartRankRefreshMode::ReorderOnly)
+            && !self.smart_rank_cache_ready(cache)
         {
-            library.active_profile = config.active_profile.clone();
-        } else {
-            config.active_profile = library.active_profile.clone();
+            return smart_rank::SmartRankRefreshMode::Incremental;
         }
-        library.save(&config.data_dir)?;
-        config.save()?;
-
-        self.game_id = game_id;
-        self.config = config;
-        self.library = library;
-        self.app_config.active_game = game_id;
-        self.app_config.save()?;
-        self.log_path = self.config.data_dir.join("sigilsmith.log");
-        self.explorer_game_expanded.insert(game_id);
-        self.explorer_profiles_expanded.insert(game_id);
-        self.explorer_selected = 0;
-        self.conflicts.clear();
-        self.conflict_selected = 0;
-
-        self.selected = 0;
-        self.set_focus(Focus::Mods);
-        self.status = format!("Active game: {}", game_id.display_name());
-        self.log_info(format!("Active game: {}", game_id.display_name()));
-        self.ensure_setup();
-        self.run_native_sync_inline();
-        self.queue_conflict_scan("game changed");
-        Ok(())
-    }
-
-    pub fn enter_create_profile(&mut self) {
-        self.move_mode = false;
-        self.input_mode = InputMode::Editing {
-            prompt: "New profile name".to_string(),
-            buffer: String::new(),
-            purpose: InputPurpose::CreateProfile,
-            auto_submit: false,
-            last_edit_at: Instant::now(),
-        };
-        self.status = "Profile: enter new profile name".to_string();
-    }
-
-    pub fn enter_rename_profile(&mut self, original: &str) {
-        self.move_mode = false;
-        self.input_mode = InputMode::Editing {
-            prompt: "Rename profile".to_string(),
-            buffer: original.to_string(),
-            purpose: InputPurpose::RenameProfile {
-                original: original.to_string(),
-            },
-            auto_submit: false,
-            last_edit_at: Instant::now(),
-        };
-        self.status = "Profile: enter new name".to_string();
-    }
-
-    pub fn enter_duplicate_profile(&mut self, source: &str) {
-        let suggested = self.unique_profile_name(&format!("{source} Copy"));
-        self.move_mode = false;
-        self.input_mode = InputMode::Editing {
-            prompt: "Duplicate profile".to_string(),
-            buffer: suggested,
-            purpose: InputPurpose::DuplicateProfile {
-                source: source.to_string(),
-            },
-            auto_submit: false,
-            last_edit_at: Instant::now(),
-        };
-        self.status = "Profile: enter duplicated profile name".to_string();
+        requested
     }
 
-    pub fn enter_export_profile(&mut self, profile: &str) {
-        self.move_mode = false;
-        self.open_export_menu(profile);
+    fn prompt_startup_dependency_notice(&mut self, disabled: Vec<String>) {
+        if disabled.is_empty() || self.dialog.is_some() {
+            return;
+        }
+        let total = disabled.len();
+        let mut lines = Vec::new();
+        lines.push(format!("Disabled {total} mod(s) missing dependencies."));
+        lines.push(String::new());
+        for (index, name) in disabled.iter().enumerate() {
+            lines.push(format!("{}. {name}", index + 1));
+        }
+        self.open_dialog(Dialog {
+            title: "Dependencies missing".to_string(),
+            message: lines.join("\n"),
+            yes_label: "OK".to_string(),
+            no_label: "Hide next time".to_string(),
+            choice: DialogChoice::Yes,
+            kind: DialogKind::StartupDependencyNotice,
+            toggle: None,
+            toggle_alt: None,
+            scroll: 0,
+        });
     }
 
-    pub fn enter_import_profile(&mut self) {
-        self.move_mode = false;
-        self.open_path_browser(PathBrowserPurpose::ImportProfile);
+    fn interrupt_smart_rank(&mut self, reason: &str) {
+        if !self.smart_rank_active {
+            return;
+        }
+        self.smart_rank_interrupt = true;
+        self.smart_rank_progress = None;
+        self.status = "SigiLink Intelligent Ranking paused for changes".to_string();
+        self.log_info(format!("SigiLink ranking interrupted: {reason}"));
     }
 
-    fn open_export_menu(&mut self, profile: &str) {
-        self.export_menu = Some(ExportMenu {
-            selected: 0,
-            profile: profile.to_string(),
-        });
-        self.status = "Export mod list".to_string();
+    fn clear_smart_rank_scan_state(&mut self) {
+        self.smart_rank_active = false;
+        self.smart_rank_progress = None;
+        self.smart_rank_mode = None;
+        self.smart_rank_refresh_kind = None;
+        self.smart_rank_interrupt = false;
+        self.smart_rank_scan_active = None;
+        self.smart_rank_scan_profile_key = None;
     }
 
-    pub(crate) fn close_export_menu(&mut self) {
-        self.export_menu = None;
+    fn allow_persistence(&self) -> bool {
+        #[cfg(debug_assertions)]
+        {
+            return !self.debug_suppress_persistence;
+        }
+        #[cfg(not(debug_assertions))]
+        {
+            return true;
+        }
     }
 
-    pub fn open_export_path_browser(&mut self, profile: &str, kind: ExportKind) {
-        self.move_mode = false;
-        self.open_path_browser(PathBrowserPurpose::ExportProfile {
-            profile: profile.to_string(),
-            kind,
-        });
+    fn smart_rank_scan_matches(&self, scan_id: u64) -> bool {
+        self.smart_rank_scan_active == Some(scan_id)
     }
 
-    fn normalize_profile_name(name: &str) -> String {
-        name.trim().to_string()
+    fn smart_rank_scan_profile_matches(&self, profile_key: &str) -> bool {
+        self.smart_rank_scan_profile_key.as_deref() == Some(profile_key)
     }
 
-    fn sanitize_filename_component(value: &str) -> String {
-        let mut out = String::new();
-        for ch in value.trim().chars() {
-            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' {
-                out.push(ch);
-            } else {
-                out.push('_');
+    fn maybe_restart_smart_rank(&mut self) {
+        let Some(pending) = self.smart_rank_refresh_pending else {
+            return;
+        };
+        if let Some(ready_at) = self.smart_rank_refresh_at {
+            if Instant::now() < ready_at {
+                return;
             }
         }
+        if self.smart_rank_active || self.automation_paused || self.is_busy() {
+            return;
+        }
+        if !self.paths_ready() {
+            return;
+        }
+        self.smart_rank_refresh_pending = None;
+        self.smart_rank_refresh_at = None;
+        let refresh = self.resolve_smart_rank_refresh_kind(pending);
+        self.start_smart_rank_scan(SmartRankMode::Warmup, refresh);
+    }
+
+    fn start_smart_rank_scan(
+        &mut self,
+        mode: SmartRankMode,
+        refresh: smart_rank::SmartRankRefreshMode,
+    ) {
+        if self.smart_rank_active {
+            return;
+        }
+        let profile_key = self.smart_rank_profile_key();
+        if let Some(cache) = &self.smart_rank_cache {
+            if cache.profile_key == profile_key && self.smart_rank_cache_ready(cache) {
+                if let Some(result) = cache.result.clone() {
+                    let report_cache =
+                        matches!(mode, SmartRankMode::Report).then(|| cache.mod_cache.clone());
+                    self.clear_smart_rank_scan_state();
+                    match mode {
+                        SmartRankMode::Auto | SmartRankMode::Preview => {
+                            self.finalize_smart_rank_preview(result, mode);
+                        }
+                        SmartRankMode::Warmup => {
+                            self.log_info("SigiLink ranking warmup: cache hit".to_string());
+                            self.status = "SigiLink Intelligent Ranking warmup cached".to_string();
+                        }
+                        SmartRankMode::Report => {
+                            if let Some(cache_data) = report_cache {
+                                self.show_compatibility_report(&cache_data);
+                            }
+                        }
+                    }
+                    return;
+                }
+            }
+            self.log_info("SigiLink ranking cache miss (profile change)".to_string());
+        }
+        if matches!(mode, SmartRankMode::Auto | SmartRankMode::Preview)
+            && !matches!(refresh, smart_rank::SmartRankRefreshMode::Full)
+            && self.try_dry_smart_rank(mode, profile_key.clone())
+        {
+            return;
+        }
+        self.smart_rank_scan_id = self.smart_rank_scan_id.wrapping_add(1);
+        let scan_id = self.smart_rank_scan_id;
+        self.smart_rank_scan_active = Some(scan_id);
+        self.smart_rank_scan_profile_key = Some(profile_key.clone());
+        self.smart_rank_mode = Some(mode);
+        self.smart_rank_active = true;
+        self.smart_rank_refresh_kind = Some(refresh);
+        self.smart_rank_progress = None;
+        self.smart_rank_view = SmartRankView::Changes;
+        self.smart_rank_scroll = 0;
+        self.status = match mode {
+            SmartRankMode::Auto | SmartRankMode::Preview => {
+                "SigiLink Intelligent Ranking: scanning...".to_string()
+            }
+            SmartRankMode::Warmup => "SigiLink Intelligent Ranking: warmup scan...".to_string(),
+            SmartRankMode::Report => "Compatibility report: scanning...".to_string(),
+        };
+        self.log_info("SigiLink ranking scan started".to_string());
+
+        let config = self.config.clone();
+        let library = self.library.clone();
+        let cache_data = self
+            .smart_rank_cache
+            .as_ref()
+            .map(|cache| cache.mod_cache.clone());
+        let weights = self.app_config.sigillink_weights;
+        let tx = self.smart_rank_tx.clone();
+        thread::spawn(move || {
+            let result = smart_rank::smart_rank_profile_cached_with_progress(
+                &config,
+                &library,
+                cache_data.as_ref(),
+                refresh,
+                weights,
+                |progress| {
+                    let _ = tx.send(SmartRankMessage::Progress { scan_id, progress });
+                },
+            );
+            match result {
+                Ok(result) => {
+                    let _ = tx.send(SmartRankMessage::Finished {
+                        scan_id,
+                        computed: result,
+                    });
+                }
+                Err(err) => {
+                    let _ = tx.send(SmartRankMessage::Failed {
+                        scan_id,
+                        error: err.to_string(),
+                    });
+                }
+            }
+        });
+    }
+
+    fn finalize_smart_rank_preview(
+        &mut self,
+        result: smart_rank::SmartRankResult,
+        mode: SmartRankMode,
+    ) {
+        let scope = self.sigillink_rank_scope.take();
+        self.smart_rank_active = false;
+        self.smart_rank_progress = None;
+        self.smart_rank_mode = None;
+        self.smart_rank_refresh_kind = None;
+        self.smart_rank_interrupt = false;
+        self.smart_rank_scan_active = None;
+        self.smart_rank_scan_profile_key = None;
+
+        self.log_info(format!(
+            "SigiLink ranking scan: loose {}/{} pak {}/{} in {}ms (missing loose {}, pak {})",
+            result.report.scanned_loose,
+            result.report.enabled_loose,
+            result.report.scanned_pak,
+            result.report.enabled_pak,
+            result.report.elapsed_ms,
+            result.report.missing_loose,
+            result.report.missing_pak,
+        ));
+
+        let (current_order, pins) = {
+            let Some(profile) = self.library.active_profile() else {
+                self.status = "SigiLink Intelligent Ranking skipped: no profile".to_string();
+                self.sigillink_force_preview = false;
+                self.sigillink_preview_notice = None;
+                return;
+            };
+            if result.order.len() != profile.order.len() {
+                self.status = "SigiLink Intelligent Ranking skipped: incomplete order".to_string();
+                self.log_warn("SigiLink Intelligent Ranking skipped: incomplete order".to_string());
+                self.sigillink_force_preview = false;
+                self.sigillink_preview_notice = None;
+                return;
+            }
+            let pins = Self::sigillink_effective_pins(
+                profile,
+                &self.library.sigillink_excluded,
+                self.app_config.sigillink_ranking_enabled,
+            );
+            (profile.order.clone(), pins)
+        };
+
+        let proposed = if let Some(scope) = scope.as_ref() {
+            Self::merge_scoped_rank(&current_order, &result.order, scope)
+        } else {
+            Self::apply_sigillink_pins(result.order, &pins)
+        };
+
+        let mod_map = self.library.index_by_id();
+        let mut current_index = HashMap::new();
+        for (index, entry) in current_order.iter().enumerate() {
+            current_index.insert(entry.id.clone(), index);
+        }
+        let mut proposed_index = HashMap::new();
+        for (index, entry) in proposed.iter().enumerate() {
+            proposed_index.insert(entry.id.clone(), index);
+        }
+        let mut moves = Vec::new();
+        for (id, from) in current_index {
+            let Some(to) = proposed_index.get(&id).copied() else {
+                continue;
+            };
+            if from == to {
+                continue;
+            }
+            let (name, created_at, added_at) = if let Some(mod_entry) = mod_map.get(&id) {
+                (
+                    mod_entry.display_name(),
+                    mod_entry.created_at,
+                    mod_entry.added_at,
+                )
+            } else {
+                (id.clone(), None, 0)
+            };
+            moves.push(SmartRankMove {
+                name,
+                from,
+                to,
+                created_at,
+                added_at,
+            });
+        }
+        moves.sort_by_key(|entry| entry.from);
+
+        let mut report = result.report;
+        report.moved = moves.len();
+        self.update_sigillink_inputs_hash();
+        let missing_items = self.refresh_sigillink_missing_paks();
+
+        if moves.is_empty() {
+            for warning in result.warnings {
+                self.log_warn(warning);
+            }
+            let notice = self.sigillink_preview_notice.take();
+            self.sigillink_force_preview = false;
+            if let Some(notice) = notice {
+                self.status = format!("{notice} (no changes)");
+            } else {
+                self.status = "SigiLink Intelligent Ranking: no changes".to_string();
+            }
+            self.open_sigillink_missing_queue(SigilLinkMissingTrigger::Auto, missing_items);
+            return;
+        }
+
+        let preview = SmartRankPreview {
+            proposed,
+            report,
+            moves,
+            warnings: result.warnings,
+            explain: result.explain,
+        };
+        self.smart_rank_scroll = 0;
+        self.smart_rank_view = SmartRankView::Changes;
+        self.sigillink_force_preview = false;
+        match mode {
+            SmartRankMode::Auto => {
+                self.smart_rank_preview = Some(preview);
+                self.apply_smart_rank_preview();
+            }
+            SmartRankMode::Preview => {
+                self.smart_rank_preview = Some(preview);
+                self.status = "SigiLink Intelligent Ranking preview ready".to_string();
+            }
+            SmartRankMode::Warmup | SmartRankMode::Report => {}
+        }
+    }
+
+    pub fn apply_smart_rank_preview(&mut self) {
+        if self.block_locked_profile("SigiLink reorder") {
+            self.smart_rank_preview = None;
+            self.sigillink_preview_notice = None;
+            return;
+        }
+        let Some(preview) = self.smart_rank_preview.take() else {
+            return;
+        };
+        self.smart_rank_scroll = 0;
+        self.smart_rank_view = SmartRankView::Changes;
+        let Some(profile) = self.library.active_profile() else {
+            self.status = "SigiLink Intelligent Ranking skipped: no profile".to_string();
+            self.sigillink_preview_notice = None;
+            return;
+        };
+        if preview.proposed.len() != profile.order.len() {
+            self.status = "SigiLink Intelligent Ranking skipped: incomplete order".to_string();
+            self.sigillink_preview_notice = None;
+            return;
+        }
+        let proposed = preview.proposed.clone();
+        let moved = preview.report.moved;
+        let total = preview.report.total;
+        let missing = preview.report.missing;
+        let pins = self.sigillink_pin_count();
+        let file_overrides = profile.file_overrides.clone();
+        let snapshot = SigilLinkRankSnapshot {
+            profile: profile.name.clone(),
+            ranked_at: now_timestamp(),
+            moves: moved,
+            before: profile.order.clone(),
+            after: proposed.clone(),
+        };
+        if let Some(profile) = self.library.active_profile_mut() {
+            profile.order = proposed.clone();
+        }
+        let inputs_hash = self.sigillink_inputs_hash();
+        if let Some(rank_profile) = self.sigillink_ranking_profile_mut() {
+            rank_profile.order = proposed.clone();
+            rank_profile.file_overrides = file_overrides;
+            rank_profile.sigillink_meta.push_history(snapshot);
+            rank_profile.sigillink_meta.last_ranked_at = Some(now_timestamp());
+            rank_profile.sigillink_meta.last_moves = moved;
+            rank_profile.sigillink_meta.last_pins = pins;
+            rank_profile.sigillink_meta.last_inputs_hash = inputs_hash;
+        }
+        if let Err(err) = self.library.save(&self.config.data_dir) {
+            self.status = format!("SigiLink ranking save failed: {err}");
+            self.log_error(format!("SigiLink ranking save failed: {err}"));
+            return;
+        }
+        if missing > 0 {
+            self.status = format!(
+                "SigiLink Intelligent Ranking applied: {moved}/{total} (missing {missing})"
+            );
+        } else {
+            self.status = format!("SigiLink Intelligent Ranking applied: {moved}/{total} mod(s)");
+        }
+        if preview.report.conflicts > 0 {
+            self.log_info(format!(
+                "SigiLink ranking analyzed {} conflict set(s)",
+                preview.report.conflicts
+            ));
+        }
+        for warning in preview.warnings {
+            self.log_warn(warning);
+        }
+        self.queue_auto_deploy("sigillink ranking");
+        self.sigillink_preview_notice = None;
+        self.maybe_prompt_sigillink_missing_paks(SigilLinkMissingTrigger::Auto);
+    }
+
+    pub fn cancel_smart_rank_preview(&mut self) {
+        if self.smart_rank_preview.take().is_some() {
+            self.status = "SigiLink Intelligent Ranking canceled".to_string();
+        }
+        self.smart_rank_scroll = 0;
+        self.smart_rank_view = SmartRankView::Changes;
+        self.sigillink_preview_notice = None;
+        self.sigillink_force_preview = false;
+        self.maybe_prompt_sigillink_missing_paks(SigilLinkMissingTrigger::Auto);
+    }
+
+    pub fn conflicts_scanning(&self) -> bool {
+        self.conflict_active
+    }
+
+    pub fn conflicts_pending(&self) -> bool {
+        self.conflict_pending
+    }
+
+    pub fn deploy_active(&self) -> bool {
+        self.deploy_active
+    }
+
+    pub fn deploy_pending(&self) -> bool {
+        self.deploy_pending
+    }
+
+    pub fn deploy_reason_contains(&self, needle: &str) -> bool {
+        self.deploy_reason
+            .as_deref()
+            .map(|reason| reason.contains(needle))
+            .unwrap_or(false)
+    }
+
+    pub fn is_busy(&self) -> bool {
+        self.startup_pending
+            || self.native_sync_active
+            || self.import_active.is_some()
+            || self.import_apply_active
+            || self.deploy_active
+            || self.deploy_pending
+            || self.conflict_active
+            || self.conflict_pending
+            || self.smart_rank_active
+            || self.metadata_active
+            || self.cache_restage_active
+    }
+
+    pub fn startup_pending(&self) -> bool {
+        self.startup_pending
+    }
+
+    #[allow(dead_code)]
+    pub fn override_swap_info(&self) -> Option<OverrideSwapInfo> {
+        if self.focus != Focus::Conflicts {
+            return None;
+        }
+        if let Some(pending) = self.pending_overrides.get(&self.conflict_selected) {
+            return Some(OverrideSwapInfo {
+                from: pending.from.clone(),
+                to: pending.to.clone(),
+            });
+        }
+        let Some(info) = self.override_swap.as_ref() else {
+            return None;
+        };
+        Some(OverrideSwapInfo {
+            from: info.from.clone(),
+            to: info.to.clone(),
+        })
+    }
+
+    pub fn explorer_items(&self) -> Vec<ExplorerItem> {
+        let mut items = Vec::new();
+
+        for game_id in game::supported_games() {
+            let expanded = self.explorer_game_expanded.contains(&game_id);
+            let active = self.game_id == game_id;
+            items.push(ExplorerItem {
+                kind: ExplorerItemKind::Game(game_id),
+                label: game_id.display_name().to_string(),
+                depth: 0,
+                active,
+                expanded,
+                disabled: false,
+                renaming: false,
+            });
+
+            if !expanded {
+                continue;
+            }
+
+            let profiles_expanded = self.explorer_profiles_expanded.contains(&game_id);
+            items.push(ExplorerItem {
+                kind: ExplorerItemKind::ProfilesHeader(game_id),
+                label: "Profiles".to_string(),
+                depth: 1,
+                active: false,
+                expanded: profiles_expanded,
+                disabled: !active,
+                renaming: false,
+            });
+
+            if !profiles_expanded {
+                continue;
+            }
+
+            if active {
+                for profile in self.explorer_profiles() {
+                    let mut label = profile.name.clone();
+                    if self.config.default_profile.as_deref() == Some(profile.name.as_str()) {
+                        label.push_str(" (default)");
+                    }
+                    if profile.locked {
+                        label.push_str(" 🔒");
+                    }
+                    let mut renaming = false;
+                    if let Some((original, buffer)) = self.rename_preview() {
+                        if original == profile.name {
+                            renaming = true;
+                            let trimmed = buffer.trim();
+                            label = if trimmed.is_empty() {
+                                "<new name>".to_string()
+                            } else {
+                                buffer
+                            };
+                        }
+                    }
+                    items.push(ExplorerItem {
+                        kind: ExplorerItemKind::Profile {
+                            name: profile.name.clone(),
+                        },
+                        label,
+                        depth: 2,
+                        active: profile.name == self.library.active_profile,
+                        expanded: false,
+                        disabled: false,
+                        renaming,
+                    });
+                }
+                items.push(ExplorerItem {
+                    kind: ExplorerItemKind::NewProfile(game_id),
+                    label: "New Profile...".to_string(),
+                    depth: 2,
+                    active: false,
+                    expanded: false,
+                    disabled: false,
+                    renaming: false,
+                });
+            } else {
+                items.push(ExplorerItem {
+                    kind: ExplorerItemKind::Info(game_id),
+                    label: "Select game to view profiles".to_string(),
+                    depth: 2,
+                    active: false,
+                    expanded: false,
+                    disabled: true,
+                    renaming: false,
+                });
+            }
+        }
+
+        items
+    }
+
+    fn explorer_profiles(&self) -> Vec<&Profile> {
+        let mut profiles: Vec<&Profile> = self
+            .library
+            .profiles
+            .iter()
+            .filter(|profile| !is_sigillink_ranking_profile(&profile.name))
+            .collect();
+        if self.app_config.sort_profiles_alphabetically {
+            profiles.sort_by_key(|profile| profile.name.to_lowercase());
+        }
+        profiles
+    }
+
+    pub fn move_profile(&mut self, name: &str, up: bool) -> Result<()> {
+        if self.app_config.sort_profiles_alphabetically {
+            self.status = "Profiles are sorted A-Z; turn off sorting to reorder".to_string();
+            self.set_toast(
+                "Profiles sorted A-Z (Settings)",
+                ToastLevel::Warn,
+                Duration::from_secs(3),
+            );
+            return Ok(());
+        }
+        let visible: Vec<usize> = self
+            .library
+            .profiles
+            .iter()
+            .enumerate()
+            .filter(|(_, profile)| !is_sigillink_ranking_profile(&profile.name))
+            .map(|(index, _)| index)
+            .collect();
+        let Some(pos) = visible
+            .iter()
+            .position(|index| self.library.profiles[*index].name == name)
+        else {
+            return Ok(());
+        };
+        let target = if up {
+            pos.checked_sub(1)
+        } else {
+            Some(pos + 1).filter(|next| *next < visible.len())
+        };
+        let Some(target) = target else {
+            return Ok(());
+        };
+        self.library.profiles.swap(visible[pos], visible[target]);
+        self.library.save(&self.config.data_dir)?;
+        if let Some(index) = self.explorer_items().iter().position(|item| {
+            matches!(&item.kind, ExplorerItemKind::Profile { name: item_name } if item_name == name)
+        }) {
+            self.explorer_selected = index;
+        }
+        self.status = format!("Profile moved: {name}");
+        Ok(())
+    }
+
+    pub fn toggle_show_mod_size_column(&mut self) -> Result<()> {
+        self.app_config.show_mod_size_column = !self.app_config.show_mod_size_column;
+        self.app_config.save()?;
+        let state = if self.app_config.show_mod_size_column {
+            "shown"
+        } else {
+            "hidden"
+        };
+        self.status = format!("Mod size column {state}");
+        Ok(())
+    }
+
+    pub fn toggle_sort_profiles_alphabetically(&mut self) -> Result<()> {
+        let selected = match self.explorer_selected_item() {
+            Some(ExplorerItem {
+                kind: ExplorerItemKind::Profile { name },
+                ..
+            }) => Some(name),
+            _ => None,
+        };
+        self.app_config.sort_profiles_alphabetically =
+            !self.app_config.sort_profiles_alphabetically;
+        self.app_config.save()?;
+        if let Some(name) = selected {
+            if let Some(index) = self.explorer_items().iter().position(|item| {
+                matches!(&item.kind, ExplorerItemKind::Profile { name: item_name } if *item_name == name)
+            }) {
+                self.explorer_selected = index;
+            }
+        }
+        let state = if self.app_config.sort_profiles_alphabetically {
+            "enabled"
+        } else {
+            "disabled"
+        };
+        self.status = format!("Sort profiles A-Z {state}");
+        Ok(())
+    }
+
+    pub fn explorer_selected_item(&self) -> Option<ExplorerItem> {
+        let items = self.explorer_items();
+        items.get(self.explorer_selected).cloned()
+    }
+
+    pub fn explorer_move_up(&mut self) {
+        if self.explorer_selected == 0 {
+            return;
+        }
+        self.explorer_selected -= 1;
+    }
+
+    pub fn explorer_move_down(&mut self) {
+        let len = self.explorer_items().len();
+        if self.explorer_selected + 1 >= len {
+            return;
+        }
+        self.explorer_selected += 1;
+    }
+
+    pub fn explorer_jump(&mut self, delta: isize) {
+        let last = self.explorer_items().len().saturating_sub(1) as isize;
+        self.explorer_selected = (self.explorer_selected as isize)
+            .saturating_add(delta)
+            .clamp(0, last) as usize;
+    }
+
+    pub fn explorer_activate(&mut self) -> Result<()> {
+        let Some(item) = self.explorer_selected_item() else {
+            return Ok(());
+        };
+
+        match item.kind {
+            ExplorerItemKind::Game(game_id) => {
+                if game_id == self.game_id {
+                    self.toggle_game_expanded(game_id);
+                } else {
+                    self.set_active_game(game_id)?;
+                }
+            }
+            ExplorerItemKind::ProfilesHeader(game_id) => {
+                self.toggle_profiles_expanded(game_id);
+            }
+            ExplorerItemKind::Profile { name, .. } => {
+                self.set_active_profile(&name)?;
+            }
+            ExplorerItemKind::NewProfile(_) => {
+                self.enter_create_profile();
+            }
+            ExplorerItemKind::Info(_) => {}
+        }
+
+        Ok(())
+    }
+
+    pub fn explorer_toggle_collapse(&mut self) {
+        let Some(item) = self.explorer_selected_item() else {
+            return;
+        };
+        match item.kind {
+            ExplorerItemKind::Game(game_id) => {
+                self.explorer_game_expanded.remove(&game_id);
+            }
+            ExplorerItemKind::ProfilesHeader(game_id) => {
+                self.explorer_profiles_expanded.remove(&game_id);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn explorer_toggle_expand(&mut self) {
+        let Some(item) = self.explorer_selected_item() else {
+            return;
+        };
+        match item.kind {
+            ExplorerItemKind::Game(game_id) => {
+                self.explorer_game_expanded.insert(game_id);
+            }
+            ExplorerItemKind::ProfilesHeader(game_id) => {
+                self.explorer_profiles_expanded.insert(game_id);
+            }
+            _ => {}
+        }
+    }
+
+    fn toggle_game_expanded(&mut self, game_id: GameId) {
+        if !self.explorer_game_expanded.insert(game_id) {
+            self.explorer_game_expanded.remove(&game_id);
+        }
+    }
+
+    fn toggle_profiles_expanded(&mut self, game_id: GameId) {
+        if !self.explorer_profiles_expanded.insert(game_id) {
+            self.explorer_profiles_expanded.remove(&game_id);
+        }
+    }
+
+    pub fn cycle_focus(&mut self) {
+        let next_focus = match self.focus {
+            Focus::Explorer => Focus::Mods,
+            Focus::Mods => Focus::Conflicts,
+            Focus::Conflicts => Focus::Log,
+            Focus::Log => Focus::Explorer,
+        };
+        if self.prompt_pending_move(PendingMoveTarget::Focus(next_focus)) {
+            return;
+        }
+        self.set_focus(next_focus);
+        self.status = match self.focus {
+            Focus::Explorer => "Focus: explorer".to_string(),
+            Focus::Mods => "Focus: mod stack".to_string(),
+            Focus::Conflicts => "Focus: overrides".to_string(),
+            Focus::Log => "Focus: log".to_string(),
+        };
+    }
+
+    pub fn focus_mods(&mut self) {
+        if self.focus != Focus::Mods {
+            self.set_focus(Focus::Mods);
+        }
+    }
+
+    fn set_focus(&mut self, focus: Focus) {
+        if self.focus != focus {
+            self.hotkey_pending_focus = Some(focus);
+            self.hotkey_transition_at = Some(Instant::now());
+            self.hotkey_fade_until = None;
+        }
+        self.focus = focus;
+        self.move_mode = false;
+    }
+
+    pub fn set_active_game(&mut self, game_id: GameId) -> Result<()> {
+        if game_id == self.game_id {
+            return Ok(());
+        }
+        if self.prompt_pending_move(PendingMoveTarget::Game(game_id)) {
+            return Ok(());
+        }
+
+        if self.import_active.is_some()
+            || self.import_apply_active
+            || self.deploy_active
+            || self.deploy_pending
+        {
+            self.status = "Game switch blocked: active tasks".to_string();
+            self.log_warn("Game switch blocked: active tasks".to_string());
+            return Ok(());
+        }
+        if self.conflict_active || self.conflict_pending {
+            self.status = "Game switch blocked: override scan running".to_string();
+            self.log_warn("Game switch blocked: override scan running".to_string());
+            return Ok(());
+        }
+
+        self.library.save(&self.config.data_dir)?;
+        self.config.save()?;
+
+        let mut config = GameConfig::load_or_create(game_id)?;
+        config.refresh_game_version();
+        let mut library = Library::load_or_create(&config.data_dir)?;
+        let duplicate_profile_entries = library.dedup_profile_orders();
+        library.ensure_mods_in_profiles();
+        let default_profile = config
+            .default_profile
+            .clone()
+            .filter(|name| library.profiles.iter().any(|profile| &profile.name == name));
+        if let Some(name) = default_profile {
+            library.active_profile = name.clone();
+            config.active_profile = name;
+        } else if !config.active_profile.is_empty()
+            && library
+                .profiles
+                .iter()
+                .any(|profile| profile.name == config.active_profile)
+        {
+            library.active_profile = config.active_profile.clone();
+        } else {
+            config.active_profile = library.active_profile.clone();
+        }
+        library.save(&config.data_dir)?;
+        config.save()?;
+
+        self.game_id = game_id;
+        self.config = config;
+        self.library = library;
+        self.app_config.active_game = game_id;
+        self.app_config.save()?;
+        self.log_path = self.config.data_dir.join("sigilsmith.log");
+        self.deployed_state_hash =
+            deploy::load_last_report(&self.config.data_dir).and_then(|report| report.state_hash);
+        self.mark_deploy_state_dirty();
+        self.log_profile_duplicates(&duplicate_profile_entries);
+        self.explorer_game_expanded.insert(game_id);
+        self.explorer_profiles_expanded.insert(game_id);
+        self.explorer_selected = 0;
+        self.conflicts.clear();
+        self.ignored_conflicts.clear();
+        self.conflict_selected = 0;
+
+        self.selected = 0;
+        self.set_focus(Focus::Mods);
+        self.status = format!("Active game: {}", game_id.display_name());
+        self.log_info(format!("Active game: {}", game_id.display_name()));
+        self.ensure_setup();
+        self.run_native_sync_inline();
+        self.queue_conflict_scan("game changed");
+        Ok(())
+    }
+
+    pub fn enter_create_profile(&mut self) {
+        self.move_mode = false;
+        self.input_mode = InputMode::Editing {
+            prompt: "New profile name".to_string(),
+            buffer: String::new(),
+            purpose: InputPurpose::CreateProfile,
+            auto_submit: false,
+            last_edit_at: Instant::now(),
+        };
+        self.status = "Profile: enter new profile name".to_string();
+    }
+
+    pub fn enter_rename_profile(&mut self, original: &str) {
+        self.move_mode = false;
+        self.input_mode = InputMode::Editing {
+            prompt: "Rename profile".to_string(),
+            buffer: original.to_string(),
+            purpose: InputPurpose::RenameProfile {
+                original: original.to_string(),
+            },
+            auto_submit: false,
+            last_edit_at: Instant::now(),
+        };
+        self.status = "Profile: enter new name".to_string();
+    }
+
+    pub fn enter_rename_mod(&mut self) {
+        let Some(id) = self.selected_profile_id() else {
+            return;
+        };
+        let Some(mod_entry) = self
+            .library
+            .mods
+            .iter()
+            .find(|mod_entry| mod_entry.id == id)
+        else {
+            return;
+        };
+        let buffer = mod_entry.display_name();
+        self.move_mode = false;
+        self.input_mode = InputMode::Editing {
+            prompt: "Rename mod".to_string(),
+            buffer,
+            purpose: InputPurpose::RenameMod { id },
+            auto_submit: false,
+            last_edit_at: Instant::now(),
+        };
+        self.status = "Rename mod: enter a display name (empty to reset)".to_string();
+    }
+
+    fn rename_mod(&mut self, id: String, value: String) -> Result<()> {
+        let Some(mod_entry) = self
+            .library
+            .mods
+            .iter_mut()
+            .find(|mod_entry| mod_entry.id == id)
+        else {
+            self.status = "Rename failed: mod not found".to_string();
+            return Ok(());
+        };
+        let trimmed = value.trim();
+        let original = mod_entry.original_display_name();
+        let previous = mod_entry.display_name();
+        mod_entry.custom_name = if trimmed.is_empty() || trimmed == original {
+            None
+        } else {
+            Some(trimmed.to_string())
+        };
+        let current = mod_entry.display_name();
+        let reset = mod_entry.custom_name.is_none();
+        if current == previous {
+            self.status = "Rename: name unchanged".to_string();
+            return Ok(());
+        }
+        self.library.save(&self.config.data_dir)?;
+        let message = if reset {
+            format!("Mod name reset: {current}")
+        } else {
+            format!("Mod renamed: {previous} -> {current}")
+        };
+        self.status = message.clone();
+        self.log_info(message);
+        Ok(())
+    }
+
+    pub fn enter_duplicate_profile(&mut self, source: &str) {
+        if self.dialog.is_some() {
+            return;
+        }
+        self.open_dialog(Dialog {
+            title: "Duplicate Profile".to_string(),
+            message: format!(
+                "Duplicate \"{source}\" with all of its mods, or only the enabled ones?\nEnabled only keeps the enabled mods in order; the rest of the library is listed after them, disabled, without overrides or pins."
+            ),
+            yes_label: "All mods".to_string(),
+            no_label: "Enabled only".to_string(),
+            choice: DialogChoice::Yes,
+            kind: DialogKind::DuplicateProfile {
+                source: source.to_string(),
+            },
+            toggle: None,
+            toggle_alt: None,
+            scroll: 0,
+        });
+    }
+
+    fn enter_duplicate_profile_name(&mut self, source: &str, enabled_only: bool) {
+        let suffix = if enabled_only { "Enabled" } else { "Copy" };
+        let suggested = self.unique_profile_name(&format!("{source} {suffix}"));
+        self.move_mode = false;
+        self.input_mode = InputMode::Editing {
+            prompt: "Duplicate profile".to_string(),
+            buffer: suggested,
+            purpose: InputPurpose::DuplicateProfile {
+                source: source.to_string(),
+                enabled_only,
+            },
+            auto_submit: false,
+            last_edit_at: Instant::now(),
+        };
+        self.status = "Profile: enter duplicated profile name".to_string();
+    }
+
+    pub fn enter_export_profile(&mut self, profile: &str) {
+        self.move_mode = false;
+        self.open_export_menu(profile);
+    }
+
+    pub fn enter_import_profile(&mut self) {
+        self.move_mode = false;
+        self.open_path_browser(PathBrowserPurpose::ImportProfile);
+    }
+
+    fn open_export_menu(&mut self, profile: &str) {
+        self.export_menu = Some(ExportMenu {
+            selected: 0,
+            profile: profile.to_string(),
+        });
+        self.status = "Export mod list".to_string();
+    }
+
+    pub(crate) fn close_export_menu(&mut self) {
+        self.export_menu = None;
+    }
+
+    pub fn open_export_path_browser(&mut self, profile: &str, kind: ExportKind) {
+        self.move_mode = false;
+        self.open_path_browser(PathBrowserPurpose::ExportProfile {
+            profile: profile.to_string(),
+            kind,
+        });
+    }
+
+    fn normalize_profile_name(name: &str) -> String {
+        name.trim().to_string()
+    }
+
+    fn sanitize_filename_component(value: &str) -> String {
+        let mut out = String::new();
+        for ch in value.trim().chars() {
+            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' {
+                out.push(ch);
+            } else {
+                out.push('_');
+            }
+        }
+        out
+    }
+
+    fn profile_exists(&self, name: &str) -> bool {
+        self.library
+            .profiles
+            .iter()
+            .any(|profile| profile.name.eq_ignore_ascii_case(name))
+    }
+
+    fn unique_profile_name(&self, base: &str) -> String {
+        let base = base.trim();
+        if base.is_empty() {
+            return "Profile".to_string();
+        }
+        if !self.profile_exists(base) {
+            return base.to_string();
+        }
+        for idx in 2..1000 {
+            let candidate = format!("{base} ({idx})");
+            if !self.profile_exists(&candidate) {
+                return candidate;
+            }
+        }
+        format!("{base} (copy)")
+    }
+
+    pub fn create_profile(&mut self, name: String) -> Result<()> {
+        let name = Self::normalize_profile_name(&name);
+        if name.is_empty() {
+            self.status = "Profile name is required".to_string();
+            self.set_toast(
+                "Profile name required",
+                ToastLevel::Warn,
+                Duration::from_secs(3),
+            );
+            return Ok(());
+        }
+        if self.profile_exists(&name) {
+            self.status = format!("Profile already exists: {name}");
+            self.set_toast(
+                "Profile already exists",
+                ToastLevel::Warn,
+                Duration::from_secs(3),
+            );
+            return Ok(());
+        }
+
+        let mut profile = crate::library::Profile::new(&name);
+        let mod_ids: Vec<String> = self.library.mods.iter().map(|m| m.id.clone()).collect();
+        profile.ensure_mods(&mod_ids);
+        self.library.profiles.push(profile);
+        self.set_active_profile(&name)?;
+        self.log_info(format!("Profile created: {name}"));
+        self.set_toast(
+            &format!("Profile created: {name}"),
+            ToastLevel::Info,
+            Duration::from_secs(3),
+        );
+        Ok(())
+    }
+
+    pub fn rename_profile(&mut self, original: String, name: String) -> Result<()> {
+        let name = Self::normalize_profile_name(&name);
+        if name.is_empty() {
+            self.status = "Profile name is required".to_string();
+            self.set_toast(
+                "Profile name required",
+                ToastLevel::Warn,
+                Duration::from_secs(3),
+            );
+            return Ok(());
+        }
+        if original.eq_ignore_ascii_case(&name) {
+            self.status = "Profile name unchanged".to_string();
+            self.set_toast("Rename cancelled", ToastLevel::Warn, Duration::from_secs(3));
+            return Ok(());
+        }
+        if self.profile_exists(&name) {
+            self.status = format!("Profile already exists: {name}");
+            self.set_toast(
+                "Profile already exists",
+                ToastLevel::Warn,
+                Duration::from_secs(3),
+            );
+            return Ok(());
+        }
+
+        let mut renamed = false;
+        for profile in &mut self.library.profiles {
+            if profile.name == original {
+                profile.name = name.clone();
+                renamed = true;
+                break;
+            }
+        }
+        if !renamed {
+            self.status = "Profile not found".to_string();
+            self.set_toast(
+                "Profile not found",
+                ToastLevel::Warn,
+                Duration::from_secs(3),
+            );
+            return Ok(());
+        }
+
+        if self.library.active_profile == original {
+            self.library.active_profile = name.clone();
+        }
+        if self.config.default_profile.as_deref() == Some(original.as_str()) {
+            self.config.default_profile = Some(name.clone());
+        }
+        self.config.active_profile = self.library.active_profile.clone();
+        self.library.save(&self.config.data_dir)?;
+        self.config.save()?;
+        self.status = format!("Profile renamed: {name}");
+        self.log_info(format!("Profile renamed: {original} -> {name}"));
+        self.set_toast(
+            &format!("Profile renamed to {name}"),
+            ToastLevel::Info,
+            Duration::from_secs(3),
+        );
+        Ok(())
+    }
+
+    pub fn duplicate_profile(
+        &mut self,
+        source: String,
+        name: String,
+        enabled_only: bool,
+    ) -> Result<()> {
+        let name = Self::normalize_profile_name(&name);
+        if name.is_empty() {
+            self.status = "Profile name is required".to_string();
+            self.set_toast(
+                "Profile name required",
+                ToastLevel::Warn,
+                Duration::from_secs(3),
+            );
+            return Ok(());
+        }
+        if self.profile_exists(&name) {
+            self.status = format!("Profile already exists: {name}");
+            self.set_toast(
+                "Profile already exists",
+                ToastLevel::Warn,
+                Duration::from_secs(3),
+            );
+            return Ok(());
+        }
+
+        let Some(source_profile) = self
+            .library
+            .profiles
+            .iter()
+            .find(|profile| profile.name == source)
+            .cloned()
+        else {
+            self.status = "Profile not found".to_string();
+            self.set_toast(
+                "Profile not found",
+                ToastLevel::Warn,
+                Duration::from_secs(3),
+            );
+            return Ok(());
+        };
+
+        let mod_ids: Vec<String> = self.library.mods.iter().map(|m| m.id.clone()).collect();
+        let (copy, dropped) = source_profile.duplicate(&name, enabled_only, &mod_ids);
+        self.library.profiles.push(copy);
+        self.set_active_profile(&name)?;
+        if enabled_only {
+            self.log_info(format!(
+                "Profile duplicated (enabled only): {source} -> {name}, dropped {dropped} disabled"
+            ));
+        } else {
+            self.log_info(format!("Profile duplicated: {source} -> {name}"));
+        }
+        self.set_toast(
+            &format!("Profile duplicated: {name}"),
+            ToastLevel::Info,
+            Duration::from_secs(3),
+        );
+        Ok(())
+    }
+
+    pub fn prompt_delete_profile(&mut self, name: String) {
+        if self.dialog.is_some() {
+            return;
+        }
+
+        let message = String::new();
+        self.open_dialog(Dialog {
+            title: "Delete Profile".to_string(),
+            message,
+            yes_label: "Delete".to_string(),
+            no_label: "Cancel".to_string(),
+            choice: DialogChoice::No,
+            kind: DialogKind::DeleteProfile { name },
+            toggle: Some(DialogToggle {
+                label: "Don't ask again for this action?".to_string(),
+                checked: false,
+            }),
+            toggle_alt: None,
+            scroll: 0,
+        });
+    }
+
+    pub fn prompt_adopt_native_mod(&mut self) {
+        if self.dialog.is_some() {
+            return;
+        }
+        if self.block_mod_changes("adopt") {
+            return;
+        }
+        if let Some(reason) = self.busy_reason() {
+            self.status = format!("Adopt blocked: {reason}");
+            return;
+        }
+        let Some(id) = self.selected_profile_id() else {
+            self.status = "No mod selected".to_string();
+            return;
+        };
+        let Some(mod_entry) = self
+            .library
+            .mods
+            .iter()
+            .find(|mod_entry| mod_entry.id == id)
+        else {
+            self.status = "No mod selected".to_string();
+            return;
+        };
+        if !mod_entry.is_native() {
+            self.status = "Selected mod is already managed".to_string();
+            return;
+        }
+        let name = mod_entry.display_name();
+        self.open_dialog(Dialog {
+            title: "Adopt Native Mod".to_string(),
+            message: format!(
+                "Move \"{name}\" into the SigiLink cache and manage it like an imported mod?\n\nThe original pak is removed from the Mods folder; deploys will link it from the cache afterwards."
+            ),
+            yes_label: "Adopt".to_string(),
+            no_label: "Cancel".to_string(),
+            choice: DialogChoice::Yes,
+            kind: DialogKind::AdoptNativeMod { id, name },
+            toggle: None,
+            toggle_alt: None,
+            scroll: 0,
+        });
+    }
+
+    pub fn prompt_delete_mod(&mut self, id: String, name: String) {
+        if self.dialog.is_some() {
+            return;
+        }
+
+        let dependents = self.find_any_profile_dependents(&[id.clone()]);
+        let is_native = self
+            .library
+            .mods
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| entry.is_native())
+            .unwrap_or(false);
+        let default_choice = DialogChoice::No;
+        let (title, toggle) = if is_native {
+            ("Remove Native Mod".to_string(), None)
+        } else {
+            (
+                "Remove Mod".to_string(),
+                Some(DialogToggle {
+                    label: "Don't ask again for this action?".to_string(),
+                    checked: false,
+                }),
+            )
+        };
+        self.open_dialog(Dialog {
+            title,
+            message: String::new(),
+            yes_label: "Remove".to_string(),
+            no_label: "Remove & update cache".to_string(),
+            choice: default_choice,
+            kind: DialogKind::DeleteMod {
+                id,
+                name,
+                native: is_native,
+                dependents,
+            },
+            toggle,
+            toggle_alt: None,
+            scroll: 0,
+        });
+    }
+
+    fn queue_pending_delete(&mut self, id: String, name: String) {
+        self.pending_delete_mod = Some((id, name));
+        if !self.metadata_active && !self.dependency_cache_ready {
+            self.start_metadata_refresh();
+        }
+        self.status = "Checking dependencies...".to_string();
+        self.set_toast(
+            "Checking dependencies...",
+            ToastLevel::Info,
+            Duration::from_secs(2),
+        );
+    }
+
+    fn maybe_prompt_pending_delete(&mut self) {
+        if self.dialog.is_some() {
+            return;
+        }
+        let Some((id, name)) = self.pending_delete_mod.take() else {
+            return;
+        };
+        if self.library.mods.iter().any(|entry| entry.id == id) {
+            self.prompt_delete_mod(id, name);
+        }
+    }
+
+    fn dependency_reverse_map(
+        &self,
+        candidate_ids: &HashSet<String>,
+    ) -> HashMap<String, Vec<String>> {
+        let lookup = DependencyLookup::new(&self.library.mods);
+        let mut reverse: HashMap<String, Vec<String>> = HashMap::new();
+        for mod_entry in &self.library.mods {
+            if !candidate_ids.contains(&mod_entry.id) {
+                continue;
+            }
+            for dep in self.cached_mod_dependencies(mod_entry) {
+                let ids = resolved_dependency_ids(&lookup, &dep, mod_entry);
+                for id in ids {
+                    if candidate_ids.contains(&id) {
+                        reverse.entry(id).or_default().push(mod_entry.id.clone());
+                    }
+                }
+            }
+        }
+        for ids in reverse.values_mut() {
+            ids.sort();
+            ids.dedup();
+        }
+        reverse
+    }
+
+    fn find_enabled_dependents(
+        &self,
+        target_ids: &[String],
+        candidate_ids: &HashSet<String>,
+        enabled_ids: &HashSet<String>,
+    ) -> Vec<DependentMod> {
+        if target_ids.is_empty() || candidate_ids.is_empty() {
+            return Vec::new();
+        }
+        let reverse = self.dependency_reverse_map(candidate_ids);
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut queue: VecDeque<String> = VecDeque::new();
+        for id in target_ids {
+            if candidate_ids.contains(id) && visited.insert(id.clone()) {
+                queue.push_back(id.clone());
+            }
+        }
+        let mut dependent_ids: HashSet<String> = HashSet::new();
+        while let Some(current) = queue.pop_front() {
+            if let Some(children) = reverse.get(&current) {
+                for child in children {
+                    if visited.insert(child.clone()) {
+                        queue.push_back(child.clone());
+                        if enabled_ids.contains(child) {
+                            dependent_ids.insert(child.clone());
+                        }
+                    }
+                }
+            }
+        }
+        for id in target_ids {
+            dependent_ids.remove(id);
+        }
+        let mut out = Vec::new();
+        for id in dependent_ids {
+            if let Some(entry) = self.library.mods.iter().find(|entry| entry.id == id) {
+                out.push(DependentMod {
+                    id,
+                    name: entry.display_name(),
+                });
+            }
+        }
+        out.sort_by(|a, b| a.name.cmp(&b.name));
         out
     }
 
-    fn profile_exists(&self, name: &str) -> bool {
-        self.library
-            .profiles
-            .iter()
-            .any(|profile| profile.name.eq_ignore_ascii_case(name))
+    fn find_active_dependents(&self, target_ids: &[String]) -> Vec<DependentMod> {
+        let candidate_ids = self.active_profile_ids();
+        if candidate_ids.is_empty() {
+            return Vec::new();
+        }
+        let enabled_ids = self.active_profile_enabled_ids();
+        self.find_enabled_dependents(target_ids, &candidate_ids, &enabled_ids)
+    }
+
+    fn find_any_profile_dependents(&self, target_ids: &[String]) -> Vec<DependentMod> {
+        let candidate_ids: HashSet<String> = self
+            .library
+            .mods
+            .iter()
+            .map(|entry| entry.id.clone())
+            .collect();
+        if candidate_ids.is_empty() {
+            return Vec::new();
+        }
+        let enabled_ids = self.enabled_mod_ids_any_profile();
+        self.find_enabled_dependents(target_ids, &candidate_ids, &enabled_ids)
+    }
+
+    pub fn prompt_move_blocked(&mut self, resume_move_mode: bool) {
+        if self.dialog.is_some() {
+            return;
+        }
+        let mod_name = self
+            .selected_profile_id()
+            .and_then(|id| {
+                self.library
+                    .mods
+                    .iter()
+                    .find(|entry| entry.id == id)
+                    .map(|entry| entry.display_name())
+            })
+            .unwrap_or_else(|| "mod".to_string());
+        let mut message = String::new();
+        if !self.mod_sort.is_order_default() {
+            message.push_str(&format!(
+                "Can't move while sorting by {} ({}).\n",
+                self.mod_sort.column_label(),
+                self.mod_sort.direction_label()
+            ));
+        }
+        if !self.mod_filter.trim().is_empty() {
+            message.push_str("Can't move while search is active.\n");
+        }
+        if self.recent_filter {
+            message.push_str("Can't move while the recently added filter is active.\n");
+        }
+        if self.starred_filter {
+            message.push_str("Can't move while the starred filter is active.\n");
+        }
+        let clear_filter = self.mod_filter_active();
+        let suffix = if clear_filter {
+            "Switch to Order view and clear search"
+        } else {
+            "Switch to Order view"
+        };
+        message.push_str(&format!("{suffix} to move \"{mod_name}\"?"));
+
+        self.open_dialog(Dialog {
+            title: "Move requires Order".to_string(),
+            message,
+            yes_label: "Switch".to_string(),
+            no_label: "Cancel".to_string(),
+            choice: DialogChoice::Yes,
+            kind: DialogKind::MoveBlocked {
+                resume_move_mode,
+                clear_filter,
+            },
+            toggle: None,
+            toggle_alt: None,
+            scroll: 0,
+        });
+    }
+
+    pub fn prompt_cancel_import(&mut self) {
+        if self.dialog.is_some() {
+            return;
+        }
+        self.open_dialog(Dialog {
+            title: "Cancel Import".to_string(),
+            message: "Cancel this import and return to the main view?".to_string(),
+            yes_label: "Continue import".to_string(),
+            no_label: "Cancel import".to_string(),
+            choice: DialogChoice::No,
+            kind: DialogKind::CancelImport,
+            toggle: Some(DialogToggle {
+                label: "Remember import choice".to_string(),
+                checked: false,
+            }),
+            toggle_alt: None,
+            scroll: 0,
+        });
+    }
+
+    pub fn delete_profile(&mut self, name: String) -> Result<()> {
+        if self.library.profiles.len() <= 1 {
+            self.status = "Cannot delete the last profile".to_string();
+            self.set_toast(
+                "At least one profile is required",
+                ToastLevel::Warn,
+                Duration::from_secs(3),
+            );
+            return Ok(());
+        }
+        if !self
+            .library
+            .profiles
+            .iter()
+            .any(|profile| profile.name == name)
+        {
+            self.status = "Profile not found".to_string();
+            self.set_toast(
+                "Profile not found",
+                ToastLevel::Warn,
+                Duration::from_secs(3),
+            );
+            return Ok(());
+        }
+
+        let was_active = self.library.active_profile == name;
+        self.library.profiles.retain(|profile| profile.name != name);
+        if self.config.default_profile.as_deref() == Some(name.as_str()) {
+            self.config.default_profile = None;
+        }
+
+        if self.library.profiles.is_empty() {
+            self.library
+                .profiles
+                .push(crate::library::Profile::new("Default"));
+        }
+
+        if was_active {
+            self.library.active_profile = self.library.profiles[0].name.clone();
+            self.config.active_profile = self.library.active_profile.clone();
+            self.selected = 0;
+            self.move_mode = false;
+            self.schedule_smart_rank_warmup();
+            self.queue_auto_deploy("profile deleted");
+        }
+
+        self.library.save(&self.config.data_dir)?;
+        self.config.save()?;
+        self.status = format!("Profile deleted: {name}");
+        self.log_info(format!("Profile deleted: {name}"));
+        self.set_toast(
+            &format!("Profile deleted: {name}"),
+            ToastLevel::Info,
+            Duration::from_secs(3),
+        );
+        Ok(())
+    }
+
+    pub fn set_active_profile(&mut self, name: &str) -> Result<()> {
+        if !self.library.profiles.iter().any(|p| p.name == name) {
+            self.status = "Profile not found".to_string();
+            return Ok(());
+        }
+        if name != self.library.active_profile
+            && self.prompt_pending_move(PendingMoveTarget::Profile(name.to_string()))
+        {
+            return Ok(());
+        }
+        self.library.active_profile = name.to_string();
+        self.config.active_profile = name.to_string();
+        self.library.save(&self.config.data_dir)?;
+        self.config.save()?;
+        self.selected = 0;
+        self.move_mode = false;
+        self.status = format!("Profile loaded: {name}");
+        self.log_info(format!("Profile loaded: {name}"));
+        self.schedule_smart_rank_warmup();
+        self.queue_auto_deploy("profile changed");
+        self.refresh_sigillink_missing_paks();
+        Ok(())
+    }
+
+    pub fn conflict_move_up(&mut self) {
+        if self.conflict_selected == 0 {
+            return;
+        }
+        self.conflict_selected -= 1;
+    }
+
+    pub fn conflict_move_down(&mut self) {
+        if self.conflict_selected + 1 >= self.conflicts.len() {
+            return;
+        }
+        self.conflict_selected += 1;
+    }
+
+    pub fn conflict_jump(&mut self, delta: isize) {
+        let last = self.conflicts.len().saturating_sub(1) as isize;
+        self.conflict_selected = (self.conflict_selected as isize)
+            .saturating_add(delta)
+            .clamp(0, last) as usize;
+    }
+
+    pub fn cycle_conflict_winner(&mut self, delta: i32) {
+        let Some(conflict) = self.conflicts.get(self.conflict_selected).cloned() else {
+            return;
+        };
+        if conflict.candidates.is_empty() {
+            return;
+        }
+        let current_id = self
+            .pending_overrides
+            .get(&self.conflict_selected)
+            .map(|pending| pending.winner_id.as_str())
+            .unwrap_or(conflict.winner_id.as_str());
+        let current_index = conflict
+            .candidates
+            .iter()
+            .position(|candidate| candidate.mod_id == current_id)
+            .unwrap_or(0);
+        let len = conflict.candidates.len();
+        let next_index = if delta < 0 {
+            current_index.saturating_sub(1)
+        } else if delta > 0 {
+            (current_index + 1).min(len.saturating_sub(1))
+        } else {
+            current_index
+        };
+        let winner_id = conflict.candidates[next_index].mod_id.clone();
+        self.schedule_conflict_winner(winner_id);
+    }
+
+    pub fn select_conflict_candidate(&mut self, index: usize) {
+        let Some(conflict) = self.conflicts.get(self.conflict_selected) else {
+            return;
+        };
+        let Some(candidate) = conflict.candidates.get(index) else {
+            return;
+        };
+        self.schedule_conflict_winner(candidate.mod_id.clone());
+    }
+
+    pub fn apply_pending_override(&mut self) {
+        if self.pending_overrides.is_empty() {
+            return;
+        }
+        let pending = std::mem::take(&mut self.pending_overrides);
+        let mut items: Vec<PendingOverride> = pending.into_values().collect();
+        items.sort_by_key(|item| item.conflict_index);
+        for pending in items {
+            if pending.conflict_index >= self.conflicts.len() {
+                continue;
+            }
+            if let Err(err) = self.set_conflict_winner(pending.conflict_index, pending.winner_id) {
+                self.status = format!("Override failed: {err}");
+                self.log_error(format!("Override failed: {err}"));
+            }
+        }
+    }
+
+    pub fn clear_conflict_override(&mut self) {
+        if self.block_locked_profile("override") {
+            return;
+        }
+        let Some(conflict) = self.conflicts.get(self.conflict_selected).cloned() else {
+            return;
+        };
+        self.pending_overrides.remove(&self.conflict_selected);
+        if let Err(err) =
+            self.set_conflict_winner(self.conflict_selected, conflict.default_winner_id)
+        {
+            self.status = format!("Override failed: {err}");
+            self.log_error(format!("Override failed: {err}"));
+        }
+    }
+
+    pub fn prompt_clear_all_file_overrides(&mut self) {
+        if self.block_locked_profile("override") {
+            return;
+        }
+        let Some(profile) = self.library.active_profile() else {
+            return;
+        };
+        let count = profile.file_overrides.len();
+        let name = profile.name.clone();
+        if count == 0 {
+            self.status = "No file overrides to clear".to_string();
+            self.set_toast(
+                "No file overrides to clear",
+                ToastLevel::Info,
+                Duration::from_secs(2),
+            );
+            return;
+        }
+        self.open_dialog(Dialog {
+            title: "Clear All Overrides".to_string(),
+            message: format!(
+                "Clear {count} file override(s) on profile \"{name}\"?\nEvery conflict reverts to its load-order winner."
+            ),
+            yes_label: "Clear".to_string(),
+            no_label: "Cancel".to_string(),
+            choice: DialogChoice::No,
+            kind: DialogKind::ClearFileOverrides { profile: name },
+            toggle: None,
+            toggle_alt: None,
+            scroll: 0,
+        });
+    }
+
+    fn clear_all_file_overrides(&mut self, profile_name: &str) {
+        let Some(profile) = self
+            .library
+            .profiles
+            .iter_mut()
+            .find(|profile| profile.name == profile_name)
+        else {
+            return;
+        };
+        let count = profile.file_overrides.len();
+        profile.file_overrides.clear();
+        if let Err(err) = self.library.save(&self.config.data_dir) {
+            self.status = format!("Clear overrides failed: {err}");
+            self.log_error(format!("Clear overrides failed: {err}"));
+            return;
+        }
+        self.pending_overrides.clear();
+        self.override_swap = None;
+        if self.library.active_profile == profile_name {
+            for conflict in &mut self.conflicts {
+                if conflict.overridden {
+                    if let Some(candidate) = conflict
+                        .candidates
+                        .iter()
+                        .find(|candidate| candidate.mod_id == conflict.default_winner_id)
+                    {
+                        conflict.winner_name = candidate.mod_name.clone();
+                    }
+                    conflict.winner_id = conflict.default_winner_id.clone();
+                    conflict.overridden = false;
+                }
+            }
+        }
+        self.status = format!("Cleared {count} file override(s)");
+        self.log_info(format!(
+            "Cleared {count} file override(s) on profile \"{profile_name}\""
+        ));
+        self.set_toast(
+            &format!("Cleared {count} file override(s)"),
+            ToastLevel::Info,
+            Duration::from_secs(2),
+        );
+        self.queue_auto_deploy("clear file overrides");
+    }
+
+    pub fn ignored_conflict_count(&self) -> usize {
+        if self.show_ignored_conflicts {
+            self.conflicts
+                .iter()
+                .filter(|conflict| conflict.ignored.is_some())
+                .count()
+        } else {
+            self.ignored_conflicts.len()
+        }
+    }
+
+    pub fn toggle_show_ignored_conflicts(&mut self) {
+        self.pending_overrides.clear();
+        self.override_picker = None;
+        let selected_path = self
+            .conflicts
+            .get(self.conflict_selected)
+            .map(|conflict| (conflict.target, conflict.relative_path.clone()));
+        self.show_ignored_conflicts = !self.show_ignored_conflicts;
+        if self.show_ignored_conflicts {
+            let ignored = std::mem::take(&mut self.ignored_conflicts);
+            self.conflicts.extend(ignored);
+            sort_conflicts(&mut self.conflicts);
+        } else {
+            let (ignored, visible): (Vec<_>, Vec<_>) = std::mem::take(&mut self.conflicts)
+                .into_iter()
+                .partition(|conflict| conflict.ignored.is_some());
+            self.conflicts = visible;
+            self.ignored_conflicts = ignored;
+        }
+        self.conflict_selected = selected_path
+            .and_then(|(target, path)| {
+                self.conflicts.iter().position(|conflict| {
+                    conflict.target == target && conflict.relative_path == path
+                })
+            })
+            .unwrap_or(0);
+        let count = self.ignored_conflict_count();
+        self.status = if self.show_ignored_conflicts {
+            format!("Showing ignored overrides ({count})")
+        } else {
+            format!("Hiding ignored overrides ({count})")
+        };
+    }
+
+    pub fn open_conflict_rules(&mut self) {
+        self.conflict_rules_view = Some(ConflictRulesView::default());
+    }
+
+    pub fn close_conflict_rules(&mut self) {
+        self.conflict_rules_view = None;
+    }
+
+    pub fn conflict_rules_move(&mut self, delta: isize) {
+        let len = self.config.conflict_ignore.len();
+        let Some(view) = &mut self.conflict_rules_view else {
+            return;
+        };
+        if len == 0 {
+            view.selected = 0;
+            return;
+        }
+        let next = (view.selected as isize + delta).clamp(0, len as isize - 1);
+        view.selected = next as usize;
+        view.test_result = None;
+    }
+
+    pub fn conflict_rules_begin_add(&mut self) {
+        if let Some(view) = &mut self.conflict_rules_view {
+            view.adding = Some(String::new());
+            view.adding_matches = 0;
+            view.test_result = None;
+        }
+    }
+
+    pub fn conflict_rules_input(&mut self, ch: char) {
+        if let Some(buffer) = self
+            .conflict_rules_view
+            .as_mut()
+            .and_then(|view| view.adding.as_mut())
+        {
+            buffer.push(ch);
+        }
+        self.refresh_conflict_rule_preview();
+    }
+
+    pub fn conflict_rules_backspace(&mut self) {
+        if let Some(buffer) = self
+            .conflict_rules_view
+            .as_mut()
+            .and_then(|view| view.adding.as_mut())
+        {
+            buffer.pop();
+        }
+        self.refresh_conflict_rule_preview();
+    }
+
+    fn refresh_conflict_rule_preview(&mut self) {
+        let Some(buffer) = self
+            .conflict_rules_view
+            .as_ref()
+            .and_then(|view| view.adding.as_deref())
+        else {
+            return;
+        };
+        let (matches, _, _) = self.conflict_rule_matches(buffer);
+        if let Some(view) = &mut self.conflict_rules_view {
+            view.adding_matches = matches;
+        }
+    }
+
+    fn refresh_conflict_rule_counts(&mut self) {
+        self.conflict_rule_counts = self
+            .config
+            .conflict_ignore
+            .iter()
+            .map(|pattern| self.conflict_rule_matches(pattern).0)
+            .collect();
+    }
+
+    /// Matches per ignore rule as of the last override scan or rule edit.
+    pub fn conflict_rule_count(&self, index: usize) -> usize {
+        self.conflict_rule_counts.get(index).copied().unwrap_or(0)
+    }
+
+    pub fn conflict_total(&self) -> usize {
+        self.conflicts.len() + self.ignored_conflicts.len()
+    }
+
+    pub fn conflict_rules_cancel_add(&mut self) {
+        if let Some(view) = &mut self.conflict_rules_view {
+            view.adding = None;
+        }
+    }
+
+    pub fn conflict_rules_submit(&mut self) {
+        let Some(pattern) = self
+            .conflict_rules_view
+            .as_mut()
+            .and_then(|view| view.adding.take())
+        else {
+            return;
+        };
+        let pattern = pattern.trim().replace('\\', "/");
+        if pattern.is_empty() {
+            return;
+        }
+        if self
+            .config
+            .conflict_ignore
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(&pattern))
+        {
+            self.status = format!("Rule already exists: {pattern}");
+            return;
+        }
+        self.config.conflict_ignore.push(pattern.clone());
+        let selected = self.config.conflict_ignore.len() - 1;
+        if let Some(view) = &mut self.conflict_rules_view {
+            view.selected = selected;
+        }
+        self.log_info(format!("Conflict ignore rule added: {pattern}"));
+        self.conflict_rules_changed();
+    }
+
+    pub fn conflict_rules_remove(&mut self) {
+        let Some(view) = &mut self.conflict_rules_view else {
+            return;
+        };
+        if view.selected >= self.config.conflict_ignore.len() {
+            return;
+        }
+        let pattern = self.config.conflict_ignore.remove(view.selected);
+        view.selected = view
+            .selected
+            .min(self.config.conflict_ignore.len().saturating_sub(1));
+        view.test_result = None;
+        self.log_info(format!("Conflict ignore rule removed: {pattern}"));
+        self.conflict_rules_changed();
+    }
+
+    pub fn conflict_rules_toggle_identical(&mut self) {
+        self.config.conflict_ignore_identical = !self.config.conflict_ignore_identical;
+        let state = if self.config.conflict_ignore_identical {
+            "on"
+        } else {
+            "off"
+        };
+        self.log_info(format!("Ignore identical conflicts: {state}"));
+        self.conflict_rules_changed();
+    }
+
+    pub fn conflict_rules_test(&mut self) {
+        let pattern = match &self.conflict_rules_view {
+            Some(view) => match &view.adding {
+                Some(buffer) => buffer.trim().to_string(),
+                None => match self.config.conflict_ignore.get(view.selected) {
+                    Some(pattern) => pattern.clone(),
+                    None => return,
+                },
+            },
+            None => return,
+        };
+        let (matches, total, sample) = self.conflict_rule_matches(&pattern);
+        let mut result = format!("\"{pattern}\" matches {matches} of {total} conflict(s)");
+        if let Some(sample) = sample {
+            result.push_str(&format!(", e.g. {sample}"));
+        }
+        if let Some(view) = &mut self.conflict_rules_view {
+            view.test_result = Some(result);
+        }
+    }
+
+    pub fn open_order_rules(&mut self) {
+        self.order_rules_view = Some(OrderRulesView::default());
+    }
+
+    pub fn close_order_rules(&mut self) {
+        self.order_rules_view = None;
+    }
+
+    pub fn active_order_rules(&self) -> &[OrderRule] {
+        self.library
+            .active_profile()
+            .map(|profile| profile.order_rules.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn order_rules_move(&mut self, delta: isize) {
+        let len = self.active_order_rules().len();
+        let Some(view) = &mut self.order_rules_view else {
+            return;
+        };
+        if len == 0 {
+            view.selected = 0;
+            return;
+        }
+        let next = (view.selected as isize + delta).clamp(0, len as isize - 1);
+        view.selected = next as usize;
+    }
+
+    pub fn order_rules_begin_add(&mut self) {
+        if self.block_locked_profile("edit ordering rules") {
+            return;
+        }
+        if let Some(view) = &mut self.order_rules_view {
+            view.adding = Some(String::new());
+            view.error = None;
+        }
+    }
+
+    pub fn order_rules_input(&mut self, ch: char) {
+        if let Some(buffer) = self
+            .order_rules_view
+            .as_mut()
+            .and_then(|view| view.adding.as_mut())
+        {
+            buffer.push(ch);
+        }
+    }
+
+    pub fn order_rules_backspace(&mut self) {
+        if let Some(buffer) = self
+            .order_rules_view
+            .as_mut()
+            .and_then(|view| view.adding.as_mut())
+        {
+            buffer.pop();
+        }
+    }
+
+    pub fn order_rules_cancel_add(&mut self) {
+        if let Some(view) = &mut self.order_rules_view {
+            view.adding = None;
+            view.error = None;
+        }
+    }
+
+    pub fn order_rules_submit(&mut self) {
+        let Some(input) = self
+            .order_rules_view
+            .as_ref()
+            .and_then(|view| view.adding.clone())
+        else {
+            return;
+        };
+        let rule = match self.parse_order_rule(input.trim()) {
+            Ok(rule) => rule,
+            Err(err) => {
+                if let Some(view) = &mut self.order_rules_view {
+                    view.error = Some(err);
+                }
+                return;
+            }
+        };
+        let label = rule.label(&self.library.index_by_id());
+        let Some(profile) = self.library.active_profile_mut() else {
+            return;
+        };
+        if profile.order_rules.contains(&rule) {
+            if let Some(view) = &mut self.order_rules_view {
+                view.error = Some(format!("Rule already exists: {label}"));
+            }
+            return;
+        }
+        profile.order_rules.push(rule);
+        let selected = profile.order_rules.len() - 1;
+        if let Some(view) = &mut self.order_rules_view {
+            view.adding = None;
+            view.error = None;
+            view.selected = selected;
+        }
+        self.log_info(format!("Ordering rule added: {label}"));
+        self.order_rules_changed();
+    }
+
+    pub fn order_rules_remove(&mut self) {
+        if self.block_locked_profile("edit ordering rules") {
+            return;
+        }
+        let Some(selected) = self.order_rules_view.as_ref().map(|view| view.selected) else {
+            return;
+        };
+        let mod_map = self.library.index_by_id();
+        let Some(profile) = self.library.active_profile_mut() else {
+            return;
+        };
+        if selected >= profile.order_rules.len() {
+            return;
+        }
+        let rule = profile.order_rules.remove(selected);
+        let remaining = profile.order_rules.len();
+        if let Some(view) = &mut self.order_rules_view {
+            view.selected = selected.min(remaining.saturating_sub(1));
+        }
+        self.log_info(format!("Ordering rule removed: {}", rule.label(&mod_map)));
+        self.order_rules_changed();
+    }
+
+    fn order_rules_changed(&mut self) {
+        if let Err(err) = self.library.save(&self.config.data_dir) {
+            self.status = format!("Failed to save ordering rules: {err}");
+            self.log_error(format!("Failed to save ordering rules: {err}"));
+        }
+        self.schedule_smart_rank_refresh(
+            smart_rank::SmartRankRefreshMode::ReorderOnly,
+            "ordering rules changed",
+            true,
+        );
+        self.request_sigillink_auto_rank();
+    }
+
+    fn parse_order_rule(&self, input: &str) -> Result<OrderRule, String> {
+        let lower = input.to_ascii_lowercase();
+        let (mod_query, after_query) = if let Some(pos) = lower.find(" after ") {
+            (&input[..pos], &input[pos + " after ".len()..])
+        } else if let Some(pos) = lower.find(" before ") {
+            (&input[pos + " before ".len()..], &input[..pos])
+        } else if lower.ends_with(" last") {
+            let tag = input[..input.len() - " last".len()].trim();
+            if tag.is_empty() {
+                return Err("Tag is required, e.g. patch last".to_string());
+            }
+            return Ok(OrderRule::TagLast {
+                tag: tag.to_string(),
+            });
+        } else {
+            return Err("Use \"A after B\", \"A before B\" or \"<tag> last\"".to_string());
+        };
+        let mod_id = self.resolve_rule_mod(mod_query)?;
+        let after_id = self.resolve_rule_mod(after_query)?;
+        if mod_id == after_id {
+            return Err("A mod cannot load after itself".to_string());
+        }
+        Ok(OrderRule::LoadAfter { mod_id, after_id })
+    }
+
+    fn resolve_rule_mod(&self, query: &str) -> Result<String, String> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err("Mod name is required".to_string());
+        }
+        if let Some(mod_entry) = self.library.mods.iter().find(|m| m.id == query) {
+            return Ok(mod_entry.id.clone());
+        }
+        let exact: Vec<&ModEntry> = self
+            .library
+            .mods
+            .iter()
+            .filter(|m| m.display_name().eq_ignore_ascii_case(query))
+            .collect();
+        if let [mod_entry] = exact.as_slice() {
+            return Ok(mod_entry.id.clone());
+        }
+        let needle = query.to_ascii_lowercase();
+        let partial: Vec<&ModEntry> = self
+            .library
+            .mods
+            .iter()
+            .filter(|m| m.display_name().to_ascii_lowercase().contains(&needle))
+            .collect();
+        match partial.as_slice() {
+            [] => Err(format!("No mod matches \"{query}\"")),
+            [mod_entry] => Ok(mod_entry.id.clone()),
+            many => Err(format!(
+                "\"{query}\" matches {} mods; be more specific",
+                many.len()
+            )),
+        }
+    }
+
+    pub fn open_trash_view(&mut self) {
+        let entries = trash::list(&self.config.sigillink_cache_root());
+        if entries.is_empty() {
+            self.status = "No recently removed mods".to_string();
+            self.set_toast(
+                "No recently removed mods",
+                ToastLevel::Info,
+                Duration::from_secs(2),
+            );
+            return;
+        }
+        self.trash_view = Some(TrashView {
+            entries,
+            selected: 0,
+        });
+    }
+
+    pub fn close_trash_view(&mut self) {
+        self.trash_view = None;
+    }
+
+    pub fn trash_view_move(&mut self, delta: isize) {
+        let Some(view) = &mut self.trash_view else {
+            return;
+        };
+        let len = view.entries.len();
+        if len == 0 {
+            view.selected = 0;
+            return;
+        }
+        view.selected = (view.selected as isize + delta).clamp(0, len as isize - 1) as usize;
+    }
+
+    pub fn restore_selected_trash(&mut self) {
+        if self.block_locked_profile("restore") || self.block_mod_changes("restore") {
+            return;
+        }
+        let Some(entry) = self
+            .trash_view
+            .as_ref()
+            .and_then(|view| view.entries.get(view.selected).cloned())
+        else {
+            return;
+        };
+        let name = entry.mod_entry.display_name();
+        let mut mod_entry = entry.mod_entry.clone();
+        mod_entry.source = ModSource::Managed;
+        let import_mod = importer::ImportMod {
+            entry: mod_entry,
+            staging_root: Some(entry.dir.clone()),
+            sigillink: entry.load_index(),
+            warnings: Vec::new(),
+        };
+        self.trash_view = None;
+        self.log_info(format!("Restoring removed mod from trash: {name}"));
+        self.stage_imports(
+            vec![import_mod],
+            &importer::ImportSource {
+                label: format!("Trash: {name}"),
+            },
+        );
+    }
+
+    pub fn evict_disabled_label(&self) -> String {
+        match self.app_config.evict_disabled_after_days {
+            0 => "Off".to_string(),
+            days => format!("{days}d"),
+        }
+    }
+
+    pub fn cycle_evict_disabled_after(&mut self) -> Result<()> {
+        let options: [u64; 5] = [0, 7, 14, 30, 90];
+        let current = self.app_config.evict_disabled_after_days;
+        self.app_config.evict_disabled_after_days = options
+            .iter()
+            .copied()
+            .find(|days| *days > current)
+            .unwrap_or(options[0]);
+        self.app_config.save()?;
+        self.status = format!(
+            "Evict disabled mods from cache after: {}",
+            self.evict_disabled_label()
+        );
+        self.sweep_disabled_mod_cache();
+        Ok(())
+    }
+
+    fn sweep_disabled_mod_cache(&mut self) {
+        if !self.allow_persistence()
+            || self.import_active.is_some()
+            || self.import_apply_active
+            || self.deploy_active
+            || self.cache_restage_active
+        {
+            return;
+        }
+        let enabled: HashSet<String> = self
+            .library
+            .profiles
+            .iter()
+            .flat_map(|profile| profile.order.iter())
+            .filter(|entry| entry.enabled)
+            .map(|entry| entry.id.clone())
+            .collect();
+        let now = now_timestamp();
+        let mut changed = false;
+        for mod_entry in &mut self.library.mods {
+            if mod_entry.is_native() || mod_entry.dev_path.is_some() {
+                continue;
+            }
+            let disabled_since = if enabled.contains(&mod_entry.id) {
+                None
+            } else {
+                Some(mod_entry.disabled_since.unwrap_or(now))
+            };
+            if mod_entry.disabled_since != disabled_since {
+                mod_entry.disabled_since = disabled_since;
+                changed = true;
+            }
+        }
+
+        let days = self.app_config.evict_disabled_after_days;
+        let cutoff = now.saturating_sub((days as i64).saturating_mul(24 * 60 * 60));
+        let cache_root = self.config.sigillink_cache_root();
+        let mods_root = library_mod_root(&cache_root);
+        let mut evicted = Vec::new();
+        let mut failed = Vec::new();
+        let mut bytes = 0u64;
+        for mod_entry in &mut self.library.mods {
+            if days == 0
+                || mod_entry.cache_evicted
+                || mod_entry.disabled_since.is_none_or(|since| since > cutoff)
+            {
+                continue;
+            }
+            // Only evict what can be restaged later.
+            if !mod_entry
+                .source_path
+                .as_deref()
+                .is_some_and(|source| Path::new(source).exists())
+            {
+                continue;
+            }
+            let mod_root = mods_root.join(&mod_entry.id);
+            if !mod_root.is_dir() || !path_within_root(&mod_root, &mods_root) {
+                continue;
+            }
+            let size = cache_entry_bytes(&mod_root);
+            if let Err(err) = fs::remove_dir_all(&mod_root) {
+                failed.push(format!(
+                    "Cache eviction failed for {}: {err}",
+                    mod_entry.display_name()
+                ));
+                continue;
+            }
+            sigillink::remove_sigillink_index(&cache_root, &mod_entry.id);
+            mod_entry.cache_evicted = true;
+            mod_entry.disk_usage = Some(ModDiskUsage { bytes: 0, files: 0 });
+            bytes += size;
+            evicted.push(mod_entry.display_name());
+            changed = true;
+        }
+        for message in failed {
+            self.log_warn(message);
+        }
+        if changed {
+            let _ = self.library.save(&self.config.data_dir);
+        }
+        if evicted.is_empty() {
+            return;
+        }
+        self.log_info(format!(
+            "Evicted {} long-disabled mod(s) from the SigiLink cache ({}): {}",
+            evicted.len(),
+            importer::format_bytes(bytes),
+            evicted.join(", ")
+        ));
+        self.set_toast(
+            &format!(
+                "Evicted {} disabled mod(s) from cache, reclaimed {}",
+                evicted.len(),
+                importer::format_bytes(bytes)
+            ),
+            ToastLevel::Info,
+            Duration::from_secs(3),
+        );
+    }
+
+    fn start_cache_restage(&mut self) -> bool {
+        let mut profiles: Vec<&str> = vec![self.library.active_profile.as_str()];
+        if let Some(profile) = self.deploy_profile.as_deref() {
+            profiles.push(profile);
+        }
+        let enabled: HashSet<&str> = self
+            .library
+            .profiles
+            .iter()
+            .filter(|profile| profiles.contains(&profile.name.as_str()))
+            .flat_map(|profile| profile.order.iter())
+            .filter(|entry| entry.enabled)
+            .map(|entry| entry.id.as_str())
+            .collect();
+        let jobs: Vec<CacheRestageJob> = self
+            .library
+            .mods
+            .iter()
+            .filter(|mod_entry| mod_entry.cache_evicted && enabled.contains(mod_entry.id.as_str()))
+            .map(|mod_entry| CacheRestageJob {
+                mod_id: mod_entry.id.clone(),
+                source: mod_entry.source_path.clone().unwrap_or_default(),
+                pak_hashes: mod_entry
+                    .pak_hashes()
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect(),
+            })
+            .collect();
+        if jobs.is_empty() {
+            return false;
+        }
+        self.status = format!("Restaging {} evicted mod(s) from source...", jobs.len());
+        self.spawn_cache_restage(jobs);
+        true
+    }
+
+    /// Restages evicted mods as soon as they are enabled, so scans and ranking don't run
+    /// against an empty cache until the next deploy (which may never come without auto-deploy).
+    fn maybe_start_cache_restage(&mut self) {
+        if self.cache_restage_active
+            || !self
+                .library
+                .mods
+                .iter()
+                .any(|mod_entry| mod_entry.cache_evicted)
+            || !self.allow_persistence()
+            || self.import_active.is_some()
+            || self.import_apply_active
+            || self.cache_verify_active
+            || self.deploy_active
+            || self.dialog.is_some()
+        {
+            return;
+        }
+        self.start_cache_restage();
+    }
+
+    pub fn restage_missing_mods(&mut self) {
+        if self.is_busy() || self.cache_verify_active {
+            self.status = "Re-stage blocked: busy".to_string();
+            return;
+        }
+        let cache_root = self.config.sigillink_cache_root();
+        let mut jobs = Vec::new();
+        let mut unrecoverable = Vec::new();
+        for mod_entry in &self.library.mods {
+            if mod_entry.is_native()
+                || mod_entry.dev_path.is_some()
+                || mod_entry.cache_evicted
+                || !mod_cache_missing(mod_entry, &cache_root)
+            {
+                continue;
+            }
+            match mod_entry
+                .source_path
+                .as_deref()
+                .filter(|source| Path::new(source).exists())
+            {
+                Some(source) => jobs.push(CacheRestageJob {
+                    mod_id: mod_entry.id.clone(),
+                    source: source.to_string(),
+                    pak_hashes: mod_entry
+                        .pak_hashes()
+                        .into_iter()
+                        .map(str::to_string)
+                        .collect(),
+                }),
+                None => unrecoverable.push(mod_entry.display_name()),
+            }
+        }
+        unrecoverable.sort();
+        if !unrecoverable.is_empty() {
+            self.log_warn(format!(
+                "Re-stage: no import source for {} mod(s) with missing cache files (reimport manually): {}",
+                unrecoverable.len(),
+                unrecoverable.join(", ")
+            ));
+        }
+        if jobs.is_empty() {
+            self.status = if unrecoverable.is_empty() {
+                "Re-stage: no mods are missing cache files".to_string()
+            } else {
+                format!(
+                    "Re-stage: {} mod(s) missing files, none with a known source (see log)",
+                    unrecoverable.len()
+                )
+            };
+            let level = if unrecoverable.is_empty() {
+                ToastLevel::Info
+            } else {
+                ToastLevel::Warn
+            };
+            self.set_toast(&self.status.clone(), level, Duration::from_secs(3));
+            return;
+        }
+        self.status = if unrecoverable.is_empty() {
+            format!("Restaging {} mod(s) from source...", jobs.len())
+        } else {
+            format!(
+                "Restaging {} mod(s) from source; {} without a source (see log)",
+                jobs.len(),
+                unrecoverable.len()
+            )
+        };
+        self.spawn_cache_restage(jobs);
+    }
+
+    fn spawn_cache_restage(&mut self, jobs: Vec<CacheRestageJob>) {
+        self.cache_restage_active = true;
+        self.log_info(self.status.clone());
+        let cache_root = self.config.sigillink_cache_root();
+        let tx = self.cache_restage_tx.clone();
+        thread::spawn(move || {
+            let results = jobs
+                .into_iter()
+                .map(|job| {
+                    let result =
+                        restage_mod_from_source(&job, &cache_root).map_err(|err| err.to_string());
+                    CacheRestageResult {
+                        mod_id: job.mod_id,
+                        result,
+                    }
+                })
+                .collect();
+            let _ = tx.send(results);
+        });
     }
 
-    fn unique_profile_name(&self, base: &str) -> String {
-        let base = base.trim();
-        if base.is_empty() {
-            return "Profile".to_string();
+    fn poll_cache_restage(&mut self) {
+        let Ok(results) = self.cache_restage_rx.try_recv() else {
+            return;
+        };
+        self.cache_restage_active = false;
+        let mut restaged = 0usize;
+        let mut failed = 0usize;
+        for CacheRestageResult { mod_id, result } in results {
+            let Some(mod_entry) = self
+                .library
+                .mods
+                .iter_mut()
+                .find(|mod_entry| mod_entry.id == mod_id)
+            else {
+                continue;
+            };
+            // Failures fall back to the regular missing-file handling on deploy.
+            mod_entry.cache_evicted = false;
+            mod_entry.disk_usage = None;
+            let name = mod_entry.display_name();
+            match result {
+                Ok(targets) => {
+                    mod_entry.targets = targets;
+                    mod_entry.disabled_since = None;
+                    restaged += 1;
+                }
+                Err(err) => {
+                    failed += 1;
+                    self.log_warn(format!(
+                        "Restage failed for {name}: {err} (reimport it to restore its files)"
+                    ));
+                }
+            }
         }
-        if !self.profile_exists(base) {
-            return base.to_string();
+        self.status = if failed > 0 {
+            format!("Restaged {restaged} mod(s); {failed} failed (see log)")
+        } else {
+            format!("Restaged {restaged} mod(s) into the SigiLink cache")
+        };
+        self.log_info(self.status.clone());
+        let level = if failed > 0 {
+            ToastLevel::Warn
+        } else {
+            ToastLevel::Info
+        };
+        self.set_toast(&self.status.clone(), level, Duration::from_secs(3));
+        let _ = self.library.save(&self.config.data_dir);
+        self.missing_pak_pending = true;
+        self.queue_disk_usage_scan();
+        self.queue_conflict_scan("cache restaged");
+    }
+
+    fn purge_expired_trash(&mut self) {
+        let expired = trash::expired(
+            &self.config.sigillink_cache_root(),
+            self.app_config.trash_retention_days,
+        );
+        if expired.is_empty() {
+            return;
         }
-        for idx in 2..1000 {
-            let candidate = format!("{base} ({idx})");
-            if !self.profile_exists(&candidate) {
-                return candidate;
+        self.log_info(format!(
+            "Trash: purging {} removed mod(s) older than {} day(s)",
+            expired.len(),
+            self.app_config.trash_retention_days
+        ));
+        thread::spawn(move || trash::purge(&expired));
+    }
+
+    pub fn conflict_rule_matches(&self, pattern: &str) -> (usize, usize, Option<String>) {
+        let pattern = pattern.trim().replace('\\', "/");
+        let all = self.conflicts.iter().chain(self.ignored_conflicts.iter());
+        let mut total = 0usize;
+        let mut matches = 0usize;
+        let mut sample = None;
+        for conflict in all {
+            total += 1;
+            let path = conflict.relative_path.to_string_lossy().replace('\\', "/");
+            if !pattern.is_empty() && glob_match(&pattern, &path) {
+                matches += 1;
+                if sample.is_none() {
+                    sample = Some(path);
+                }
             }
         }
-        format!("{base} (copy)")
+        (matches, total, sample)
     }
 
-    pub fn create_profile(&mut self, name: String) -> Result<()> {
-        let name = Self::normalize_profile_name(&name);
-        if name.is_empty() {
-            self.status = "Profile name is required".to_string();
-            self.set_toast(
-                "Profile name required",
-                ToastLevel::Warn,
-                Duration::from_secs(3),
-            );
-            return Ok(());
+    fn conflict_rules_changed(&mut self) {
+        if let Err(err) = self.config.save() {
+            self.status = format!("Failed to save conflict rules: {err}");
+            self.log_error(format!("Failed to save conflict rules: {err}"));
         }
-        if self.profile_exists(&name) {
-            self.status = format!("Profile already exists: {name}");
-            self.set_toast(
-                "Profile already exists",
-                ToastLevel::Warn,
-                Duration::from_secs(3),
-            );
+        self.conflict_rules_rev = self.conflict_rules_rev.wrapping_add(1);
+        self.refresh_conflict_rule_counts();
+        self.queue_conflict_scan("conflict rules");
+    }
+
+    fn schedule_conflict_winner(&mut self, winner_id: String) {
+        if self.block_locked_profile("override") {
+            return;
+        }
+        let Some(conflict) = self.conflicts.get(self.conflict_selected) else {
+            return;
+        };
+        let current_id = self
+            .pending_overrides
+            .get(&self.conflict_selected)
+            .map(|pending| pending.winner_id.as_str())
+            .unwrap_or(conflict.winner_id.as_str());
+        if winner_id == current_id {
+            self.pending_overrides.remove(&self.conflict_selected);
+            return;
+        }
+        let from_name = conflict
+            .candidates
+            .iter()
+            .find(|candidate| candidate.mod_id == current_id)
+            .map(|candidate| candidate.mod_name.clone())
+            .unwrap_or_else(|| conflict.winner_name.clone());
+        let to_name = conflict
+            .candidates
+            .iter()
+            .find(|candidate| candidate.mod_id == winner_id)
+            .map(|candidate| candidate.mod_name.clone())
+            .unwrap_or_else(|| winner_id.clone());
+        self.pending_overrides.insert(
+            self.conflict_selected,
+            PendingOverride {
+                conflict_index: self.conflict_selected,
+                winner_id,
+                from: from_name,
+                to: to_name,
+                last_input: Instant::now(),
+            },
+        );
+    }
+
+    fn set_conflict_winner(&mut self, index: usize, winner_id: String) -> Result<()> {
+        let Some(conflict) = self.conflicts.get(index).cloned() else {
+            return Ok(());
+        };
+        let Some(profile) = self.library.active_profile_mut() else {
             return Ok(());
+        };
+
+        let rel_path = conflict.relative_path.to_string_lossy().to_string();
+        if winner_id == conflict.default_winner_id {
+            profile.file_overrides.retain(|override_entry| {
+                override_entry.kind != conflict.target || override_entry.relative_path != rel_path
+            });
+        } else if let Some(existing) = profile.file_overrides.iter_mut().find(|override_entry| {
+            override_entry.kind == conflict.target && override_entry.relative_path == rel_path
+        }) {
+            existing.mod_id = winner_id.clone();
+        } else {
+            profile.file_overrides.push(FileOverride {
+                kind: conflict.target,
+                relative_path: rel_path.clone(),
+                mod_id: winner_id.clone(),
+            });
         }
 
-        let mut profile = crate::library::Profile::new(&name);
-        let mod_ids: Vec<String> = self.library.mods.iter().map(|m| m.id.clone()).collect();
-        profile.ensure_mods(&mod_ids);
-        self.library.profiles.push(profile);
-        self.set_active_profile(&name)?;
-        self.log_info(format!("Profile created: {name}"));
+        self.library.save(&self.config.data_dir)?;
+        let previous_name = conflict.winner_name.clone();
+        let mut updated = conflict.clone();
+        updated.winner_id = winner_id.clone();
+        let updated_name = updated
+            .candidates
+            .iter()
+            .find(|candidate| candidate.mod_id == winner_id)
+            .map(|candidate| candidate.mod_name.clone())
+            .unwrap_or_else(|| winner_id.clone());
+        updated.winner_name = updated_name.clone();
+        updated.overridden = updated.winner_id != updated.default_winner_id;
+        self.conflicts[index] = updated;
+        if previous_name != updated_name {
+            self.override_swap = Some(OverrideSwap {
+                from: previous_name,
+                to: updated_name,
+            });
+        } else {
+            self.override_swap = None;
+        }
+
+        self.status = "Override updated".to_string();
+        self.log_info("Override updated".to_string());
+        self.queue_auto_deploy("conflict override");
+        Ok(())
+    }
+
+    fn build_profile_export(&self, profile_data: &Profile) -> ProfileExport {
+        let mod_map = self.library.index_by_id();
+        let entries = profile_data
+            .order
+            .iter()
+            .filter(|entry| entry.missing_label.is_none())
+            .filter_map(|entry| mod_map.get(&entry.id).map(|mod_entry| (entry, mod_entry)))
+            .map(|(entry, mod_entry)| ProfileExportEntry {
+                id: entry.id.clone(),
+                name: mod_entry.display_name(),
+                enabled: entry.enabled,
+            })
+            .collect();
+
+        ProfileExport {
+            schema_version: default_modlist_schema_version(),
+            exported_at: rfc3339_label(time::OffsetDateTime::now_utc()),
+            sigilsmith_version: env!("CARGO_PKG_VERSION").to_string(),
+            game_id: self.game_id.as_str().to_string(),
+            game_name: self.game_id.display_name().to_string(),
+            profile_name: profile_data.name.clone(),
+            entries,
+            file_overrides: profile_data.file_overrides.clone(),
+            last_deployed_at: profile_data.last_deployed_at,
+        }
+    }
+
+    fn mod_list_export_json(&self, profile_data: &Profile) -> Result<String> {
+        let export = self.build_profile_export(profile_data);
+        serde_json::to_string_pretty(&export).context("serialize mod list export")
+    }
+
+    fn export_mod_list_file(&mut self, profile_data: &Profile, path: &Path) -> Result<()> {
+        let raw = self.mod_list_export_json(profile_data)?;
+        Self::write_atomic_text(path, &raw).context("write mod list export")?;
+        self.status = format!("Mod list exported: {}", path.display());
+        self.log_info(format!("Mod list exported: {}", path.display()));
+        self.set_toast(
+            &format!("Mod list exported: {}", path.display()),
+            ToastLevel::Info,
+            Duration::from_secs(3),
+        );
+        Ok(())
+    }
+
+    fn profile_paks(&self, profile_data: &Profile) -> (Vec<PakInfo>, Vec<PakInfo>) {
+        let mod_map = self.library.index_by_id();
+        let mut enabled_paks = Vec::new();
+        let mut installed_paks = Vec::new();
+        let mut enabled_ids: HashSet<String> = HashSet::new();
+        let mut installed_ids: HashSet<String> = HashSet::new();
+
+        for entry in &profile_data.order {
+            if entry.missing_label.is_some() {
+                continue;
+            }
+            let Some(mod_entry) = mod_map.get(&entry.id) else {
+                continue;
+            };
+            for target in &mod_entry.targets {
+                let kind = target.kind();
+                if !mod_entry.is_target_enabled(kind) {
+                    continue;
+                }
+                if let InstallTarget::Pak { info, .. } = target {
+                    if installed_ids.insert(info.uuid.clone()) {
+                        installed_paks.push(info.as_ref().clone());
+                    }
+                    if entry.enabled && enabled_ids.insert(info.uuid.clone()) {
+                        enabled_paks.push(info.as_ref().clone());
+                    }
+                }
+            }
+        }
+        (installed_paks, enabled_paks)
+    }
+
+    fn build_profile_modsettings(
+        &self,
+        profile_data: &Profile,
+    ) -> Result<larian_formats::bg3::raw::Save> {
+        let paths = game::detect_paths(
+            self.game_id,
+            Some(&self.config.game_root),
+            Some(&self.config.larian_dir),
+        )?;
+        let (installed_paks, enabled_paks) = self.profile_paks(profile_data);
+        deploy::build_modsettings_export(
+            Some(&paths.modsettings_path),
+            &installed_paks,
+            &enabled_paks,
+        )
+    }
+
+    fn export_modsettings_file(&mut self, profile_data: &Profile, path: &Path) -> Result<()> {
+        let save = self.build_profile_modsettings(profile_data)?;
+        deploy::write_modsettings_export(path, &save)?;
+        self.status = format!("modsettings exported: {}", path.display());
+        self.log_info(format!("modsettings exported: {}", path.display()));
         self.set_toast(
-            &format!("Profile created: {name}"),
+            &format!("modsettings exported: {}", path.display()),
             ToastLevel::Info,
             Duration::from_secs(3),
         );
         Ok(())
     }
 
-    pub fn rename_profile(&mut self, original: String, name: String) -> Result<()> {
-        let name = Self::normalize_profile_name(&name);
-        if name.is_empty() {
-            self.status = "Profile name is required".to_string();
+    pub fn export_mod_list_clipboard(&mut self, profile: &str) -> Result<()> {
+        let Some(profile_data) = self
+            .library
+            .profiles
+            .iter()
+            .find(|entry| entry.name == profile)
+        else {
+            self.status = "Profile not found".to_string();
             self.set_toast(
-                "Profile name required",
+                "Profile not found",
                 ToastLevel::Warn,
                 Duration::from_secs(3),
             );
             return Ok(());
-        }
-        if original.eq_ignore_ascii_case(&name) {
-            self.status = "Profile name unchanged".to_string();
-            self.set_toast("Rename cancelled", ToastLevel::Warn, Duration::from_secs(3));
-            return Ok(());
-        }
-        if self.profile_exists(&name) {
-            self.status = format!("Profile already exists: {name}");
+        };
+        let raw = self.mod_list_export_json(profile_data)?;
+        if self.copy_to_clipboard(&raw) {
+            self.status = "Mod list copied to clipboard".to_string();
             self.set_toast(
-                "Profile already exists",
-                ToastLevel::Warn,
-                Duration::from_secs(3),
+                "Mod list copied to clipboard",
+                ToastLevel::Info,
+                Duration::from_secs(2),
             );
-            return Ok(());
         }
+        Ok(())
+    }
 
-        let mut renamed = false;
-        for profile in &mut self.library.profiles {
-            if profile.name == original {
-                profile.name = name.clone();
-                renamed = true;
-                break;
-            }
-        }
-        if !renamed {
-            self.status = "Profile not found".to_string();
-            self.set_toast(
-                "Profile not found",
-                ToastLevel::Warn,
-                Duration::from_secs(3),
-            );
-            return Ok(());
+    fn uuid_list_text(&self, profile_data: &Profile) -> String {
+        let (_, enabled_paks) = self.profile_paks(profile_data);
+        let mut text = enabled_paks
+            .iter()
+            .map(|info| info.uuid.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        if !text.is_empty() {
+            text.push('\n');
         }
+        text
+    }
 
-        if self.library.active_profile == original {
-            self.library.active_profile = name.clone();
-        }
-        self.config.active_profile = self.library.active_profile.clone();
-        self.library.save(&self.config.data_dir)?;
-        self.config.save()?;
-        self.status = format!("Profile renamed: {name}");
-        self.log_info(format!("Profile renamed: {original} -> {name}"));
+    fn export_uuid_list_file(&mut self, profile_data: &Profile, path: &Path) -> Result<()> {
+        let text = self.uuid_list_text(profile_data);
+        Self::write_atomic_text(path, &text).context("write UUID list export")?;
+        let count = text.lines().count();
+        self.status = format!("UUID list exported ({count}): {}", path.display());
+        self.log_info(format!(
+            "UUID list exported ({count} enabled): {}",
+            path.display()
+        ));
         self.set_toast(
-            &format!("Profile renamed to {name}"),
+            &format!("UUID list exported: {}", path.display()),
             ToastLevel::Info,
             Duration::from_secs(3),
         );
         Ok(())
     }
 
-    pub fn duplicate_profile(&mut self, source: String, name: String) -> Result<()> {
-        let name = Self::normalize_profile_name(&name);
-        if name.is_empty() {
-            self.status = "Profile name is required".to_string();
+    pub fn export_uuid_list_clipboard(&mut self, profile: &str) {
+        let Some(profile_data) = self
+            .library
+            .profiles
+            .iter()
+            .find(|entry| entry.name == profile)
+        else {
+            self.status = "Profile not found".to_string();
             self.set_toast(
-                "Profile name required",
+                "Profile not found",
                 ToastLevel::Warn,
                 Duration::from_secs(3),
             );
-            return Ok(());
+            return;
+        };
+        let text = self.uuid_list_text(profile_data);
+        let count = text.lines().count();
+        if count == 0 {
+            self.status = "No enabled pak mods to export".to_string();
+            return;
         }
-        if self.profile_exists(&name) {
-            self.status = format!("Profile already exists: {name}");
+        if self.copy_to_clipboard(&text) {
+            self.status = format!("{count} enabled UUID(s) copied to clipboard");
             self.set_toast(
-                "Profile already exists",
-                ToastLevel::Warn,
-                Duration::from_secs(3),
+                "UUID list copied to clipboard",
+                ToastLevel::Info,
+                Duration::from_secs(2),
             );
-            return Ok(());
         }
+    }
 
-        let Some(source_profile) = self
+    pub fn export_modsettings_clipboard(&mut self, profile: &str) -> Result<()> {
+        let Some(profile_data) = self
             .library
             .profiles
             .iter()
-            .find(|profile| profile.name == source)
+            .find(|entry| entry.name == profile)
             .cloned()
         else {
             self.status = "Profile not found".to_string();
@@ -3503,721 +8109,643 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             );
             return Ok(());
         };
-
-        let mut copy = source_profile.clone();
-        copy.name = name.clone();
-        self.library.profiles.push(copy);
-        self.set_active_profile(&name)?;
-        self.log_info(format!("Profile duplicated: {source} -> {name}"));
-        self.set_toast(
-            &format!("Profile duplicated: {name}"),
-            ToastLevel::Info,
-            Duration::from_secs(3),
-        );
-        Ok(())
-    }
-
-    pub fn prompt_delete_profile(&mut self, name: String) {
-        if self.dialog.is_some() {
-            return;
-        }
-
-        let message = String::new();
-        self.open_dialog(Dialog {
-            title: "Delete Profile".to_string(),
-            message,
-            yes_label: "Delete".to_string(),
-            no_label: "Cancel".to_string(),
-            choice: DialogChoice::No,
-            kind: DialogKind::DeleteProfile { name },
-            toggle: Some(DialogToggle {
-                label: "Don't ask again for this action?".to_string(),
-                checked: false,
-            }),
-            toggle_alt: None,
-            scroll: 0,
-        });
-    }
-
-    pub fn prompt_delete_mod(&mut self, id: String, name: String) {
-        if self.dialog.is_some() {
-            return;
-        }
-
-        let dependents = self.find_any_profile_dependents(&[id.clone()]);
-        let is_native = self
-            .library
-            .mods
-            .iter()
-            .find(|entry| entry.id == id)
-            .map(|entry| entry.is_native())
-            .unwrap_or(false);
-        let default_choice = DialogChoice::No;
-        let (title, toggle) = if is_native {
-            ("Remove Native Mod".to_string(), None)
-        } else {
-            (
-                "Remove Mod".to_string(),
-                Some(DialogToggle {
-                    label: "Don't ask again for this action?".to_string(),
-                    checked: false,
-                }),
-            )
-        };
-        self.open_dialog(Dialog {
-            title,
-            message: String::new(),
-            yes_label: "Remove".to_string(),
-            no_label: "Remove & update cache".to_string(),
-            choice: default_choice,
-            kind: DialogKind::DeleteMod {
-                id,
-                name,
-                native: is_native,
-                dependents,
-            },
-            toggle,
-            toggle_alt: None,
-            scroll: 0,
-        });
-    }
-
-    fn queue_pending_delete(&mut self, id: String, name: String) {
-        self.pending_delete_mod = Some((id, name));
-        if !self.metadata_active && !self.dependency_cache_ready {
-            self.start_metadata_refresh();
-        }
-        self.status = "Checking dependencies...".to_string();
-        self.set_toast(
-            "Checking dependencies...",
-            ToastLevel::Info,
-            Duration::from_secs(2),
-        );
-    }
-
-    fn maybe_prompt_pending_delete(&mut self) {
-        if self.dialog.is_some() {
-            return;
-        }
-        let Some((id, name)) = self.pending_delete_mod.take() else {
-            return;
-        };
-        if self.library.mods.iter().any(|entry| entry.id == id) {
-            self.prompt_delete_mod(id, name);
+        let save = self.build_profile_modsettings(&profile_data)?;
+        let xml = deploy::modsettings_xml(&save)?;
+        if self.copy_to_clipboard(&xml) {
+            self.status = "modsettings.lsx copied to clipboard".to_string();
+            self.log_info(format!("modsettings.lsx copied to clipboard ({profile})"));
+            self.set_toast(
+                "modsettings.lsx copied to clipboard",
+                ToastLevel::Info,
+                Duration::from_secs(2),
+            );
         }
+        Ok(())
     }
 
-    fn dependency_reverse_map(
-        &self,
-        candidate_ids: &HashSet<String>,
-    ) -> HashMap<String, Vec<String>> {
-        let lookup = DependencyLookup::new(&self.library.mods);
-        let mut reverse: HashMap<String, Vec<String>> = HashMap::new();
-        for mod_entry in &self.library.mods {
-            if !candidate_ids.contains(&mod_entry.id) {
-                continue;
-            }
-            for dep in self.cached_mod_dependencies(mod_entry) {
-                let ids = resolved_dependency_ids(&lookup, &dep, mod_entry);
-                for id in ids {
-                    if candidate_ids.contains(&id) {
-                        reverse.entry(id).or_default().push(mod_entry.id.clone());
-                    }
+    pub fn copy_log_tail_to_clipboard(&mut self, lines: usize) {
+        match self.log_tail_text(lines) {
+            Ok(text) => {
+                if text.is_empty() {
+                    self.status = "Log is empty".to_string();
+                    self.set_toast("Log is empty", ToastLevel::Warn, Duration::from_secs(2));
+                    return;
+                }
+                if self.copy_to_clipboard(&text) {
+                    self.status = format!("Copied last {lines} log lines");
+                    self.set_toast(
+                        &format!("Copied last {lines} log lines"),
+                        ToastLevel::Info,
+                        Duration::from_secs(2),
+                    );
+                } else {
+                    self.status = "Copy failed".to_string();
                 }
             }
+            Err(err) => {
+                self.status = format!("Copy failed: {err}");
+                self.log_error(format!("Copy failed: {err}"));
+            }
         }
-        for ids in reverse.values_mut() {
-            ids.sort();
-            ids.dedup();
-        }
-        reverse
-    }
-
-    fn find_enabled_dependents(
-        &self,
-        target_ids: &[String],
-        candidate_ids: &HashSet<String>,
-        enabled_ids: &HashSet<String>,
-    ) -> Vec<DependentMod> {
-        if target_ids.is_empty() || candidate_ids.is_empty() {
-            return Vec::new();
-        }
-        let reverse = self.dependency_reverse_map(candidate_ids);
-        let mut visited: HashSet<String> = HashSet::new();
-        let mut queue: VecDeque<String> = VecDeque::new();
-        for id in target_ids {
-            if candidate_ids.contains(id) && visited.insert(id.clone()) {
-                queue.push_back(id.clone());
-            }
-        }
-        let mut dependent_ids: HashSet<String> = HashSet::new();
-        while let Some(current) = queue.pop_front() {
-            if let Some(children) = reverse.get(&current) {
-                for child in children {
-                    if visited.insert(child.clone()) {
-                        queue.push_back(child.clone());
-                        if enabled_ids.contains(child) {
-                            dependent_ids.insert(child.clone());
-                        }
-                    }
+    }
+
+    pub fn copy_log_to_clipboard(&mut self) {
+        match self.log_text() {
+            Ok(text) => {
+                if text.is_empty() {
+                    self.status = "Log is empty".to_string();
+                    self.set_toast("Log is empty", ToastLevel::Warn, Duration::from_secs(2));
+                    return;
+                }
+                if self.copy_to_clipboard(&text) {
+                    self.status = "Log copied to clipboard".to_string();
+                    self.set_toast(
+                        "Log copied to clipboard",
+                        ToastLevel::Info,
+                        Duration::from_secs(2),
+                    );
+                } else {
+                    self.status = "Copy failed".to_string();
                 }
             }
-        }
-        for id in target_ids {
-            dependent_ids.remove(id);
-        }
-        let mut out = Vec::new();
-        for id in dependent_ids {
-            if let Some(entry) = self.library.mods.iter().find(|entry| entry.id == id) {
-                out.push(DependentMod {
-                    id,
-                    name: entry.display_name(),
-                });
+            Err(err) => {
+                self.status = format!("Copy failed: {err}");
+                self.log_error(format!("Copy failed: {err}"));
             }
         }
-        out.sort_by(|a, b| a.name.cmp(&b.name));
-        out
     }
 
-    fn find_active_dependents(&self, target_ids: &[String]) -> Vec<DependentMod> {
-        let candidate_ids = self.active_profile_ids();
-        if candidate_ids.is_empty() {
-            return Vec::new();
-        }
-        let enabled_ids = self.active_profile_enabled_ids();
-        self.find_enabled_dependents(target_ids, &candidate_ids, &enabled_ids)
+    pub fn open_log_export(&mut self) {
+        self.move_mode = false;
+        self.open_path_browser(PathBrowserPurpose::ExportLog);
     }
 
-    fn find_any_profile_dependents(&self, target_ids: &[String]) -> Vec<DependentMod> {
-        let candidate_ids: HashSet<String> = self
-            .library
-            .mods
-            .iter()
-            .map(|entry| entry.id.clone())
-            .collect();
-        if candidate_ids.is_empty() {
-            return Vec::new();
+    fn export_log_to_dir(&mut self, dir: &Path) -> Result<()> {
+        let text = self.log_text()?;
+        if text.is_empty() {
+            self.status = "Log is empty".to_string();
+            self.set_toast("Log is empty", ToastLevel::Warn, Duration::from_secs(2));
+            return Ok(());
         }
-        let enabled_ids = self.enabled_mod_ids_any_profile();
-        self.find_enabled_dependents(target_ids, &candidate_ids, &enabled_ids)
+        let filename = format!("sigilsmith-log-{}.txt", self.export_timestamp());
+        let path = dir.join(filename);
+        Self::write_atomic_text(&path, &text).context("write log export")?;
+        self.status = format!("Log exported: {}", path.display());
+        self.log_info(format!("Log exported: {}", path.display()));
+        self.set_toast(
+            &format!("Log exported: {}", path.display()),
+            ToastLevel::Info,
+            Duration::from_secs(3),
+        );
+        Ok(())
     }
 
-    pub fn prompt_move_blocked(&mut self, resume_move_mode: bool) {
+    pub fn prompt_repair_modsettings(&mut self) {
         if self.dialog.is_some() {
             return;
         }
-        let mod_name = self
-            .selected_profile_id()
-            .and_then(|id| {
-                self.library
-                    .mods
-                    .iter()
-                    .find(|entry| entry.id == id)
-                    .map(|entry| entry.display_name())
-            })
-            .unwrap_or_else(|| "mod".to_string());
-        let mut message = String::new();
-        if !self.mod_sort.is_order_default() {
-            message.push_str(&format!(
-                "Can't move while sorting by {} ({}).\n",
-                self.mod_sort.column_label(),
-                self.mod_sort.direction_label()
-            ));
-        }
-        if self.mod_filter_active() {
-            message.push_str("Can't move while search is active.\n");
+        if let Some(reason) = self.busy_reason() {
+            self.status = format!("Repair modsettings blocked: {reason}");
+            self.set_toast(
+                &format!("Repair blocked: {reason}"),
+                ToastLevel::Warn,
+                Duration::from_secs(3),
+            );
+            return;
         }
-        let clear_filter = self.mod_filter_active();
-        let suffix = if clear_filter {
-            "Switch to Order view and clear search"
-        } else {
-            "Switch to Order view"
-        };
-        message.push_str(&format!("{suffix} to move \"{mod_name}\"?"));
-
         self.open_dialog(Dialog {
-            title: "Move requires Order".to_string(),
-            message,
-            yes_label: "Switch".to_string(),
+            title: "Repair modsettings.lsx".to_string(),
+            message: format!(
+                "Rebuild modsettings.lsx from the enabled paks in profile \"{}\"?\n\nThe current file is ignored and backed up first. Loose files are not touched.",
+                self.library.active_profile
+            ),
+            yes_label: "Repair".to_string(),
             no_label: "Cancel".to_string(),
             choice: DialogChoice::Yes,
-            kind: DialogKind::MoveBlocked {
-                resume_move_mode,
-                clear_filter,
-            },
+            kind: DialogKind::RepairModsettings,
             toggle: None,
             toggle_alt: None,
             scroll: 0,
         });
     }
 
-    pub fn prompt_cancel_import(&mut self) {
+    pub fn prompt_freeze_sort(&mut self) {
         if self.dialog.is_some() {
             return;
         }
+        if self.mod_sort.is_order_default() {
+            self.status = "Sort is already Order".to_string();
+            return;
+        }
+        if self.block_mod_changes("freeze sort") || self.block_locked_profile("freeze sort") {
+            return;
+        }
+        let pins_note =
+            if self.app_config.sigillink_ranking_enabled && self.sigillink_pin_count() > 0 {
+                "\nSigiLink-pinned mods keep their slots."
+            } else {
+                ""
+            };
         self.open_dialog(Dialog {
-            title: "Cancel Import".to_string(),
-            message: "Cancel this import and return to the main view?".to_string(),
-            yes_label: "Continue import".to_string(),
-            no_label: "Cancel import".to_string(),
+            title: "Freeze Sort".to_string(),
+            message: format!(
+                "Rewrite the load order of profile \"{}\" to match the current sort ({} {})?\n\nThe whole profile is reordered, including mods hidden by the filter, and the view returns to Order.{pins_note}",
+                self.library.active_profile,
+                self.mod_sort.column_label(),
+                self.mod_sort.direction_label()
+            ),
+            yes_label: "Freeze".to_string(),
+            no_label: "Cancel".to_string(),
             choice: DialogChoice::No,
-            kind: DialogKind::CancelImport,
-            toggle: Some(DialogToggle {
-                label: "Remember import choice".to_string(),
-                checked: false,
-            }),
+            kind: DialogKind::FreezeSort,
+            toggle: None,
             toggle_alt: None,
             scroll: 0,
         });
     }
 
-    pub fn delete_profile(&mut self, name: String) -> Result<()> {
-        if self.library.profiles.len() <= 1 {
-            self.status = "Cannot delete the last profile".to_string();
-            self.set_toast(
-                "At least one profile is required",
-                ToastLevel::Warn,
-                Duration::from_secs(3),
-            );
-            return Ok(());
-        }
-        if !self
-            .library
-            .profiles
-            .iter()
-            .any(|profile| profile.name == name)
-        {
-            self.status = "Profile not found".to_string();
-            self.set_toast(
-                "Profile not found",
-                ToastLevel::Warn,
-                Duration::from_secs(3),
-            );
-            return Ok(());
-        }
-
-        let was_active = self.library.active_profile == name;
-        self.library.profiles.retain(|profile| profile.name != name);
-
-        if self.library.profiles.is_empty() {
-            self.library
-                .profiles
-                .push(crate::library::Profile::new("Default"));
-        }
-
-        if was_active {
-            self.library.active_profile = self.library.profiles[0].name.clone();
-            self.config.active_profile = self.library.active_profile.clone();
-            self.selected = 0;
-            self.move_mode = false;
-            self.schedule_smart_rank_warmup();
-            self.queue_auto_deploy("profile deleted");
+    fn freeze_sort(&mut self) {
+        if self.block_locked_profile("freeze sort") {
+            return;
         }
-
-        self.library.save(&self.config.data_dir)?;
-        self.config.save()?;
-        self.status = format!("Profile deleted: {name}");
-        self.log_info(format!("Profile deleted: {name}"));
-        self.set_toast(
-            &format!("Profile deleted: {name}"),
-            ToastLevel::Info,
-            Duration::from_secs(3),
-        );
-        Ok(())
-    }
-
-    pub fn set_active_profile(&mut self, name: &str) -> Result<()> {
-        if !self.library.profiles.iter().any(|p| p.name == name) {
-            self.status = "Profile not found".to_string();
-            return Ok(());
+        let sort = self.mod_sort;
+        let respect_pins = self.app_config.sigillink_ranking_enabled;
+        let previous_id = self.selected_profile_id();
+        let mod_map = self.library.index_by_id();
+        let moved = {
+            let Some(profile) = self.library.active_profile_mut() else {
+                return;
+            };
+            let free: Vec<usize> = (0..profile.order.len())
+                .filter(|index| {
+                    !respect_pins
+                        || !profile
+                            .sigillink_pins
+                            .contains_key(&profile.order[*index].id)
+                })
+                .collect();
+            let mut sorted = free.clone();
+            sort_mod_indices(&mut sorted, profile, &mod_map, sort);
+            let entries: Vec<ProfileEntry> = sorted
+                .iter()
+                .map(|index| profile.order[*index].clone())
+                .collect();
+            let moved = free
+                .iter()
+                .zip(sorted.iter())
+                .filter(|(slot, index)| slot != index)
+                .count();
+            for (slot, entry) in free.iter().zip(entries) {
+                profile.order[*slot] = entry;
+            }
+            moved
+        };
+        self.mod_sort = ModSort::default();
+        self.reselect_mod_by_id(previous_id);
+        if let Err(err) = self.library.save(&self.config.data_dir) {
+            self.status = format!("Freeze sort save failed: {err}");
+            self.log_error(format!("Freeze sort save failed: {err}"));
+            return;
         }
-        self.library.active_profile = name.to_string();
-        self.config.active_profile = name.to_string();
-        self.library.save(&self.config.data_dir)?;
-        self.config.save()?;
-        self.selected = 0;
-        self.move_mode = false;
-        self.status = format!("Profile loaded: {name}");
-        self.log_info(format!("Profile loaded: {name}"));
-        self.schedule_smart_rank_warmup();
-        self.queue_auto_deploy("profile changed");
-        self.refresh_sigillink_missing_paks();
-        Ok(())
-    }
-
-    pub fn conflict_move_up(&mut self) {
-        if self.conflict_selected == 0 {
+        self.status = format!("Sort frozen into load order: {moved} mod(s) moved");
+        self.log_info(format!(
+            "Sort frozen into load order ({} {}): {moved} mod(s) moved",
+            sort.column_label(),
+            sort.direction_label()
+        ));
+        if moved == 0 {
             return;
         }
-        self.conflict_selected -= 1;
+        self.schedule_smart_rank_refresh(
+            smart_rank::SmartRankRefreshMode::ReorderOnly,
+            "order changed",
+            true,
+        );
+        self.queue_auto_deploy("order changed");
     }
 
-    pub fn conflict_move_down(&mut self) {
-        if self.conflict_selected + 1 >= self.conflicts.len() {
+    pub fn prompt_reset_profile_order(&mut self) {
+        if self.dialog.is_some() {
             return;
         }
-        self.conflict_selected += 1;
+        if self.block_mod_changes("reset order") || self.block_locked_profile("reset order") {
+            return;
+        }
+        self.open_dialog(Dialog {
+            title: "Reset Profile Order".to_string(),
+            message: format!(
+                "Reset the load order of profile \"{}\" to the library's import order?\n\nEnabled states are kept and other profiles are untouched. SigiLink pins in this profile are cleared.",
+                self.library.active_profile
+            ),
+            yes_label: "Reset".to_string(),
+            no_label: "Cancel".to_string(),
+            choice: DialogChoice::No,
+            kind: DialogKind::ResetProfileOrder,
+            toggle: None,
+            toggle_alt: None,
+            scroll: 0,
+        });
     }
 
-    pub fn cycle_conflict_winner(&mut self, delta: i32) {
-        let Some(conflict) = self.conflicts.get(self.conflict_selected).cloned() else {
-            return;
-        };
-        if conflict.candidates.is_empty() {
+    fn reset_profile_order(&mut self) {
+        if self.block_locked_profile("reset order") {
             return;
         }
-        let current_id = self
-            .pending_overrides
-            .get(&self.conflict_selected)
-            .map(|pending| pending.winner_id.as_str())
-            .unwrap_or(conflict.winner_id.as_str());
-        let current_index = conflict
-            .candidates
+        let previous_id = self.selected_profile_id();
+        let mod_ids: Vec<String> = self
+            .library
+            .mods
             .iter()
-            .position(|candidate| candidate.mod_id == current_id)
-            .unwrap_or(0);
-        let len = conflict.candidates.len();
-        let next_index = if delta < 0 {
-            current_index.saturating_sub(1)
-        } else if delta > 0 {
-            (current_index + 1).min(len.saturating_sub(1))
-        } else {
-            current_index
-        };
-        let winner_id = conflict.candidates[next_index].mod_id.clone();
-        self.schedule_conflict_winner(winner_id);
-    }
-
-    pub fn select_conflict_candidate(&mut self, index: usize) {
-        let Some(conflict) = self.conflicts.get(self.conflict_selected) else {
+            .map(|mod_entry| mod_entry.id.clone())
+            .collect();
+        let Some(profile) = self.library.active_profile_mut() else {
             return;
         };
-        let Some(candidate) = conflict.candidates.get(index) else {
+        let moved = profile.reset_order(&mod_ids);
+        let name = profile.name.clone();
+        self.reselect_mod_by_id(previous_id);
+        if let Err(err) = self.library.save(&self.config.data_dir) {
+            self.status = format!("Reset order save failed: {err}");
+            self.log_error(format!("Reset order save failed: {err}"));
             return;
-        };
-        self.schedule_conflict_winner(candidate.mod_id.clone());
+        }
+        self.status = format!("Profile order reset: {moved} mod(s) moved");
+        self.log_info(format!(
+            "Profile \"{name}\" order reset to default: {moved} mod(s) moved"
+        ));
+        if moved == 0 {
+            return;
+        }
+        self.schedule_smart_rank_refresh(
+            smart_rank::SmartRankRefreshMode::ReorderOnly,
+            "order changed",
+            true,
+        );
+        self.queue_auto_deploy("order reset");
     }
 
-    pub fn apply_pending_override(&mut self) {
-        if self.pending_overrides.is_empty() {
+    fn log_profile_duplicates(&mut self, duplicates: &[(String, usize)]) {
+        for (name, count) in duplicates {
+            self.log_warn(format!(
+                "Profile \"{name}\": collapsed {count} duplicate entr{} (kept first occurrence)",
+                if *count == 1 { "y" } else { "ies" }
+            ));
+        }
+    }
+
+    pub fn normalize_all_profiles(&mut self) {
+        if self.is_busy() {
+            self.status = "Normalize blocked: busy".to_string();
             return;
         }
-        let pending = std::mem::take(&mut self.pending_overrides);
-        let mut items: Vec<PendingOverride> = pending.into_values().collect();
-        items.sort_by_key(|item| item.conflict_index);
-        for pending in items {
-            if pending.conflict_index >= self.conflicts.len() {
+        let previous_id = self.selected_profile_id();
+        let mod_ids = self.library.mod_ids();
+        let known_names = removed_mod_names(&self.config.sigillink_cache_root());
+        let mut report = EnsureModsReport::default();
+        let mut duplicates = 0;
+        let mut shadowed_overrides = 0;
+        let mut skipped = Vec::new();
+        for profile in &mut self.library.profiles {
+            if is_sigillink_ranking_profile(&profile.name) {
                 continue;
             }
-            if let Err(err) = self.set_conflict_winner(pending.conflict_index, pending.winner_id) {
-                self.status = format!("Override failed: {err}");
-                self.log_error(format!("Override failed: {err}"));
+            if profile.locked {
+                skipped.push(profile.name.clone());
+                continue;
             }
+            duplicates += profile.dedup_order();
+            report += profile.ensure_mods(&mod_ids);
+            profile.label_placeholders(&known_names);
+            shadowed_overrides += profile.dedup_file_overrides();
         }
-    }
+        self.library.prune_mod_sets();
+        let relinked = self.relink_missing_entries(|profile| !profile.locked);
 
-    pub fn clear_conflict_override(&mut self) {
-        let Some(conflict) = self.conflicts.get(self.conflict_selected).cloned() else {
-            return;
-        };
-        self.pending_overrides.remove(&self.conflict_selected);
-        if let Err(err) =
-            self.set_conflict_winner(self.conflict_selected, conflict.default_winner_id)
-        {
-            self.status = format!("Override failed: {err}");
-            self.log_error(format!("Override failed: {err}"));
+        let mut changes = Vec::new();
+        for (count, label) in [
+            (report.added, "missing mod entries added"),
+            (duplicates, "duplicate entries removed"),
+            (
+                report.placeholders,
+                "stale entries turned into placeholders",
+            ),
+            (relinked, "placeholders relinked"),
+            (report.overrides_removed, "dangling overrides removed"),
+            (shadowed_overrides, "shadowed overrides removed"),
+            (report.pins_removed, "dangling pins removed"),
+        ] {
+            if count > 0 {
+                changes.push(format!("{count} {label}"));
+            }
         }
-    }
-
-    fn schedule_conflict_winner(&mut self, winner_id: String) {
-        let Some(conflict) = self.conflicts.get(self.conflict_selected) else {
-            return;
+        let skipped_note = if skipped.is_empty() {
+            String::new()
+        } else {
+            format!(" (skipped locked: {})", skipped.join(", "))
         };
-        let current_id = self
-            .pending_overrides
-            .get(&self.conflict_selected)
-            .map(|pending| pending.winner_id.as_str())
-            .unwrap_or(conflict.winner_id.as_str());
-        if winner_id == current_id {
-            self.pending_overrides.remove(&self.conflict_selected);
+        if changes.is_empty() {
+            self.status = format!("Profiles normalized: nothing to fix{skipped_note}");
+            self.log_info(self.status.clone());
+            self.set_toast(
+                &self.status.clone(),
+                ToastLevel::Info,
+                Duration::from_secs(3),
+            );
             return;
         }
-        let from_name = conflict
-            .candidates
-            .iter()
-            .find(|candidate| candidate.mod_id == current_id)
-            .map(|candidate| candidate.mod_name.clone())
-            .unwrap_or_else(|| conflict.winner_name.clone());
-        let to_name = conflict
-            .candidates
-            .iter()
-            .find(|candidate| candidate.mod_id == winner_id)
-            .map(|candidate| candidate.mod_name.clone())
-            .unwrap_or_else(|| winner_id.clone());
-        self.pending_overrides.insert(
-            self.conflict_selected,
-            PendingOverride {
-                conflict_index: self.conflict_selected,
-                winner_id,
-                from: from_name,
-                to: to_name,
-                last_input: Instant::now(),
-            },
+        self.reselect_mod_by_id(previous_id);
+        if let Err(err) = self.library.save(&self.config.data_dir) {
+            self.status = format!("Normalize save failed: {err}");
+            self.log_error(format!("Normalize save failed: {err}"));
+            return;
+        }
+        let summary = changes.join(", ");
+        self.status = format!("Profiles normalized: {summary}{skipped_note}");
+        self.log_info(self.status.clone());
+        self.set_toast(
+            &self.status.clone(),
+            ToastLevel::Info,
+            Duration::from_secs(4),
+        );
+        self.schedule_smart_rank_refresh(
+            smart_rank::SmartRankRefreshMode::Incremental,
+            "profiles normalized",
+            true,
         );
+        self.queue_auto_deploy("profiles normalized");
     }
 
-    fn set_conflict_winner(&mut self, index: usize, winner_id: String) -> Result<()> {
-        let Some(conflict) = self.conflicts.get(index).cloned() else {
-            return Ok(());
-        };
-        let Some(profile) = self.library.active_profile_mut() else {
+    pub fn repair_modsettings(&mut self) -> Result<()> {
+        let paths = game::detect_paths(
+            self.game_id,
+            Some(&self.config.game_root),
+            Some(&self.config.larian_dir),
+        )?;
+        let Some(profile_data) = self.library.active_profile() else {
+            self.status = "Profile not found".to_string();
             return Ok(());
         };
-
-        let rel_path = conflict.relative_path.to_string_lossy().to_string();
-        if winner_id == conflict.default_winner_id {
-            profile.file_overrides.retain(|override_entry| {
-                override_entry.kind != conflict.target || override_entry.relative_path != rel_path
-            });
-        } else if let Some(existing) = profile.file_overrides.iter_mut().find(|override_entry| {
-            override_entry.kind == conflict.target && override_entry.relative_path == rel_path
-        }) {
-            existing.mod_id = winner_id.clone();
-        } else {
-            profile.file_overrides.push(FileOverride {
-                kind: conflict.target,
-                relative_path: rel_path.clone(),
-                mod_id: winner_id.clone(),
-            });
-        }
-
-        self.library.save(&self.config.data_dir)?;
-        let previous_name = conflict.winner_name.clone();
-        let mut updated = conflict.clone();
-        updated.winner_id = winner_id.clone();
-        let updated_name = updated
-            .candidates
-            .iter()
-            .find(|candidate| candidate.mod_id == winner_id)
-            .map(|candidate| candidate.mod_name.clone())
-            .unwrap_or_else(|| winner_id.clone());
-        updated.winner_name = updated_name.clone();
-        updated.overridden = updated.winner_id != updated.default_winner_id;
-        self.conflicts[index] = updated;
-        if previous_name != updated_name {
-            self.override_swap = Some(OverrideSwap {
-                from: previous_name,
-                to: updated_name,
-            });
-        } else {
-            self.override_swap = None;
-        }
-
-        self.status = "Override updated".to_string();
-        self.log_info("Override updated".to_string());
-        self.queue_auto_deploy("conflict override");
+        let (installed_paks, enabled_paks) = self.profile_paks(profile_data);
+        let backup_dir = backup::create_backup(
+            &self.config,
+            &self.library,
+            &paths,
+            Some("repair modsettings"),
+        )?;
+        let save = deploy::build_modsettings_export(None, &installed_paks, &enabled_paks)?;
+        deploy::write_modsettings_export(&paths.modsettings_path, &save)?;
+        self.status = format!("modsettings repaired: {} pak(s)", enabled_paks.len());
+        self.log_info(format!(
+            "modsettings rebuilt from profile {} ({} pak(s)); backup: {}",
+            self.library.active_profile,
+            enabled_paks.len(),
+            backup_dir.display()
+        ));
+        self.set_toast(
+            &format!("modsettings repaired: {} pak(s)", enabled_paks.len()),
+            ToastLevel::Info,
+            Duration::from_secs(3),
+        );
         Ok(())
     }
 
-    fn build_profile_export(&self, profile_data: &Profile) -> ProfileExport {
-        let mod_map = self.library.index_by_id();
-        let entries = profile_data
-            .order
-            .iter()
-            .filter(|entry| entry.missing_label.is_none())
-            .filter_map(|entry| mod_map.get(&entry.id).map(|mod_entry| (entry, mod_entry)))
-            .map(|(entry, mod_entry)| ProfileExportEntry {
-                id: entry.id.clone(),
-                name: mod_entry.display_name(),
-                enabled: entry.enabled,
-            })
-            .collect();
-
-        ProfileExport {
-            schema_version: default_modlist_schema_version(),
-            exported_at: self.export_timestamp_rfc3339(),
-            sigilsmith_version: env!("CARGO_PKG_VERSION").to_string(),
-            game_id: self.game_id.as_str().to_string(),
-            game_name: self.game_id.display_name().to_string(),
-            profile_name: profile_data.name.clone(),
-            entries,
-            file_overrides: profile_data.file_overrides.clone(),
+    pub fn prompt_support_bundle(&mut self) {
+        if self.dialog.is_some() {
+            return;
         }
+        let dir = self.export_dir();
+        self.open_dialog(Dialog {
+            title: "Create Support Bundle".to_string(),
+            message: format!(
+                "Write a zip with the log tail, app and game config, library.json, the last deploy report, and version info to:\n{}\n\nYour home directory is replaced with ~ in every file.",
+                dir.display()
+            ),
+            yes_label: "Create".to_string(),
+            no_label: "Cancel".to_string(),
+            choice: DialogChoice::Yes,
+            kind: DialogKind::SupportBundle,
+            toggle: Some(DialogToggle {
+                label: "Strip mod names from library.json".to_string(),
+                checked: false,
+            }),
+            toggle_alt: None,
+            scroll: 0,
+        });
     }
 
-    fn mod_list_export_json(&self, profile_data: &Profile) -> Result<String> {
-        let export = self.build_profile_export(profile_data);
-        serde_json::to_string_pretty(&export).context("serialize mod list export")
+    pub fn create_support_bundle(
+        &mut self,
+        strip_mod_names: bool,
+        dest: Option<PathBuf>,
+    ) -> Result<PathBuf> {
+        let name = support::bundle_file_name(&self.export_timestamp());
+        let path = match dest {
+            Some(dest) if dest.is_dir() => dest.join(name),
+            Some(dest) => dest,
+            None => self.export_dir().join(name),
+        };
+        let last_deploy_report =
+            fs::read_to_string(deploy::last_report_path(&self.config.data_dir)).ok();
+        let library_detail = if strip_mod_names {
+            support::LibraryDetail::StripModNames
+        } else {
+            support::LibraryDetail::Full
+        };
+        self.write_support_bundle(&path, library_detail, last_deploy_report)?;
+        self.status = format!("Support bundle written: {}", path.display());
+        self.log_info(format!("Support bundle written: {}", path.display()));
+        self.set_toast(
+            &format!("Support bundle written: {}", path.display()),
+            ToastLevel::Info,
+            Duration::from_secs(3),
+        );
+        Ok(path)
+    }
+
+    pub fn prompt_export_settings(&mut self) {
+        if self.dialog.is_some() {
+            return;
+        }
+        let dir = self.export_dir();
+        self.open_dialog(Dialog {
+            title: "Export Settings".to_string(),
+            message: format!(
+                "Save app and game settings (not mod lists) to:\n{}",
+                dir.display()
+            ),
+            yes_label: "Export".to_string(),
+            no_label: "Cancel".to_string(),
+            choice: DialogChoice::Yes,
+            kind: DialogKind::ExportSettings,
+            toggle: Some(DialogToggle {
+                label: "Include machine-specific paths".to_string(),
+                checked: false,
+            }),
+            toggle_alt: None,
+            scroll: 0,
+        });
     }
 
-    fn export_mod_list_file(&mut self, profile_data: &Profile, path: &Path) -> Result<()> {
-        let raw = self.mod_list_export_json(profile_data)?;
-        Self::write_atomic_text(path, &raw).context("write mod list export")?;
-        self.status = format!("Mod list exported: {}", path.display());
-        self.log_info(format!("Mod list exported: {}", path.display()));
+    fn export_settings(&mut self, include_paths: bool) -> Result<()> {
+        let value = config::export_settings(&self.app_config, &[&self.config], include_paths)?;
+        let raw = serde_json::to_string_pretty(&value).context("serialize settings")?;
+        let path = self.export_dir().join(format!(
+            "sigilsmith-settings-{}.json",
+            self.export_timestamp()
+        ));
+        Self::write_atomic_text(&path, &raw).context("write settings export")?;
+        self.status = format!("Settings exported: {}", path.display());
+        self.log_info(format!("Settings exported: {}", path.display()));
         self.set_toast(
-            &format!("Mod list exported: {}", path.display()),
+            &format!("Settings exported: {}", path.display()),
             ToastLevel::Info,
             Duration::from_secs(3),
         );
         Ok(())
     }
 
-    fn export_modsettings_file(&mut self, profile_data: &Profile, path: &Path) -> Result<()> {
-        let paths = game::detect_paths(
-            self.game_id,
-            Some(&self.config.game_root),
-            Some(&self.config.larian_dir),
-        )?;
-        let mod_map = self.library.index_by_id();
-        let mut enabled_paks = Vec::new();
-        let mut installed_paks = Vec::new();
-        let mut enabled_ids: HashSet<String> = HashSet::new();
-        let mut installed_ids: HashSet<String> = HashSet::new();
-
-        for entry in &profile_data.order {
-            if entry.missing_label.is_some() {
-                continue;
-            }
-            let Some(mod_entry) = mod_map.get(&entry.id) else {
-                continue;
-            };
-            for target in &mod_entry.targets {
-                let kind = target.kind();
-                if !mod_entry.is_target_enabled(kind) {
-                    continue;
-                }
-                if let InstallTarget::Pak { info, .. } = target {
-                    if installed_ids.insert(info.uuid.clone()) {
-                        installed_paks.push(info.clone());
-                    }
-                    if entry.enabled && enabled_ids.insert(info.uuid.clone()) {
-                        enabled_paks.push(info.clone());
-                    }
-                }
-            }
-        }
-
-        let save = deploy::build_modsettings_export(
-            &paths.modsettings_path,
-            &installed_paks,
-            &enabled_paks,
-        )?;
-        deploy::write_modsettings_export(path, &save)?;
-        self.status = format!("modsettings exported: {}", path.display());
-        self.log_info(format!("modsettings exported: {}", path.display()));
+    pub fn enter_import_settings(&mut self) {
+        self.move_mode = false;
+        self.open_path_browser(PathBrowserPurpose::ImportSettings);
+    }
+
+    fn import_settings(&mut self, path: &Path) -> Result<()> {
+        let raw = fs::read_to_string(path).context("read settings file")?;
+        let mut app_config = self.app_config.clone();
+        let mut game_config = self.config.clone();
+        config::import_settings(&raw, &mut app_config, &mut game_config)?;
+        app_config.active_game = self.game_id;
+        game_config.active_profile = self.config.active_profile.clone();
+        app_config.save()?;
+        game_config.save()?;
+        self.app_config = app_config;
+        self.config = game_config;
+        self.status = format!("Settings imported: {}", path.display());
+        self.log_info(format!("Settings imported: {}", path.display()));
         self.set_toast(
-            &format!("modsettings exported: {}", path.display()),
+            "Settings imported",
             ToastLevel::Info,
             Duration::from_secs(3),
         );
         Ok(())
     }
 
-    pub fn export_mod_list_clipboard(&mut self, profile: &str) -> Result<()> {
-        let Some(profile_data) = self
-            .library
-            .profiles
-            .iter()
-            .find(|entry| entry.name == profile)
-        else {
-            self.status = "Profile not found".to_string();
-            self.set_toast(
-                "Profile not found",
-                ToastLevel::Warn,
-                Duration::from_secs(3),
-            );
-            return Ok(());
-        };
-        let raw = self.mod_list_export_json(profile_data)?;
-        if self.copy_to_clipboard(&raw) {
-            self.status = "Mod list copied to clipboard".to_string();
-            self.set_toast(
-                "Mod list copied to clipboard",
-                ToastLevel::Info,
-                Duration::from_secs(2),
-            );
-        }
-        Ok(())
+    pub fn open_diagnostics_export(&mut self) {
+        self.move_mode = false;
+        self.open_path_browser(PathBrowserPurpose::ExportDiagnostics);
     }
 
-    pub fn copy_log_tail_to_clipboard(&mut self, lines: usize) {
-        match self.log_tail_text(lines) {
-            Ok(text) => {
-                if text.is_empty() {
-                    self.status = "Log is empty".to_string();
-                    self.set_toast("Log is empty", ToastLevel::Warn, Duration::from_secs(2));
-                    return;
-                }
-                if self.copy_to_clipboard(&text) {
-                    self.status = format!("Copied last {lines} log lines");
-                    self.set_toast(
-                        &format!("Copied last {lines} log lines"),
-                        ToastLevel::Info,
-                        Duration::from_secs(2),
-                    );
-                } else {
-                    self.status = "Copy failed".to_string();
-                }
-            }
-            Err(err) => {
-                self.status = format!("Copy failed: {err}");
-                self.log_error(format!("Copy failed: {err}"));
-            }
-        }
+    pub fn open_stage_deploy(&mut self) {
+        self.move_mode = false;
+        self.open_path_browser(PathBrowserPurpose::StageDeploy);
     }
 
-    pub fn copy_log_to_clipboard(&mut self) {
-        match self.log_text() {
-            Ok(text) => {
-                if text.is_empty() {
-                    self.status = "Log is empty".to_string();
-                    self.set_toast("Log is empty", ToastLevel::Warn, Duration::from_secs(2));
-                    return;
-                }
-                if self.copy_to_clipboard(&text) {
-                    self.status = "Log copied to clipboard".to_string();
-                    self.set_toast(
-                        "Log copied to clipboard",
-                        ToastLevel::Info,
-                        Duration::from_secs(2),
-                    );
-                } else {
-                    self.status = "Copy failed".to_string();
-                }
+    fn check_staging_dir(&self, dir: &Path) -> Result<()> {
+        for root in [&self.config.game_root, &self.config.larian_dir] {
+            if root.as_os_str().is_empty() {
+                continue;
             }
-            Err(err) => {
-                self.status = format!("Copy failed: {err}");
-                self.log_error(format!("Copy failed: {err}"));
+            if dir.starts_with(root) || root.starts_with(dir) {
+                return Err(anyhow::anyhow!(
+                    "Staging folder must be outside the game and Larian dirs ({})",
+                    root.display()
+                ));
             }
         }
+        Ok(())
     }
 
-    pub fn open_log_export(&mut self) {
-        self.move_mode = false;
-        self.open_path_browser(PathBrowserPurpose::ExportLog);
+    fn queue_stage_deploy(&mut self, dir: &Path) -> Result<()> {
+        if self.deploy_pending || self.deploy_active {
+            return Err(anyhow::anyhow!("A deploy is already queued or running"));
+        }
+        let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+        self.check_staging_dir(&dir)?;
+        self.deploy_pending = true;
+        self.deploy_due = None;
+        self.deploy_reason = Some("staging".to_string());
+        self.deploy_backup = false;
+        self.deploy_skip_loose = false;
+        self.status = format!("Staged deploy queued: {}", dir.display());
+        self.log_info(format!("Staged deploy queued: {}", dir.display()));
+        self.deploy_staging = Some(dir);
+        Ok(())
     }
 
-    fn export_log_to_dir(&mut self, dir: &Path) -> Result<()> {
-        let text = self.log_text()?;
-        if text.is_empty() {
-            self.status = "Log is empty".to_string();
-            self.set_toast("Log is empty", ToastLevel::Warn, Duration::from_secs(2));
-            return Ok(());
+    pub fn stage_deploy_cli(
+        &mut self,
+        profile: Option<&str>,
+        dir: PathBuf,
+        keep: &[String],
+    ) -> Result<deploy::DeployReport> {
+        let name = profile
+            .map(str::to_string)
+            .unwrap_or_else(|| self.library.active_profile.clone());
+        if !self
+            .library
+            .profiles
+            .iter()
+            .any(|profile| profile.name == name)
+        {
+            return Err(anyhow::anyhow!("Unknown profile: {name}"));
         }
-        let filename = format!("sigilsmith-log-{}.txt", self.export_timestamp());
-        let path = dir.join(filename);
-        Self::write_atomic_text(&path, &text).context("write log export")?;
-        self.status = format!("Log exported: {}", path.display());
-        self.log_info(format!("Log exported: {}", path.display()));
+        fs::create_dir_all(&dir).context("create staging folder")?;
+        let dir = dir.canonicalize().context("resolve staging folder")?;
+        self.check_staging_dir(&dir)?;
+        let mut library = self.library.clone();
+        library.active_profile = name;
+        let resolved = resolve_cli_uuid_collisions(&mut library, keep)?;
+        let mut report = deploy::deploy_with_options(
+            &self.config,
+            &mut library,
+            deploy::DeployOptions {
+                backup: false,
+                reason: Some("staging".to_string()),
+                target_override: Some(dir),
+                ..deploy::DeployOptions::default()
+            },
+        )
+        .map_err(cli_deploy_error)?;
+        report.warnings.extend(resolved);
+        Ok(report)
+    }
+
+    fn export_diagnostics_to_dir(&mut self, dir: &Path) -> Result<()> {
+        let path = dir.join(support::diagnostics_file_name(&self.export_timestamp()));
+        self.write_support_bundle(&path, support::LibraryDetail::Summary, None)
+            .context("write diagnostics bundle")?;
+        self.status = format!("Diagnostics bundle written: {}", path.display());
+        self.log_info(format!("Diagnostics bundle written: {}", path.display()));
         self.set_toast(
-            &format!("Log exported: {}", path.display()),
+            &format!("Diagnostics bundle written: {}", path.display()),
             ToastLevel::Info,
             Duration::from_secs(3),
         );
         Ok(())
     }
 
+    fn write_support_bundle(
+        &self,
+        path: &Path,
+        library_detail: support::LibraryDetail,
+        last_deploy_report: Option<String>,
+    ) -> Result<()> {
+        let log_text = self.log_text()?;
+        let home = BaseDirs::new().map(|base| base.home_dir().to_path_buf());
+        let bundle = support::SupportBundle::collect(
+            &support::SupportBundleInput {
+                app_config: &self.app_config,
+                game_config: &self.config,
+                library: &self.library,
+                log_text: &log_text,
+                last_deploy_report,
+                library_detail,
+            },
+            home.as_deref(),
+        )?;
+        bundle.write(path)
+    }
+
     pub fn export_profile(
         &mut self,
         profile: String,
@@ -4256,13 +8784,30 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         match kind {
             ExportKind::ModList => self.export_mod_list_file(&profile_data, &path)?,
             ExportKind::Modsettings => self.export_modsettings_file(&profile_data, &path)?,
+            ExportKind::UuidList => self.export_uuid_list_file(&profile_data, &path)?,
         }
 
         Ok(())
     }
 
     fn parse_mod_list_json(&self, raw: &str, source_label: String) -> Result<ModListImport> {
-        let export: ProfileExport = serde_json::from_str(raw).context("parse mod list export")?;
+        let value: serde_json::Value =
+            serde_json::from_str(raw).context("parse mod list export")?;
+        // Exports predating the field were already written in the v1 layout.
+        let version = if value.get("schema_version").is_some() {
+            schema_version_of(&value)
+        } else {
+            MODLIST_SCHEMA_VERSION
+        };
+        if version > MODLIST_SCHEMA_VERSION {
+            return Err(anyhow::anyhow!(
+                "mod list export uses schema v{version}, but this SigilSmith supports up to v{MODLIST_SCHEMA_VERSION}; update SigilSmith to import it"
+            ));
+        }
+        let value = run_schema_migrations(value, version, MODLIST_MIGRATIONS)
+            .context("migrate mod list export")?;
+        let export: ProfileExport =
+            serde_json::from_value(value).context("parse mod list export")?;
         let mut warnings = Vec::new();
         if export.game_id != self.game_id.as_str() {
             warnings.push(format!(
@@ -4284,6 +8829,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 id: entry.id,
                 name: entry.name,
                 enabled: entry.enabled,
+                uuid: None,
             })
             .collect();
         Ok(ModListImport {
@@ -4323,16 +8869,18 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             if let Some(module) = modules_by_uuid.remove(&uuid) {
                 let enabled = enabled_set.contains(&uuid);
                 entries.push(ModListEntry {
-                    id: uuid,
+                    id: uuid.clone(),
                     name: module.info.name,
                     enabled,
+                    uuid: Some(uuid),
                 });
             } else {
                 warnings.push(format!("Missing module entry for {uuid}"));
                 entries.push(ModListEntry {
                     id: uuid.clone(),
-                    name: uuid,
+                    name: uuid.clone(),
                     enabled: true,
+                    uuid: Some(uuid),
                 });
             }
         }
@@ -4342,9 +8890,10 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             }
             let enabled = enabled_set.contains(&module.info.uuid);
             entries.push(ModListEntry {
-                id: module.info.uuid,
+                id: module.info.uuid.clone(),
                 name: module.info.name,
                 enabled,
+                uuid: Some(module.info.uuid),
             });
         }
         Ok(ModListImport {
@@ -4371,6 +8920,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             destination: ModListDestination::NewProfile,
             mode: ModListApplyMode::Merge,
             override_mode: ModListOverrideMode::Merge,
+            missing_mode: ModListMissingMode::Placeholder,
+            missing_confirm: false,
+            missing_only: false,
         }
     }
 
@@ -4378,7 +8930,16 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         let mod_map = self.library.index_by_id();
         let mut name_map: HashMap<String, Vec<String>> = HashMap::new();
         let mut label_map: HashMap<String, Vec<String>> = HashMap::new();
+        let mut uuid_map: HashMap<String, Vec<String>> = HashMap::new();
         for mod_entry in &self.library.mods {
+            for target in &mod_entry.targets {
+                if let InstallTarget::Pak { info, .. } = target {
+                    let bucket = uuid_map.entry(info.uuid.to_ascii_lowercase()).or_default();
+                    if !bucket.iter().any(|id| id == &mod_entry.id) {
+                        bucket.push(mod_entry.id.clone());
+                    }
+                }
+            }
             let mut names = Vec::new();
             names.push(mod_entry.display_name());
             names.push(mod_entry.name.clone());
@@ -4402,12 +8963,40 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
 
         let mut planned = Vec::new();
         for entry in entries {
+            let uuid_key = entry
+                .uuid
+                .as_deref()
+                .unwrap_or(&entry.id)
+                .trim()
+                .to_ascii_lowercase();
             let mut outcome = if let Some(mod_entry) = mod_map.get(&entry.id) {
                 ModListMatchOutcome::Matched {
                     resolved_id: mod_entry.id.clone(),
                     resolved_name: mod_entry.display_name(),
                     method: ModListMatchMethod::Id,
                 }
+            } else if let Some(ids) = uuid_map.get(&uuid_key).filter(|ids| !ids.is_empty()) {
+                if ids.len() == 1 {
+                    let id = ids[0].clone();
+                    let name = mod_map
+                        .get(&id)
+                        .map(|mod_entry| mod_entry.display_name())
+                        .unwrap_or_else(|| entry.name.clone());
+                    ModListMatchOutcome::Matched {
+                        resolved_id: id,
+                        resolved_name: name,
+                        method: ModListMatchMethod::Uuid,
+                    }
+                } else {
+                    let candidates = ids
+                        .iter()
+                        .filter_map(|id| mod_map.get(id).map(mod_list_candidate))
+                        .collect();
+                    ModListMatchOutcome::Ambiguous {
+                        candidates,
+                        method: ModListMatchMethod::Uuid,
+                    }
+                }
             } else {
                 let name_key = entry.name.trim().to_lowercase();
                 match name_map.get(&name_key) {
@@ -4426,7 +9015,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                     Some(ids) if !ids.is_empty() => {
                         let candidates = ids
                             .iter()
-                            .filter_map(|id| mod_map.get(id).map(|entry| entry.display_name()))
+                            .filter_map(|id| mod_map.get(id).map(mod_list_candidate))
                             .collect();
                         ModListMatchOutcome::Ambiguous {
                             candidates,
@@ -4451,9 +9040,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                             Some(ids) if !ids.is_empty() => {
                                 let candidates = ids
                                     .iter()
-                                    .filter_map(|id| {
-                                        mod_map.get(id).map(|entry| entry.display_name())
-                                    })
+                                    .filter_map(|id| mod_map.get(id).map(mod_list_candidate))
                                     .collect();
                                 ModListMatchOutcome::Ambiguous {
                                     candidates,
@@ -4467,8 +9054,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             };
             let mut make_missing = false;
             if let ModListMatchOutcome::Ambiguous { candidates, .. } = &mut outcome {
-                candidates.sort();
-                candidates.dedup();
+                candidates
+                    .sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.mod_id.cmp(&b.mod_id)));
+                candidates.dedup_by(|a, b| a.mod_id == b.mod_id);
                 if candidates.is_empty() {
                     make_missing = true;
                 }
@@ -4485,6 +9073,20 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         planned
     }
 
+    pub fn mod_list_preview_missing_count(preview: &ModListPreview) -> usize {
+        preview
+            .entries
+            .iter()
+            .filter(|entry| matches!(entry.outcome, ModListMatchOutcome::Missing))
+            .count()
+    }
+
+    fn mod_list_preview_needs_missing_confirm(preview: &ModListPreview) -> bool {
+        let missing = Self::mod_list_preview_missing_count(preview);
+        missing >= MOD_LIST_MISSING_CONFIRM_MIN
+            && missing * 100 >= preview.entries.len() * MOD_LIST_MISSING_CONFIRM_PERCENT
+    }
+
     fn mod_list_preview_has_ambiguous(preview: &ModListPreview) -> bool {
         preview
             .entries
@@ -4534,6 +9136,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                     return Ok(());
                 }
             }
+        } else if importer::is_url(trimmed) {
+            self.queue_url_import(trimmed);
+            return Ok(());
         } else {
             let path = expand_tilde(trimmed);
             if !path.exists() {
@@ -4550,76 +9155,185 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 .and_then(|name| name.to_str())
                 .map(|name| name.to_string())
                 .unwrap_or_else(|| path.display().to_string());
-            let parsed = if path
-                .extension()
-                .and_then(|ext| ext.to_str())
-                .map(|ext| ext.eq_ignore_ascii_case("lsx"))
-                .unwrap_or(false)
-                || path
-                    .file_name()
-                    .and_then(|name| name.to_str())
-                    .map(|name| name.eq_ignore_ascii_case("modsettings.lsx"))
-                    .unwrap_or(false)
-            {
-                self.parse_modsettings_import(&path, source_label)
-            } else {
-                let raw = match fs::read_to_string(&path) {
-                    Ok(raw) => raw,
-                    Err(err) => {
-                        self.status = format!("Import failed: {}", path.display());
-                        self.log_error(format!("Import read failed: {err}"));
-                        self.set_toast(
-                            "Import failed: unable to read file",
-                            ToastLevel::Warn,
-                            Duration::from_secs(3),
-                        );
-                        return Ok(());
-                    }
-                };
-                let raw_trimmed = raw.trim_start();
-                if raw_trimmed.starts_with('{') {
-                    self.parse_mod_list_json(&raw, source_label)
-                } else if raw_trimmed.starts_with('<') {
-                    self.parse_modsettings_import(&path, source_label)
-                } else {
-                    self.status = format!("Import failed: {}", path.display());
-                    self.set_toast(
-                        "Import failed: invalid mod list",
-                        ToastLevel::Warn,
-                        Duration::from_secs(3),
-                    );
-                    return Ok(());
-                }
+            let Some(import) = self.load_mod_list_file(&path, source_label) else {
+                return Ok(());
             };
-            match parsed {
-                Ok(import) => import,
+            import
+        };
+
+        let preview = self.build_mod_list_preview(import);
+        self.mod_list_preview = Some(preview);
+        self.mod_list_scroll = 0;
+        self.status = "Mod list preview ready".to_string();
+        Ok(())
+    }
+
+    fn load_mod_list_file(&mut self, path: &Path, source_label: String) -> Option<ModListImport> {
+        let parsed = if path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.eq_ignore_ascii_case("lsx"))
+            .unwrap_or(false)
+            || path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| name.eq_ignore_ascii_case("modsettings.lsx"))
+                .unwrap_or(false)
+        {
+            self.parse_modsettings_import(path, source_label)
+        } else {
+            let raw = match fs::read_to_string(path) {
+                Ok(raw) => raw,
                 Err(err) => {
                     self.status = format!("Import failed: {}", path.display());
-                    self.log_error(format!("Import parse failed: {err}"));
+                    self.log_error(format!("Import read failed: {err}"));
                     self.set_toast(
-                        "Import failed: invalid mod list",
+                        "Import failed: unable to read file",
                         ToastLevel::Warn,
                         Duration::from_secs(3),
                     );
-                    return Ok(());
+                    return None;
                 }
+            };
+            let raw_trimmed = raw.trim_start();
+            if raw_trimmed.starts_with('{') {
+                self.parse_mod_list_json(&raw, source_label)
+            } else if raw_trimmed.starts_with('<') {
+                self.parse_modsettings_import(path, source_label)
+            } else {
+                self.status = format!("Import failed: {}", path.display());
+                self.set_toast(
+                    "Import failed: invalid mod list",
+                    ToastLevel::Warn,
+                    Duration::from_secs(3),
+                );
+                return None;
+            }
+        };
+        match parsed {
+            Ok(import) => Some(import),
+            Err(err) => {
+                self.status = format!("Import failed: {}", path.display());
+                self.log_error(format!("Import parse failed: {err}"));
+                self.set_toast(
+                    "Import failed: invalid mod list",
+                    ToastLevel::Warn,
+                    Duration::from_secs(3),
+                );
+                None
             }
-        };
-
-        let preview = self.build_mod_list_preview(import);
-        self.mod_list_preview = Some(preview);
-        self.mod_list_scroll = 0;
-        self.status = "Mod list preview ready".to_string();
-        Ok(())
+        }
     }
 
     pub fn cancel_mod_list_preview(&mut self) {
+        self.mod_list_picker = None;
         if self.mod_list_preview.take().is_some() {
             self.status = "Mod list import canceled".to_string();
         }
         self.mod_list_scroll = 0;
     }
 
+    pub fn open_mod_list_picker(&mut self) {
+        let Some(preview) = &self.mod_list_preview else {
+            return;
+        };
+        let Some((entry_index, entry, candidates)) =
+            preview
+                .entries
+                .iter()
+                .enumerate()
+                .find_map(|(index, entry)| match &entry.outcome {
+                    ModListMatchOutcome::Ambiguous { candidates, .. } => {
+                        Some((index, entry, candidates))
+                    }
+                    _ => None,
+                })
+        else {
+            self.mod_list_picker = None;
+            self.status = "No ambiguous matches to resolve".to_string();
+            return;
+        };
+        let label = if entry.source.name.trim().is_empty() {
+            entry.source.id.trim().to_string()
+        } else {
+            entry.source.name.trim().to_string()
+        };
+        self.mod_list_picker = Some(ModListMatchPicker {
+            entry_index,
+            label,
+            items: candidates.clone(),
+            selected: 0,
+        });
+    }
+
+    pub fn mod_list_picker_move(&mut self, delta: isize) {
+        let Some(picker) = &mut self.mod_list_picker else {
+            return;
+        };
+        let len = picker.items.len();
+        if len == 0 {
+            picker.selected = 0;
+            return;
+        }
+        picker.selected = (picker.selected as isize + delta).clamp(0, len as isize - 1) as usize;
+    }
+
+    pub fn mod_list_picker_cancel(&mut self) {
+        self.mod_list_picker = None;
+    }
+
+    pub fn mod_list_picker_select(&mut self) {
+        let Some(picker) = self.mod_list_picker.take() else {
+            return;
+        };
+        let Some(item) = picker.items.get(picker.selected) else {
+            return;
+        };
+        let Some(entry) = self
+            .mod_list_preview
+            .as_mut()
+            .and_then(|preview| preview.entries.get_mut(picker.entry_index))
+        else {
+            return;
+        };
+        entry.outcome = ModListMatchOutcome::Matched {
+            resolved_id: item.mod_id.clone(),
+            resolved_name: item.name.clone(),
+            method: ModListMatchMethod::Picked,
+        };
+        self.log_info(format!(
+            "Mod list import: matched \"{}\" to {}",
+            picker.label, item.name
+        ));
+        let remaining = self
+            .mod_list_preview
+            .as_ref()
+            .map(Self::mod_list_preview_has_ambiguous)
+            .unwrap_or(false);
+        if remaining {
+            self.open_mod_list_picker();
+        } else {
+            self.status = "All ambiguous matches resolved; Enter to apply".to_string();
+        }
+    }
+
+    pub fn toggle_mod_list_missing_mode(&mut self) {
+        if let Some(preview) = &mut self.mod_list_preview {
+            preview.missing_mode = match preview.missing_mode {
+                ModListMissingMode::Placeholder => ModListMissingMode::Block,
+                ModListMissingMode::Block => ModListMissingMode::Placeholder,
+            };
+            preview.missing_confirm = false;
+        }
+    }
+
+    pub fn toggle_mod_list_missing_only(&mut self) {
+        if let Some(preview) = &mut self.mod_list_preview {
+            preview.missing_only = !preview.missing_only;
+            self.mod_list_scroll = 0;
+        }
+    }
+
     pub fn toggle_mod_list_destination(&mut self) {
         if let Some(preview) = &mut self.mod_list_preview {
             preview.destination = match preview.destination {
@@ -4639,6 +9353,11 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 ModListApplyMode::Merge => ModListOverrideMode::Merge,
                 ModListApplyMode::Strict => ModListOverrideMode::Replace,
             };
+            preview.missing_mode = match preview.mode {
+                ModListApplyMode::Merge => ModListMissingMode::Placeholder,
+                ModListApplyMode::Strict => ModListMissingMode::Block,
+            };
+            preview.missing_confirm = false;
         }
     }
 
@@ -4652,9 +9371,65 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         }
         if Self::mod_list_preview_has_ambiguous(&preview) {
             self.mod_list_preview = Some(preview);
-            self.status = "Mod list import blocked: ambiguous matches".to_string();
+            self.status =
+                "Mod list import: pick the intended mod for each ambiguous entry".to_string();
+            self.open_mod_list_picker();
+            return Ok(());
+        }
+        if matches!(preview.missing_mode, ModListMissingMode::Block) {
+            let missing: Vec<String> = preview
+                .entries
+                .iter()
+                .filter(|entry| matches!(entry.outcome, ModListMatchOutcome::Missing))
+                .map(|entry| {
+                    let name = entry.source.name.trim();
+                    if name.is_empty() {
+                        entry.source.id.trim().to_string()
+                    } else {
+                        name.to_string()
+                    }
+                })
+                .collect();
+            if !missing.is_empty() {
+                let count = missing.len();
+                self.mod_list_preview = Some(ModListPreview {
+                    missing_only: true,
+                    ..preview
+                });
+                self.mod_list_scroll = 0;
+                self.status =
+                    format!("Mod list import blocked: {count} missing mod(s); install them first");
+                self.log_warn(format!(
+                    "Mod list import blocked: {count} missing mod(s):\n{}",
+                    missing
+                        .iter()
+                        .map(|name| format!("- {name}"))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                ));
+                self.set_toast(
+                    &format!("Import blocked: {count} missing mod(s)"),
+                    ToastLevel::Warn,
+                    Duration::from_secs(3),
+                );
+                return Ok(());
+            }
+        }
+        if !preview.missing_confirm && Self::mod_list_preview_needs_missing_confirm(&preview) {
+            let missing = Self::mod_list_preview_missing_count(&preview);
+            let total = preview.entries.len();
+            self.mod_list_preview = Some(ModListPreview {
+                missing_confirm: true,
+                ..preview
+            });
+            self.status = format!(
+                "Mod list import: {missing} of {total} entries missing; Enter again to import anyway"
+            );
+            self.log_warn(format!(
+                "Mod list import: {missing} of {total} entries are not in the library"
+            ));
             self.set_toast(
-                "Ambiguous matches detected; import blocked",
+                &format!("{missing} of {total} mods missing"),
                 ToastLevel::Warn,
                 Duration::from_secs(3),
             );
@@ -4671,6 +9446,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                             id: resolved_id.clone(),
                             enabled: plan.source.enabled,
                             missing_label: None,
+                            collision_with: None,
                         });
                     }
                 }
@@ -4688,6 +9464,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                             id,
                             enabled: plan.source.enabled,
                             missing_label: Some(label),
+                            collision_with: None,
                         });
                     }
                 }
@@ -4712,6 +9489,10 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 preview.new_profile_name.clone()
             }
             ModListDestination::ActiveProfile => {
+                if self.block_locked_profile("mod list import") {
+                    self.mod_list_preview = Some(preview);
+                    return Ok(());
+                }
                 let Some(profile) = self.library.active_profile_mut() else {
                     self.status = "Mod list import failed: no profile".to_string();
                     return Ok(());
@@ -4791,8 +9572,14 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     fn resolve_missing_profile_entries(&mut self) -> bool {
+        self.relink_missing_entries(|_| true) > 0
+    }
+
+    /// Points placeholders back at library mods with a matching id or unique name; returns
+    /// how many were relinked in the profiles `include` accepts.
+    fn relink_missing_entries(&mut self, include: impl Fn(&Profile) -> bool) -> usize {
         if self.library.mods.is_empty() {
-            return false;
+            return 0;
         }
 
         let mod_map = self.library.index_by_id();
@@ -4819,9 +9606,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             }
         }
 
-        let mut changed = false;
+        let mut relinked = 0;
         for profile in &mut self.library.profiles {
-            if is_sigillink_ranking_profile(&profile.name) {
+            if is_sigillink_ranking_profile(&profile.name) || !include(profile) {
                 continue;
             }
             for entry in &mut profile.order {
@@ -4830,7 +9617,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 };
                 if mod_map.contains_key(&entry.id) {
                     entry.missing_label = None;
-                    changed = true;
+                    relinked += 1;
                     continue;
                 }
                 let mut resolved: Option<String> = None;
@@ -4853,12 +9640,12 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 if let Some(id) = resolved {
                     entry.id = id;
                     entry.missing_label = None;
-                    changed = true;
+                    relinked += 1;
                 }
             }
         }
 
-        changed
+        relinked
     }
 
     pub fn tick(&mut self) {
@@ -4902,6 +9689,26 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         }
 
         self.maybe_start_missing_pak_scan();
+        self.maybe_queue_background_conflict_scan();
+        self.maybe_refresh_deploy_state();
+    }
+
+    fn maybe_refresh_deploy_state(&mut self) {
+        if self.deploy_state_dirty {
+            self.refresh_deploy_state();
+        }
+    }
+
+    fn mark_deploy_state_dirty(&mut self) {
+        self.deploy_state_dirty = true;
+    }
+
+    fn refresh_deploy_state(&mut self) {
+        self.deploy_state_dirty = false;
+        self.deploy_out_of_date = match &self.deployed_state_hash {
+            Some(deployed) => deploy::profile_state_hash(&self.library).as_ref() != Some(deployed),
+            None => false,
+        };
     }
 
     fn maybe_return_to_settings_menu(&mut self) {
@@ -4917,6 +9724,17 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             || self.sigillink_missing_queue.is_some()
             || self.dependency_queue.is_some()
             || self.smart_rank_preview.is_some()
+            || self.compatibility_view.is_some()
+            || self.command_palette.is_some()
+            || self.conflict_rules_view.is_some()
+            || self.order_rules_view.is_some()
+            || self.rank_history_view.is_some()
+            || self.targets_view.is_some()
+            || self.load_order_check_view.is_some()
+            || self.toggle_impact_view.is_some()
+            || self.trash_view.is_some()
+            || self.setup_wizard.is_some()
+            || self.uuid_collision_view.is_some()
             || self.mod_list_preview.is_some()
         {
             return;
@@ -4941,6 +9759,17 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             || self.settings_menu.is_some()
             || self.mod_list_preview.is_some()
             || self.smart_rank_preview.is_some()
+            || self.compatibility_view.is_some()
+            || self.command_palette.is_some()
+            || self.conflict_rules_view.is_some()
+            || self.order_rules_view.is_some()
+            || self.rank_history_view.is_some()
+            || self.targets_view.is_some()
+            || self.load_order_check_view.is_some()
+            || self.toggle_impact_view.is_some()
+            || self.trash_view.is_some()
+            || self.setup_wizard.is_some()
+            || self.uuid_collision_view.is_some()
             || self.help_open
             || self.paths_overlay_open
             || self.import_summary_pending
@@ -4989,6 +9818,17 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             || self.export_menu.is_some()
             || self.mod_list_preview.is_some()
             || self.smart_rank_preview.is_some()
+            || self.compatibility_view.is_some()
+            || self.command_palette.is_some()
+            || self.conflict_rules_view.is_some()
+            || self.order_rules_view.is_some()
+            || self.rank_history_view.is_some()
+            || self.targets_view.is_some()
+            || self.load_order_check_view.is_some()
+            || self.toggle_impact_view.is_some()
+            || self.trash_view.is_some()
+            || self.setup_wizard.is_some()
+            || self.uuid_collision_view.is_some()
             || self.help_open
             || self.paths_overlay_open
             || self.import_summary_pending
@@ -5021,11 +9861,23 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             }
             self.sigillink_rank_debounce_until = None;
         }
-        if self.dialog.is_some()
+        if self.automation_paused
+            || self.dialog.is_some()
             || !matches!(self.input_mode, InputMode::Normal)
             || self.settings_menu.is_some()
             || self.mod_list_preview.is_some()
             || self.smart_rank_preview.is_some()
+            || self.compatibility_view.is_some()
+            || self.command_palette.is_some()
+            || self.conflict_rules_view.is_some()
+            || self.order_rules_view.is_some()
+            || self.rank_history_view.is_some()
+            || self.targets_view.is_some()
+            || self.load_order_check_view.is_some()
+            || self.toggle_impact_view.is_some()
+            || self.trash_view.is_some()
+            || self.setup_wizard.is_some()
+            || self.uuid_collision_view.is_some()
             || self.help_open
             || self.paths_overlay_open
             || self.import_summary_pending
@@ -5099,6 +9951,51 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         self.import_progress.as_ref()
     }
 
+    pub fn import_rate(&self) -> Option<(f64, Option<Duration>)> {
+        let progress = self.import_progress.as_ref()?;
+        let (first_at, first_bytes) = self.import_rate_samples.front()?;
+        let (last_at, last_bytes) = self.import_rate_samples.back()?;
+        let elapsed = last_at.duration_since(*first_at).as_secs_f64();
+        if elapsed < 0.5 || last_bytes <= first_bytes {
+            return None;
+        }
+        let rate = (last_bytes - first_bytes) as f64 / elapsed;
+        let remaining = progress
+            .bytes_total
+            .saturating_sub(progress.bytes_processed);
+        let eta = (rate > 0.0).then(|| Duration::from_secs_f64(remaining as f64 / rate));
+        Some((rate, eta))
+    }
+
+    fn track_import_rate(&mut self, progress: &importer::ImportProgress) {
+        let restart = match &self.import_progress {
+            Some(previous) => {
+                previous.label != progress.label
+                    || previous.stage != progress.stage
+                    || previous.bytes_processed > progress.bytes_processed
+            }
+            None => true,
+        };
+        if restart || progress.bytes_total == 0 {
+            self.import_rate_samples.clear();
+        }
+        if progress.bytes_total == 0 {
+            return;
+        }
+        let now = Instant::now();
+        self.import_rate_samples
+            .push_back((now, progress.bytes_processed));
+        while let Some((at, _)) = self.import_rate_samples.front() {
+            if self.import_rate_samples.len() > 2
+                && now.duration_since(*at).as_millis() > IMPORT_RATE_WINDOW_MS
+            {
+                self.import_rate_samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
     pub fn import_summary_pending(&self) -> bool {
         self.import_summary_pending
     }
@@ -5109,6 +10006,141 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             .unwrap_or(false)
     }
 
+    pub fn open_setup_wizard(&mut self) {
+        self.move_mode = false;
+        let detected = game::detect_paths(self.game_id, None, None).ok();
+        let game_root = if game::looks_like_game_root(self.game_id, &self.config.game_root) {
+            self.config.game_root.clone()
+        } else {
+            detected
+                .as_ref()
+                .map(|paths| paths.game_root.clone())
+                .unwrap_or_default()
+        };
+        let larian_dir = if game::looks_like_user_dir(self.game_id, &self.config.larian_dir) {
+            self.config.larian_dir.clone()
+        } else {
+            detected
+                .as_ref()
+                .map(|paths| paths.larian_dir.clone())
+                .unwrap_or_default()
+        };
+        self.setup_wizard = Some(SetupWizard {
+            step: 0,
+            game_root,
+            larian_dir,
+            downloads_dir: self.app_config.downloads_dir.clone(),
+        });
+    }
+
+    pub fn setup_wizard_valid(&self, step: SetupStep) -> bool {
+        let Some(wizard) = &self.setup_wizard else {
+            return false;
+        };
+        let path = wizard.path(step);
+        match step {
+            SetupStep::GameRoot => game::looks_like_game_root(self.game_id, path),
+            SetupStep::LarianDir => game::looks_like_user_dir(self.game_id, path),
+            SetupStep::DownloadsDir => path.is_dir(),
+        }
+    }
+
+    pub fn setup_wizard_next(&mut self) -> Result<()> {
+        let Some(step) = self
+            .setup_wizard
+            .as_ref()
+            .map(|wizard| wizard.current_step())
+        else {
+            return Ok(());
+        };
+        let Some(step) = step else {
+            return self.setup_wizard_finish();
+        };
+        if !self.setup_wizard_valid(step) {
+            let hint = match step {
+                SetupStep::GameRoot => "Select the BG3 install root (Data/ and bin/)",
+                SetupStep::LarianDir => "Select the Larian data dir (PlayerProfiles/)",
+                SetupStep::DownloadsDir => "Select an existing downloads folder",
+            };
+            self.status = hint.to_string();
+            self.set_toast(hint, ToastLevel::Warn, Duration::from_secs(3));
+            return Ok(());
+        }
+        if step == SetupStep::GameRoot && !self.setup_wizard_valid(SetupStep::LarianDir) {
+            let game_root = self
+                .setup_wizard
+                .as_ref()
+                .map(|wizard| wizard.game_root.clone())
+                .unwrap_or_default();
+            if let Ok(paths) = game::detect_paths(self.game_id, Some(&game_root), None) {
+                if let Some(wizard) = &mut self.setup_wizard {
+                    wizard.larian_dir = paths.larian_dir;
+                }
+            }
+        }
+        if let Some(wizard) = &mut self.setup_wizard {
+            wizard.step += 1;
+        }
+        Ok(())
+    }
+
+    pub fn setup_wizard_back(&mut self) {
+        let Some(wizard) = &mut self.setup_wizard else {
+            return;
+        };
+        if wizard.step == 0 {
+            self.setup_wizard = None;
+            // Backing out counts as dismissed; the wizard stays reachable from the menu.
+            if !self.app_config.setup_completed {
+                self.app_config.setup_completed = true;
+                if let Err(err) = self.app_config.save() {
+                    self.log_warn(format!("Setup wizard dismiss not saved: {err}"));
+                }
+            }
+            self.status = "Setup wizard closed (Menu → Run Setup Wizard reopens it)".to_string();
+            self.ensure_setup();
+            return;
+        }
+        wizard.step -= 1;
+    }
+
+    pub fn setup_wizard_browse(&mut self) {
+        let Some(step) = self
+            .setup_wizard
+            .as_ref()
+            .and_then(|wizard| wizard.current_step())
+        else {
+            return;
+        };
+        self.start_setup(step);
+    }
+
+    fn setup_wizard_set_path(&mut self, step: SetupStep, path: PathBuf) {
+        if let Some(wizard) = &mut self.setup_wizard {
+            *wizard.path_mut(step) = path;
+        }
+    }
+
+    fn setup_wizard_finish(&mut self) -> Result<()> {
+        let Some(wizard) = self.setup_wizard.take() else {
+            return Ok(());
+        };
+        self.config.game_root = wizard.game_root;
+        self.config.set_larian_dir(wizard.larian_dir);
+        self.config.save()?;
+        self.app_config.downloads_dir = wizard.downloads_dir;
+        self.app_config.setup_completed = true;
+        self.app_config.save()?;
+        self.status = "Setup complete".to_string();
+        self.log_info(format!(
+            "Setup complete: root {}, user {}",
+            self.config.game_root.display(),
+            self.config.larian_dir.display()
+        ));
+        self.set_toast("Setup complete", ToastLevel::Info, Duration::from_secs(3));
+        Ok(())
+    }
+
     fn ensure_setup(&mut self) {
         if self.paths_ready() {
             return;
@@ -5171,6 +10203,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             PathBrowserPurpose::Setup(_)
             | PathBrowserPurpose::ImportProfile
             | PathBrowserPurpose::ExportLog
+            | PathBrowserPurpose::ExportDiagnostics
+            | PathBrowserPurpose::StageDeploy
+            | PathBrowserPurpose::ImportSettings
             | PathBrowserPurpose::SigilLinkCache { .. } => current.display().to_string(),
             PathBrowserPurpose::ExportProfile { profile, kind } => {
                 let default_path = self.default_profile_export_path(profile, *kind);
@@ -5194,8 +10229,12 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             PathBrowserPurpose::ExportProfile { kind, .. } => match kind {
                 ExportKind::ModList => "Export mod list",
                 ExportKind::Modsettings => "Export modsettings.lsx",
+                ExportKind::UuidList => "Export enabled UUID list",
             },
             PathBrowserPurpose::ExportLog => "Export Log File",
+            PathBrowserPurpose::ExportDiagnostics => "Copy Diagnostics Bundle",
+            PathBrowserPurpose::StageDeploy => "Deploy To Staging Folder",
+            PathBrowserPurpose::ImportSettings => "Import Settings",
             PathBrowserPurpose::SigilLinkCache { action, .. } => match action {
                 SigilLinkCacheAction::Move => "Move SigiLink Cache",
                 SigilLinkCacheAction::Relocate { .. } => "Select SigiLink Cache Folder",
@@ -5231,6 +10270,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 }
                 candidates.push(home.join(".steam/steam/steamapps/common"));
                 candidates.push(home.join(".local/share/Steam/steamapps/common"));
+                candidates.push(home.join(FLATPAK_STEAM_DIR).join("steamapps/common"));
             }
             PathBrowserPurpose::Setup(SetupStep::LarianDir) => {
                 if !self.config.larian_dir.as_os_str().is_empty() {
@@ -5240,6 +10280,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 candidates.push(home.join(
                     ".local/share/Steam/steamapps/compatdata/1086940/pfx/drive_c/users/steamuser/AppData/Local/Larian Studios",
                 ));
+                candidates.push(home.join(FLATPAK_STEAM_DIR).join(
+                    "steamapps/compatdata/1086940/pfx/drive_c/users/steamuser/AppData/Local/Larian Studios",
+                ));
             }
             PathBrowserPurpose::Setup(SetupStep::DownloadsDir) => {
                 if !self.app_config.downloads_dir.as_os_str().is_empty() {
@@ -5266,7 +10309,10 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 }
                 candidates.push(self.export_dir());
             }
-            PathBrowserPurpose::ExportLog => {
+            PathBrowserPurpose::ExportLog
+            | PathBrowserPurpose::ExportDiagnostics
+            | PathBrowserPurpose::StageDeploy
+            | PathBrowserPurpose::ImportSettings => {
                 if let Some(last_dir) = last_browser_dir {
                     candidates.push(last_dir);
                 }
@@ -5298,7 +10344,10 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         match purpose {
             PathBrowserPurpose::ImportProfile
             | PathBrowserPurpose::ExportProfile { .. }
-            | PathBrowserPurpose::ExportLog => {}
+            | PathBrowserPurpose::ExportLog
+            | PathBrowserPurpose::ExportDiagnostics
+            | PathBrowserPurpose::StageDeploy
+            | PathBrowserPurpose::ImportSettings => {}
             _ => return,
         }
         let dir = if path.is_dir() {
@@ -5358,19 +10407,6 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         )
     }
 
-    fn export_timestamp_rfc3339(&self) -> String {
-        let now = time::OffsetDateTime::now_utc();
-        format!(
-            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
-            now.year(),
-            now.month() as u8,
-            now.day(),
-            now.hour(),
-            now.minute(),
-            now.second()
-        )
-    }
-
     fn write_atomic_text(path: &Path, contents: &str) -> Result<()> {
         let parent = path.parent().context("export parent dir")?;
         fs::create_dir_all(parent).context("create export dir")?;
@@ -5414,6 +10450,12 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 profile_part,
                 stamp
             ),
+            ExportKind::UuidList => format!(
+                "uuids-{}-{}-{}.txt",
+                self.game_id.as_str(),
+                profile_part,
+                stamp
+            ),
         };
         base.join(filename)
     }
@@ -5431,8 +10473,12 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 game::looks_like_user_dir(self.game_id, path)
             }
             PathBrowserPurpose::Setup(SetupStep::DownloadsDir) => path.is_dir(),
-            PathBrowserPurpose::ImportProfile => path.is_file(),
-            PathBrowserPurpose::ExportLog => path.is_dir(),
+            PathBrowserPurpose::ImportProfile | PathBrowserPurpose::ImportSettings => {
+                path.is_file()
+            }
+            PathBrowserPurpose::ExportLog
+            | PathBrowserPurpose::ExportDiagnostics
+            | PathBrowserPurpose::StageDeploy => path.is_dir(),
             PathBrowserPurpose::ExportProfile { .. } => {
                 let parent = path.parent().unwrap_or_else(|| Path::new("."));
                 parent.is_dir() && path.file_name().is_some() && !path.is_dir()
@@ -5498,6 +10544,8 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             purpose,
             PathBrowserPurpose::Setup(_)
                 | PathBrowserPurpose::ExportLog
+                | PathBrowserPurpose::ExportDiagnostics
+                | PathBrowserPurpose::StageDeploy
                 | PathBrowserPurpose::SigilLinkCache { .. }
         );
         if show_select {
@@ -5533,7 +10581,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         let mut files = Vec::new();
         let include_files = matches!(
             purpose,
-            PathBrowserPurpose::ImportProfile | PathBrowserPurpose::ExportProfile { .. }
+            PathBrowserPurpose::ImportProfile
+                | PathBrowserPurpose::ImportSettings
+                | PathBrowserPurpose::ExportProfile { .. }
         );
         if let Ok(read_dir) = fs::read_dir(current) {
             for entry in read_dir.flatten() {
@@ -5580,6 +10630,10 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     ) -> Result<()> {
         self.remember_last_browser_dir(purpose, &path);
         self.input_mode = InputMode::Normal;
+        if let (PathBrowserPurpose::Setup(step), Some(_)) = (purpose, &self.setup_wizard) {
+            self.setup_wizard_set_path(*step, path);
+            return Ok(());
+        }
         match purpose {
             PathBrowserPurpose::Setup(SetupStep::GameRoot) => self.submit_game_root_path(path),
             PathBrowserPurpose::Setup(SetupStep::LarianDir) => self.submit_larian_dir_path(path),
@@ -5596,6 +10650,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 self.export_profile(profile.clone(), path.display().to_string(), *kind)
             }
             PathBrowserPurpose::ExportLog => self.export_log_to_dir(&path),
+            PathBrowserPurpose::ExportDiagnostics => self.export_diagnostics_to_dir(&path),
+            PathBrowserPurpose::StageDeploy => self.queue_stage_deploy(&path),
+            PathBrowserPurpose::ImportSettings => self.import_settings(&path),
             PathBrowserPurpose::SigilLinkCache { action, .. } => {
                 self.apply_sigillink_cache_selection(path, action.clone())
             }
@@ -5726,7 +10783,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     pub fn enter_import_mode(&mut self) {
-        if self.block_mod_changes("import") {
+        if self.block_locked_profile("import") || self.block_mod_changes("import") {
             return;
         }
         self.move_mode = false;
@@ -5740,6 +10797,23 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         self.status = "Import: paste a file or folder path, then press Enter".to_string();
     }
 
+    pub fn enter_adopt_dev_folder(&mut self) {
+        if self.block_locked_profile("adopt dev folder")
+            || self.block_mod_changes("adopt dev folder")
+        {
+            return;
+        }
+        self.move_mode = false;
+        self.input_mode = InputMode::Editing {
+            prompt: "Dev folder".to_string(),
+            buffer: String::new(),
+            purpose: InputPurpose::AdoptDevFolder,
+            auto_submit: false,
+            last_edit_at: Instant::now(),
+        };
+        self.status = "Adopt dev folder: paste a mod folder path, then press Enter".to_string();
+    }
+
     pub fn enter_mod_filter(&mut self) {
         self.move_mode = false;
         self.mod_filter_snapshot = Some(self.mod_filter.clone());
@@ -5754,6 +10828,18 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     pub fn clear_mod_filter(&mut self) {
+        if self.starred_filter {
+            self.toggle_starred_filter();
+            if self.mod_filter.trim().is_empty() && !self.recent_filter {
+                return;
+            }
+        }
+        if self.recent_filter {
+            self.toggle_recent_filter();
+            if self.mod_filter.trim().is_empty() {
+                return;
+            }
+        }
         if self.mod_filter.trim().is_empty() {
             self.status = "Search already cleared".to_string();
             return;
@@ -6280,11 +11366,102 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         self.clipboard.as_mut()
     }
 
-    pub fn open_link(&mut self, link: &str) {
-        if link.trim().is_empty() {
-            return;
+    pub fn open_data_dir(&mut self) {
+        let dir = self.config.data_dir.clone();
+        self.open_dir(&dir, "data dir");
+    }
+
+    pub fn open_game_root(&mut self) {
+        let dir = self.config.game_root.clone();
+        self.open_dir(&dir, "game root");
+    }
+
+    pub fn open_larian_dir(&mut self) {
+        let dir = self.config.larian_dir.clone();
+        self.open_dir(&dir, "Larian dir");
+    }
+
+    fn open_dir(&mut self, dir: &Path, label: &str) {
+        if dir.as_os_str().is_empty() {
+            self.status = format!("{label} not set: open Menu (Esc) to configure");
+            return;
+        }
+        if !dir.is_dir() {
+            self.status = format!("{label} missing: {}", dir.display());
+            self.log_warn(format!("Open {label} failed: {} not found", dir.display()));
+            return;
+        }
+        self.open_external(&dir.to_string_lossy(), label);
+    }
+
+    pub fn open_link(&mut self, link: &str) {
+        if link.trim().is_empty() {
+            return;
+        }
+        self.open_external(link, "link");
+    }
+
+    pub fn active_profile_locked(&self) -> bool {
+        self.library
+            .active_profile()
+            .is_some_and(|profile| profile.locked)
+    }
+
+    fn block_locked_profile(&mut self, action: &str) -> bool {
+        if !self.active_profile_locked() {
+            return false;
+        }
+        self.status = format!(
+            "Profile \"{}\" is locked: {action} blocked (x in Profiles to unlock)",
+            self.library.active_profile
+        );
+        self.set_toast(
+            "Profile is locked - unlock it to make changes",
+            ToastLevel::Warn,
+            Duration::from_secs(2),
+        );
+        true
+    }
+
+    pub fn toggle_profile_lock(&mut self, name: &str) -> Result<()> {
+        let Some(profile) = self
+            .library
+            .profiles
+            .iter_mut()
+            .find(|profile| profile.name == name)
+        else {
+            return Ok(());
+        };
+        profile.locked = !profile.locked;
+        let state = if profile.locked { "locked" } else { "unlocked" };
+        self.library.save(&self.config.data_dir)?;
+        self.status = format!("Profile {state}: {name}");
+        self.log_info(format!("Profile {state}: {name}"));
+        Ok(())
+    }
+
+    pub fn toggle_default_profile(&mut self, name: &str) -> Result<()> {
+        if !self
+            .library
+            .profiles
+            .iter()
+            .any(|profile| profile.name == name)
+        {
+            return Ok(());
         }
-        self.open_external(link, "link");
+        let game = self.game_id.display_name();
+        if self.config.default_profile.as_deref() == Some(name) {
+            self.config.default_profile = None;
+            self.config.save()?;
+            self.status = format!("Default profile cleared: {name}");
+            self.log_info(format!("Default profile for {game} cleared: {name}"));
+        } else {
+            self.config.default_profile = Some(name.to_string());
+            self.config.save()?;
+            self.status = format!("Default profile: {name}");
+            self.log_info(format!("Default profile for {game}: {name}"));
+        }
+        Ok(())
     }
 
     fn block_mod_changes(&mut self, action: &str) -> bool {
@@ -6310,6 +11487,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                         Some(SmartRankMode::Warmup) => "SigiLink ranking warmup",
                         Some(SmartRankMode::Auto) => "SigiLink ranking",
                         Some(SmartRankMode::Preview) => "SigiLink ranking preview",
+                        Some(SmartRankMode::Report) => "Compatibility report",
                         None => "SigiLink Intelligent Ranking",
                     };
                     self.status = format!("{label} running: {action} blocked");
@@ -6403,9 +11581,14 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     pub fn handle_submit(&mut self, purpose: InputPurpose, value: String) -> Result<()> {
         match purpose {
             InputPurpose::ImportPath => self.import_mod(value),
+            InputPurpose::AdoptDevFolder => self.adopt_dev_folder(value),
             InputPurpose::CreateProfile => self.create_profile(value),
             InputPurpose::RenameProfile { original } => self.rename_profile(original, value),
-            InputPurpose::DuplicateProfile { source } => self.duplicate_profile(source, value),
+            InputPurpose::RenameMod { id } => self.rename_mod(id, value),
+            InputPurpose::DuplicateProfile {
+                source,
+                enabled_only,
+            } => self.duplicate_profile(source, value, enabled_only),
             InputPurpose::ExportProfile { profile, kind } => {
                 self.export_profile(profile, value, kind)
             }
@@ -6415,6 +11598,14 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 self.mod_filter_snapshot = None;
                 Ok(())
             }
+            InputPurpose::SearchLog => {
+                self.apply_log_search(value);
+                Ok(())
+            }
+            InputPurpose::MoveToSlot => {
+                self.move_selected_to_slot(&value);
+                Ok(())
+            }
         }
     }
 
@@ -6458,7 +11649,11 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     pub fn import_mod(&mut self, raw_path: String) -> Result<()> {
-        if self.block_mod_changes("import") {
+        if self.block_locked_profile("import") || self.block_mod_changes("import") {
+            return Ok(());
+        }
+        if importer::is_url(&raw_path) {
+            self.queue_url_import(&raw_path);
             return Ok(());
         }
         let path = expand_tilde(raw_path.trim());
@@ -6487,6 +11682,51 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         Ok(())
     }
 
+    pub fn adopt_dev_folder(&mut self, raw_path: String) -> Result<()> {
+        if self.block_locked_profile("adopt dev folder")
+            || self.block_mod_changes("adopt dev folder")
+        {
+            return Ok(());
+        }
+        let path = expand_tilde(raw_path.trim());
+        if !path.is_dir() {
+            let display = display_path(&path);
+            self.status = format!("Adopt failed: {display} (not a folder)");
+            self.log_warn(format!("Dev folder not found: {}", path.display()));
+            self.set_toast(
+                &format!("Adopt failed: {display} (not a folder)"),
+                ToastLevel::Warn,
+                Duration::from_secs(3),
+            );
+            return Ok(());
+        }
+
+        self.import_dev_paths.insert(path.clone());
+        self.import_queue.push_back(path.clone());
+        self.log_info(format!("Queued dev folder link: {}", path.display()));
+        if let Some(active) = &self.import_active {
+            let queued = self.import_queue.len();
+            self.status = format!("Importing {} (queued {})", display_path(active), queued);
+        } else {
+            self.status = format!("Linking dev folder: {}", display_path(&path));
+        }
+        self.start_next_import();
+        Ok(())
+    }
+
+    fn queue_url_import(&mut self, url: &str) {
+        let url = url.trim();
+        self.import_queue.push_back(PathBuf::from(url));
+        self.log_info(format!("Queued download: {url}"));
+        if let Some(active) = &self.import_active {
+            let queued = self.import_queue.len();
+            self.status = format!("Importing {} (queued {})", display_path(active), queued);
+        } else {
+            self.status = format!("Queued download: {url}");
+        }
+        self.start_next_import();
+    }
+
     fn submit_game_root_path(&mut self, path: PathBuf) -> Result<()> {
         if !path.exists() {
             self.status = format!("Path not found: {}", path.display());
@@ -6505,8 +11745,14 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         self.config.game_root = path.clone();
         match game::detect_paths(self.game_id, Some(&path), None) {
             Ok(paths) => {
-                self.config.larian_dir = paths.larian_dir;
+                self.config.set_larian_dir(paths.larian_dir);
                 self.config.save()?;
+                if self.config.larian_dir_source == Some(LarianDirSource::Proton) {
+                    self.log_info(format!(
+                        "Using Proton Larian dir: {}",
+                        self.config.larian_dir.display()
+                    ));
+                }
                 self.status = "Game paths set".to_string();
                 self.log_info(format!("Game root set: {}", path.display()));
                 self.set_toast("Paths updated", ToastLevel::Info, Duration::from_secs(2));
@@ -6544,7 +11790,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             return Ok(());
         }
 
-        self.config.larian_dir = path.clone();
+        self.config.set_larian_dir(path.clone());
         self.config.save()?;
         self.status = "Game paths set".to_string();
         self.log_info(format!("Larian dir set: {}", path.display()));
@@ -6572,35 +11818,170 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         Ok(())
     }
 
-    pub fn import_mods_cli(&mut self, paths: Vec<String>, options: CliImportOptions) -> Result<()> {
-        let mut total_imported = 0usize;
-        let mut failures: Vec<importer::ImportFailure> = Vec::new();
+    pub fn rank_profile_cli(
+        &mut self,
+        profile: Option<&str>,
+        apply: bool,
+    ) -> Result<CliRankReport> {
+        if !self.paths_ready() {
+            return Err(anyhow::anyhow!(
+                "Paths not set (configure game root + Larian dir)"
+            ));
+        }
+        let name = profile
+            .map(str::to_string)
+            .unwrap_or_else(|| self.library.active_profile.clone());
+        let Some(target) = self
+            .library
+            .profiles
+            .iter()
+            .find(|profile| profile.name == name)
+            .cloned()
+        else {
+            return Err(anyhow::anyhow!("Unknown profile: {name}"));
+        };
+        if is_sigillink_ranking_profile(&name) {
+            return Err(anyhow::anyhow!("Cannot rank the internal SigiLink profile"));
+        }
+        if apply && target.locked {
+            return Err(anyhow::anyhow!("Profile \"{name}\" is locked"));
+        }
+        let is_active = name == self.library.active_profile;
+        let mut library = self.library.clone();
+        library.active_profile = name.clone();
+        let mod_cache = self
+            .smart_rank_cache
+            .as_ref()
+            .filter(|cache| cache.version == SMART_RANK_CACHE_VERSION)
+            .map(|cache| cache.mod_cache.clone());
+        let computed = smart_rank::smart_rank_profile_cached_with_progress(
+            &self.config,
+            &library,
+            mod_cache.as_ref(),
+            smart_rank::SmartRankRefreshMode::Incremental,
+            self.app_config.sigillink_weights,
+            |_| {},
+        )?;
+        if computed.result.order.len() != target.order.len() {
+            return Err(anyhow::anyhow!(
+                "SigiLink ranking returned an incomplete order"
+            ));
+        }
+        let pins = Self::sigillink_effective_pins(
+            &target,
+            &self.library.sigillink_excluded,
+            self.app_config.sigillink_ranking_enabled,
+        );
+        let proposed = Self::apply_sigillink_pins(computed.result.order.clone(), &pins);
 
-        for raw_path in paths {
-            let mut apply_all: Option<bool> = None;
-            let path = expand_tilde(raw_path.trim());
-            if !path.exists() {
-                let label = path.display().to_string();
-                if options.verbosity != CliVerbosity::Quiet {
-                    eprintln!("Import path not found: {label}");
-                }
-                failures.push(importer::ImportFailure {
-                    source: importer::ImportSource { label },
-                    error: "path not found".to_string(),
-                });
+        let mod_map = self.library.index_by_id();
+        let proposed_index: HashMap<&str, usize> = proposed
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (entry.id.as_str(), index))
+            .collect();
+        let mut moves = Vec::new();
+        for (from, entry) in target.order.iter().enumerate() {
+            let Some(to) = proposed_index.get(entry.id.as_str()).copied() else {
+                continue;
+            };
+            if from == to {
                 continue;
             }
+            let (name, created_at, added_at) = match mod_map.get(&entry.id) {
+                Some(mod_entry) => (
+                    mod_entry.display_name(),
+                    mod_entry.created_at,
+                    mod_entry.added_at,
+                ),
+                None => (entry.id.clone(), None, 0),
+            };
+            moves.push(SmartRankMove {
+                name,
+                from,
+                to,
+                created_at,
+                added_at,
+            });
+        }
 
-            if options.verbosity != CliVerbosity::Quiet {
-                println!("Importing: {}", path.display());
+        if is_active {
+            let profile_key = self.smart_rank_profile_key();
+            self.smart_rank_cache = Some(SmartRankCache {
+                version: SMART_RANK_CACHE_VERSION,
+                profile_key,
+                mod_cache: computed.cache,
+                result: Some(computed.result.clone()),
+            });
+            self.maybe_save_smart_rank_cache(true);
+        }
+
+        let applied = apply && !moves.is_empty();
+        if applied {
+            let snapshot = SigilLinkRankSnapshot {
+                profile: name.clone(),
+                ranked_at: now_timestamp(),
+                moves: moves.len(),
+                before: target.order.clone(),
+                after: proposed.clone(),
+            };
+            if let Some(profile) = self
+                .library
+                .profiles
+                .iter_mut()
+                .find(|profile| profile.name == name)
+            {
+                profile.order = proposed.clone();
             }
+            let inputs_hash = if is_active {
+                self.sigillink_inputs_hash()
+            } else {
+                None
+            };
+            let moved = moves.len();
+            let pins = target.sigillink_pins.len();
+            if let Some(rank_profile) = self.sigillink_ranking_profile_mut() {
+                rank_profile.sigillink_meta.push_history(snapshot);
+                rank_profile.sigillink_meta.last_ranked_at = Some(now_timestamp());
+                rank_profile.sigillink_meta.last_moves = moved;
+                rank_profile.sigillink_meta.last_pins = pins;
+                if is_active {
+                    rank_profile.order = proposed;
+                    rank_profile.file_overrides = target.file_overrides.clone();
+                    rank_profile.sigillink_meta.last_inputs_hash = inputs_hash;
+                }
+            }
+            self.library.save(&self.config.data_dir)?;
+            self.log_info(format!(
+                "SigiLink ranking applied from CLI to \"{name}\": {moved} mod(s) moved"
+            ));
+        }
+
+        Ok(CliRankReport {
+            profile: name,
+            total: target.order.len(),
+            moves,
+            warnings: computed.result.warnings,
+            applied,
+        })
+    }
+
+    pub fn import_mods_cli(&mut self, paths: Vec<String>, options: CliImportOptions) -> Result<()> {
+        let mut total_imported = 0usize;
+        let mut failures: Vec<importer::ImportFailure> = Vec::new();
+        let mut sources: Vec<CliImportSourceResult> = Vec::new();
+        let to_stderr = options.json;
 
+        for raw_path in paths {
+            let failures_before = failures.len();
+            let mut apply_all: Option<bool> = None;
             let printer = if matches!(
                 options.verbosity,
                 CliVerbosity::Verbose | CliVerbosity::Debug
             ) {
                 Some(Arc::new(Mutex::new(CliProgressPrinter::new(
                     options.verbosity,
+                    to_stderr,
                 ))))
             } else {
                 None
@@ -6616,14 +11997,83 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 callback
             });
 
+            let mut downloaded: Option<importer::DownloadedFile> = None;
+            let path = if importer::is_url(&raw_path) {
+                let url = raw_path.trim().to_string();
+                if options.verbosity != CliVerbosity::Quiet {
+                    cli_line(to_stderr, format_args!("Downloading: {url}"));
+                }
+                let result = importer::download_url(
+                    &url,
+                    &self.config.sigillink_cache_root(),
+                    progress.clone(),
+                    None,
+                );
+                let download = match result {
+                    Ok(file) if file.kind == importer::DownloadKind::Archive => {
+                        let path = file.path.clone();
+                        downloaded = Some(file);
+                        Ok(path)
+                    }
+                    Ok(file) => {
+                        file.cleanup();
+                        Err("mod list downloads must be imported from the TUI".to_string())
+                    }
+                    Err(err) => Err(err.to_string()),
+                };
+                match download {
+                    Ok(path) => path,
+                    Err(error) => {
+                        if options.verbosity != CliVerbosity::Quiet {
+                            eprintln!("Import failed: {url} ({})", summarize_error(&error));
+                        }
+                        failures.push(importer::ImportFailure {
+                            source: importer::ImportSource { label: url.clone() },
+                            error,
+                        });
+                        sources.push(CliImportSourceResult::failed(&raw_path, url));
+                        continue;
+                    }
+                }
+            } else {
+                let path = expand_tilde(raw_path.trim());
+                if !path.exists() {
+                    let label = path.display().to_string();
+                    if options.verbosity != CliVerbosity::Quiet {
+                        eprintln!("Import path not found: {label}");
+                    }
+                    failures.push(importer::ImportFailure {
+                        source: importer::ImportSource {
+                            label: label.clone(),
+                        },
+                        error: "path not found".to_string(),
+                    });
+                    sources.push(CliImportSourceResult::failed(&raw_path, label));
+                    continue;
+                }
+                path
+            };
+
+            let source_display = downloaded
+                .as_ref()
+                .map(|file| file.url.clone())
+                .unwrap_or_else(|| path.display().to_string());
+            if options.verbosity != CliVerbosity::Quiet {
+                cli_line(to_stderr, format_args!("Importing: {source_display}"));
+            }
+
             let start = Instant::now();
-            let imports = match importer::import_path_with_progress(
+            let imports = importer::import_path_with_progress(
                 &path,
                 &self.config.sigillink_cache_root(),
                 progress,
+                None,
             )
-            .with_context(|| format!("import {path:?}"))
-            {
+            .with_context(|| format!("import {path:?}"));
+            if let Some(file) = &downloaded {
+                file.cleanup();
+            }
+            let imports = match imports {
                 Ok(imports) => imports,
                 Err(err) => {
                     let label = path.display().to_string();
@@ -6637,6 +12087,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                         source: importer::ImportSource { label },
                         error: err.to_string(),
                     });
+                    sources.push(CliImportSourceResult::failed(&raw_path, source_display));
                     continue;
                 }
             };
@@ -6650,6 +12101,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                     source: importer::ImportSource { label },
                     error: "unrecognized layout".to_string(),
                 });
+                sources.push(CliImportSourceResult::failed(&raw_path, source_display));
                 continue;
             }
 
@@ -6668,27 +12120,56 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             }
 
             let mut path_imported = 0usize;
+            let mut path_mods: Vec<String> = Vec::new();
             for batch in imports.batches {
                 let source_label = batch.source.label.clone();
                 if matches!(
                     options.verbosity,
                     CliVerbosity::Verbose | CliVerbosity::Debug
                 ) {
-                    println!("  Source: {}", source_label);
+                    cli_line(to_stderr, format_args!("  Source: {}", source_label));
                 }
                 let mut approved = Vec::new();
                 for import_mod in batch.mods {
                     let mod_entry = &import_mod.entry;
+                    if let Some(existing) = self.find_duplicate_by_content(&import_mod).cloned() {
+                        self.link_identical_import(&existing.id, mod_entry);
+                        if options.verbosity != CliVerbosity::Quiet {
+                            cli_line(
+                                to_stderr,
+                                format_args!(
+                                    "  Already in library as {} (identical content), linked as update",
+                                    existing.display_name()
+                                ),
+                            );
+                        }
+                        self.cleanup_import_staging(&import_mod);
+                        continue;
+                    }
                     if let Some(existing) = self.find_duplicate_by_name(&mod_entry.name).cloned() {
                         let default_overwrite = duplicate_default_overwrite(mod_entry, &existing);
                         let overwrite = if let Some(choice) = apply_all {
                             choice
+                        } else if let Some(choice) = options.on_duplicate.resolve(default_overwrite)
+                        {
+                            if options.verbosity != CliVerbosity::Quiet {
+                                cli_line(
+                                    to_stderr,
+                                    format_args!(
+                                        "  Duplicate of {}: {}",
+                                        existing.display_name(),
+                                        if choice { "overwriting" } else { "skipping" }
+                                    ),
+                                );
+                            }
+                            choice
                         } else {
                             let resolution = prompt_duplicate_cli(
                                 mod_entry,
                                 &existing,
                                 default_overwrite,
                                 None,
+                                to_stderr,
                             )?;
                             match resolution {
                                 CliDuplicateAction::Overwrite => true,
@@ -6734,12 +12215,26 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                         };
                         let overwrite = if let Some(choice) = apply_all {
                             choice
+                        } else if let Some(choice) = options.on_duplicate.resolve(default_overwrite)
+                        {
+                            if options.verbosity != CliVerbosity::Quiet {
+                                cli_line(
+                                    to_stderr,
+                                    format_args!(
+                                        "  Duplicate of {}: {}",
+                                        existing.display_name(),
+                                        if choice { "overwriting" } else { "skipping" }
+                                    ),
+                                );
+                            }
+                            choice
                         } else {
                             let resolution = prompt_duplicate_cli(
                                 mod_entry,
                                 &existing,
                                 default_overwrite,
                                 Some(similar.similarity),
+                                to_stderr,
                             )?;
                             match resolution {
                                 CliDuplicateAction::Overwrite => true,
@@ -6785,9 +12280,12 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                         self.log_warn(warning);
                     }
                     if !applied.is_empty() {
+                        let names: Vec<String> =
+                            applied.iter().map(|entry| entry.display_name()).collect();
                         match self.apply_imported_mod_entries(applied) {
                             Ok(count) => {
                                 path_imported = path_imported.saturating_add(count);
+                                path_mods.extend(names);
                             }
                             Err(err) => {
                                 failures.push(importer::ImportFailure {
@@ -6818,18 +12316,33 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             }
 
             total_imported = total_imported.saturating_add(path_imported);
+            let elapsed = start.elapsed().as_millis();
             if options.verbosity != CliVerbosity::Quiet {
-                let elapsed = start.elapsed().as_millis();
-                println!(
-                    "Imported {} mod(s) from {} in {}ms",
-                    path_imported,
-                    path.display(),
-                    elapsed
+                cli_line(
+                    to_stderr,
+                    format_args!(
+                        "Imported {} mod(s) from {} in {}ms",
+                        path_imported, source_display, elapsed
+                    ),
                 );
             }
+            let path_failures = failures.len() - failures_before;
+            sources.push(CliImportSourceResult {
+                input: raw_path,
+                source: source_display,
+                status: match (path_imported, path_failures) {
+                    (_, 0) => "ok",
+                    (0, _) => "failed",
+                    _ => "partial",
+                },
+                imported: path_imported,
+                mods: path_mods,
+                failures: path_failures,
+                elapsed_ms: elapsed,
+            });
         }
 
-        if options.verbosity != CliVerbosity::Quiet {
+        if options.verbosity != CliVerbosity::Quiet && !options.json {
             if failures.is_empty() {
                 println!("Import complete: {} mod(s) imported", total_imported);
             } else {
@@ -6851,53 +12364,102 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             }
         }
 
+        let mut deploy_summary = None;
+        let mut deploy_error = None;
         if options.deploy {
             if !self.paths_ready() {
                 if options.verbosity != CliVerbosity::Quiet {
                     eprintln!("Deploy skipped: game paths not set");
                 }
-                return Ok(());
-            }
-            if total_imported == 0 {
+                deploy_summary = Some(CliImportDeploySummary::skipped("game paths not set"));
+                deploy_error = Some(
+                    anyhow::anyhow!("game paths not set; deploy skipped")
+                        .context(CliExitCode::PathsNotReady),
+                );
+            } else if total_imported == 0 {
                 if options.verbosity != CliVerbosity::Quiet {
-                    println!("No imports to deploy");
+                    cli_line(to_stderr, format_args!("No imports to deploy"));
                 }
-                return Ok(());
-            }
-
-            if options.verbosity != CliVerbosity::Quiet {
-                println!("Deploying imported mods...");
-            }
-            let mut library = self.library.clone();
-            match deploy::deploy_with_options(
-                &self.config,
-                &mut library,
-                deploy::DeployOptions {
-                    backup: true,
-                    reason: Some("cli import".to_string()),
-                },
-            ) {
-                Ok(report) => {
-                    if options.verbosity != CliVerbosity::Quiet {
-                        println!(
-                            "Deploy complete: {} pak, {} loose ({} files)",
-                            report.pak_count, report.loose_count, report.file_count
-                        );
-                        for warning in &report.warnings {
-                            eprintln!("Deploy warning: {warning}");
+                deploy_summary = Some(CliImportDeploySummary::skipped("nothing imported"));
+            } else {
+                if options.verbosity != CliVerbosity::Quiet {
+                    cli_line(to_stderr, format_args!("Deploying imported mods..."));
+                }
+                let mut library = self.library.clone();
+                match deploy::deploy_with_options(
+                    &self.config,
+                    &mut library,
+                    deploy::DeployOptions {
+                        backup: true,
+                        reason: Some("cli import".to_string()),
+                        skip_loose: false,
+                        warnings: Vec::new(),
+                        cancel: None,
+                        target_override: None,
+                    },
+                ) {
+                    Ok(report) => {
+                        if options.verbosity != CliVerbosity::Quiet {
+                            cli_line(
+                                to_stderr,
+                                format_args!(
+                                    "Deploy complete: {} pak, {} loose ({} files)",
+                                    report.pak_count, report.loose_count, report.file_count
+                                ),
+                            );
+                            for warning in &report.warnings {
+                                eprintln!("Deploy warning: {warning}");
+                            }
                         }
+                        deploy_summary = Some(CliImportDeploySummary {
+                            status: "deployed",
+                            reason: None,
+                            pak_count: report.pak_count,
+                            loose_count: report.loose_count,
+                            file_count: report.file_count,
+                            warnings: report.warnings.clone(),
+                        });
+                        self.library = library;
                     }
-                    self.library = library;
-                }
-                Err(err) => {
-                    if options.verbosity != CliVerbosity::Quiet {
-                        eprintln!("Deploy failed: {}", summarize_error(&err.to_string()));
+                    Err(err) => {
+                        if options.verbosity != CliVerbosity::Quiet {
+                            eprintln!("Deploy failed: {}", summarize_error(&err.to_string()));
+                        }
+                        deploy_summary = Some(CliImportDeploySummary {
+                            status: "failed",
+                            reason: Some(err.to_string()),
+                            ..CliImportDeploySummary::skipped("")
+                        });
+                        deploy_error = Some(cli_deploy_error(err));
                     }
-                    return Err(err);
                 }
             }
         }
 
+        if options.json {
+            let summary = CliImportSummary {
+                imported: total_imported,
+                sources,
+                failures: failures
+                    .iter()
+                    .map(|failure| CliImportFailureEntry {
+                        source: failure.source.label.clone(),
+                        reason: summarize_error(&failure.error),
+                        error: failure.error.clone(),
+                    })
+                    .collect(),
+                deploy: deploy_summary,
+            };
+            println!("{}", serde_json::to_string_pretty(&summary)?);
+        }
+
+        if let Some(err) = deploy_error {
+            return Err(err);
+        }
+        if !failures.is_empty() {
+            return Err(anyhow::anyhow!("{} import failure(s)", failures.len())
+                .context(CliExitCode::ImportFailed));
+        }
         Ok(())
     }
 
@@ -6923,6 +12485,11 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         self.maybe_start_deploy();
         self.poll_conflicts();
         self.maybe_start_conflict_scan();
+        self.poll_toggle_impact();
+        self.poll_cache_verify();
+        self.poll_cache_size();
+        self.poll_cache_restage();
+        self.maybe_start_cache_restage();
 
         if self.dependency_queue.is_none()
             && self.import_active.is_none()
@@ -6999,10 +12566,12 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                                 "SigiLink ranking scan ignored (profile changed)".to_string(),
                             );
                             self.clear_smart_rank_scan_state();
+                            self.sigillink_rank_scope = None;
                             continue;
                         }
                         if self.smart_rank_interrupt {
                             self.clear_smart_rank_scan_state();
+                            self.sigillink_rank_scope = None;
                             self.log_info("SigiLink ranking result ignored (stale)".to_string());
                             continue;
                         }
@@ -7040,12 +12609,17 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                                     SigilLinkMissingTrigger::Auto,
                                 );
                             }
+                            SmartRankMode::Report => {
+                                self.clear_smart_rank_scan_state();
+                                self.show_compatibility_report(&computed.cache);
+                            }
                         }
                     }
                     SmartRankMessage::Failed { scan_id, error } => {
                         if !self.smart_rank_scan_matches(scan_id) {
                             continue;
                         }
+                        self.sigillink_rank_scope = None;
                         if self.smart_rank_interrupt {
                             self.clear_smart_rank_scan_state();
                             self.log_warn("SigiLink ranking failed after interrupt".to_string());
@@ -7070,24 +12644,58 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     fn start_metadata_refresh(&mut self) {
+        self.start_metadata_refresh_for(None);
+    }
+
+    fn start_metadata_refresh_for(&mut self, ids: Option<HashSet<String>>) {
         if self.metadata_active {
             return;
         }
+        let mut library = self.library.clone();
+        let force = ids.is_some();
+        if let Some(ids) = &ids {
+            library.mods.retain(|mod_entry| ids.contains(&mod_entry.id));
+        } else {
+            self.dependency_cache_ready = false;
+            let cached_mods =
+                take_cached_metadata(&mut library.mods, &self.library.metadata_mod_keys);
+            let cached = cached_mods.len();
+            for mod_entry in cached_mods {
+                self.dependency_cache
+                    .insert(mod_entry.id, mod_entry.dependencies);
+            }
+            if cached > 0 {
+                self.log_info(format!(
+                    "Metadata refresh resumed: {cached} cached, {} to scan",
+                    library.mods.len()
+                ));
+            }
+        }
         self.metadata_active = true;
         self.metadata_processed = 0;
-        self.metadata_total = self.library.mods.len();
+        self.metadata_total = library.mods.len();
         self.metadata_processed_ids.clear();
         self.metadata_dirty = false;
-        self.dependency_cache_ready = false;
+        self.metadata_unsaved = 0;
+        self.metadata_targeted = force;
+        self.metadata_cancel = Arc::new(AtomicBool::new(false));
         let tx = self.metadata_tx.clone();
         let config = self.config.clone();
-        let library = self.library.clone();
         let game_id = self.game_id;
         let pak_cache = self.pak_meta_cache.clone();
+        let cancel = Arc::clone(&self.metadata_cancel);
         thread::spawn(move || {
-            let result =
-                collect_metadata_updates(game_id, &config, &library, pak_cache.as_ref(), Some(&tx));
+            let result = collect_metadata_updates(
+                game_id,
+                &config,
+                &library,
+                pak_cache.as_ref(),
+                force,
+                Some(&tx),
+                Some(cancel.as_ref()),
+            );
             let message = match result {
+                Ok(_) if cancel.load(AtomicOrdering::Relaxed) => MetadataMessage::Cancelled,
                 Ok(_) => MetadataMessage::Completed,
                 Err(err) => MetadataMessage::Failed {
                     error: err.to_string(),
@@ -7109,6 +12717,11 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 hasher.update(entry.id.as_bytes());
                 hasher.update(&[entry.enabled as u8]);
             }
+            if !profile.order_rules.is_empty() {
+                if let Ok(raw) = serde_json::to_vec(&profile.order_rules) {
+                    hasher.update(&raw);
+                }
+            }
         }
         hasher.finalize().to_hex().to_string()
     }
@@ -7255,6 +12868,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                         self.native_sync_active = false;
                         self.native_sync_progress = None;
                         self.apply_native_sync_delta(delta);
+                        self.check_uuid_collisions();
                         if self.startup_post_sync_pending {
                             self.startup_post_sync_pending = false;
                             self.run_post_sync_tasks();
@@ -7381,6 +12995,68 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         }
     }
 
+    fn apply_metadata_update(&mut self, update: MetadataUpdate) {
+        let dependencies = update.dependencies;
+        self.dependency_cache
+            .insert(update.id.clone(), dependencies.clone());
+        let Some(mod_entry) = self
+            .library
+            .mods
+            .iter_mut()
+            .find(|entry| entry.id == update.id)
+        else {
+            return;
+        };
+        if mod_entry.created_at != update.created_at {
+            mod_entry.created_at = update.created_at;
+            self.metadata_dirty = true;
+        }
+        if mod_entry.modified_at != update.modified_at {
+            mod_entry.modified_at = update.modified_at;
+            self.metadata_dirty = true;
+        }
+        if mod_entry.dependencies != dependencies {
+            mod_entry.dependencies = dependencies;
+            self.metadata_dirty = true;
+        }
+        if mod_entry.min_game_version != update.min_game_version {
+            mod_entry.min_game_version = update.min_game_version;
+            self.metadata_dirty = true;
+        }
+        let mod_key = mod_metadata_key(mod_entry);
+        for (pak_file, pak_hash) in &update.pak_hashes {
+            if record_pak_hash(&mut mod_entry.targets, pak_file, pak_hash) {
+                self.metadata_dirty = true;
+            }
+        }
+        if self.library.metadata_mod_keys.get(&update.id) != Some(&mod_key) {
+            self.library.metadata_mod_keys.insert(update.id, mod_key);
+            self.metadata_dirty = true;
+        }
+    }
+
+    pub fn scan_metadata_inline(&mut self) -> Result<()> {
+        let updates = collect_metadata_updates(
+            self.game_id,
+            &self.config,
+            &self.library,
+            self.pak_meta_cache.as_ref(),
+            false,
+            None,
+            None,
+        )?;
+        self.metadata_dirty = false;
+        for update in updates {
+            self.apply_metadata_update(update);
+        }
+        self.dependency_cache_ready = true;
+        if self.metadata_dirty {
+            self.library.save(&self.config.data_dir)?;
+            self.metadata_dirty = false;
+        }
+        Ok(())
+    }
+
     pub fn poll_metadata_refresh(&mut self) {
         loop {
             match self.metadata_rx.try_recv() {
@@ -7393,36 +13069,31 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                         self.metadata_processed = current;
                         self.metadata_total = total;
                         self.metadata_processed_ids.insert(update.id.clone());
-                        let dependencies = update.dependencies;
-                        self.dependency_cache
-                            .insert(update.id.clone(), dependencies.clone());
-                        if let Some(mod_entry) = self
-                            .library
-                            .mods
-                            .iter_mut()
-                            .find(|entry| entry.id == update.id)
-                        {
-                            if mod_entry.created_at != update.created_at {
-                                mod_entry.created_at = update.created_at;
-                                self.metadata_dirty = true;
-                            }
-                            if mod_entry.modified_at != update.modified_at {
-                                mod_entry.modified_at = update.modified_at;
-                                self.metadata_dirty = true;
-                            }
-                            if mod_entry.dependencies != dependencies {
-                                mod_entry.dependencies = dependencies;
-                                self.metadata_dirty = true;
-                            }
-                        }
+                        self.apply_metadata_update(update);
+                        self.metadata_unsaved += 1;
+                        self.maybe_save_metadata_progress();
                     }
                     MetadataMessage::Completed => {
                         self.metadata_active = false;
+                        self.mark_deploy_state_dirty();
                         self.dependency_cache_ready =
                             self.metadata_total == 0 || !self.dependency_cache.is_empty();
                         if self.dependency_cache_ready {
                             self.refresh_dependency_blocks();
                         }
+                        let before = self.library.metadata_mod_keys.len();
+                        let mod_ids: HashSet<String> = self
+                            .library
+                            .mods
+                            .iter()
+                            .map(|mod_entry| mod_entry.id.clone())
+                            .collect();
+                        self.library
+                            .metadata_mod_keys
+                            .retain(|id, _| mod_ids.contains(id));
+                        if self.library.metadata_mod_keys.len() != before {
+                            self.metadata_dirty = true;
+                        }
                         let cache_key = self.metadata_cache_key();
                         if self.library.metadata_cache_key.as_deref() != Some(&cache_key)
                             || self.library.metadata_cache_version != METADATA_CACHE_VERSION
@@ -7436,6 +13107,39 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                             self.log_info("Metadata refresh applied".to_string());
                             self.metadata_dirty = false;
                         }
+                        if self.metadata_targeted {
+                            self.metadata_targeted = false;
+                            let message =
+                                format!("Metadata refreshed for {} mod(s)", self.metadata_total);
+                            self.status = message.clone();
+                            self.set_toast(&message, ToastLevel::Info, Duration::from_secs(2));
+                        }
+                        self.run_startup_dependency_check();
+                        self.schedule_smart_rank_warmup();
+                        self.maybe_restart_smart_rank();
+                        self.maybe_prompt_pending_delete();
+                    }
+                    MetadataMessage::Cancelled => {
+                        self.metadata_active = false;
+                        self.mark_deploy_state_dirty();
+                        self.metadata_targeted = false;
+                        // Partial results stay applied; dropping the cache key forces a rescan
+                        // on the next launch, which skips mods with a valid per-mod key.
+                        if self.library.metadata_cache_key.take().is_some() {
+                            self.metadata_dirty = true;
+                        }
+                        if self.metadata_dirty {
+                            let _ = self.library.save(&self.config.data_dir);
+                            self.metadata_dirty = false;
+                        }
+                        let message = format!(
+                            "Metadata scan cancelled ({}/{} scanned)",
+                            self.metadata_processed, self.metadata_total
+                        );
+                        self.log_warn(message.clone());
+                        self.status = message.clone();
+                        self.set_toast(&message, ToastLevel::Warn, Duration::from_secs(3));
+                        self.prime_dependency_cache_from_library();
                         self.run_startup_dependency_check();
                         self.schedule_smart_rank_warmup();
                         self.maybe_restart_smart_rank();
@@ -7443,6 +13147,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                     }
                     MetadataMessage::Failed { error } => {
                         self.metadata_active = false;
+                        self.metadata_targeted = false;
                         self.log_warn(format!("Metadata refresh failed: {error}"));
                         self.schedule_smart_rank_warmup();
                         self.maybe_restart_smart_rank();
@@ -7485,6 +13190,293 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         }
     }
 
+    pub fn poll_dev_watch(&mut self) {
+        while let Ok(DevWatchMessage::Changed(id)) = self.dev_watch_rx.try_recv() {
+            self.dev_watch_changed.insert(id);
+            self.dev_watch_last_change = Some(Instant::now());
+        }
+        // Changes stay collected while paused and redeploy once automation resumes.
+        if self.automation_paused {
+            return;
+        }
+        let Some(last_change) = self.dev_watch_last_change else {
+            return;
+        };
+        if last_change.elapsed() < Duration::from_millis(DEV_WATCH_DEBOUNCE_MS) {
+            return;
+        }
+        self.dev_watch_last_change = None;
+        let changed = std::mem::take(&mut self.dev_watch_changed);
+        let enabled: HashSet<String> = self
+            .library
+            .active_profile()
+            .map(|profile| {
+                profile
+                    .order
+                    .iter()
+                    .filter(|entry| entry.enabled)
+                    .map(|entry| entry.id.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut names: Vec<String> = self
+            .library
+            .mods
+            .iter()
+            .filter(|mod_entry| {
+                mod_entry.dev_watch
+                    && changed.contains(&mod_entry.id)
+                    && enabled.contains(&mod_entry.id)
+            })
+            .map(|mod_entry| mod_entry.display_name())
+            .collect();
+        if names.is_empty() {
+            return;
+        }
+        names.sort();
+        let reason = format!("auto: dev folder changed: {}", names.join(", "));
+        self.log_info(format!("Dev watch: {}", names.join(", ")));
+        self.queue_deploy(&reason);
+    }
+
+    fn queue_disk_usage_scan(&mut self) {
+        if !matches!(self.startup_mode, StartupMode::Ui) {
+            return;
+        }
+        if self.disk_usage_active {
+            self.disk_usage_pending = true;
+            return;
+        }
+        let mods_root = library_mod_root(&self.config.sigillink_cache_root());
+        let targets: Vec<(String, PathBuf)> = self
+            .library
+            .mods
+            .iter()
+            .filter(|mod_entry| !mod_entry.is_native() && mod_entry.disk_usage.is_none())
+            .map(|mod_entry| (mod_entry.id.clone(), mods_root.join(&mod_entry.id)))
+            .collect();
+        if targets.is_empty() {
+            return;
+        }
+        let tx = self.disk_usage_tx.clone();
+        self.disk_usage_active = true;
+        thread::spawn(move || {
+            let usage = targets
+                .into_iter()
+                .filter(|(_, root)| root.exists())
+                .map(|(id, root)| (id, compute_mod_disk_usage(&root)))
+                .collect();
+            let _ = tx.send(DiskUsageMessage::Completed(usage));
+        });
+    }
+
+    pub fn poll_disk_usage(&mut self) {
+        let Ok(DiskUsageMessage::Completed(usage)) = self.disk_usage_rx.try_recv() else {
+            return;
+        };
+        self.disk_usage_active = false;
+        let usage: HashMap<String, ModDiskUsage> = usage.into_iter().collect();
+        let mut updated = 0usize;
+        for mod_entry in &mut self.library.mods {
+            if let Some(found) = usage.get(&mod_entry.id) {
+                mod_entry.disk_usage = Some(*found);
+                updated += 1;
+            }
+        }
+        if updated > 0 && self.allow_persistence() {
+            if let Err(err) = self.library.save(&self.config.data_dir) {
+                self.log_warn(format!("Library save failed: {err}"));
+            }
+        }
+        if self.disk_usage_pending {
+            self.disk_usage_pending = false;
+            self.queue_disk_usage_scan();
+        }
+    }
+
+    pub fn recompute_mod_sizes(&mut self) {
+        for mod_entry in &mut self.library.mods {
+            mod_entry.disk_usage = None;
+        }
+        self.status = "Recomputing mod sizes...".to_string();
+        self.log_info("Recomputing mod sizes".to_string());
+        self.queue_disk_usage_scan();
+    }
+
+    fn sync_dev_watch(&mut self) {
+        let targets: Vec<(String, PathBuf)> = self
+            .library
+            .mods
+            .iter()
+            .filter(|mod_entry| mod_entry.dev_watch)
+            .filter_map(|mod_entry| {
+                let path = mod_entry.dev_path.as_ref()?;
+                Some((mod_entry.id.clone(), PathBuf::from(path)))
+            })
+            .collect();
+        let watching = !targets.is_empty();
+        if let Ok(mut shared) = self.dev_watch_targets.lock() {
+            *shared = targets;
+        }
+        if !watching || self.dev_watch_started {
+            return;
+        }
+        self.dev_watch_started = true;
+        let shared = Arc::clone(&self.dev_watch_targets);
+        let tx = self.dev_watch_tx.clone();
+        thread::spawn(move || {
+            let mut seen: HashMap<String, String> = HashMap::new();
+            loop {
+                thread::sleep(Duration::from_millis(DEV_WATCH_POLL_MS));
+                let targets = match shared.lock() {
+                    Ok(targets) => targets.clone(),
+                    Err(_) => return,
+                };
+                seen.retain(|id, _| targets.iter().any(|(target, _)| target == id));
+                for (id, path) in targets {
+                    let fingerprint = dev_folder_fingerprint(&path);
+                    let previous = seen.insert(id.clone(), fingerprint.clone());
+                    if previous.is_some_and(|previous| previous != fingerprint)
+                        && tx.send(DevWatchMessage::Changed(id)).is_err()
+                    {
+                        return;
+                    }
+                }
+            }
+        });
+    }
+
+    pub fn open_targets_view(&mut self) {
+        let Some(id) = self.selected_profile_id() else {
+            self.status = "No mod selected".to_string();
+            return;
+        };
+        self.targets_view = Some(TargetsView {
+            mod_id: id,
+            scroll: 0,
+        });
+    }
+
+    pub fn close_targets_view(&mut self) {
+        self.targets_view = None;
+    }
+
+    pub fn targets_view_scroll(&mut self, delta: isize) {
+        let Some(view) = &mut self.targets_view else {
+            return;
+        };
+        view.scroll = (view.scroll as isize + delta).max(0) as usize;
+    }
+
+    pub fn mod_target_details(&self, id: &str) -> Vec<TargetDetail> {
+        let Some(mod_entry) = self
+            .library
+            .mods
+            .iter()
+            .find(|mod_entry| mod_entry.id == id)
+        else {
+            return Vec::new();
+        };
+        let paths = game::detect_paths(
+            self.game_id,
+            Some(&self.config.game_root),
+            Some(&self.config.larian_dir),
+        )
+        .ok();
+        let mod_root = library_mod_root(&self.config.sigillink_cache_root()).join(&mod_entry.id);
+        mod_entry
+            .targets
+            .iter()
+            .map(|target| {
+                let kind = target.kind();
+                let (label, source, dest) = match target {
+                    InstallTarget::Pak { file, info, .. } => {
+                        if mod_entry.is_native() {
+                            let source = paths
+                                .as_ref()
+                                .map(|paths| paths.larian_mods_dir.join(file))
+                                .unwrap_or_else(|| PathBuf::from(file));
+                            (file.clone(), source, None)
+                        } else {
+                            let dest = paths.as_ref().map(|paths| {
+                                paths.larian_mods_dir.join(format!("{}.pak", info.folder))
+                            });
+                            (file.clone(), mod_root.join(file), dest)
+                        }
+                    }
+                    InstallTarget::Generated { dir } => (
+                        dir.clone(),
+                        mod_root.join(dir),
+                        paths.as_ref().map(|paths| paths.data_dir.join("Generated")),
+                    ),
+                    InstallTarget::Data { dir } => (
+                        dir.clone(),
+                        mod_root.join(dir),
+                        paths.as_ref().map(|paths| paths.data_dir.clone()),
+                    ),
+                    InstallTarget::Bin { dir } => (
+                        dir.clone(),
+                        mod_root.join(dir),
+                        paths.as_ref().map(|paths| paths.game_root.join("bin")),
+                    ),
+                };
+                TargetDetail {
+                    kind,
+                    label,
+                    source_exists: source.exists(),
+                    source,
+                    dest,
+                    enabled: mod_entry.is_target_enabled(kind),
+                    overridden: mod_entry
+                        .target_overrides
+                        .iter()
+                        .any(|override_entry| override_entry.kind == kind),
+                }
+            })
+            .collect()
+    }
+
+    pub fn toggle_dev_watch_selected(&mut self) {
+        let Some(id) = self.selected_profile_id() else {
+            return;
+        };
+        let Some(mod_entry) = self
+            .library
+            .mods
+            .iter_mut()
+            .find(|mod_entry| mod_entry.id == id)
+        else {
+            return;
+        };
+        if mod_entry.dev_path.is_none() {
+            let name = mod_entry.display_name();
+            self.status = format!("Dev watch: {name} is not a live-linked dev folder");
+            self.set_toast(
+                "Dev watch needs a live-linked dev folder",
+                ToastLevel::Warn,
+                Duration::from_secs(2),
+            );
+            return;
+        }
+        mod_entry.dev_watch = !mod_entry.dev_watch;
+        let watching = mod_entry.dev_watch;
+        let name = mod_entry.display_name();
+        if let Err(err) = self.library.save(&self.config.data_dir) {
+            self.status = format!("Dev watch save failed: {err}");
+            self.log_error(format!("Dev watch save failed: {err}"));
+            return;
+        }
+        self.sync_dev_watch();
+        let message = if watching {
+            format!("Dev watch ON: {name} redeploys on change")
+        } else {
+            format!("Dev watch OFF: {name}")
+        };
+        self.status = message.clone();
+        self.log_info(message.clone());
+        self.set_toast(&message, ToastLevel::Info, Duration::from_secs(2));
+    }
+
     pub fn poll_updates(&mut self) {
         loop {
             match self.update_rx.try_recv() {
@@ -7609,20 +13601,126 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         self.log_scroll = self.log_scroll.saturating_sub(lines);
     }
 
-    pub fn page_mods_up(&mut self) {
+    pub fn visible_logs(&self) -> Vec<&LogEntry> {
+        self.logs
+            .iter()
+            .filter(|entry| self.log_filter.allows(entry.level))
+            .collect()
+    }
+
+    pub fn set_log_filter(&mut self, filter: LogFilter) {
+        self.log_filter = filter;
+        self.log_scroll = 0;
+        self.log_search_match = None;
+        self.status = format!("Log filter: {}", filter.label());
+    }
+
+    pub fn enter_log_search(&mut self) {
+        self.input_mode = InputMode::Editing {
+            prompt: "Search log".to_string(),
+            buffer: self.log_search.clone(),
+            purpose: InputPurpose::SearchLog,
+            auto_submit: false,
+            last_edit_at: Instant::now(),
+        };
+        self.status = "Search log: type, Enter to find (empty clears)".to_string();
+    }
+
+    fn apply_log_search(&mut self, value: String) {
+        self.log_search = value.trim().to_string();
+        self.log_search_match = None;
+        if self.log_search.is_empty() {
+            self.status = "Log search cleared".to_string();
+            return;
+        }
+        let matches = self.log_search_matches();
+        let Some(last) = matches.last().copied() else {
+            self.status = format!("Log search: no matches for \"{}\"", self.log_search);
+            return;
+        };
+        self.jump_to_log_match(last, matches.len(), matches.len());
+    }
+
+    pub fn log_search_matches(&self) -> Vec<usize> {
+        if self.log_search.is_empty() {
+            return Vec::new();
+        }
+        let needle = self.log_search.to_ascii_lowercase();
+        self.visible_logs()
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.message.to_ascii_lowercase().contains(&needle))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    pub fn log_search_step(&mut self, older: bool) {
+        if self.log_search.is_empty() {
+            self.status = "Log search: press / to search".to_string();
+            return;
+        }
+        let matches = self.log_search_matches();
+        if matches.is_empty() {
+            self.log_search_match = None;
+            self.status = format!("Log search: no matches for \"{}\"", self.log_search);
+            return;
+        }
+        let position = match self.log_search_match {
+            Some(current) if older => matches
+                .iter()
+                .rposition(|index| *index < current)
+                .unwrap_or(matches.len() - 1),
+            Some(current) => matches
+                .iter()
+                .position(|index| *index > current)
+                .unwrap_or(0),
+            None => matches.len() - 1,
+        };
+        self.jump_to_log_match(matches[position], position + 1, matches.len());
+    }
+
+    fn jump_to_log_match(&mut self, index: usize, number: usize, total: usize) {
+        let visible = self.visible_logs().len();
+        self.log_search_match = Some(index);
+        self.log_scroll = visible.saturating_sub(index + 1);
+        self.status = format!("Log search \"{}\": match {number}/{total}", self.log_search);
+    }
+
+    pub fn cycle_log_filter(&mut self) {
+        self.set_log_filter(self.log_filter.next());
+    }
+
+    pub fn page_step(&self) -> usize {
+        self.mods_view_height.saturating_sub(1).max(1)
+    }
+
+    pub fn page_mods_up(&mut self) {
+        if self.move_mode {
+            return;
+        }
+        self.selected = self.selected.saturating_sub(self.page_step());
+    }
+
+    pub fn page_mods_down(&mut self) {
+        if self.move_mode {
+            return;
+        }
+        self.selected = self.selected.saturating_add(self.page_step());
+        self.clamp_selection();
+    }
+
+    pub fn mods_home(&mut self) {
         if self.move_mode {
             return;
         }
-        let page = self.mods_view_height.saturating_sub(1).max(1);
-        self.selected = self.selected.saturating_sub(page);
+        self.selected = 0;
     }
 
-    pub fn page_mods_down(&mut self) {
+    pub fn mods_end(&mut self) {
         if self.move_mode {
             return;
         }
-        let page = self.mods_view_height.saturating_sub(1).max(1);
-        self.selected = self.selected.saturating_add(page);
+        self.selected = self.visible_profile_indices().len().saturating_sub(1);
     }
 
     pub fn jump_mod_selection(&mut self, delta: isize) {
@@ -7671,28 +13769,36 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     fn log_text_from_entries(&self) -> String {
         self.logs
             .iter()
-            .map(|entry| format!("[{}] {}", log_level_label(entry.level), entry.message))
+            .map(LogEntry::line)
             .collect::<Vec<String>>()
             .join("\n")
     }
 
     fn push_log(&mut self, level: LogLevel, message: String) {
-        if self.log_scroll > 0 {
+        if self.log_scroll > 0 && self.log_filter.allows(level) {
             self.log_scroll = self.log_scroll.saturating_add(1);
         }
 
-        self.logs.push(LogEntry {
+        let entry = LogEntry {
             level,
-            message: message.clone(),
-        });
+            message,
+            timestamp: time::OffsetDateTime::now_utc(),
+        };
+        let _ = append_log_file(&self.log_path, &entry, self.app_config.log_max_bytes);
+        self.logs.push(entry);
 
         if self.logs.len() > LOG_CAPACITY {
             let overflow = self.logs.len() - LOG_CAPACITY;
-            self.logs.drain(0..overflow);
-            self.log_scroll = self.log_scroll.saturating_sub(overflow);
+            let dropped = self
+                .logs
+                .drain(0..overflow)
+                .filter(|entry| self.log_filter.allows(entry.level))
+                .count();
+            self.log_scroll = self.log_scroll.saturating_sub(dropped);
+            self.log_search_match = self
+                .log_search_match
+                .and_then(|index| index.checked_sub(dropped));
         }
-
-        let _ = append_log_file(&self.log_path, level, &message);
     }
 
     fn start_next_import(&mut self) {
@@ -7719,29 +13825,86 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
 
         self.import_active = Some(path.clone());
         self.import_progress = None;
+        self.import_rate_samples.clear();
+        self.import_cancel = Arc::new(AtomicBool::new(false));
         self.status = format!("Importing {}", display_path(&path));
         self.log_info(format!("Import started: {}", path.display()));
 
         let tx = self.import_tx.clone();
         let progress_tx = tx.clone();
         let cache_root = self.config.sigillink_cache_root();
+        let cancel = Arc::clone(&self.import_cancel);
+        let dev_folder = self.import_dev_paths.remove(&path);
+        let unhashed = self.unhashed_library_paks();
         thread::spawn(move || {
             let progress = Arc::new(move |progress: importer::ImportProgress| {
                 let _ = progress_tx.send(ImportMessage::Progress(progress));
             });
-            let result = importer::import_path_with_progress(&path, &cache_root, Some(progress))
-                .with_context(|| format!("import {path:?}"));
-            let message = match result {
-                Ok(result) => ImportMessage::Completed { path, result },
-                Err(err) => ImportMessage::Failed {
-                    path,
-                    error: err.to_string(),
-                },
+            let url = path.to_string_lossy().to_string();
+            let mut message = if importer::is_url(&url) {
+                import_url_worker(path, &url, &cache_root, progress, cancel)
+            } else {
+                import_path_worker(path, dev_folder, &cache_root, progress, cancel)
             };
+            if let ImportMessage::Completed {
+                result, pak_hashes, ..
+            } = &mut message
+            {
+                *pak_hashes = hash_library_paks_for_import(result, &unhashed);
+            }
             let _ = tx.send(message);
         });
     }
 
+    /// Library paks imported before pak hashing, for the import worker to backfill.
+    fn unhashed_library_paks(&self) -> Vec<(String, String, PathBuf)> {
+        let mods_root = self.config.sigillink_mods_root();
+        let mut out = Vec::new();
+        for mod_entry in &self.library.mods {
+            if mod_entry.is_native() || mod_entry.cache_evicted {
+                continue;
+            }
+            for target in &mod_entry.targets {
+                if let InstallTarget::Pak {
+                    file, hash: None, ..
+                } = target
+                {
+                    let path = mods_root.join(&mod_entry.id).join(file);
+                    out.push((mod_entry.id.clone(), file.clone(), path));
+                }
+            }
+        }
+        out
+    }
+
+    fn apply_library_pak_hashes(&mut self, pak_hashes: Vec<LibraryPakHash>) {
+        let mut filled = 0usize;
+        for pak_hash in &pak_hashes {
+            let Some(mod_entry) = self
+                .library
+                .mods
+                .iter_mut()
+                .find(|mod_entry| mod_entry.id == pak_hash.mod_id)
+            else {
+                continue;
+            };
+            if record_pak_hash(&mut mod_entry.targets, &pak_hash.file, &pak_hash.hash) {
+                filled += 1;
+            }
+        }
+        if filled == 0 {
+            return;
+        }
+        self.log_info(format!(
+            "Hashed {filled} existing pak(s) for duplicate detection"
+        ));
+        if self.allow_persistence() {
+            if let Err(err) = self.library.save(&self.config.data_dir) {
+                self.log_warn(format!("Library save failed after pak hashing: {err}"));
+            }
+        }
+    }
+
     fn start_import_apply(
         &mut self,
         mods: Vec<importer::ImportMod>,
@@ -7770,11 +13933,26 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     fn handle_import_message(&mut self, message: ImportMessage) {
         match message {
             ImportMessage::Progress(progress) => {
+                self.track_import_rate(&progress);
                 self.import_progress = Some(progress);
             }
-            ImportMessage::Completed { path, result } => {
+            ImportMessage::Completed {
+                path,
+                result,
+                pak_hashes,
+            } => {
                 self.import_active = None;
                 self.import_progress = None;
+                self.apply_library_pak_hashes(pak_hashes);
+                if self.import_cancel.load(AtomicOrdering::Relaxed) {
+                    for batch in &result.batches {
+                        for import_mod in &batch.mods {
+                            import_mod.cleanup_staging();
+                        }
+                    }
+                    self.finish_canceled_import(&path);
+                    return;
+                }
                 if !result.failures.is_empty() {
                     for failure in &result.failures {
                         self.log_error(format!(
@@ -7802,7 +13980,11 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             ImportMessage::Failed { path, error } => {
                 self.import_active = None;
                 self.import_progress = None;
-                let display = display_path(&path);
+                if self.import_cancel.load(AtomicOrdering::Relaxed) {
+                    self.finish_canceled_import(&path);
+                    return;
+                }
+                let display = import_source_label(&path);
                 let reason = summarize_error(&error);
                 self.status = format!("Import failed: {display} ({reason})");
                 self.log_error(format!("Import failed for {}: {error}", path.display()));
@@ -7818,6 +14000,34 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 self.import_summary_pending = true;
                 self.maybe_show_import_summary();
             }
+            ImportMessage::ModListDownloaded { path, file } => {
+                self.import_active = None;
+                self.import_progress = None;
+                if self.import_cancel.load(AtomicOrdering::Relaxed) {
+                    file.cleanup();
+                    self.finish_canceled_import(&path);
+                    return;
+                }
+                self.log_info(format!("Downloaded mod list: {}", file.url));
+                let import = self.load_mod_list_file(&file.path, file.label());
+                file.cleanup();
+                if import.is_none() {
+                    self.import_failures.push(importer::ImportFailure {
+                        source: importer::ImportSource {
+                            label: file.url.clone(),
+                        },
+                        error: "downloaded mod list could not be read".to_string(),
+                    });
+                    self.import_summary_pending = true;
+                    self.maybe_show_import_summary();
+                }
+                if let Some(import) = import {
+                    let preview = self.build_mod_list_preview(import);
+                    self.mod_list_preview = Some(preview);
+                    self.mod_list_scroll = 0;
+                    self.status = "Mod list preview ready".to_string();
+                }
+            }
             ImportMessage::ApplyCompleted(outcome) => {
                 self.import_apply_active = false;
                 self.import_progress = None;
@@ -7840,9 +14050,25 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
 
         let mut applied_count = 0usize;
         if !applied.is_empty() {
+            let applied_names: Vec<(String, String)> = applied
+                .iter()
+                .map(|entry| (entry.id.clone(), entry.display_name()))
+                .collect();
             match self.apply_imported_mod_entries(applied) {
                 Ok(count) => {
                     applied_count = count;
+                    for (id, name) in applied_names {
+                        let (kind, detail) = match self.import_replacing.remove(&id) {
+                            Some(existing) => (
+                                ImportOutcomeKind::Replaced,
+                                Some(format!("over \"{existing}\"")),
+                            ),
+                            None => (ImportOutcomeKind::Imported, None),
+                        };
+                        self.import_outcomes
+                            .push(ImportOutcome { name, kind, detail });
+                    }
+                    self.import_summary_pending = true;
                     self.status = format!("Imported {count} mod(s)");
                     self.log_info(format!(
                         "Import complete: {count} mod(s) from {}",
@@ -7898,14 +14124,49 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
 
         for import_mod in mods {
             let mod_entry = &import_mod.entry;
-            if let Some(existing) = self.find_duplicate_by_name(&mod_entry.name) {
-                let default_overwrite = duplicate_default_overwrite(mod_entry, existing);
+            if let Some(earlier) = approved.iter().find(|earlier: &&importer::ImportMod| {
+                same_mod_content(
+                    mod_entry,
+                    import_mod.sigillink.as_ref(),
+                    &earlier.entry,
+                    || earlier.sigillink.clone(),
+                )
+            }) {
+                self.log_warn(format!(
+                    "Skipped \"{}\" (identical to \"{}\" in the same import)",
+                    mod_entry.display_name(),
+                    earlier.entry.display_name()
+                ));
+                self.import_outcomes.push(ImportOutcome {
+                    name: mod_entry.display_name(),
+                    kind: ImportOutcomeKind::Skipped,
+                    detail: Some(format!(
+                        "identical to \"{}\" in the same import",
+                        earlier.entry.display_name()
+                    )),
+                });
+                self.cleanup_import_staging(&import_mod);
+                continue;
+            }
+            if let Some(existing) = self.find_duplicate_by_content(&import_mod) {
                 duplicates.push_back(DuplicateDecision {
+                    existing_id: existing.id.clone(),
+                    existing_label: existing.display_name(),
                     import_mod,
+                    kind: DuplicateKind::Identical,
+                    default_overwrite: Some(true),
+                    hash_mismatch: None,
+                });
+            } else if let Some(existing) = self.find_duplicate_by_name(&mod_entry.name) {
+                let default_overwrite = duplicate_default_overwrite(mod_entry, existing);
+                let hash_mismatch = pak_hash_mismatch(mod_entry, existing);
+                duplicates.push_back(DuplicateDecision {
                     existing_id: existing.id.clone(),
                     existing_label: existing.display_name(),
+                    import_mod,
                     kind: DuplicateKind::Exact,
                     default_overwrite,
+                    hash_mismatch,
                 });
             } else if let Some(similar) = self.find_similar_by_label(mod_entry) {
                 let existing_label = similar.existing_label.clone();
@@ -7913,6 +14174,12 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                     .new_stamp
                     .zip(similar.existing_stamp)
                     .map(|(new_stamp, existing_stamp)| new_stamp > existing_stamp);
+                let hash_mismatch = self
+                    .library
+                    .mods
+                    .iter()
+                    .find(|entry| entry.id == similar.existing_id)
+                    .and_then(|existing| pak_hash_mismatch(mod_entry, existing));
                 duplicates.push_back(DuplicateDecision {
                     import_mod,
                     existing_id: similar.existing_id,
@@ -7925,6 +14192,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                         similarity: similar.similarity,
                     },
                     default_overwrite,
+                    hash_mismatch,
                 });
             } else {
                 approved.push(import_mod);
@@ -7973,6 +14241,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 continue;
             }
             let required_by = mod_entry.display_name();
+            let required_by_link = mod_entry.nexus_link();
             for dep in deps {
                 let resolved_ids = resolved_dependency_ids(&existing_lookup, &dep, mod_entry);
                 if !resolved_ids.is_empty() {
@@ -8000,6 +14269,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                     }
                 });
                 entry.required_by.push(required_by.clone());
+                if entry.link.is_none() {
+                    entry.link = required_by_link.clone();
+                }
                 if entry.display_label == "Unknown dependency"
                     && display_label != "Unknown dependency"
                 {
@@ -8284,13 +14556,78 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         if !self.metadata_active {
             return None;
         }
+        if self.metadata_cancel.load(AtomicOrdering::Relaxed) {
+            return Some("Metadata scan: cancelling...".to_string());
+        }
+        let hint = if self.metadata_scan_cancelable() {
+            " (Esc to cancel)"
+        } else {
+            ""
+        };
         if self.metadata_total > 0 {
             return Some(format!(
-                "Metadata scan: {}/{}",
+                "Metadata scan: {}/{}{hint}",
                 self.metadata_processed, self.metadata_total
             ));
         }
-        Some("Metadata scan: working...".to_string())
+        Some(format!("Metadata scan: working...{hint}"))
+    }
+
+    // Only scans the user asked for; the startup scan feeds dependency checks.
+    pub fn metadata_scan_cancelable(&self) -> bool {
+        self.metadata_active && self.metadata_targeted
+    }
+
+    pub fn metadata_progress(&self) -> Option<f64> {
+        if !self.metadata_active || self.metadata_total == 0 {
+            return None;
+        }
+        Some((self.metadata_processed as f64 / self.metadata_total as f64).min(1.0))
+    }
+
+    pub fn deploy_cancelable(&self) -> bool {
+        self.deploy_active && self.deploy_manual
+    }
+
+    pub fn prompt_cancel_deploy(&mut self) {
+        if !self.deploy_cancelable() || self.dialog.is_some() {
+            return;
+        }
+        self.open_dialog(Dialog {
+            title: "Cancel Deploy".to_string(),
+            message: "Stop the running deploy? Files linked so far stay tracked and \
+modsettings.lsx is written for the mods that were deployed."
+                .to_string(),
+            yes_label: "Keep deploying".to_string(),
+            no_label: "Cancel deploy".to_string(),
+            choice: DialogChoice::Yes,
+            kind: DialogKind::CancelDeploy,
+            toggle: None,
+            toggle_alt: None,
+            scroll: 0,
+        });
+    }
+
+    fn cancel_deploy(&mut self) {
+        if !self.deploy_cancelable() {
+            return;
+        }
+        if self.deploy_cancel.swap(true, AtomicOrdering::Relaxed) {
+            return;
+        }
+        self.status = "Cancelling deploy...".to_string();
+        self.log_info("Deploy cancel requested".to_string());
+    }
+
+    pub fn cancel_metadata_refresh(&mut self) {
+        if !self.metadata_scan_cancelable() {
+            return;
+        }
+        if self.metadata_cancel.swap(true, AtomicOrdering::Relaxed) {
+            return;
+        }
+        self.status = "Cancelling metadata scan...".to_string();
+        self.log_info("Metadata scan cancel requested".to_string());
     }
 
     fn smart_rank_status_line(&self) -> Option<String> {
@@ -8443,7 +14780,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             let mut targets = Vec::new();
             for target in &mod_entry.targets {
                 match target {
-                    InstallTarget::Pak { file, info } => {
+                    InstallTarget::Pak { file, info, .. } => {
                         targets.push(format!(
                             "Pak:{} (uuid {}, folder {})",
                             file, info.uuid, info.folder
@@ -8549,6 +14886,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             &self.library,
             None,
             smart_rank::SmartRankRefreshMode::Full,
+            self.app_config.sigillink_weights,
             |_| {},
         )?;
         let profile_key = self.smart_rank_profile_key();
@@ -8637,6 +14975,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             &library,
             cache_data.as_ref(),
             smart_rank::SmartRankRefreshMode::Incremental,
+            self.app_config.sigillink_weights,
             |_| {},
         );
 
@@ -8696,6 +15035,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 library,
                 cache_data,
                 resolved,
+                smart_rank::SigilLinkWeights::default(),
                 |_| {},
             );
 
@@ -8822,6 +15162,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                     &library,
                     cache_data,
                     resolved,
+                    smart_rank::SigilLinkWeights::default(),
                     |_| {},
                 ) {
                     stress_scans += computed.scanned_mods;
@@ -8885,6 +15226,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                     id: remove_id,
                     enabled: true,
                     missing_label: None,
+                    collision_with: None,
                 });
             }
             run_step(
@@ -9141,14 +15483,19 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         }
 
         self.library = Library {
+            schema_version: LIBRARY_SCHEMA_VERSION,
             mods: Vec::new(),
             profiles: vec![Profile::new("Default")],
             active_profile: "Default".to_string(),
             dependency_blocks: HashSet::new(),
             metadata_cache_version: 0,
             metadata_cache_key: None,
+            metadata_mod_keys: HashMap::new(),
             modsettings_hash: None,
             modsettings_sync_enabled: true,
+            new_mods_seen_at: 0,
+            sigillink_excluded: HashSet::new(),
+            starred: HashSet::new(),
         };
         self.config.active_profile = "Default".to_string();
         self.config.data_dir = temp_data_dir;
@@ -9223,6 +15570,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 path,
                 &self.config.sigillink_cache_root(),
                 None,
+                None,
             ) {
                 Ok(result) => result,
                 Err(err) => {
@@ -9610,7 +15958,21 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         }
     }
 
+    fn finish_canceled_import(&mut self, path: &Path) {
+        self.import_rate_samples.clear();
+        self.status = "Import canceled".to_string();
+        self.log_warn(format!("Import canceled: {}", path.display()));
+        self.set_toast("Import canceled", ToastLevel::Warn, Duration::from_secs(2));
+        self.maybe_show_import_summary();
+    }
+
     fn cancel_pending_import(&mut self, _keep_files: bool) {
+        if self.import_active.is_some() {
+            self.import_cancel.store(true, AtomicOrdering::Relaxed);
+            self.import_queue.clear();
+            self.import_dev_paths.clear();
+            self.status = "Canceling import...".to_string();
+        }
         let Some(batch) = self.pending_import_batch.take() else {
             return;
         };
@@ -9636,35 +15998,64 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         {
             return;
         }
-        if self.import_failures.is_empty() {
-            self.import_summary_pending = false;
+        self.import_summary_pending = false;
+        self.import_replacing.clear();
+        let mut outcomes = std::mem::take(&mut self.import_outcomes);
+        let failures = std::mem::take(&mut self.import_failures);
+        if failures.is_empty() && outcomes.len() < 2 {
             return;
         }
+        outcomes.extend(failures.into_iter().map(|failure| ImportOutcome {
+            name: failure.source.label,
+            kind: ImportOutcomeKind::Failed,
+            detail: Some(summarize_error(&failure.error)),
+        }));
 
-        let total = self.import_failures.len();
-        let mut lines = Vec::new();
-        lines.push(format!("Import completed with {total} failure(s)."));
-        lines.push("".to_string());
-        for failure in self.import_failures.iter().take(6) {
-            lines.push(format!(
-                "- {}: {}",
-                failure.source.label,
-                summarize_error(&failure.error)
-            ));
-        }
-        if total > 6 {
-            lines.push(format!("...and {} more (see log)", total - 6));
+        let count = |kind: ImportOutcomeKind| {
+            outcomes
+                .iter()
+                .filter(|outcome| outcome.kind == kind)
+                .count()
+        };
+        let totals = [
+            ImportOutcomeKind::Imported,
+            ImportOutcomeKind::Replaced,
+            ImportOutcomeKind::Linked,
+            ImportOutcomeKind::Skipped,
+            ImportOutcomeKind::Failed,
+        ]
+        .into_iter()
+        .filter_map(|kind| {
+            let total = count(kind);
+            (total > 0).then(|| format!("{total} {}", kind.label()))
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+        let mut lines = vec![format!("Import finished: {totals}."), String::new()];
+        for outcome in &outcomes {
+            let mut line = format!(
+                "{} {} ({})",
+                outcome.kind.marker(),
+                outcome.name,
+                outcome.kind.label()
+            );
+            if let Some(detail) = &outcome.detail {
+                line.push_str(&format!(": {detail}"));
+            }
+            lines.push(line);
         }
-
-        self.import_summary_pending = false;
-        self.import_failures.clear();
+        let report = lines.join("\n");
+        self.log_info(format!(
+            "Import summary: {totals}\n{}",
+            lines[2..].join("\n")
+        ));
         self.open_dialog(Dialog {
             title: "Import Summary".to_string(),
-            message: lines.join("\n"),
-            yes_label: "Close".to_string(),
+            message: report.clone(),
+            yes_label: "Copy".to_string(),
             no_label: "Close".to_string(),
-            choice: DialogChoice::Yes,
-            kind: DialogKind::ImportSummary,
+            choice: DialogChoice::No,
+            kind: DialogKind::ImportSummary { report },
             toggle: None,
             toggle_alt: None,
             scroll: 0,
@@ -9685,7 +16076,15 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
 
         let mut added = Vec::new();
         let mut added_ids = Vec::new();
-        for mod_entry in mods {
+        for mut mod_entry in mods {
+            if mod_entry.custom_name.is_none() {
+                mod_entry.custom_name = self
+                    .library
+                    .mods
+                    .iter()
+                    .find(|entry| entry.id == mod_entry.id)
+                    .and_then(|entry| entry.custom_name.clone());
+            }
             self.library.mods.retain(|entry| entry.id != mod_entry.id);
             self.library.mods.push(mod_entry.clone());
             added_ids.push(mod_entry.id.clone());
@@ -9706,6 +16105,10 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             }
         }
         self.update_dependency_cache_for_entries(&added);
+        if self.app_config.enable_mods_after_import && self.app_config.enable_import_dependencies {
+            self.enable_import_dependencies(&added);
+        }
+        self.queue_disk_usage_scan();
         self.library.metadata_cache_key = Some(self.metadata_cache_key());
         self.library.metadata_cache_version = METADATA_CACHE_VERSION;
         if self.allow_persistence() {
@@ -9723,6 +16126,32 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         Ok(count)
     }
 
+    fn enable_import_dependencies(&mut self, added: &[ModEntry]) {
+        let scan = self.scan_dependencies_to_enable(added);
+        let added_ids: HashSet<&str> = added.iter().map(|entry| entry.id.as_str()).collect();
+        let mut dependency_ids: Vec<String> = scan
+            .present
+            .into_iter()
+            .filter(|id| !added_ids.contains(id.as_str()))
+            .collect();
+        dependency_ids.sort();
+        if !scan.missing_mod_ids.is_empty() {
+            self.log_warn(format!(
+                "{} dependency mod(s) of imported mods have missing files; not enabled",
+                scan.missing_mod_ids.len()
+            ));
+        }
+        if dependency_ids.is_empty() {
+            return;
+        }
+        let changed = self.set_mods_enabled_in_active(&dependency_ids, true);
+        if changed > 0 {
+            self.log_info(format!(
+                "Enabled {changed} dependency mod(s) for imported mods"
+            ));
+        }
+    }
+
     fn cleanup_import_staging(&mut self, import_mod: &importer::ImportMod) {
         import_mod.cleanup_staging();
     }
@@ -9748,9 +16177,14 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             }
             fs::rename(staging_root, &final_root)
                 .with_context(|| format!("finalize import {:?}", staging_root))?;
+            trash::forget(staging_root);
         }
 
-        let mut warnings = Vec::new();
+        let mut warnings: Vec<String> = import_mod
+            .warnings
+            .iter()
+            .map(|warning| format!("{}: {warning}", mod_entry.display_name()))
+            .collect();
         if let Some(index) = &import_mod.sigillink {
             if let Err(err) = sigillink::write_sigillink_index(cache_root, &mod_entry.id, index) {
                 warnings.push(format!(
@@ -9800,7 +16234,15 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
 
         let display_name = next.import_mod.entry.display_name();
         let existing_label = next.existing_label.clone();
-        let (title, message, kind) = match &next.kind {
+        let (title, mut message, kind) = match &next.kind {
+            DuplicateKind::Identical => (
+                "Already In Library".to_string(),
+                format!(
+                    "\"{}\" is already in the library as \"{}\" (identical content).\nLink as update?",
+                    display_name, existing_label
+                ),
+                DialogKind::Identical,
+            ),
             DuplicateKind::Exact => (
                 "Overwrite Duplicate".to_string(),
                 format!(
@@ -9840,6 +16282,39 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 )
             }
         };
+        if let Some((new_hash, existing_hash)) = &next.hash_mismatch {
+            message.push_str(&format!(
+                "\nContent differs (hash {} vs {}).",
+                short_hash(new_hash),
+                short_hash(existing_hash)
+            ));
+        }
+        if !matches!(next.kind, DuplicateKind::Identical) {
+            let existing_entry = self
+                .library
+                .mods
+                .iter()
+                .find(|entry| entry.id == next.existing_id);
+            let existing_root = self.config.sigillink_mods_root().join(&next.existing_id);
+            let lines = duplicate_diff_lines(
+                &next.import_mod.entry,
+                next.import_mod.staging_root.as_deref(),
+                existing_entry,
+                &existing_root,
+            );
+            if !lines.is_empty() {
+                message.push('\n');
+                for line in lines {
+                    message.push('\n');
+                    message.push_str(&line);
+                }
+            }
+        }
+        let (yes_label, no_label) = if matches!(next.kind, DuplicateKind::Identical) {
+            ("Link as Update", "Skip")
+        } else {
+            ("Overwrite", "Skip")
+        };
 
         let default_choice = if matches!(next.default_overwrite, Some(true)) {
             DialogChoice::Yes
@@ -9850,8 +16325,8 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         self.open_dialog(Dialog {
             title,
             message,
-            yes_label: "Overwrite".to_string(),
-            no_label: "Skip".to_string(),
+            yes_label: yes_label.to_string(),
+            no_label: no_label.to_string(),
             choice: default_choice,
             kind,
             toggle: Some(DialogToggle {
@@ -9878,6 +16353,34 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     fn apply_duplicate_decision(&mut self, decision: DuplicateDecision, overwrite: bool) {
+        if matches!(decision.kind, DuplicateKind::Identical) {
+            let name = decision.import_mod.entry.display_name();
+            if overwrite {
+                self.link_identical_import(&decision.existing_id, &decision.import_mod.entry);
+                self.log_info(format!(
+                    "Linked \"{}\" as update of \"{}\" (identical content)",
+                    name, decision.existing_label
+                ));
+                self.import_outcomes.push(ImportOutcome {
+                    name,
+                    kind: ImportOutcomeKind::Linked,
+                    detail: Some(format!("identical to \"{}\"", decision.existing_label)),
+                });
+            } else {
+                self.log_info(format!(
+                    "Skipped \"{}\" (identical to \"{}\")",
+                    name, decision.existing_label
+                ));
+                self.import_outcomes.push(ImportOutcome {
+                    name,
+                    kind: ImportOutcomeKind::Skipped,
+                    detail: Some(format!("identical to \"{}\"", decision.existing_label)),
+                });
+            }
+            self.import_summary_pending = true;
+            self.cleanup_import_staging(&decision.import_mod);
+            return;
+        }
         if overwrite {
             let same_id = decision.existing_id == decision.import_mod.entry.id;
             let removed = if same_id {
@@ -9886,7 +16389,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 self.remove_mod_by_id(&decision.existing_id)
             };
             let label = match decision.kind {
-                DuplicateKind::Exact => "duplicate",
+                DuplicateKind::Exact | DuplicateKind::Identical => "duplicate",
                 DuplicateKind::Similar { .. } => "similar",
             };
             if same_id {
@@ -9900,16 +16403,26 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                     decision.existing_label
                 ));
             }
+            self.import_replacing.insert(
+                decision.import_mod.entry.id.clone(),
+                decision.existing_label.clone(),
+            );
             self.approved_imports.push(decision.import_mod);
         } else {
             let label = match decision.kind {
-                DuplicateKind::Exact => "duplicate",
+                DuplicateKind::Exact | DuplicateKind::Identical => "duplicate",
                 DuplicateKind::Similar { .. } => "similar",
             };
             self.log_warn(format!(
                 "Skipped {label} \"{}\"",
                 decision.import_mod.entry.display_name()
             ));
+            self.import_outcomes.push(ImportOutcome {
+                name: decision.import_mod.entry.display_name(),
+                kind: ImportOutcomeKind::Skipped,
+                detail: Some(format!("{label} of \"{}\"", decision.existing_label)),
+            });
+            self.import_summary_pending = true;
             self.cleanup_import_staging(&decision.import_mod);
         }
     }
@@ -9963,7 +16476,10 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     fn dialog_supports_cancel(dialog: &Dialog) -> bool {
-        matches!(dialog.kind, DialogKind::DeleteMod { .. })
+        matches!(
+            dialog.kind,
+            DialogKind::DeleteMod { .. } | DialogKind::PendingMove { .. }
+        )
     }
 
     pub fn dialog_choice_left(&mut self) {
@@ -10007,7 +16523,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
 
         let choice = dialog.choice;
         match dialog.kind {
-            DialogKind::Overwrite | DialogKind::Similar => {
+            DialogKind::Overwrite | DialogKind::Similar | DialogKind::Identical => {
                 let apply_all = dialog
                     .toggle
                     .as_ref()
@@ -10022,6 +16538,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                         entry,
                         staging_root: None,
                         sigillink: None,
+                        warnings: Vec::new(),
                     };
                     self.log_warn(format!("Importing unknown layout: {label}"));
                     self.stage_imports(
@@ -10049,6 +16566,30 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                     }
                 }
             }
+            DialogKind::AdoptNativeMod { id, name } => {
+                if !matches!(choice, DialogChoice::Yes) {
+                    return;
+                }
+                match self.adopt_native_mod(&id) {
+                    Ok(()) => {
+                        self.status = format!("Native mod adopted: {name}");
+                        self.set_toast(
+                            &format!("Adopted into cache: {name}"),
+                            ToastLevel::Info,
+                            Duration::from_secs(3),
+                        );
+                    }
+                    Err(err) => {
+                        self.status = format!("Adopt failed: {err}");
+                        self.log_error(format!("Adopt failed for {name}: {err}"));
+                    }
+                }
+            }
+            DialogKind::ClearFileOverrides { profile } => {
+                if matches!(choice, DialogChoice::Yes) {
+                    self.clear_all_file_overrides(&profile);
+                }
+            }
             DialogKind::DeleteMod {
                 id,
                 name,
@@ -10169,33 +16710,132 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                             "Disabled {disabled} duplicate mod(s): {label}"
                         ));
                     }
-                    self.status = format!("Enabled {changed} mod(s)");
-                    self.log_info(format!("Enabled {changed} mod(s)"));
-                    self.queue_auto_deploy("enable duplicates");
-                    self.request_sigillink_auto_rank();
-                } else {
-                    self.status = "Enable canceled".to_string();
+                    self.status = format!("Enabled {changed} mod(s)");
+                    self.log_info(format!("Enabled {changed} mod(s)"));
+                    self.queue_auto_deploy("enable duplicates");
+                    self.request_sigillink_auto_rank();
+                } else {
+                    self.status = "Enable canceled".to_string();
+                }
+            }
+            DialogKind::MoveBlocked {
+                resume_move_mode,
+                clear_filter,
+            } => {
+                if matches!(choice, DialogChoice::Yes) {
+                    let previous_id = self.selected_profile_id();
+                    if clear_filter {
+                        self.mod_filter_snapshot = None;
+                        self.recent_filter = false;
+                        self.starred_filter = false;
+                        self.apply_mod_filter(String::new(), false);
+                    }
+                    self.mod_sort = ModSort::default();
+                    self.reselect_mod_by_id(previous_id);
+                    if resume_move_mode {
+                        self.toggle_move_mode();
+                    } else {
+                        self.status = "Order view restored".to_string();
+                    }
+                }
+            }
+            DialogKind::DuplicateProfile { source } => {
+                self.enter_duplicate_profile_name(&source, matches!(choice, DialogChoice::No));
+            }
+            DialogKind::PendingMove { target } => {
+                if matches!(choice, DialogChoice::Cancel) {
+                    self.move_mode = self.focus == Focus::Mods;
+                    self.status = "Move pending: Enter to apply, Esc to discard".to_string();
+                    return;
+                }
+                self.move_mode = true;
+                if matches!(choice, DialogChoice::Yes) {
+                    self.confirm_move_mode();
+                } else {
+                    self.cancel_move_mode();
+                }
+                match target {
+                    PendingMoveTarget::Focus(focus) => self.set_focus(focus),
+                    PendingMoveTarget::Profile(name) => {
+                        if let Err(err) = self.set_active_profile(&name) {
+                            self.status = format!("Profile switch failed: {err}");
+                            self.log_error(format!("Profile switch failed: {err}"));
+                        }
+                    }
+                    PendingMoveTarget::Game(game_id) => {
+                        if let Err(err) = self.set_active_game(game_id) {
+                            self.status = format!("Game switch failed: {err}");
+                            self.log_error(format!("Game switch failed: {err}"));
+                        }
+                    }
+                    PendingMoveTarget::Palette(action) => {
+                        if let Err(err) = self.run_palette_action(action) {
+                            self.status = format!("Command failed: {err}");
+                            self.log_error(format!("Command failed: {err}"));
+                        }
+                    }
+                    PendingMoveTarget::Quit => self.request_quit(),
+                }
+            }
+            DialogKind::ExportSettings => {
+                if matches!(choice, DialogChoice::Yes) {
+                    let include_paths = dialog
+                        .toggle
+                        .as_ref()
+                        .map(|toggle| toggle.checked)
+                        .unwrap_or(false);
+                    if let Err(err) = self.export_settings(include_paths) {
+                        self.status = format!("Settings export failed: {err}");
+                        self.log_error(format!("Settings export failed: {err}"));
+                    }
                 }
             }
-            DialogKind::MoveBlocked {
-                resume_move_mode,
-                clear_filter,
-            } => {
+            DialogKind::FreezeSort => {
                 if matches!(choice, DialogChoice::Yes) {
-                    let previous_id = self.selected_profile_id();
-                    if clear_filter {
-                        self.mod_filter_snapshot = None;
-                        self.apply_mod_filter(String::new(), false);
+                    self.freeze_sort();
+                }
+            }
+            DialogKind::ResetProfileOrder => {
+                if matches!(choice, DialogChoice::Yes) {
+                    self.reset_profile_order();
+                }
+            }
+            DialogKind::GameUpdated { build } => {
+                if matches!(choice, DialogChoice::Yes) {
+                    self.queue_deploy("game update");
+                    return;
+                }
+                self.config.game_update_acknowledged = Some(build);
+                if let Err(err) = self.config.save() {
+                    self.log_warn(format!("Game update acknowledgement not saved: {err}"));
+                }
+            }
+            DialogKind::RepairModsettings => {
+                if matches!(choice, DialogChoice::Yes) {
+                    if let Err(err) = self.repair_modsettings() {
+                        self.status = format!("Repair modsettings failed: {err}");
+                        self.log_error(format!("Repair modsettings failed: {err}"));
                     }
-                    self.mod_sort = ModSort::default();
-                    self.reselect_mod_by_id(previous_id);
-                    if resume_move_mode {
-                        self.toggle_move_mode();
-                    } else {
-                        self.status = "Order view restored".to_string();
+                }
+            }
+            DialogKind::SupportBundle => {
+                if matches!(choice, DialogChoice::Yes) {
+                    let strip_names = dialog
+                        .toggle
+                        .as_ref()
+                        .map(|toggle| toggle.checked)
+                        .unwrap_or(false);
+                    if let Err(err) = self.create_support_bundle(strip_names, None) {
+                        self.status = format!("Support bundle failed: {err}");
+                        self.log_error(format!("Support bundle failed: {err}"));
                     }
                 }
             }
+            DialogKind::CancelDeploy => {
+                if matches!(choice, DialogChoice::No) {
+                    self.cancel_deploy();
+                }
+            }
             DialogKind::CancelImport => {
                 if matches!(choice, DialogChoice::No) {
                     let keep_files = dialog
@@ -10269,6 +16909,13 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                     self.clear_all_sigillink_pins();
                 }
             }
+            DialogKind::SigilLinkCacheGc => {
+                if matches!(choice, DialogChoice::Yes) {
+                    self.collect_sigillink_cache_garbage();
+                } else {
+                    self.status = "SigiLink cache cleanup canceled".to_string();
+                }
+            }
             DialogKind::SigilLinkPinNotice => {
                 if let Some(toggle) = dialog.toggle {
                     if toggle.checked {
@@ -10277,13 +16924,70 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                     }
                 }
             }
-            DialogKind::ImportSummary => {}
+            DialogKind::ImportSummary { report } => {
+                if matches!(choice, DialogChoice::Yes) {
+                    if self.copy_to_clipboard(&report) {
+                        self.status = "Import summary copied to clipboard".to_string();
+                        self.set_toast(
+                            "Import summary copied",
+                            ToastLevel::Info,
+                            Duration::from_secs(2),
+                        );
+                    } else {
+                        self.status = "Clipboard unavailable (summary is in the log)".to_string();
+                    }
+                }
+            }
             DialogKind::EnableAllVisible => {}
             DialogKind::DisableAllVisible => {}
             DialogKind::InvertVisible => {}
         }
     }
 
+    fn find_duplicate_by_content(&self, import_mod: &importer::ImportMod) -> Option<&ModEntry> {
+        let cache_root = self.config.sigillink_cache_root();
+        self.library.mods.iter().find(|existing| {
+            same_mod_content(
+                &import_mod.entry,
+                import_mod.sigillink.as_ref(),
+                existing,
+                || sigillink::load_sigillink_index(&cache_root, &existing.id),
+            )
+        })
+    }
+
+    fn link_identical_import(&mut self, existing_id: &str, incoming: &ModEntry) {
+        let Some(existing) = self
+            .library
+            .mods
+            .iter_mut()
+            .find(|entry| entry.id == existing_id)
+        else {
+            return;
+        };
+        if incoming.source_label.is_some() {
+            existing.source_label = incoming.source_label.clone();
+        }
+        if incoming.source_path.is_some() {
+            existing.source_path = incoming.source_path.clone();
+        }
+        if incoming.author.is_some() {
+            existing.author = incoming.author.clone();
+        }
+        if incoming.version.is_some() {
+            existing.version = incoming.version.clone();
+        }
+        if incoming.website.is_some() {
+            existing.website = incoming.website.clone();
+        }
+        if incoming.nexus_id.is_some() {
+            existing.nexus_id = incoming.nexus_id;
+        }
+        if self.allow_persistence() {
+            let _ = self.library.save(&self.config.data_dir);
+        }
+    }
+
     fn find_duplicate_by_name(&self, name: &str) -> Option<&ModEntry> {
         let needle = name.trim();
         self.library
@@ -10383,7 +17087,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         }
 
         if self.allow_persistence() && delete_files {
-            self.queue_remove_mod_root(id);
+            self.queue_remove_mod_root(&mod_entry);
         }
         self.dependency_cache.remove(id);
         if self.dependency_cache_ready && self.allow_persistence() {
@@ -10480,6 +17184,86 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         }
     }
 
+    fn adopt_native_mod(&mut self, id: &str) -> Result<()> {
+        let paths = game::detect_paths(
+            self.game_id,
+            Some(&self.config.game_root),
+            Some(&self.config.larian_dir),
+        )?;
+        let index = self
+            .library
+            .mods
+            .iter()
+            .position(|mod_entry| mod_entry.id == id && mod_entry.is_native())
+            .context("native mod not found")?;
+        let (file, info) = self.library.mods[index]
+            .targets
+            .iter()
+            .find_map(|target| match target {
+                InstallTarget::Pak { file, info, .. } => Some((file.clone(), info.clone())),
+                _ => None,
+            })
+            .context("native mod has no pak target")?;
+        let mut source = paths.larian_mods_dir.join(&file);
+        if !source.exists() {
+            let native_pak_index =
+                native_pak::build_native_pak_index_cached(&paths.larian_mods_dir);
+            if let Some(resolved) =
+                native_pak::resolve_native_pak_filename(&info, &native_pak_index)
+            {
+                source = paths.larian_mods_dir.join(resolved);
+            }
+        }
+        if !source.is_file() {
+            return Err(anyhow::anyhow!(
+                "native pak not found: {}",
+                source.display()
+            ));
+        }
+        if !path_within_root(&source, &paths.larian_mods_dir) {
+            return Err(anyhow::anyhow!(
+                "native pak is outside the Mods folder: {}",
+                source.display()
+            ));
+        }
+
+        let filename = format!("{}.pak", info.folder);
+        let mod_root = self.config.sigillink_mods_root().join(id);
+        fs::create_dir_all(&mod_root).context("create mod cache dir")?;
+        let dest = mod_root.join(&filename);
+        let temp = mod_root.join(format!("{filename}.tmp"));
+        fs::copy(&source, &temp).context("copy native pak")?;
+        fs::rename(&temp, &dest).context("finalize adopted pak")?;
+        // The original must go, otherwise the game loads it next to the deployed copy.
+        if let Err(err) = fs::remove_file(&source) {
+            let _ = fs::remove_file(&dest);
+            return Err(anyhow::anyhow!("remove original pak {file}: {err}"));
+        }
+
+        let mod_entry = &mut self.library.mods[index];
+        mod_entry.source = ModSource::Managed;
+        for target in &mut mod_entry.targets {
+            if let InstallTarget::Pak { file, .. } = target {
+                *file = filename.clone();
+            }
+        }
+        self.library.save(&self.config.data_dir)?;
+        self.log_info(format!(
+            "Native mod moved into cache: {} -> {}",
+            source.display(),
+            dest.display()
+        ));
+
+        self.schedule_smart_rank_refresh(
+            smart_rank::SmartRankRefreshMode::Incremental,
+            "native adopt",
+            true,
+        );
+        self.refresh_sigillink_missing_paks();
+        self.queue_auto_deploy("native adopt");
+        Ok(())
+    }
+
     #[allow(dead_code)]
     fn remove_mod_root(&mut self, id: &str) {
         let mod_root = self.config.sigillink_mods_root().join(id);
@@ -10499,7 +17283,8 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         sigillink::remove_sigillink_index(&self.config.sigillink_cache_root(), id);
     }
 
-    fn queue_remove_mod_root(&mut self, id: &str) {
+    fn queue_remove_mod_root(&mut self, mod_entry: &ModEntry) {
+        let id = mod_entry.id.as_str();
         let mod_root = self.config.sigillink_mods_root().join(id);
         if !mod_root.exists() {
             sigillink::remove_sigillink_index(&self.config.sigillink_cache_root(), id);
@@ -10513,7 +17298,18 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             ));
             return;
         }
-        let trash_root = self.config.sigillink_cache_root().join("trash");
+        if self.app_config.quarantine_removed_mods {
+            match trash::quarantine(&self.config.sigillink_cache_root(), &mod_root, mod_entry) {
+                Ok(_) => self.log_info(format!(
+                    "Mod files moved to trash for {} day(s): {}",
+                    self.app_config.trash_retention_days,
+                    mod_entry.display_name()
+                )),
+                Err(err) => self.log_warn(format!("Remove mod files skipped: {err}")),
+            }
+            return;
+        }
+        let trash_root = trash::trash_root(&self.config.sigillink_cache_root());
         if let Err(err) = fs::create_dir_all(&trash_root) {
             self.log_warn(format!("Remove mod files skipped: {err}"));
             return;
@@ -10538,6 +17334,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         let mut dependencies_changed = false;
         let updated_native_files = delta.updated_native_files;
         let adopted_native = delta.adopted_native;
+        let ignored_native = delta.ignored_native;
         let modsettings_hash_changed = delta.modsettings_hash != self.library.modsettings_hash;
 
         for update in delta.updates {
@@ -10569,8 +17366,12 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 entry.source_label = update.source_label;
                 changed = true;
             }
-            if entry.targets != update.targets {
-                entry.targets = update.targets;
+            let mut targets = update.targets;
+            if entry.modified_at == update.modified_at {
+                carry_pak_hashes(&entry.targets, &mut targets);
+            }
+            if entry.targets != targets {
+                entry.targets = targets;
                 changed = true;
             }
             if entry.created_at != update.created_at {
@@ -10756,6 +17557,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             if let Err(err) = self.library.save(&self.config.data_dir) {
                 self.log_warn(format!("Native mod sync save failed: {err}"));
             }
+            self.mark_deploy_state_dirty();
             if dependencies_changed {
                 self.prime_dependency_cache_from_library();
             }
@@ -10775,6 +17577,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             if adopted_native > 0 {
                 self.log_info(format!("Native mods reconciled: {adopted_native}"));
             }
+            if ignored_native > 0 {
+                self.log_info(format!("Native mods ignored by config: {ignored_native}"));
+            }
             if reordered {
                 self.log_info("Native mod order synced".to_string());
             }
@@ -10920,6 +17725,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
 
         if changed {
             let _ = self.library.save(&self.config.data_dir);
+            self.mark_deploy_state_dirty();
         }
 
         actions.len() + restores.len()
@@ -10930,6 +17736,268 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         Ok(())
     }
 
+    pub fn deploy_profile(&mut self, name: &str) -> Result<()> {
+        if name == self.library.active_profile {
+            return self.deploy();
+        }
+        if !self
+            .library
+            .profiles
+            .iter()
+            .any(|profile| profile.name == name)
+        {
+            return Err(anyhow::anyhow!("Unknown profile: {name}"));
+        }
+        if self.deploy_pending || self.deploy_active {
+            self.status = "Deploy already queued or running".to_string();
+            return Ok(());
+        }
+        self.queue_deploy(&format!("profile {name}"));
+        if self.deploy_pending {
+            self.deploy_profile = Some(name.to_string());
+            self.log_warn(format!(
+                "Deploying profile \"{name}\" while \"{}\" stays active: the game will run {name} until the next deploy",
+                self.library.active_profile
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn deploy_profile_cli(
+        &mut self,
+        profile: Option<&str>,
+        keep: &[String],
+    ) -> Result<deploy::DeployReport> {
+        let name = profile
+            .map(str::to_string)
+            .unwrap_or_else(|| self.library.active_profile.clone());
+        if !self
+            .library
+            .profiles
+            .iter()
+            .any(|profile| profile.name == name)
+        {
+            return Err(anyhow::anyhow!("Unknown profile: {name}"));
+        }
+        if !self.paths_ready() {
+            return Err(anyhow::anyhow!("game paths not set").context(CliExitCode::PathsNotReady));
+        }
+        let mut library = self.library.clone();
+        library.active_profile = name.clone();
+        let resolved = resolve_cli_uuid_collisions(&mut library, keep)?;
+        let mut report = deploy::deploy_with_options(
+            &self.config,
+            &mut library,
+            deploy::DeployOptions {
+                backup: true,
+                reason: Some("cli deploy".to_string()),
+                ..deploy::DeployOptions::default()
+            },
+        )
+        .map_err(cli_deploy_error)?;
+        let resolved_order = library
+            .active_profile()
+            .map(|profile| profile.order.clone());
+        self.library.mark_deployed(&name, report.deployed_at);
+        if let (false, Some(order)) = (resolved.is_empty(), resolved_order) {
+            if let Some(profile) = self
+                .library
+                .profiles
+                .iter_mut()
+                .find(|profile| profile.name == name)
+            {
+                profile.order = order;
+            }
+        }
+        for note in &resolved {
+            self.log_warn(note.clone());
+        }
+        report.warnings.extend(resolved);
+        self.library.save(&self.config.data_dir)?;
+        Ok(report)
+    }
+
+    pub fn deploy_load_order_only(&mut self) -> Result<()> {
+        let idle = !self.deploy_pending && !self.deploy_active;
+        self.queue_deploy("load order only");
+        if idle && self.deploy_pending {
+            self.deploy_skip_loose = true;
+            self.deploy_staging = None;
+            self.deploy_profile = None;
+        }
+        Ok(())
+    }
+
+    pub fn uuid_collision_note(&self, mod_id: &str) -> Option<String> {
+        let profile = self.library.active_profile()?;
+        let entry = profile.order.iter().find(|entry| entry.id == mod_id)?;
+        let winner_id = entry.collision_with.as_deref()?;
+        let winner = self
+            .library
+            .mods
+            .iter()
+            .find(|mod_entry| mod_entry.id == winner_id)
+            .map(|mod_entry| mod_entry.display_name())
+            .unwrap_or_else(|| winner_id.to_string());
+        Some(format!("disabled: UUID collision with {winner}"))
+    }
+
+    fn check_uuid_collisions(&mut self) -> bool {
+        self.refresh_uuid_collision_notes();
+        let collisions = deploy::find_uuid_collisions(&self.library);
+        if collisions.is_empty() {
+            return false;
+        }
+        if self.uuid_collision_view.is_none() {
+            self.log_warn(format!(
+                "Pak UUID collision: {} group(s) need a winner before deploy",
+                collisions.len()
+            ));
+            self.status = "Pak UUID collision: choose which mod to keep".to_string();
+        }
+        self.uuid_collision_view = Some(UuidCollisionView {
+            collisions,
+            selected: 0,
+        });
+        true
+    }
+
+    fn refresh_uuid_collision_notes(&mut self) {
+        let mod_map = self.library.index_by_id();
+        let Some(profile) = self.library.active_profile() else {
+            return;
+        };
+        let enabled: HashSet<&str> = profile
+            .order
+            .iter()
+            .filter(|entry| entry.enabled)
+            .map(|entry| entry.id.as_str())
+            .collect();
+        let stale: HashSet<String> = profile
+            .order
+            .iter()
+            .filter_map(|entry| {
+                let winner_id = entry.collision_with.as_deref()?;
+                let still_colliding = !entry.enabled
+                    && enabled.contains(winner_id)
+                    && match (mod_map.get(&entry.id), mod_map.get(winner_id)) {
+                        (Some(loser), Some(winner)) => {
+                            let winner_uuids = pak_uuids(winner);
+                            pak_uuids(loser)
+                                .iter()
+                                .any(|uuid| winner_uuids.contains(uuid))
+                        }
+                        _ => false,
+                    };
+                (!still_colliding).then(|| entry.id.clone())
+            })
+            .collect();
+        if stale.is_empty() {
+            return;
+        }
+        if let Some(profile) = self.library.active_profile_mut() {
+            for entry in &mut profile.order {
+                if stale.contains(&entry.id) {
+                    entry.collision_with = None;
+                }
+            }
+        }
+        if self.allow_persistence() {
+            let _ = self.library.save(&self.config.data_dir);
+        }
+    }
+
+    pub fn uuid_collision_move(&mut self, delta: isize) {
+        let Some(view) = &mut self.uuid_collision_view else {
+            return;
+        };
+        let len = view
+            .collisions
+            .first()
+            .map(|collision| collision.members.len())
+            .unwrap_or(0);
+        if len == 0 {
+            return;
+        }
+        let next = (view.selected as isize + delta).rem_euclid(len as isize);
+        view.selected = next as usize;
+    }
+
+    pub fn uuid_collision_keep_selected(&mut self) {
+        if self.uuid_collision_view.is_none() {
+            return;
+        }
+        if self.block_locked_profile("resolve UUID collision") {
+            return;
+        }
+        let Some(view) = &self.uuid_collision_view else {
+            return;
+        };
+        let Some(collision) = view.collisions.first().cloned() else {
+            self.uuid_collision_view = None;
+            return;
+        };
+        let Some(winner) = collision.members.get(view.selected).cloned() else {
+            return;
+        };
+        let loser_ids: Vec<String> = collision
+            .members
+            .iter()
+            .filter(|member| member.mod_id != winner.mod_id)
+            .map(|member| member.mod_id.clone())
+            .collect();
+        self.set_mods_enabled_in_active(&loser_ids, false);
+        let losers = match self.library.active_profile_mut() {
+            Some(profile) => {
+                deploy::keep_uuid_collision_winner(profile, &collision, &winner.mod_id)
+            }
+            None => Vec::new(),
+        };
+        if self.allow_persistence() {
+            let _ = self.library.save(&self.config.data_dir);
+        }
+        for loser in &losers {
+            let note = format!(
+                "UUID collision {}: kept {} ({}), disabled {} ({})",
+                collision.uuid, winner.mod_name, winner.pak_file, loser.mod_name, loser.pak_file
+            );
+            self.log_warn(note.clone());
+            self.uuid_collision_notes.push(note);
+        }
+
+        let remaining = deploy::find_uuid_collisions(&self.library);
+        if !remaining.is_empty() {
+            self.uuid_collision_view = Some(UuidCollisionView {
+                collisions: remaining,
+                selected: 0,
+            });
+            return;
+        }
+        self.uuid_collision_view = None;
+        self.status = format!("UUID collision resolved: kept {}", winner.mod_name);
+        self.set_toast(
+            "Pak UUID collisions resolved",
+            ToastLevel::Info,
+            Duration::from_secs(2),
+        );
+        if !self.deploy_pending {
+            self.queue_auto_deploy("uuid collision");
+        }
+    }
+
+    pub fn uuid_collision_cancel(&mut self) {
+        self.uuid_collision_view = None;
+        if self.deploy_pending {
+            self.deploy_pending = false;
+            self.deploy_reason = None;
+            self.deploy_skip_loose = false;
+            self.deploy_staging = None;
+            self.deploy_profile = None;
+            self.status = "Deploy blocked: unresolved pak UUID collision".to_string();
+            self.log_warn("Deploy blocked: unresolved pak UUID collision".to_string());
+        }
+    }
+
     pub fn rollback_last_backup(&mut self) -> Result<()> {
         if self.import_active.is_some()
             || self.import_apply_active
@@ -10977,6 +18045,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         self.library.save(&self.config.data_dir)?;
         self.config.save()?;
         self.conflicts.clear();
+        self.ignored_conflicts.clear();
         self.conflict_selected = 0;
 
         self.queue_deploy_with_options("rollback", false);
@@ -10987,7 +18056,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     pub fn toggle_selected(&mut self) {
-        if self.block_mod_changes("toggle") {
+        if self.block_locked_profile("toggle") || self.block_mod_changes("toggle") {
             return;
         }
         let Some(index) = self.selected_profile_index() else {
@@ -11041,6 +18110,61 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         }
     }
 
+    pub fn toggle_all_targets_selected(&mut self) {
+        if self.block_locked_profile("toggle") || self.block_mod_changes("toggle") {
+            return;
+        }
+        let Some(index) = self.selected_profile_index() else {
+            return;
+        };
+        let Some(entry) = self
+            .library
+            .active_profile()
+            .and_then(|profile| profile.order.get(index))
+        else {
+            return;
+        };
+        let Some(mod_entry) = self
+            .library
+            .mods
+            .iter()
+            .find(|mod_entry| mod_entry.id == entry.id)
+            .filter(|_| entry.missing_label.is_none())
+        else {
+            self.status = "Missing mod file".to_string();
+            self.set_toast("Missing mod file", ToastLevel::Warn, Duration::from_secs(2));
+            return;
+        };
+        let id = entry.id.clone();
+        let entry_enabled = entry.enabled && !self.sigillink_missing_pak(&id);
+        let all_targets_on = mod_entry
+            .targets
+            .iter()
+            .all(|target| mod_entry.is_target_enabled(target.kind()));
+        if entry_enabled && all_targets_on {
+            self.toggle_selected();
+            return;
+        }
+
+        if let Some(mod_entry) = self
+            .library
+            .mods
+            .iter_mut()
+            .find(|mod_entry| mod_entry.id == id)
+        {
+            if !mod_entry.target_overrides.is_empty() {
+                mod_entry.target_overrides.clear();
+                let _ = self.library.save(&self.config.data_dir);
+            }
+        }
+        if entry_enabled {
+            self.status = "All targets enabled".to_string();
+            self.queue_auto_deploy("target toggle");
+        } else {
+            self.enable_mods_with_dependencies(vec![id]);
+        }
+    }
+
     pub fn restore_sigillink_rank_for_selected(&mut self) {
         let Some(id) = self.selected_profile_id() else {
             return;
@@ -11060,8 +18184,45 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         }
     }
 
+    /// Resolves the required dependencies of `mods`: installed ones to enable, missing
+    /// ones, and installed ones whose files are missing from the cache.
+    fn scan_dependencies_to_enable(&self, mods: &[ModEntry]) -> DependencyEnableScan {
+        let lookup = DependencyLookup::new(&self.library.mods);
+        let mut scan = DependencyEnableScan::default();
+        for mod_entry in mods {
+            for dep in self.cached_mod_dependencies(mod_entry) {
+                let resolved_ids = resolved_dependency_ids(&lookup, &dep, mod_entry);
+                let mut ids: Vec<String> = resolved_ids
+                    .iter()
+                    .filter(|id| !self.sigillink_missing_pak(id))
+                    .cloned()
+                    .collect();
+                if ids.is_empty() && !resolved_ids.is_empty() {
+                    for id in resolved_ids {
+                        if self.sigillink_missing_pak(&id) {
+                            scan.missing_mod_ids.insert(id);
+                        }
+                    }
+                }
+                if ids.is_empty() {
+                    if is_unverified_dependency(&dep) {
+                        continue;
+                    }
+                    scan.missing.push(dep);
+                } else {
+                    for id in ids.drain(..) {
+                        scan.present.insert(id);
+                    }
+                }
+            }
+        }
+        scan.missing.sort();
+        scan.missing.dedup();
+        scan
+    }
+
     fn enable_mods_with_dependencies(&mut self, ids: Vec<String>) {
-        if self.block_mod_changes("enable") {
+        if self.block_locked_profile("enable") || self.block_mod_changes("enable") {
             return;
         }
         let mut mods = Vec::new();
@@ -11088,39 +18249,11 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
 
         self.refresh_sigillink_missing_paks();
 
-        let lookup = DependencyLookup::new(&self.library.mods);
-        let mut present: HashSet<String> = HashSet::new();
-        let mut missing = Vec::new();
-        let mut missing_mod_ids: HashSet<String> = HashSet::new();
-        for mod_entry in &mods {
-            for dep in self.cached_mod_dependencies(mod_entry) {
-                let resolved_ids = resolved_dependency_ids(&lookup, &dep, mod_entry);
-                let mut ids: Vec<String> = resolved_ids
-                    .iter()
-                    .filter(|id| !self.sigillink_missing_pak(id))
-                    .cloned()
-                    .collect();
-                if ids.is_empty() && !resolved_ids.is_empty() {
-                    for id in resolved_ids {
-                        if self.sigillink_missing_pak(&id) {
-                            missing_mod_ids.insert(id);
-                        }
-                    }
-                }
-                if ids.is_empty() {
-                    if is_unverified_dependency(&dep) {
-                        continue;
-                    }
-                    missing.push(dep);
-                } else {
-                    for id in ids.drain(..) {
-                        present.insert(id);
-                    }
-                }
-            }
-        }
-        missing.sort();
-        missing.dedup();
+        let DependencyEnableScan {
+            present,
+            missing,
+            missing_mod_ids,
+        } = self.scan_dependencies_to_enable(&mods);
 
         if !missing_mod_ids.is_empty() {
             let mut ids: Vec<String> = missing_mod_ids.into_iter().collect();
@@ -11262,10 +18395,58 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             if self.app_config.sigillink_ranking_enabled {
                 self.request_sigillink_auto_rank();
             }
+            if enabled {
+                self.warn_game_version_requirements(ids);
+            }
         }
         changed
     }
 
+    pub fn game_version_requirement(&self, mod_entry: &ModEntry) -> Option<u64> {
+        let required = mod_entry.min_game_version?;
+        let installed = self.config.game_version?;
+        (!metadata::game_version_supports(installed, required)).then_some(required)
+    }
+
+    fn warn_game_version_requirements(&mut self, ids: &[String]) {
+        let Some(installed) = self.config.game_version else {
+            return;
+        };
+        let blocked: Vec<(String, u64)> = self
+            .library
+            .mods
+            .iter()
+            .filter(|mod_entry| ids.contains(&mod_entry.id))
+            .filter_map(|mod_entry| {
+                self.game_version_requirement(mod_entry)
+                    .map(|required| (mod_entry.display_name(), required))
+            })
+            .collect();
+        let Some((name, required)) = blocked.first() else {
+            return;
+        };
+        let installed = metadata::format_version64(installed);
+        for (name, required) in &blocked {
+            self.log_warn(format!(
+                "{name} requires game version {} (installed {installed})",
+                metadata::format_version64(*required)
+            ));
+        }
+        let message = if blocked.len() == 1 {
+            format!(
+                "{name} needs game {} (installed {installed})",
+                metadata::format_version64(*required)
+            )
+        } else {
+            format!(
+                "{} mods need a newer game (installed {installed})",
+                blocked.len()
+            )
+        };
+        self.status = message.clone();
+        self.set_toast(&message, ToastLevel::Warn, Duration::from_secs(4));
+    }
+
     fn sigillink_missing_pak_for_mod_with(
         mod_entry: &ModEntry,
         cache_root: &PathBuf,
@@ -11303,6 +18484,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             let Some(mod_entry) = library.mods.iter().find(|entry| entry.id == *id) else {
                 continue;
             };
+            if mod_entry.cache_evicted {
+                continue;
+            }
             if !Self::sigillink_missing_pak_for_mod_with(
                 mod_entry,
                 &cache_root,
@@ -11344,7 +18528,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     fn maybe_start_missing_pak_scan(&mut self) {
-        if !self.missing_pak_pending || self.missing_pak_active {
+        if !self.missing_pak_pending || self.missing_pak_active || self.automation_paused {
             return;
         }
         if !self.paths_ready() {
@@ -11457,6 +18641,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     fn start_move_mode(&mut self) {
+        if self.block_locked_profile("reorder") {
+            return;
+        }
         self.move_mode = true;
         self.move_dirty = false;
         self.move_origin_id = self.selected_profile_id();
@@ -11515,6 +18702,31 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         }
     }
 
+    pub fn request_quit(&mut self) {
+        if self.prompt_pending_move(PendingMoveTarget::Quit) {
+            return;
+        }
+        self.should_quit = true;
+    }
+
+    fn prompt_pending_move(&mut self, target: PendingMoveTarget) -> bool {
+        if !self.move_dirty || self.dialog.is_some() {
+            return false;
+        }
+        self.open_dialog(Dialog {
+            title: "Unapplied Move".to_string(),
+            message: "The mod order has changes from move mode that were not applied.\nApply them before continuing, or discard and restore the previous order?".to_string(),
+            yes_label: "Apply".to_string(),
+            no_label: "Discard".to_string(),
+            choice: DialogChoice::Yes,
+            kind: DialogKind::PendingMove { target },
+            toggle: None,
+            toggle_alt: None,
+            scroll: 0,
+        });
+        true
+    }
+
     pub fn cancel_move_mode(&mut self) {
         if !self.move_mode {
             return;
@@ -11574,7 +18786,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     pub fn request_remove_selected(&mut self) {
-        if self.block_mod_changes("remove") {
+        if self.block_locked_profile("remove") || self.block_mod_changes("remove") {
             return;
         }
         let Some(selected_id) = self.selected_profile_id() else {
@@ -11607,6 +18819,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     pub fn select_target_override(&mut self, selection: Option<TargetKind>) {
+        if self.block_locked_profile("override") {
+            return;
+        }
         let selected_id = self.selected_profile_id();
         let Some(selected_id) = selected_id else {
             return;
@@ -11652,6 +18867,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     pub fn move_selected_up(&mut self) {
+        if self.block_locked_profile("reorder") {
+            return;
+        }
         let indices = self.visible_profile_indices();
         if indices.is_empty() || self.selected == 0 {
             return;
@@ -11699,7 +18917,91 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         }
     }
 
+    pub fn enter_move_to_slot(&mut self) {
+        if !self.move_mode || self.selected_profile_index().is_none() {
+            return;
+        }
+        self.input_mode = InputMode::Editing {
+            prompt: "Move to slot".to_string(),
+            buffer: String::new(),
+            purpose: InputPurpose::MoveToSlot,
+            auto_submit: false,
+            last_edit_at: Instant::now(),
+        };
+        self.status = "Move: enter target slot".to_string();
+    }
+
+    fn move_selected_to_slot(&mut self, value: &str) {
+        if !self.move_mode || self.block_locked_profile("reorder") {
+            return;
+        }
+        let Ok(slot) = value.trim().parse::<usize>() else {
+            self.status = format!("Invalid slot: {value}");
+            return;
+        };
+        let Some(current_index) = self.selected_profile_index() else {
+            return;
+        };
+        let respect_pins = self.app_config.sigillink_ranking_enabled;
+        let (final_index, target) = {
+            let Some(profile) = self.library.active_profile_mut() else {
+                return;
+            };
+            let len = profile.order.len();
+            if current_index >= len {
+                return;
+            }
+            let target = slot.clamp(1, len) - 1;
+            let free: Vec<usize> = (0..len)
+                .filter(|index| {
+                    *index == current_index
+                        || !respect_pins
+                        || !profile
+                            .sigillink_pins
+                            .contains_key(&profile.order[*index].id)
+                })
+                .collect();
+            let Some(free_pos) = (0..free.len()).min_by_key(|pos| free[*pos].abs_diff(target))
+            else {
+                return;
+            };
+            let moved = profile.order[current_index].clone();
+            let mut movable: Vec<ProfileEntry> = free
+                .iter()
+                .filter(|index| **index != current_index)
+                .map(|index| profile.order[*index].clone())
+                .collect();
+            movable.insert(free_pos, moved);
+            for (index, entry) in free.iter().zip(movable) {
+                profile.order[*index] = entry;
+            }
+            (free[free_pos], target)
+        };
+        if final_index != current_index {
+            self.move_dirty = true;
+        }
+        if let Some(selected) = self
+            .visible_profile_indices()
+            .iter()
+            .position(|index| *index == final_index)
+        {
+            self.selected = selected;
+        }
+        self.status = if final_index == target {
+            format!("Moved to slot {}", final_index + 1)
+        } else {
+            format!(
+                "Slot {} is pinned; moved to slot {}",
+                target + 1,
+                final_index + 1
+            )
+        };
+    }
+
     pub fn move_selected_down(&mut self) {
+        if self.block_locked_profile("reorder") {
+            return;
+        }
         let indices = self.visible_profile_indices();
         if indices.is_empty() || self.selected + 1 >= indices.len() {
             return;
@@ -11747,8 +19049,59 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         }
     }
 
+    pub fn refresh_selected_metadata(&mut self) {
+        let Some(id) = self.selected_profile_id() else {
+            self.status = "No mod selected".to_string();
+            return;
+        };
+        if let Some(mod_entry) = self
+            .library
+            .mods
+            .iter_mut()
+            .find(|mod_entry| mod_entry.id == id)
+        {
+            mod_entry.disk_usage = None;
+        }
+        self.queue_disk_usage_scan();
+        let ids = HashSet::from([id]);
+        self.refresh_metadata_for_ids(ids);
+    }
+
+    pub fn refresh_visible_metadata(&mut self) {
+        let Some(profile) = self.library.active_profile() else {
+            return;
+        };
+        let ids: HashSet<String> = self
+            .visible_profile_indices()
+            .into_iter()
+            .filter_map(|index| profile.order.get(index))
+            .filter(|entry| entry.missing_label.is_none())
+            .map(|entry| entry.id.clone())
+            .collect();
+        if ids.is_empty() {
+            self.status = "No visible mods to refresh".to_string();
+            return;
+        }
+        self.refresh_metadata_for_ids(ids);
+    }
+
+    fn refresh_metadata_for_ids(&mut self, ids: HashSet<String>) {
+        if self.metadata_active {
+            self.status = "Metadata refresh already running".to_string();
+            return;
+        }
+        if self.is_busy() {
+            self.status = "Busy; try the metadata refresh again shortly".to_string();
+            return;
+        }
+        let count = ids.len();
+        self.log_info(format!("Refreshing metadata for {count} mod(s)"));
+        self.status = format!("Refreshing metadata for {count} mod(s)...");
+        self.start_metadata_refresh_for(Some(ids));
+    }
+
     pub fn enable_visible_mods(&mut self) {
-        if self.block_mod_changes("enable") {
+        if self.block_locked_profile("enable") || self.block_mod_changes("enable") {
             return;
         }
         let indices = self.visible_profile_indices();
@@ -11781,7 +19134,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     pub fn disable_visible_mods(&mut self) {
-        if self.block_mod_changes("disable") {
+        if self.block_locked_profile("disable") || self.block_mod_changes("disable") {
             return;
         }
         let indices = self.visible_profile_indices();
@@ -11841,7 +19194,7 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     pub fn invert_visible_mods(&mut self) {
-        if self.block_mod_changes("toggle") {
+        if self.block_locked_profile("toggle") || self.block_mod_changes("toggle") {
             return;
         }
         let indices = self.visible_profile_indices();
@@ -11910,6 +19263,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     pub fn clear_visible_overrides(&mut self) {
+        if self.block_locked_profile("override") {
+            return;
+        }
         let indices = self.visible_profile_indices();
         if indices.is_empty() {
             self.status = "No visible mods to clear overrides".to_string();
@@ -11942,14 +19298,27 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
     }
 
     fn queue_auto_deploy(&mut self, reason: &str) {
+        self.mark_deploy_state_dirty();
         if !self.allow_persistence() {
             return;
         }
+        self.refresh_uuid_collision_notes();
+        if self.automation_paused {
+            // Held until resume; queuing here would leave `conflict_pending` set and block
+            // game switches for the whole pause.
+            self.automation_deploy_deferred |= self.app_config.auto_deploy_enabled;
+            self.automation_scan_deferred = true;
+            return;
+        }
         if !self.app_config.auto_deploy_enabled {
             self.queue_conflict_scan(reason);
             return;
         }
         self.queue_deploy(&format!("auto: {reason}"));
+        let debounce = self.app_config.auto_deploy_debounce_ms;
+        if debounce > 0 && self.deploy_pending {
+            self.deploy_due = Some(Instant::now() + Duration::from_millis(debounce));
+        }
         self.queue_conflict_scan(reason);
     }
 
@@ -11960,8 +19329,15 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             return;
         }
 
+        self.deploy_due = None;
         if self.deploy_pending || self.deploy_active {
             self.deploy_pending = true;
+            self.deploy_skip_loose = false;
+            if self.deploy_staging.take().is_some() {
+                // The pending staging run had no backup; the game deploy replacing it needs one.
+                self.deploy_backup = true;
+            }
+            self.deploy_profile = None;
             if self.deploy_reason.is_none() {
                 self.deploy_reason = Some(reason.to_string());
             }
@@ -11971,6 +19347,9 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         self.deploy_pending = true;
         self.deploy_reason = Some(reason.to_string());
         self.deploy_backup = true;
+        self.deploy_skip_loose = false;
+        self.deploy_staging = None;
+        self.deploy_profile = None;
         self.status = format!("Deploy queued ({reason})");
         self.log_info(format!("Deploy queued ({reason})"));
     }
@@ -11987,14 +19366,61 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         }
 
         self.deploy_pending = true;
+        self.deploy_due = None;
         self.deploy_reason = Some(reason.to_string());
         self.deploy_backup = backup;
+        self.deploy_skip_loose = false;
+        self.deploy_staging = None;
+        self.deploy_profile = None;
         self.status = format!("Deploy queued ({reason})");
         self.log_info(format!("Deploy queued ({reason})"));
     }
 
+    fn maybe_queue_background_conflict_scan(&mut self) {
+        if !self.app_config.background_conflict_scan
+            || self.automation_paused
+            || self.conflict_active
+            || self.conflict_pending
+            || !self.pending_overrides.is_empty()
+            || !self.paths_ready()
+        {
+            return;
+        }
+        let interval = Duration::from_secs(
+            self.app_config
+                .background_conflict_scan_secs
+                .max(BACKGROUND_CONFLICT_SCAN_MIN_SECS),
+        );
+        match self.conflict_last_activity {
+            Some(last) if last.elapsed() < interval => {}
+            Some(_) => {
+                self.queue_conflict_scan("background");
+                self.conflict_background = true;
+            }
+            None => self.conflict_last_activity = Some(Instant::now()),
+        }
+    }
+
+    pub fn toggle_background_conflict_scan(&mut self) -> Result<()> {
+        self.app_config.background_conflict_scan = !self.app_config.background_conflict_scan;
+        self.app_config.save()?;
+        self.conflict_last_activity = Some(Instant::now());
+        self.status = if self.app_config.background_conflict_scan {
+            format!(
+                "Background override scan: every {}s",
+                self.app_config
+                    .background_conflict_scan_secs
+                    .max(BACKGROUND_CONFLICT_SCAN_MIN_SECS)
+            )
+        } else {
+            "Background override scan: off".to_string()
+        };
+        Ok(())
+    }
+
     fn queue_conflict_scan(&mut self, _reason: &str) {
         if !self.paths_ready() {
+            self.ignored_conflicts.clear();
             if !self.conflicts.is_empty() {
                 self.conflicts.clear();
                 self.conflict_selected = 0;
@@ -12002,6 +19428,8 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             return;
         }
 
+        self.conflict_last_activity = Some(Instant::now());
+        self.conflict_background = false;
         if self.conflict_active {
             self.conflict_pending = true;
             return;
@@ -12023,10 +19451,14 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         let tx = self.conflict_tx.clone();
         let config = self.config.clone();
         let library = self.library.clone();
+        let rules_rev = self.conflict_rules_rev;
         thread::spawn(move || {
             let result = deploy::scan_conflicts(&config, &library);
             let message = match result {
-                Ok(conflicts) => ConflictMessage::Completed { conflicts },
+                Ok(conflicts) => ConflictMessage::Completed {
+                    conflicts,
+                    rules_rev,
+                },
                 Err(err) => ConflictMessage::Failed {
                     error: err.to_string(),
                 },
@@ -12039,14 +19471,22 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
         if !self.deploy_pending || self.deploy_active {
             return;
         }
+        if self.deploy_due.is_some_and(|due| Instant::now() < due) {
+            return;
+        }
         if self.import_active.is_some()
             || self.import_apply_active
+            || self.cache_verify_active
             || self.dialog.is_some()
+            || self.uuid_collision_view.is_some()
             || self.pending_duplicate.is_some()
             || !self.duplicate_queue.is_empty()
         {
             return;
         }
+        if self.cache_restage_active || self.start_cache_restage() {
+            return;
+        }
 
         let healed = self.self_heal_missing_paks();
         if healed > 0 {
@@ -12060,14 +19500,24 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             );
         }
         self.refresh_sigillink_missing_paks();
+        if self.check_uuid_collisions() {
+            return;
+        }
+        self.check_cache_filesystems();
 
         let reason = self
             .deploy_reason
             .take()
             .unwrap_or_else(|| "deploy".to_string());
         self.deploy_pending = false;
+        self.deploy_due = None;
         self.deploy_active = true;
+        self.deploy_manual = !reason.starts_with("auto:");
         let backup = self.deploy_backup;
+        let skip_loose = self.deploy_skip_loose;
+        let target_override = self.deploy_staging.take();
+        let profile_override = self.deploy_profile.take();
+        let warnings = std::mem::take(&mut self.uuid_collision_notes);
 
         let link_label = game::detect_paths(
             self.game_id,
@@ -12082,16 +19532,30 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
             )
             .ok()
         });
+        let cancel_hint = if self.deploy_manual {
+            " | Esc to cancel"
+        } else {
+            ""
+        };
         if let Some(label) = link_label {
-            self.status = format!("Deploying ({reason}) | Linking (SigiLink: {label})");
+            self.status =
+                format!("Deploying ({reason}) | Linking (SigiLink: {label}){cancel_hint}");
         } else {
-            self.status = format!("Deploying ({reason})");
+            self.status = format!("Deploying ({reason}){cancel_hint}");
+        }
+        if let Some(root) = &target_override {
+            self.status = format!("Staging deploy into {}{cancel_hint}", root.display());
         }
         self.log_info(format!("Deploy started ({reason})"));
 
         let tx = self.deploy_tx.clone();
         let config = self.config.clone();
         let mut library = self.library.clone();
+        if let Some(name) = profile_override {
+            library.active_profile = name;
+        }
+        self.deploy_cancel = Arc::new(AtomicBool::new(false));
+        let cancel = Arc::clone(&self.deploy_cancel);
         thread::spawn(move || {
             let result = deploy::deploy_with_options(
                 &config,
@@ -12099,10 +19563,16 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                 deploy::DeployOptions {
                     backup,
                     reason: Some(reason.clone()),
+                    skip_loose,
+                    warnings,
+                    cancel: Some(cancel),
+                    target_override,
                 },
             );
             let message = match result {
+                Ok(report) if report.staging_root.is_some() => DeployMessage::Staged { report },
                 Ok(report) => DeployMessage::Completed { report },
+                Err(err) if err.is::<deploy::DeployCancelled>() => DeployMessage::Cancelled,
                 Err(err) => {
                     let relocate = err
                         .downcast_ref::<deploy::SigilLinkRelocationError>()
@@ -12149,15 +19619,56 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
 
     fn handle_conflict_message(&mut self, message: ConflictMessage) {
         self.conflict_active = false;
+        self.conflict_last_activity = Some(Instant::now());
+        let background = std::mem::take(&mut self.conflict_background);
         self.pending_overrides.clear();
         match message {
-            ConflictMessage::Completed { conflicts } => {
-                let count = conflicts.len();
-                self.conflicts = conflicts;
-                if self.conflict_selected >= count {
+            ConflictMessage::Completed {
+                conflicts,
+                rules_rev,
+            } => {
+                if rules_rev != self.conflict_rules_rev {
+                    self.conflict_pending = true;
+                    return;
+                }
+                let (ignored, visible): (Vec<_>, Vec<_>) = conflicts
+                    .into_iter()
+                    .partition(|conflict| conflict.ignored.is_some());
+                let count = visible.len();
+                let ignored_count = ignored.len();
+                let previous_ignored = self
+                    .conflicts
+                    .iter()
+                    .chain(self.ignored_conflicts.iter())
+                    .filter(|conflict| conflict.ignored.is_some())
+                    .count();
+                let previous_count =
+                    self.conflicts.len() + self.ignored_conflicts.len() - previous_ignored;
+                let unchanged =
+                    background && previous_count == count && previous_ignored == ignored_count;
+                if self.show_ignored_conflicts {
+                    self.conflicts = visible;
+                    self.conflicts.extend(ignored);
+                    sort_conflicts(&mut self.conflicts);
+                    self.ignored_conflicts.clear();
+                } else {
+                    self.conflicts = visible;
+                    self.ignored_conflicts = ignored;
+                }
+                if self.conflict_selected >= self.conflicts.len() {
                     self.conflict_selected = 0;
                 }
-                self.log_info(format!("Override scan complete: {count} override(s)"));
+                self.refresh_conflict_rule_counts();
+                if unchanged {
+                    return;
+                }
+                if ignored_count > 0 {
+                    self.log_info(format!(
+                        "Override scan complete: {count} override(s), {ignored_count} ignored"
+                    ));
+                } else {
+                    self.log_info(format!("Override scan complete: {count} override(s)"));
+                }
             }
             ConflictMessage::Failed { error } => {
                 self.status = format!("Override scan failed: {error}");
@@ -12183,6 +19694,16 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                         report.removed_count
                     ));
                 }
+                if report.loose_skipped {
+                    self.status = format!(
+                        "Deployed load order: {} pak | Loose files skipped",
+                        report.pak_count
+                    );
+                    self.log_info(format!(
+                        "Loose deployment skipped: {} loose mod(s) left as deployed",
+                        report.loose_count
+                    ));
+                }
                 for warning in &report.warnings {
                     self.log_warn(format!("Deploy warning: {warning}"));
                 }
@@ -12196,13 +19717,67 @@ Use Ctrl+R to reset this mod or F12 to reset all pins."
                     report.file_count,
                     report.overridden_files
                 ));
+                if report.staging_root.is_none() {
+                    self.library
+                        .mark_deployed(&report.profile, report.deployed_at);
+                }
                 let _ = self.library.save(&self.config.data_dir);
+                self.deployed_state_hash = report.state_hash.clone();
+                self.refresh_deploy_state();
+                if report.profile != self.library.active_profile {
+                    self.status = format!(
+                        "Deployed profile \"{}\" (active: \"{}\")",
+                        report.profile, self.library.active_profile
+                    );
+                    self.set_toast(
+                        &format!(
+                            "Game now runs \"{}\"; the next deploy restores \"{}\"",
+                            report.profile, self.library.active_profile
+                        ),
+                        ToastLevel::Warn,
+                        Duration::from_secs(4),
+                    );
+                }
+                self.check_script_extender_requirement();
+            }
+            DeployMessage::Staged { report } => {
+                let root = report
+                    .staging_root
+                    .as_ref()
+                    .map(|root| root.display().to_string())
+                    .unwrap_or_default();
+                self.status = format!(
+                    "Staged: {} pak, {} loose | Files: {} | {root}",
+                    report.pak_count, report.loose_count, report.file_count
+                );
+                for warning in &report.warnings {
+                    self.log_warn(format!("Deploy warning: {warning}"));
+                }
+                self.log_info(format!(
+                    "Staged deploy complete in {root}: {} pak, {} loose, {} files",
+                    report.pak_count, report.loose_count, report.file_count
+                ));
+                self.set_toast(
+                    "Staged deploy complete",
+                    ToastLevel::Info,
+                    Duration::from_secs(3),
+                );
             }
             DeployMessage::SigilLinkRelocation { error, target_root } => {
                 self.status = format!("Deploy paused: {error}");
                 self.log_warn(format!("Deploy halted for SigiLink relocation: {error}"));
                 self.open_sigillink_relocation_dialog(target_root);
             }
+            DeployMessage::Cancelled => {
+                self.status =
+                    "Deploy cancelled: deploy again (d) or roll back (b) to finish".to_string();
+                self.log_warn(
+                    "Deploy cancelled; partial files are tracked in the deploy manifest and \
+modsettings.lsx lists only the paks that were linked"
+                        .to_string(),
+                );
+                self.set_toast("Deploy cancelled", ToastLevel::Warn, Duration::from_secs(3));
+            }
             DeployMessage::Failed { error } => {
                 self.status = format!("Deploy failed: {error}");
                 self.log_error(format!("Deploy failed: {error}"));
@@ -12342,6 +19917,11 @@ fn compare_mod_indices(
             compare_option_i64(a_mod.created_at, b_mod.created_at, sort.direction)
         }
         ModSortColumn::Added => compare_i64(a_mod.added_at, b_mod.added_at, sort.direction),
+        ModSortColumn::Size => compare_option_i64(
+            a_mod.disk_usage.map(|usage| usage.bytes as i64),
+            b_mod.disk_usage.map(|usage| usage.bytes as i64),
+            sort.direction,
+        ),
     };
 
     if ordering == Ordering::Equal {
@@ -12470,6 +20050,7 @@ fn target_kind_label(kind: TargetKind) -> &'static str {
 }
 
 const LOG_CAPACITY: usize = 200;
+const LOG_ROTATE_KEEP: usize = 2;
 
 pub(crate) fn expand_tilde(input: &str) -> PathBuf {
     let mut value = input.trim().to_string();
@@ -12608,6 +20189,155 @@ fn octal_value(byte: u8) -> Option<u8> {
     }
 }
 
+fn compute_mod_disk_usage(root: &Path) -> ModDiskUsage {
+    let mut usage = ModDiskUsage { bytes: 0, files: 0 };
+    for entry in WalkDir::new(root)
+        .follow_links(true)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+    {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        usage.files += 1;
+        usage.bytes += entry.metadata().map(|meta| meta.len()).unwrap_or(0);
+    }
+    usage
+}
+
+fn mod_cache_missing(mod_entry: &ModEntry, cache_root: &Path) -> bool {
+    let mod_root = library_mod_root(cache_root).join(&mod_entry.id);
+    let index = sigillink::load_sigillink_index(cache_root, &mod_entry.id);
+    mod_entry.targets.iter().any(|target| match target {
+        InstallTarget::Pak { file, .. } => !mod_root.join(file).is_file(),
+        InstallTarget::Data { dir }
+        | InstallTarget::Generated { dir }
+        | InstallTarget::Bin { dir } => {
+            let root = mod_root.join(dir);
+            if !root.is_dir() {
+                return true;
+            }
+            index.as_ref().is_some_and(|index| {
+                index
+                    .entries
+                    .iter()
+                    .filter(|entry| entry.kind == target.kind())
+                    .any(|entry| !root.join(&entry.relative_path).exists())
+            })
+        }
+    })
+}
+
+/// Re-imports `source` into staging and keeps only the mod matching `mod_id` (or the only mod).
+fn stage_mod_from_source(
+    source: &Path,
+    mod_id: &str,
+    cache_root: &Path,
+) -> Result<importer::ImportMod> {
+    let result = importer::import_path_with_progress(source, cache_root, None, None)?;
+    let mut mods: Vec<importer::ImportMod> = result
+        .batches
+        .into_iter()
+        .flat_map(|batch| batch.mods)
+        .collect();
+    let index = mods
+        .iter()
+        .position(|import_mod| import_mod.entry.id == mod_id)
+        .or_else(|| (mods.len() == 1).then_some(0));
+    let Some(index) = index else {
+        for import_mod in &mods {
+            import_mod.cleanup_staging();
+        }
+        return Err(anyhow::anyhow!("mod not found in {}", source.display()));
+    };
+    let import_mod = mods.swap_remove(index);
+    for other in &mods {
+        other.cleanup_staging();
+    }
+    Ok(import_mod)
+}
+
+fn restage_mod_from_source(job: &CacheRestageJob, cache_root: &Path) -> Result<Vec<InstallTarget>> {
+    let mod_id = job.mod_id.as_str();
+    let source = Path::new(&job.source);
+    if !source.exists() {
+        return Err(anyhow::anyhow!(
+            "source {} no longer exists",
+            source.display()
+        ));
+    }
+    let mut import_mod = stage_mod_from_source(source, mod_id, cache_root)?;
+    // The source may have been replaced since import; only accept the exact paks we evicted.
+    let restaged_hashes: HashSet<&str> = import_mod.entry.pak_hashes().into_iter().collect();
+    if let Some(changed) = job
+        .pak_hashes
+        .iter()
+        .find(|hash| !restaged_hashes.contains(hash.as_str()))
+    {
+        import_mod.cleanup_staging();
+        return Err(anyhow::anyhow!(
+            "{} changed since import (pak hash {} not found); reimport it instead",
+            source.display(),
+            &changed[..changed.len().min(12)]
+        ));
+    }
+    import_mod.entry.id = mod_id.to_string();
+    let mods_root = library_mod_root(cache_root);
+    let finalized = App::finalize_import_mod_io(&import_mod, &mods_root, cache_root);
+    if finalized.is_err() {
+        import_mod.cleanup_staging();
+    }
+    Ok(finalized?.0.targets)
+}
+
+/// Last known names of removed mods, from their trash records (newest removal wins).
+fn removed_mod_names(cache_root: &Path) -> HashMap<String, String> {
+    let mut names = HashMap::new();
+    for entry in trash::list(cache_root) {
+        names
+            .entry(entry.mod_entry.id.clone())
+            .or_insert_with(|| entry.mod_entry.display_name());
+    }
+    names
+}
+
+fn cache_entry_bytes(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.metadata().map(|meta| meta.len()).unwrap_or(0))
+        .sum()
+}
+
+fn dev_folder_fingerprint(root: &Path) -> String {
+    let mut hasher = Hasher::new();
+    for entry in WalkDir::new(root)
+        .follow_links(false)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+    {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        let modified = meta
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|time| time.as_nanos())
+            .unwrap_or(0);
+        hasher.update(entry.path().to_string_lossy().as_bytes());
+        hasher.update(&meta.len().to_le_bytes());
+        hasher.update(&modified.to_le_bytes());
+    }
+    hasher.finalize().to_hex().to_string()
+}
+
 fn copy_dir_recursive(source: &Path, dest: &Path) -> Result<()> {
     fs::create_dir_all(dest).with_context(|| format!("create dir {}", dest.display()))?;
     for entry in WalkDir::new(source).follow_links(false) {
@@ -12723,6 +20453,196 @@ fn from_hex(byte: u8) -> Option<u8> {
     }
 }
 
+const CLI_UUID_COLLISION_HELP: &str =
+    "keep one mod per UUID: rerun with --keep <mod id or name> for each UUID (the other mods are disabled in the profile), or pick one in the UI";
+
+fn cli_deploy_error(err: anyhow::Error) -> anyhow::Error {
+    if err.downcast_ref::<deploy::UuidCollisionError>().is_some() {
+        return err
+            .context(CLI_UUID_COLLISION_HELP)
+            .context(CliExitCode::UuidCollision);
+    }
+    err.context(CliExitCode::DeployFailed)
+}
+
+/// Applies `--keep` choices to the profile about to be deployed. Collisions without a choice
+/// are left for the deploy to reject; returns one note per disabled mod.
+fn resolve_cli_uuid_collisions(library: &mut Library, keep: &[String]) -> Result<Vec<String>> {
+    let collisions = deploy::find_uuid_collisions(library);
+    if collisions.is_empty() || keep.is_empty() {
+        return Ok(Vec::new());
+    }
+    let Some(profile) = library.active_profile_mut() else {
+        return Ok(Vec::new());
+    };
+    let mut notes = Vec::new();
+    for collision in &collisions {
+        let Some(winner) = collision.members.iter().find(|member| {
+            keep.iter().any(|value| {
+                let value = value.trim();
+                value.eq_ignore_ascii_case(&member.mod_id)
+                    || value.eq_ignore_ascii_case(&member.mod_name)
+            })
+        }) else {
+            continue;
+        };
+        if profile.locked {
+            return Err(anyhow::anyhow!(
+                "profile \"{}\" is locked; unlock it before resolving UUID collisions with --keep",
+                profile.name
+            )
+            .context(CliExitCode::UuidCollision));
+        }
+        for loser in deploy::keep_uuid_collision_winner(profile, collision, &winner.mod_id) {
+            notes.push(format!(
+                "UUID collision {}: kept {} ({}), disabled {} ({})",
+                collision.uuid, winner.mod_name, winner.pak_file, loser.mod_name, loser.pak_file
+            ));
+        }
+    }
+    Ok(notes)
+}
+
+fn import_url_worker(
+    path: PathBuf,
+    url: &str,
+    cache_root: &Path,
+    progress: importer::ProgressCallback,
+    cancel: importer::CancelFlag,
+) -> ImportMessage {
+    let file = match importer::download_url(
+        url,
+        cache_root,
+        Some(progress.clone()),
+        Some(cancel.clone()),
+    ) {
+        Ok(file) => file,
+        Err(err) => {
+            return ImportMessage::Failed {
+                path,
+                error: err.to_string(),
+            }
+        }
+    };
+    if file.kind != importer::DownloadKind::Archive {
+        return ImportMessage::ModListDownloaded { path, file };
+    }
+    let result =
+        importer::import_path_with_progress(&file.path, cache_root, Some(progress), Some(cancel))
+            .with_context(|| format!("import {url}"));
+    file.cleanup();
+    match result {
+        Ok(result) if result.unrecognized && result.batches.is_empty() => ImportMessage::Failed {
+            path,
+            error: "unsupported download content".to_string(),
+        },
+        Ok(result) => ImportMessage::Completed {
+            path,
+            result,
+            pak_hashes: Vec::new(),
+        },
+        Err(err) => ImportMessage::Failed {
+            path,
+            error: err.to_string(),
+        },
+    }
+}
+
+fn import_path_worker(
+    path: PathBuf,
+    dev_folder: bool,
+    cache_root: &Path,
+    progress: importer::ProgressCallback,
+    cancel: importer::CancelFlag,
+) -> ImportMessage {
+    let result = if dev_folder {
+        importer::import_dev_folder(&path, cache_root, Some(progress), Some(cancel))
+            .with_context(|| format!("adopt dev folder {path:?}"))
+    } else {
+        importer::import_path_with_progress(&path, cache_root, Some(progress), Some(cancel))
+            .with_context(|| format!("import {path:?}"))
+    };
+    match result {
+        Ok(result) => ImportMessage::Completed {
+            path,
+            result,
+            pak_hashes: Vec::new(),
+        },
+        Err(err) => ImportMessage::Failed {
+            path,
+            error: err.to_string(),
+        },
+    }
+}
+
+/// Hashes the unhashed library paks whose size matches an incoming hashed pak, so content
+/// duplicates of mods imported before pak hashing are still detected.
+fn hash_library_paks_for_import(
+    result: &importer::ImportResult,
+    unhashed: &[(String, String, PathBuf)],
+) -> Vec<LibraryPakHash> {
+    let mut sizes = HashSet::new();
+    for import_mod in result.batches.iter().flat_map(|batch| &batch.mods) {
+        let Some(staging_root) = &import_mod.staging_root else {
+            continue;
+        };
+        for target in &import_mod.entry.targets {
+            if let InstallTarget::Pak {
+                file,
+                hash: Some(_),
+                ..
+            } = target
+            {
+                if let Ok(meta) = fs::metadata(staging_root.join(file)) {
+                    sizes.insert(meta.len());
+                }
+            }
+        }
+    }
+    if sizes.is_empty() {
+        return Vec::new();
+    }
+    unhashed
+        .iter()
+        .filter(|(_, _, path)| fs::metadata(path).is_ok_and(|meta| sizes.contains(&meta.len())))
+        .filter_map(|(mod_id, file, path)| {
+            let hash = importer::hash_file(path).ok()?;
+            Some(LibraryPakHash {
+                mod_id: mod_id.clone(),
+                file: file.clone(),
+                hash,
+            })
+        })
+        .collect()
+}
+
+fn record_pak_hash(targets: &mut [InstallTarget], pak_file: &str, pak_hash: &str) -> bool {
+    let mut recorded = false;
+    for target in targets {
+        if let InstallTarget::Pak {
+            file,
+            hash: hash @ None,
+            ..
+        } = target
+        {
+            if file == pak_file {
+                *hash = Some(pak_hash.to_string());
+                recorded = true;
+            }
+        }
+    }
+    recorded
+}
+
+/// Summary label for a queued import: the full URL for downloads, else the file name.
+fn import_source_label(path: &PathBuf) -> String {
+    let raw = path.to_string_lossy();
+    if importer::is_url(&raw) {
+        return raw.to_string();
+    }
+    display_path(path)
+}
+
 fn display_path(path: &PathBuf) -> String {
     path.file_name()
         .and_then(|name| name.to_str())
@@ -12746,6 +20666,8 @@ fn import_apply_progress(
         stage: importer::ImportStage::Finalizing,
         stage_current: current,
         stage_total: total,
+        bytes_processed: 0,
+        bytes_total: 0,
         overall_progress: overall_progress.clamp(0.0, 1.0),
         detail,
     }
@@ -12833,18 +20755,24 @@ enum CliDuplicateAction {
 
 struct CliProgressPrinter {
     verbosity: CliVerbosity,
+    to_stderr: bool,
     last_label: Option<String>,
     last_stage: Option<importer::ImportStage>,
     last_tick: Instant,
+    bytes_line_open: bool,
+    bytes_started: Option<(Instant, u64)>,
 }
 
 impl CliProgressPrinter {
-    fn new(verbosity: CliVerbosity) -> Self {
+    fn new(verbosity: CliVerbosity, to_stderr: bool) -> Self {
         Self {
             verbosity,
+            to_stderr,
             last_label: None,
             last_stage: None,
             last_tick: Instant::now(),
+            bytes_line_open: false,
+            bytes_started: None,
         }
     }
 
@@ -12854,15 +20782,34 @@ impl CliProgressPrinter {
         }
         let label_changed = self.last_label.as_deref() != Some(progress.label.as_str());
         if label_changed {
-            println!(
-                "  -> {} ({}/{})",
-                progress.label, progress.unit_index, progress.unit_count
+            self.close_bytes_line();
+            cli_line(
+                self.to_stderr,
+                format_args!(
+                    "  -> {} ({}/{})",
+                    progress.label, progress.unit_index, progress.unit_count
+                ),
             );
             self.last_label = Some(progress.label.clone());
             self.last_stage = None;
         }
 
         let stage_changed = self.last_stage != Some(progress.stage);
+        if stage_changed {
+            self.close_bytes_line();
+        }
+        if progress.bytes_total > 0 {
+            if stage_changed {
+                cli_line(
+                    self.to_stderr,
+                    format_args!("     {}", progress.stage.label()),
+                );
+                self.last_stage = Some(progress.stage);
+            }
+            self.print_bytes_line(progress);
+            return;
+        }
+
         let should_tick = self.last_tick.elapsed().as_millis() >= 250;
         if stage_changed || (matches!(self.verbosity, CliVerbosity::Debug) && should_tick) {
             let mut line = format!("     {}", progress.stage.label());
@@ -12875,11 +20822,58 @@ impl CliProgressPrinter {
             if let Some(detail) = &progress.detail {
                 line.push_str(&format!(" - {}", detail));
             }
-            println!("{line}");
+            cli_line(self.to_stderr, format_args!("{line}"));
             self.last_stage = Some(progress.stage);
             self.last_tick = Instant::now();
         }
     }
+
+    fn print_bytes_line(&mut self, progress: &importer::ImportProgress) {
+        let done = progress.bytes_processed >= progress.bytes_total;
+        if !done && self.bytes_line_open && self.last_tick.elapsed().as_millis() < 250 {
+            return;
+        }
+        let (started_at, started_bytes) = *self
+            .bytes_started
+            .get_or_insert((Instant::now(), progress.bytes_processed));
+        let percent = progress.bytes_processed.saturating_mul(100) / progress.bytes_total;
+        let mut line = format!(
+            "     {} / {} ({percent}%)",
+            importer::format_bytes(progress.bytes_processed),
+            importer::format_bytes(progress.bytes_total)
+        );
+        let elapsed = started_at.elapsed().as_secs_f64();
+        let moved = progress.bytes_processed.saturating_sub(started_bytes);
+        if elapsed >= 0.5 && moved > 0 {
+            let rate = moved as f64 / elapsed;
+            line.push_str(&format!(" {}/s", importer::format_bytes(rate as u64)));
+            if !done {
+                let remaining = progress.bytes_total - progress.bytes_processed;
+                let eta = Duration::from_secs_f64(remaining as f64 / rate);
+                line.push_str(&format!(" ETA {}", importer::format_eta(eta)));
+            }
+        }
+        if self.to_stderr {
+            eprint!("\r{line:<72}");
+            let _ = io::stderr().flush();
+        } else {
+            print!("\r{line:<72}");
+            let _ = io::stdout().flush();
+        }
+        self.bytes_line_open = true;
+        self.last_tick = Instant::now();
+        if done {
+            self.close_bytes_line();
+        }
+    }
+
+    fn close_bytes_line(&mut self) {
+        if self.bytes_line_open {
+            cli_line(self.to_stderr, format_args!(""));
+            self.bytes_line_open = false;
+        }
+        self.bytes_started = None;
+    }
 }
 
 fn prompt_duplicate_cli(
@@ -12887,13 +20881,30 @@ fn prompt_duplicate_cli(
     existing: &ModEntry,
     default_overwrite: Option<bool>,
     similarity: Option<f32>,
+    to_stderr: bool,
 ) -> Result<CliDuplicateAction> {
-    println!();
-    println!("Duplicate mod detected:");
-    println!("  New: {}", new_mod.display_name());
-    println!("  Existing: {}", existing.display_name());
+    cli_line(to_stderr, format_args!(""));
+    cli_line(to_stderr, format_args!("Duplicate mod detected:"));
+    cli_line(to_stderr, format_args!("  New: {}", new_mod.display_name()));
+    cli_line(
+        to_stderr,
+        format_args!("  Existing: {}", existing.display_name()),
+    );
     if let Some(similarity) = similarity {
-        println!("  Similarity: {:.0}%", similarity * 100.0);
+        cli_line(
+            to_stderr,
+            format_args!("  Similarity: {:.0}%", similarity * 100.0),
+        );
+    }
+    if let Some((new_hash, existing_hash)) = pak_hash_mismatch(new_mod, existing) {
+        cli_line(
+            to_stderr,
+            format_args!(
+                "  Content differs: {} vs {}",
+                short_hash(&new_hash),
+                short_hash(&existing_hash)
+            ),
+        );
     }
     if let Some(default_overwrite) = default_overwrite {
         let hint = if default_overwrite {
@@ -12901,10 +20912,16 @@ fn prompt_duplicate_cli(
         } else {
             "skip (existing newer)"
         };
-        println!("  Default: {}", hint);
+        cli_line(to_stderr, format_args!("  Default: {}", hint));
+    }
+    let prompt = "Choose [o]verwrite, [s]kip, overwrite [a]ll, skip all [k] (Enter = default): ";
+    if to_stderr {
+        eprint!("{prompt}");
+        io::stderr().flush().ok();
+    } else {
+        print!("{prompt}");
+        io::stdout().flush().ok();
     }
-    print!("Choose [o]verwrite, [s]kip, overwrite [a]ll, skip all [k] (Enter = default): ");
-    io::stdout().flush().ok();
     let mut input = String::new();
     io::stdin().read_line(&mut input)?;
     let choice = input.trim().to_lowercase();
@@ -12924,6 +20941,14 @@ fn prompt_duplicate_cli(
     }
 }
 
+fn cli_line(to_stderr: bool, line: std::fmt::Arguments) {
+    if to_stderr {
+        eprintln!("{line}");
+    } else {
+        println!("{line}");
+    }
+}
+
 fn summarize_error(error: &str) -> String {
     let first_line = error.lines().next().unwrap_or(error).trim();
     let last = first_line.rsplit(": ").next().unwrap_or(first_line).trim();
@@ -13073,6 +21098,34 @@ fn dependency_is_self_alias(
     dep_lower.contains(&mod_entry.id.to_ascii_lowercase())
 }
 
+fn script_extender_label(info: &ScriptExtenderInfo) -> String {
+    match info.version {
+        Some(version) => format!("v{}", metadata::format_version64(version)),
+        None => "present (version unknown)".to_string(),
+    }
+}
+
+fn is_patch_label(name: &str) -> bool {
+    let lower = name.to_ascii_lowercase();
+    ["patch", "compat", "fix", "addon", "add-on"]
+        .iter()
+        .any(|keyword| lower.contains(keyword))
+}
+
+fn is_framework_label(name: &str) -> bool {
+    let key = normalize_label(name);
+    [
+        "modconfigurationmenu",
+        "communitylibrary",
+        "improvedui",
+        "volitioncabinet",
+        "modfixer",
+        "framework",
+    ]
+    .iter()
+    .any(|keyword| key.contains(keyword))
+}
+
 fn filter_ignored_dependencies(deps: &mut Vec<String>) {
     deps.retain(|dep| {
         if metadata::is_base_dependency_uuid(dep) || metadata::is_base_dependency_label(dep) {
@@ -13208,7 +21261,7 @@ fn mod_dependency_keys(mod_entry: &ModEntry) -> Vec<String> {
         }
     }
     for target in &mod_entry.targets {
-        if let InstallTarget::Pak { file, info } = target {
+        if let InstallTarget::Pak { file, info, .. } = target {
             push_key(file);
             for token in file.split(|ch: char| !ch.is_ascii_alphanumeric()) {
                 if token.len() >= 4 {
@@ -13299,6 +21352,9 @@ fn extract_timestamp(label: &str) -> Option<u64> {
 }
 
 fn mod_version_stamp(entry: &ModEntry) -> Option<u64> {
+    if let Some((major, minor, patch, build)) = entry.version.as_deref().and_then(extract_semver) {
+        return Some(semver_stamp(major, minor, patch, build));
+    }
     let label = entry.source_label().unwrap_or(entry.name.as_str());
     if let Some((major, minor, patch, build)) = extract_semver(label) {
         return Some(semver_stamp(major, minor, patch, build));
@@ -13376,6 +21432,19 @@ fn semver_stamp(major: u64, minor: u64, patch: u64, build: u64) -> u64 {
         .saturating_add(build)
 }
 
+fn rfc3339_label(timestamp: time::OffsetDateTime) -> String {
+    let utc = timestamp.to_offset(time::UtcOffset::UTC);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        utc.year(),
+        utc.month() as u8,
+        utc.day(),
+        utc.hour(),
+        utc.minute(),
+        utc.second()
+    )
+}
+
 fn log_level_label(level: LogLevel) -> &'static str {
     match level {
         LogLevel::Info => "INFO",
@@ -13384,13 +21453,35 @@ fn log_level_label(level: LogLevel) -> &'static str {
     }
 }
 
-fn append_log_file(path: &PathBuf, level: LogLevel, message: &str) -> std::io::Result<()> {
-    let label = log_level_label(level);
+fn append_log_file(path: &PathBuf, entry: &LogEntry, max_bytes: u64) -> std::io::Result<()> {
+    if max_bytes > 0 {
+        if let Ok(meta) = fs::metadata(path) {
+            if meta.len() >= max_bytes {
+                rotate_log_file(path)?;
+            }
+        }
+    }
     let mut file = fs::OpenOptions::new()
         .create(true)
         .append(true)
         .open(path)?;
-    writeln!(file, "[{label}] {message}")
+    writeln!(file, "{}", entry.line())
+}
+
+fn rotate_log_file(path: &Path) -> std::io::Result<()> {
+    let rotated = |generation: usize| {
+        let mut name = path.as_os_str().to_os_string();
+        name.push(format!(".{generation}"));
+        PathBuf::from(name)
+    };
+    let _ = fs::remove_file(rotated(LOG_ROTATE_KEEP));
+    for generation in (1..LOG_ROTATE_KEEP).rev() {
+        let from = rotated(generation);
+        if from.exists() {
+            fs::rename(&from, rotated(generation + 1))?;
+        }
+    }
+    fs::rename(path, rotated(1))
 }
 
 fn build_unknown_entry(path: &PathBuf, label: &str) -> ModEntry {
@@ -13407,6 +21498,18 @@ fn build_unknown_entry(path: &PathBuf, label: &str) -> ModEntry {
         source_label: Some(label.to_string()),
         source: ModSource::Managed,
         dependencies: Vec::new(),
+        author: None,
+        version: None,
+        website: None,
+        nexus_id: None,
+        min_game_version: None,
+        dev_path: None,
+        dev_watch: false,
+        custom_name: None,
+        disk_usage: None,
+        source_path: None,
+        disabled_since: None,
+        cache_evicted: false,
     }
 }
 
@@ -13449,35 +21552,196 @@ fn earliest_timestamp(values: &[Option<i64>]) -> Option<i64> {
             None => value,
         });
     }
-    out
+    out
+}
+
+fn resolve_native_times(
+    primary_created: Option<i64>,
+    file_created: Option<i64>,
+    file_modified: Option<i64>,
+) -> (Option<i64>, Option<i64>) {
+    if primary_created.is_some() {
+        return resolve_times(primary_created, file_created, file_modified);
+    }
+    let modified = file_modified.or(file_created);
+    (None, modified)
+}
+
+fn should_clear_native_created(
+    current_created: Option<i64>,
+    file_created: Option<i64>,
+    file_modified: Option<i64>,
+    added_at: i64,
+) -> bool {
+    let Some(current) = current_created else {
+        return false;
+    };
+    if current == added_at {
+        return true;
+    }
+    file_created.map_or(false, |value| value == current)
+        || file_modified.map_or(false, |value| value == current)
+}
+
+fn compatibility_report_text(report: &smart_rank::CompatibilityReport) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("Compatibility report: {}\n", report.profile));
+    out.push_str(&format!(
+        "Scanned {} enabled mod(s); {} overlapping\n",
+        report.scanned,
+        report.entries.len()
+    ));
+    if !report.unscanned.is_empty() {
+        out.push_str(&format!("Not scanned: {}\n", report.unscanned.join(", ")));
+    }
+    for entry in &report.entries {
+        out.push_str(&format!(
+            "\n[{}] {} — {} file(s) with {} mod(s)\n",
+            entry.severity.label(),
+            entry.name,
+            entry.overlap_files,
+            entry.overlaps.len()
+        ));
+        for overlap in &entry.overlaps {
+            let outcome = if overlap.wins { "wins" } else { "loses" };
+            out.push_str(&format!(
+                "  vs {} ({}, {} file(s), {outcome})\n",
+                overlap.other_name,
+                overlap.severity.label(),
+                overlap.paths.len()
+            ));
+            for path in &overlap.paths {
+                out.push_str(&format!("    {path}\n"));
+            }
+        }
+    }
+    out
+}
+
+fn hash_metadata_inputs(hasher: &mut Hasher, mod_entry: &ModEntry) {
+    hasher.update(mod_entry.id.as_bytes());
+    hasher.update(mod_entry.name.as_bytes());
+    if let Some(label) = mod_entry.source_label.as_deref() {
+        hasher.update(label.as_bytes());
+    }
+    let source_tag = match mod_entry.source {
+        ModSource::Managed => 0u8,
+        ModSource::Native => 1u8,
+    };
+    hasher.update(&[source_tag]);
+    let mut targets: Vec<String> = Vec::new();
+    for target in &mod_entry.targets {
+        let key = match target {
+            InstallTarget::Pak { file, info, .. } => {
+                format!("pak|{}|{}|{}", file, info.uuid, info.folder)
+            }
+            InstallTarget::Generated { dir } => format!("gen|{dir}"),
+            InstallTarget::Data { dir } => format!("data|{dir}"),
+            InstallTarget::Bin { dir } => format!("bin|{dir}"),
+        };
+        targets.push(key);
+    }
+    targets.sort();
+    for target in targets {
+        hasher.update(target.as_bytes());
+    }
+}
+
+fn scan_toggle_impact(
+    config: &GameConfig,
+    library: &Library,
+    hypothetical: &Library,
+    baseline: Option<Vec<deploy::ConflictEntry>>,
+) -> Result<Vec<ToggleImpactChange>> {
+    let before = match baseline {
+        Some(conflicts) => conflicts,
+        None => deploy::scan_conflicts(config, library)?,
+    };
+    let after = deploy::scan_conflicts(config, hypothetical)?;
+    Ok(conflict_delta(&before, &after))
+}
+
+fn conflict_delta(
+    before: &[deploy::ConflictEntry],
+    after: &[deploy::ConflictEntry],
+) -> Vec<ToggleImpactChange> {
+    let index = |conflicts: &[deploy::ConflictEntry]| -> HashMap<(TargetKind, PathBuf), deploy::ConflictEntry> {
+        conflicts
+            .iter()
+            .filter(|conflict| conflict.ignored.is_none())
+            .map(|conflict| {
+                (
+                    (conflict.target, conflict.relative_path.clone()),
+                    conflict.clone(),
+                )
+            })
+            .collect()
+    };
+    let before = index(before);
+    let after = index(after);
+
+    let mut changes = Vec::new();
+    for (key, conflict) in &after {
+        let path = conflict.relative_path.to_string_lossy().to_string();
+        match before.get(key) {
+            None => changes.push(ToggleImpactChange {
+                kind: ToggleImpactKind::Introduced,
+                target: conflict.target,
+                path,
+                detail: format!(
+                    "{} mods, {} wins",
+                    conflict.candidates.len(),
+                    conflict.winner_name
+                ),
+            }),
+            Some(previous) if previous.winner_id != conflict.winner_id => {
+                changes.push(ToggleImpactChange {
+                    kind: ToggleImpactKind::WinnerChanged,
+                    target: conflict.target,
+                    path,
+                    detail: format!("{} → {}", previous.winner_name, conflict.winner_name),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    for (key, conflict) in &before {
+        if after.contains_key(key) {
+            continue;
+        }
+        changes.push(ToggleImpactChange {
+            kind: ToggleImpactKind::Resolved,
+            target: conflict.target,
+            path: conflict.relative_path.to_string_lossy().to_string(),
+            detail: format!("{} no longer overrides", conflict.winner_name),
+        });
+    }
+    changes.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.path.cmp(&b.path)));
+    changes
 }
 
-fn resolve_native_times(
-    primary_created: Option<i64>,
-    file_created: Option<i64>,
-    file_modified: Option<i64>,
-) -> (Option<i64>, Option<i64>) {
-    if primary_created.is_some() {
-        return resolve_times(primary_created, file_created, file_modified);
+fn mod_list_candidate(mod_entry: &ModEntry) -> OverrideCandidateItem {
+    OverrideCandidateItem {
+        mod_id: mod_entry.id.clone(),
+        name: mod_entry.display_name(),
     }
-    let modified = file_modified.or(file_created);
-    (None, modified)
 }
 
-fn should_clear_native_created(
-    current_created: Option<i64>,
-    file_created: Option<i64>,
-    file_modified: Option<i64>,
-    added_at: i64,
-) -> bool {
-    let Some(current) = current_created else {
-        return false;
-    };
-    if current == added_at {
-        return true;
-    }
-    file_created.map_or(false, |value| value == current)
-        || file_modified.map_or(false, |value| value == current)
+/// Splits off mods whose stored per-mod key still matches, leaving only those to rescan.
+fn take_cached_metadata(mods: &mut Vec<ModEntry>, keys: &HashMap<String, String>) -> Vec<ModEntry> {
+    let (cached, pending) = std::mem::take(mods)
+        .into_iter()
+        .partition(|mod_entry| keys.get(&mod_entry.id) == Some(&mod_metadata_key(mod_entry)));
+    *mods = pending;
+    cached
+}
+
+fn mod_metadata_key(mod_entry: &ModEntry) -> String {
+    let mut hasher = Hasher::new();
+    hasher.update(b"metadata-mod-v1");
+    hasher.update(&METADATA_CACHE_VERSION.to_le_bytes());
+    hash_metadata_inputs(&mut hasher, mod_entry);
+    hasher.finalize().to_hex().to_string()
 }
 
 fn collect_metadata_updates(
@@ -13485,7 +21749,9 @@ fn collect_metadata_updates(
     config: &GameConfig,
     library: &Library,
     pak_cache: &metadata::PakMetaCache,
+    force: bool,
     progress: Option<&Sender<MetadataMessage>>,
+    cancel: Option<&AtomicBool>,
 ) -> Result<Vec<MetadataUpdate>> {
     let paths = game::detect_paths(game_id, Some(&config.game_root), Some(&config.larian_dir)).ok();
     let native_index = paths
@@ -13495,6 +21761,9 @@ fn collect_metadata_updates(
     let mut updates = Vec::new();
     let total = library.mods.len();
     for (index, mod_entry) in library.mods.iter().enumerate() {
+        if cancel.is_some_and(|cancel| cancel.load(AtomicOrdering::Relaxed)) {
+            break;
+        }
         let should_refresh_created =
             mod_entry.created_at.is_none() || mod_entry.created_at == Some(mod_entry.added_at);
         let should_refresh_modified = mod_entry.modified_at.is_none()
@@ -13507,6 +21776,7 @@ fn collect_metadata_updates(
         let mut file_created: Option<i64> = None;
         let mut file_modified: Option<i64> = None;
         let mut dependencies: Vec<String> = Vec::new();
+        let mut min_game_version: Option<u64> = None;
 
         for pak_path in resolve_pak_paths(
             mod_entry,
@@ -13514,7 +21784,11 @@ fn collect_metadata_updates(
             paths.as_ref(),
             native_index.as_deref(),
         ) {
+            if force {
+                pak_cache.invalidate(&pak_path);
+            }
             if let Some(meta) = metadata::read_meta_lsx_from_pak_cached(pak_cache, &pak_path) {
+                min_game_version = min_game_version.max(meta.min_game_version);
                 if let Some(created) = meta.created_at {
                     meta_created = Some(match meta_created {
                         Some(existing) => existing.min(created),
@@ -13544,6 +21818,7 @@ fn collect_metadata_updates(
         if mod_root.exists() {
             if let Some(meta_path) = metadata::find_meta_lsx(&mod_root) {
                 if let Some(meta) = metadata::read_meta_lsx(&meta_path) {
+                    min_game_version = min_game_version.max(meta.min_game_version);
                     if let Some(created) = meta.created_at {
                         meta_created = Some(match meta_created {
                             Some(existing) => existing.min(created),
@@ -13643,11 +21918,41 @@ fn collect_metadata_updates(
             }
         }
 
+        let mut pak_hashes = Vec::new();
+        for target in &mod_entry.targets {
+            let InstallTarget::Pak {
+                file,
+                info,
+                hash: None,
+            } = target
+            else {
+                continue;
+            };
+            let pak_path = if mod_entry.is_native() {
+                paths
+                    .as_ref()
+                    .map(|paths| paths.larian_mods_dir.join(file))
+                    .filter(|path| path.exists())
+                    .or_else(|| {
+                        native_index
+                            .as_deref()
+                            .and_then(|index| native_pak::resolve_native_pak_path(info, index))
+                    })
+            } else {
+                Some(mod_root.join(file)).filter(|path| path.exists())
+            };
+            if let Some(hash) = pak_path.and_then(|path| importer::hash_file(&path).ok()) {
+                pak_hashes.push((file.clone(), hash));
+            }
+        }
+
         let update = MetadataUpdate {
             id: mod_entry.id.clone(),
             created_at: next_created,
             modified_at: next_modified,
             dependencies,
+            pak_hashes,
+            min_game_version,
         };
         if let Some(tx) = progress {
             let _ = tx.send(MetadataMessage::Progress {
@@ -13847,6 +22152,7 @@ fn sync_native_mods_delta(
     }
 
     let mut adopted_native = 0usize;
+    let mut ignored_native = 0usize;
     let non_native_mods: Vec<&ModEntry> = library
         .mods
         .iter()
@@ -13877,6 +22183,10 @@ fn sync_native_mods_delta(
         let modsettings_created = module.created_at;
         let filename = native_pak::resolve_native_pak_filename(info, &native_pak_index)
             .unwrap_or_else(|| format!("{}.pak", info.folder));
+        if config.native_sync_ignored(&[&filename, &info.folder, &info.name, &info.uuid]) {
+            ignored_native += 1;
+            continue;
+        }
         let pak_path = paths.larian_mods_dir.join(&filename);
         let pak_meta = metadata::read_meta_lsx_from_pak_cached(pak_cache, &pak_path);
         let meta_created = pak_meta.as_ref().and_then(|meta| meta.created_at);
@@ -13908,7 +22218,8 @@ fn sync_native_mods_delta(
             source_label: None,
             targets: vec![InstallTarget::Pak {
                 file: filename.clone(),
-                info: info.clone(),
+                info: Box::new(info.clone()),
+                hash: None,
             }],
             created_at: next_created,
             modified_at: next_modified,
@@ -13943,6 +22254,10 @@ fn sync_native_mods_delta(
         }
         let filename = native_pak::resolve_native_pak_filename(&info, &native_pak_index)
             .unwrap_or_else(|| format!("{}.pak", info.folder));
+        if config.native_sync_ignored(&[&filename, &info.folder, &info.name, &uuid]) {
+            ignored_native += 1;
+            continue;
+        }
         let pak_path = paths.larian_mods_dir.join(&filename);
         let pak_meta = metadata::read_meta_lsx_from_pak_cached(pak_cache, &pak_path);
         let meta_created = pak_meta.as_ref().and_then(|meta| meta.created_at);
@@ -13957,6 +22272,7 @@ fn sync_native_mods_delta(
         let primary_created = earliest_timestamp(&[modsettings_created, meta_created]);
         let (created_at, modified_at) =
             resolve_native_times(primary_created, raw_created, raw_modified);
+        let author = info.author.clone();
         let mod_entry = ModEntry {
             id: uuid.clone(),
             name: info.name.clone(),
@@ -13965,12 +22281,25 @@ fn sync_native_mods_delta(
             added_at: now_timestamp(),
             targets: vec![InstallTarget::Pak {
                 file: filename,
-                info,
+                info: Box::new(info),
+                hash: None,
             }],
             target_overrides: Vec::new(),
             source_label: None,
             source: ModSource::Native,
             dependencies,
+            author,
+            version: None,
+            website: None,
+            nexus_id: None,
+            min_game_version: None,
+            dev_path: None,
+            dev_watch: false,
+            custom_name: None,
+            disk_usage: None,
+            source_path: None,
+            disabled_since: None,
+            cache_evicted: false,
         };
         added.push(mod_entry);
         existing_ids.insert(uuid);
@@ -13981,6 +22310,7 @@ fn sync_native_mods_delta(
         added,
         updated_native_files,
         adopted_native,
+        ignored_native,
         modsettings_exists,
         modsettings_hash,
         enabled_set,
@@ -13995,6 +22325,245 @@ fn now_timestamp() -> i64 {
         .as_secs() as i64
 }
 
+fn format_snapshot_time(timestamp: i64) -> String {
+    time::OffsetDateTime::from_unix_timestamp(timestamp)
+        .map(|dt| {
+            format!(
+                "{:04}-{:02}-{:02} {:02}:{:02}",
+                dt.year(),
+                dt.month() as u8,
+                dt.day(),
+                dt.hour(),
+                dt.minute()
+            )
+        })
+        .unwrap_or_else(|_| "unknown time".to_string())
+}
+
+fn pak_uuids(mod_entry: &ModEntry) -> HashSet<String> {
+    mod_entry
+        .targets
+        .iter()
+        .filter_map(|target| match target {
+            InstallTarget::Pak { info, .. } => Some(info.uuid.to_ascii_lowercase()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn sort_conflicts(conflicts: &mut [deploy::ConflictEntry]) {
+    conflicts.sort_by(|a, b| {
+        a.relative_path
+            .to_string_lossy()
+            .cmp(&b.relative_path.to_string_lossy())
+    });
+}
+
+/// True when both mods have the same pak hashes and the same hashed loose files. Anything
+/// unhashed never matches. The loose index is only loaded once the cheap checks pass.
+fn same_mod_content(
+    left: &ModEntry,
+    left_index: Option<&sigillink::SigilLinkIndex>,
+    right: &ModEntry,
+    right_index: impl FnOnce() -> Option<sigillink::SigilLinkIndex>,
+) -> bool {
+    if left.targets.is_empty() || !same_pak_content(left, right) {
+        return false;
+    }
+    let loose_kinds = |mod_entry: &ModEntry| {
+        let mut kinds: Vec<TargetKind> = mod_entry
+            .targets
+            .iter()
+            .map(InstallTarget::kind)
+            .filter(|kind| *kind != TargetKind::Pak)
+            .collect();
+        kinds.sort_by_key(|kind| *kind as u8);
+        kinds
+    };
+    let kinds = loose_kinds(left);
+    if kinds != loose_kinds(right) {
+        return false;
+    }
+    if kinds.is_empty() {
+        return true;
+    }
+    let Some(left_index) = left_index else {
+        return false;
+    };
+    right_index().is_some_and(|right_index| same_loose_content(left_index, &right_index))
+}
+
+fn same_pak_content(left: &ModEntry, right: &ModEntry) -> bool {
+    match (sorted_pak_hashes(left), sorted_pak_hashes(right)) {
+        (Some(left), Some(right)) => left == right,
+        _ => false,
+    }
+}
+
+fn sorted_pak_hashes(mod_entry: &ModEntry) -> Option<Vec<&str>> {
+    let paks = mod_entry
+        .targets
+        .iter()
+        .filter(|target| matches!(target, InstallTarget::Pak { .. }))
+        .count();
+    let mut hashes = mod_entry.pak_hashes();
+    hashes.sort_unstable();
+    (hashes.len() == paks).then_some(hashes)
+}
+
+fn same_loose_content(left: &sigillink::SigilLinkIndex, right: &sigillink::SigilLinkIndex) -> bool {
+    if left.total_bytes != right.total_bytes || left.entries.len() != right.entries.len() {
+        return false;
+    }
+    let files = |index: &sigillink::SigilLinkIndex| {
+        index
+            .entries
+            .iter()
+            .map(|entry| {
+                let hash = entry.hash.as_deref()?;
+                Some((
+                    entry.kind,
+                    entry.relative_path.replace('\\', "/"),
+                    entry.size,
+                    hash.to_string(),
+                ))
+            })
+            .collect::<Option<HashSet<_>>>()
+    };
+    match (files(left), files(right)) {
+        (Some(left), Some(right)) => left == right,
+        _ => false,
+    }
+}
+
+fn pak_hash_mismatch(incoming: &ModEntry, existing: &ModEntry) -> Option<(String, String)> {
+    let new_hash = incoming.pak_hashes().first()?.to_string();
+    let existing_hashes = existing.pak_hashes();
+    if existing_hashes.contains(&new_hash.as_str()) {
+        return None;
+    }
+    let existing_hash = existing_hashes.first()?.to_string();
+    Some((new_hash, existing_hash))
+}
+
+const DUPLICATE_DIFF_SAMPLES: usize = 6;
+
+fn duplicate_diff_lines(
+    incoming: &ModEntry,
+    incoming_root: Option<&Path>,
+    existing: Option<&ModEntry>,
+    existing_root: &Path,
+) -> Vec<String> {
+    let new_files = incoming_root.map(collect_file_sizes).unwrap_or_default();
+    let old_files = if existing_root.is_dir() {
+        collect_file_sizes(existing_root)
+    } else {
+        BTreeMap::new()
+    };
+    let mut lines = Vec::new();
+    let describe = |files: &BTreeMap<String, u64>, modified: Option<i64>| {
+        let total: u64 = files.values().sum();
+        let date = modified
+            .map(format_snapshot_time)
+            .unwrap_or_else(|| "unknown date".to_string());
+        format!(
+            "{} file(s), {}, modified {date}",
+            files.len(),
+            importer::format_bytes(total)
+        )
+    };
+    if !new_files.is_empty() {
+        lines.push(format!(
+            "New:      {}",
+            describe(&new_files, incoming.modified_at.or(incoming.created_at))
+        ));
+    }
+    if let Some(existing) = existing.filter(|_| !old_files.is_empty()) {
+        lines.push(format!(
+            "Existing: {}",
+            describe(&old_files, existing.modified_at.or(existing.created_at))
+        ));
+    }
+    if new_files.is_empty() || old_files.is_empty() {
+        return lines;
+    }
+
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    let mut changed = Vec::new();
+    for (path, size) in &new_files {
+        match old_files.get(path) {
+            None => added.push(format!("+ {path}")),
+            Some(old_size) if old_size != size => changed.push(format!(
+                "~ {path} ({} -> {})",
+                importer::format_bytes(*old_size),
+                importer::format_bytes(*size)
+            )),
+            Some(_) => {}
+        }
+    }
+    for path in old_files.keys() {
+        if !new_files.contains_key(path) {
+            removed.push(format!("- {path}"));
+        }
+    }
+    if added.is_empty() && removed.is_empty() && changed.is_empty() {
+        lines.push("Files: same paths and sizes".to_string());
+        return lines;
+    }
+    lines.push(format!(
+        "Files: {} added, {} removed, {} resized",
+        added.len(),
+        removed.len(),
+        changed.len()
+    ));
+    let samples: Vec<String> = changed.into_iter().chain(added).chain(removed).collect();
+    let extra = samples.len().saturating_sub(DUPLICATE_DIFF_SAMPLES);
+    lines.extend(samples.into_iter().take(DUPLICATE_DIFF_SAMPLES));
+    if extra > 0 {
+        lines.push(format!("... and {extra} more"));
+    }
+    lines
+}
+
+fn collect_file_sizes(root: &Path) -> BTreeMap<String, u64> {
+    let mut files = BTreeMap::new();
+    for entry in WalkDir::new(root).into_iter().filter_map(Result::ok) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(relative) = entry.path().strip_prefix(root) else {
+            continue;
+        };
+        let size = entry.metadata().map(|meta| meta.len()).unwrap_or(0);
+        files.insert(relative.to_string_lossy().replace('\\', "/"), size);
+    }
+    files
+}
+
+fn short_hash(hash: &str) -> &str {
+    hash.get(..12).unwrap_or(hash)
+}
+
+fn carry_pak_hashes(existing: &[InstallTarget], targets: &mut [InstallTarget]) {
+    for target in targets {
+        let InstallTarget::Pak { file, hash, .. } = target else {
+            continue;
+        };
+        if hash.is_some() {
+            continue;
+        }
+        *hash = existing.iter().find_map(|existing| match existing {
+            InstallTarget::Pak {
+                file: existing_file,
+                hash: Some(existing_hash),
+                ..
+            } if existing_file == file => Some(existing_hash.clone()),
+            _ => None,
+        });
+    }
+}
+
 fn resolve_pak_paths(
     mod_entry: &ModEntry,
     cache_root: &PathBuf,
@@ -14009,7 +22578,7 @@ fn resolve_pak_paths(
     };
     let mods_root = library_mod_root(cache_root);
     for target in &mod_entry.targets {
-        let InstallTarget::Pak { file, info } = target else {
+        let InstallTarget::Pak { file, info, .. } = target else {
             continue;
         };
         if mod_entry.is_native() {
@@ -14102,3 +22671,462 @@ fn path_within_root(path: &Path, root: &Path) -> bool {
     }
     path.starts_with(root)
 }
+
+fn next_debounce_option(options: &[u64], current: u64) -> u64 {
+    options
+        .iter()
+        .copied()
+        .find(|ms| *ms > current)
+        .unwrap_or(options[0])
+}
+
+fn debounce_label(ms: u64) -> String {
+    if ms == 0 {
+        "Instant".to_string()
+    } else if ms >= 1000 && ms.is_multiple_of(1000) {
+        format!("{}s", ms / 1000)
+    } else {
+        format!("{ms}ms")
+    }
+}
+
+fn scan_cache_integrity(cache_root: &Path, jobs: &[CacheVerifyJob]) -> CacheVerifyReport {
+    let mods_root = library_mod_root(cache_root);
+    let mut report = CacheVerifyReport::default();
+    for job in jobs {
+        let mod_root = mods_root.join(&job.mod_id);
+        let issue = |path: &str, problem: &str| CacheVerifyIssue {
+            mod_name: job.mod_name.clone(),
+            path: path.to_string(),
+            problem: problem.to_string(),
+        };
+        let mut damaged = Vec::new();
+        for target in &job.targets {
+            let InstallTarget::Pak { file, hash, .. } = target else {
+                continue;
+            };
+            report.checked += 1;
+            let Some(expected) = hash else {
+                report
+                    .unverified
+                    .push(issue(file, "no pak hash recorded at import"));
+                continue;
+            };
+            let dest = mod_root.join(file);
+            let problem = match importer::hash_file(&dest) {
+                Ok(actual) if &actual == expected => continue,
+                Ok(_) => "hash mismatch",
+                Err(_) if !dest.exists() => "missing",
+                Err(_) => "unreadable",
+            };
+            damaged.push(CacheVerifyDamage {
+                kind: None,
+                relative_path: file.clone(),
+                dest,
+                size: 0,
+                hash: Some(expected.clone()),
+                problem,
+            });
+        }
+
+        if let Some(index) = sigillink::load_sigillink_index(cache_root, &job.mod_id) {
+            for entry in &index.entries {
+                report.checked += 1;
+                let candidates: Vec<PathBuf> = job
+                    .targets
+                    .iter()
+                    .filter_map(|target| match target {
+                        InstallTarget::Data { dir }
+                        | InstallTarget::Generated { dir }
+                        | InstallTarget::Bin { dir }
+                            if target.kind() == entry.kind =>
+                        {
+                            Some(mod_root.join(dir).join(&entry.relative_path))
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                let Some(dest) = candidates
+                    .iter()
+                    .find(|path| path.is_file())
+                    .or(candidates.first())
+                    .cloned()
+                else {
+                    report.issues.push(issue(&entry.relative_path, "missing"));
+                    continue;
+                };
+                let problem = loose_file_problem(&dest, entry.size, entry.hash.as_deref());
+                if let Some(problem) = problem {
+                    damaged.push(CacheVerifyDamage {
+                        kind: Some(entry.kind),
+                        relative_path: entry.relative_path.clone(),
+                        dest,
+                        size: entry.size,
+                        hash: entry.hash.clone(),
+                        problem,
+                    });
+                }
+            }
+        }
+
+        if damaged.is_empty() {
+            continue;
+        }
+        let repaired = repair_cache_from_source(job, cache_root, &damaged);
+        for (damage, repaired) in damaged.iter().zip(repaired) {
+            let entry = issue(&damage.relative_path, damage.problem);
+            if repaired {
+                report.repaired.push(entry);
+            } else {
+                report.issues.push(entry);
+            }
+        }
+    }
+    report
+}
+
+/// Indexes written before per-file hashes were recorded can only be checked by size.
+fn loose_file_problem(path: &Path, size: u64, hash: Option<&str>) -> Option<&'static str> {
+    let Ok(meta) = fs::metadata(path) else {
+        return Some("missing");
+    };
+    if meta.len() != size {
+        return Some("size mismatch");
+    }
+    let expected = hash?;
+    match importer::hash_file(path) {
+        Ok(actual) if actual == expected => None,
+        Ok(_) => Some("hash mismatch"),
+        Err(_) => Some("unreadable"),
+    }
+}
+
+/// Restages the mod's recorded import source and copies back only the damaged files whose
+/// restaged copies still match what was recorded at import.
+fn repair_cache_from_source(
+    job: &CacheVerifyJob,
+    cache_root: &Path,
+    damaged: &[CacheVerifyDamage],
+) -> Vec<bool> {
+    let mut repaired = vec![false; damaged.len()];
+    let Some(source) = job.source.as_deref() else {
+        return repaired;
+    };
+    let Ok(import_mod) = stage_mod_from_source(Path::new(source), &job.mod_id, cache_root) else {
+        return repaired;
+    };
+    let Some(staging_root) = import_mod.staging_root.clone() else {
+        return repaired;
+    };
+    for (damage, repaired) in damaged.iter().zip(repaired.iter_mut()) {
+        let staged = match damage.kind {
+            None => Some(staging_root.join(&damage.relative_path)),
+            Some(kind) => import_mod
+                .entry
+                .targets
+                .iter()
+                .find_map(|target| match target {
+                    InstallTarget::Data { dir }
+                    | InstallTarget::Generated { dir }
+                    | InstallTarget::Bin { dir }
+                        if target.kind() == kind =>
+                    {
+                        let path = staging_root.join(dir).join(&damage.relative_path);
+                        path.is_file().then_some(path)
+                    }
+                    _ => None,
+                }),
+        };
+        let Some(staged) = staged else {
+            continue;
+        };
+        let matches = match damage.kind {
+            None => importer::hash_file(&staged).ok() == damage.hash,
+            Some(_) => loose_file_problem(&staged, damage.size, damage.hash.as_deref()).is_none(),
+        };
+        if matches {
+            *repaired = replace_cache_file(&staged, &damage.dest);
+        }
+    }
+    import_mod.cleanup_staging();
+    repaired
+}
+
+fn replace_cache_file(source: &Path, dest: &Path) -> bool {
+    if let Some(parent) = dest.parent() {
+        if fs::create_dir_all(parent).is_err() {
+            return false;
+        }
+    }
+    let mut temp = dest.as_os_str().to_owned();
+    temp.push(".repair");
+    let temp = PathBuf::from(temp);
+    if fs::copy(source, &temp).is_err() || fs::rename(&temp, dest).is_err() {
+        let _ = fs::remove_file(&temp);
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("sigilsmith-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    fn pak_target(file: &str, hash: Option<String>) -> InstallTarget {
+        InstallTarget::Pak {
+            file: file.to_string(),
+            info: Box::new(PakInfo {
+                uuid: "uuid".to_string(),
+                name: "Mod".to_string(),
+                folder: "Mod".to_string(),
+                version: 0,
+                md5: None,
+                publish_handle: None,
+                author: None,
+                description: None,
+                module_type: None,
+            }),
+            hash,
+        }
+    }
+
+    fn mod_with_targets(id: &str, targets: Vec<InstallTarget>) -> ModEntry {
+        let mut mod_entry: ModEntry = serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": id,
+            "added_at": 0,
+            "targets": [],
+        }))
+        .unwrap();
+        mod_entry.targets = targets;
+        mod_entry
+    }
+
+    fn loose_index(files: &[(&str, u64, Option<&str>)]) -> sigillink::SigilLinkIndex {
+        sigillink::SigilLinkIndex {
+            version: sigillink::SIGILLINK_VERSION,
+            entries: files
+                .iter()
+                .map(|(path, size, hash)| sigillink::SigilLinkEntry {
+                    kind: TargetKind::Data,
+                    relative_path: path.to_string(),
+                    size: *size,
+                    hash: hash.map(str::to_string),
+                })
+                .collect(),
+            total_bytes: files.iter().map(|(_, size, _)| size).sum(),
+        }
+    }
+
+    #[test]
+    fn pak_content_duplicates_match_in_both_directions() {
+        let hash = |value: &str| Some(value.to_string());
+        let both = mod_with_targets(
+            "both",
+            vec![
+                pak_target("A.pak", hash("a")),
+                pak_target("B.pak", hash("b")),
+            ],
+        );
+        let swapped = mod_with_targets(
+            "swapped",
+            vec![
+                pak_target("B.pak", hash("b")),
+                pak_target("A.pak", hash("a")),
+            ],
+        );
+        let single = mod_with_targets("single", vec![pak_target("A.pak", hash("a"))]);
+        let unhashed = mod_with_targets("unhashed", vec![pak_target("A.pak", None)]);
+
+        assert!(same_mod_content(&both, None, &swapped, || None));
+        assert!(same_mod_content(&swapped, None, &both, || None));
+        assert!(!same_mod_content(&single, None, &both, || None));
+        assert!(!same_mod_content(&both, None, &single, || None));
+        assert!(!same_mod_content(&unhashed, None, &single, || None));
+        assert!(!same_mod_content(&single, None, &unhashed, || None));
+    }
+
+    #[test]
+    fn loose_content_duplicates_match_in_both_directions() {
+        let data = || InstallTarget::Data {
+            dir: "Data".to_string(),
+        };
+        let incoming = mod_with_targets("incoming", vec![data()]);
+        let existing = mod_with_targets("existing", vec![data()]);
+        let index = loose_index(&[("a.txt", 3, Some("h1")), ("b.txt", 5, Some("h2"))]);
+        let reordered = loose_index(&[("b.txt", 5, Some("h2")), ("a.txt", 3, Some("h1"))]);
+        let changed = loose_index(&[("a.txt", 3, Some("h1")), ("b.txt", 5, Some("h3"))]);
+        let unhashed = loose_index(&[("a.txt", 3, Some("h1")), ("b.txt", 5, None)]);
+
+        assert!(same_mod_content(&incoming, Some(&index), &existing, || {
+            Some(reordered.clone())
+        }));
+        assert!(same_mod_content(
+            &existing,
+            Some(&reordered),
+            &incoming,
+            || { Some(index.clone()) }
+        ));
+        assert!(!same_mod_content(
+            &incoming,
+            Some(&index),
+            &existing,
+            || { Some(changed.clone()) }
+        ));
+        assert!(!same_mod_content(
+            &existing,
+            Some(&changed),
+            &incoming,
+            || { Some(index.clone()) }
+        ));
+        assert!(!same_mod_content(
+            &incoming,
+            Some(&index),
+            &existing,
+            || { Some(unhashed.clone()) }
+        ));
+        assert!(!same_mod_content(
+            &incoming,
+            Some(&index),
+            &existing,
+            || None
+        ));
+        assert!(!same_mod_content(&incoming, None, &existing, || {
+            Some(index.clone())
+        }));
+
+        let pak_only = mod_with_targets("pak", vec![pak_target("A.pak", Some("h1".to_string()))]);
+        assert!(!same_mod_content(
+            &incoming,
+            Some(&index),
+            &pak_only,
+            || None
+        ));
+    }
+
+    #[test]
+    fn cache_integrity_reports_unhashed_paks_and_changed_loose_files() {
+        let cache_root = temp_root("cache-verify");
+        let mod_root = library_mod_root(&cache_root).join("mod");
+        fs::create_dir_all(mod_root.join("Data")).unwrap();
+        fs::write(mod_root.join("Mod.pak"), b"pak").unwrap();
+        fs::write(mod_root.join("Data").join("file.txt"), b"new").unwrap();
+        let index = sigillink::SigilLinkIndex {
+            version: sigillink::SIGILLINK_VERSION,
+            entries: vec![sigillink::SigilLinkEntry {
+                kind: TargetKind::Data,
+                relative_path: "file.txt".to_string(),
+                size: 3,
+                hash: Some("not-the-hash".to_string()),
+            }],
+            total_bytes: 3,
+        };
+        sigillink::write_sigillink_index(&cache_root, "mod", &index).unwrap();
+        let job = CacheVerifyJob {
+            mod_id: "mod".to_string(),
+            mod_name: "Mod".to_string(),
+            targets: vec![
+                pak_target("Mod.pak", None),
+                InstallTarget::Data {
+                    dir: "Data".to_string(),
+                },
+            ],
+            source: None,
+        };
+
+        let report = scan_cache_integrity(&cache_root, &[job]);
+        let _ = fs::remove_dir_all(&cache_root);
+
+        assert_eq!(report.checked, 2);
+        assert_eq!(report.unverified.len(), 1);
+        assert_eq!(report.unverified[0].path, "Mod.pak");
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].problem, "hash mismatch");
+        assert!(report.repaired.is_empty());
+    }
+
+    #[test]
+    fn partial_metadata_cache_rescans_only_missing_mods() {
+        let mut done = mod_with_targets("done", vec![pak_target("Done.pak", None)]);
+        done.dependencies = vec!["dep".to_string()];
+        let renamed = mod_with_targets("renamed", vec![pak_target("Renamed.pak", None)]);
+        let missing = mod_with_targets("missing", vec![pak_target("Missing.pak", None)]);
+        let mut keys = HashMap::new();
+        keys.insert(done.id.clone(), mod_metadata_key(&done));
+        keys.insert(renamed.id.clone(), mod_metadata_key(&renamed));
+        let mut renamed = renamed;
+        renamed.name = "Renamed Mod".to_string();
+
+        let mut mods = vec![done, renamed, missing];
+        let cached = take_cached_metadata(&mut mods, &keys);
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached[0].id, "done");
+        assert_eq!(cached[0].dependencies, vec!["dep".to_string()]);
+        let pending: Vec<&str> = mods.iter().map(|mod_entry| mod_entry.id.as_str()).collect();
+        assert_eq!(pending, vec!["renamed", "missing"]);
+    }
+
+    #[test]
+    fn rfc3339_label_normalizes_to_utc() {
+        let local = time::OffsetDateTime::from_unix_timestamp(1_700_000_000)
+            .expect("timestamp")
+            .to_offset(time::UtcOffset::from_hms(2, 0, 0).expect("offset"));
+        assert_eq!(rfc3339_label(local), "2023-11-14T22:13:20Z");
+        let entry = LogEntry {
+            level: LogLevel::Info,
+            message: "hello".to_string(),
+            timestamp: local,
+        };
+        assert_eq!(entry.timestamp_label(), "2023-11-14T22:13:20Z");
+    }
+
+    #[test]
+    fn record_pak_hash_fills_only_missing_hashes() {
+        let mut targets = vec![
+            pak_target("A.pak", None),
+            pak_target("B.pak", Some("kept".to_string())),
+        ];
+        assert!(record_pak_hash(&mut targets, "A.pak", "new"));
+        assert!(!record_pak_hash(&mut targets, "B.pak", "new"));
+        assert!(!record_pak_hash(&mut targets, "C.pak", "new"));
+        let hashes: Vec<&str> = targets
+            .iter()
+            .filter_map(|target| match target {
+                InstallTarget::Pak { hash, .. } => hash.as_deref(),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(hashes, vec!["new", "kept"]);
+    }
+
+    #[test]
+    fn loose_file_problem_checks_size_then_hash() {
+        let root = temp_root("loose-problem");
+        let path = root.join("file.txt");
+        fs::write(&path, b"data").unwrap();
+        let hash = importer::hash_file(&path).unwrap();
+
+        assert_eq!(loose_file_problem(&path, 4, Some(&hash)), None);
+        assert_eq!(loose_file_problem(&path, 4, None), None);
+        assert_eq!(
+            loose_file_problem(&path, 5, Some(&hash)),
+            Some("size mismatch")
+        );
+        assert_eq!(
+            loose_file_problem(&path, 4, Some("other")),
+            Some("hash mismatch")
+        );
+        assert_eq!(
+            loose_file_problem(&root.join("gone.txt"), 4, None),
+            Some("missing")
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+}
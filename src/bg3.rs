@@ -1,11 +1,33 @@
+use crate::metadata;
 use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
 use std::{
     fs,
+    io::{Read, Seek, SeekFrom},
     path::{Path, PathBuf},
 };
 
 pub const GAME_NAME: &str = "Baldur's Gate 3";
 const STEAM_APP_ID: &str = "1086940";
+pub const FLATPAK_STEAM_DIR: &str = ".var/app/com.valvesoftware.Steam/.local/share/Steam";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LarianDirSource {
+    Native,
+    Proton,
+    Custom,
+}
+
+impl LarianDirSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            LarianDirSource::Native => "native",
+            LarianDirSource::Proton => "Proton",
+            LarianDirSource::Custom => "custom",
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct GamePaths {
@@ -29,7 +51,7 @@ pub fn detect_paths(
 
     let larian_dir = match larian_dir_override {
         Some(path) => path.to_path_buf(),
-        None => find_larian_dir().context("locate BG3 Larian data directory")?,
+        None => find_larian_dir(Some(&game_root)).context("locate BG3 Larian data directory")?,
     };
 
     let data_dir = game_root.join("Data");
@@ -61,12 +83,13 @@ pub fn detect_paths(
     })
 }
 
-fn find_game_root() -> Option<PathBuf> {
+fn steam_libraries() -> Vec<PathBuf> {
     let mut candidates = Vec::new();
 
     if let Some(home) = dirs_home() {
         candidates.push(home.join(".local/share/Steam"));
         candidates.push(home.join(".steam/steam"));
+        candidates.push(home.join(FLATPAK_STEAM_DIR));
     }
 
     let mut libraries = Vec::new();
@@ -79,8 +102,11 @@ fn find_game_root() -> Option<PathBuf> {
         }
         libraries.push(base);
     }
+    libraries
+}
 
-    for lib in libraries {
+fn find_game_root() -> Option<PathBuf> {
+    for lib in steam_libraries() {
         for folder in ["Baldurs Gate 3", "Baldur's Gate 3"] {
             let candidate = lib.join("steamapps/common").join(folder);
             if candidate.exists() {
@@ -92,23 +118,60 @@ fn find_game_root() -> Option<PathBuf> {
     None
 }
 
-fn find_larian_dir() -> Option<PathBuf> {
-    let home = dirs_home()?;
-    let native = home.join(".local/share/Larian Studios").join(GAME_NAME);
-    if native.exists() {
-        return Some(native);
-    }
+fn native_larian_dir() -> Option<PathBuf> {
+    Some(
+        dirs_home()?
+            .join(".local/share/Larian Studios")
+            .join(GAME_NAME),
+    )
+}
 
-    let proton = home
-        .join(".local/share/Steam/steamapps/compatdata")
+fn proton_larian_dir(steam_library: &Path) -> PathBuf {
+    steam_library
+        .join("steamapps/compatdata")
         .join(STEAM_APP_ID)
         .join("pfx/drive_c/users/steamuser/AppData/Local/Larian Studios")
-        .join(GAME_NAME);
-    if proton.exists() {
-        return Some(proton);
+        .join(GAME_NAME)
+}
+
+fn steam_library_of(game_root: &Path) -> Option<&Path> {
+    let common = game_root.parent()?;
+    let steamapps = common.parent()?;
+    if common.file_name()? != "common" || steamapps.file_name()? != "steamapps" {
+        return None;
     }
+    steamapps.parent()
+}
 
-    None
+fn find_larian_dir(game_root: Option<&Path>) -> Option<PathBuf> {
+    let native = native_larian_dir();
+    if let Some(native) = native.as_ref().filter(|path| looks_like_larian_dir(path)) {
+        return Some(native.clone());
+    }
+
+    // Proton keeps the Larian dir in the compatdata prefix of the library the game lives in.
+    let mut proton = Vec::new();
+    if let Some(library) = game_root.and_then(steam_library_of) {
+        proton.push(proton_larian_dir(library));
+    }
+    proton.extend(steam_libraries().iter().map(|lib| proton_larian_dir(lib)));
+    if let Some(path) = proton.iter().find(|path| looks_like_larian_dir(path)) {
+        return Some(path.clone());
+    }
+
+    native.into_iter().chain(proton).find(|path| path.exists())
+}
+
+pub fn classify_larian_dir(path: &Path) -> LarianDirSource {
+    if native_larian_dir().as_deref() == Some(path) {
+        return LarianDirSource::Native;
+    }
+    let has = |name: &str| path.components().any(|c| c.as_os_str() == name);
+    if has("compatdata") && has("pfx") {
+        LarianDirSource::Proton
+    } else {
+        LarianDirSource::Custom
+    }
 }
 
 fn parse_steam_library_paths(path: &Path) -> Result<Vec<PathBuf>> {
@@ -139,6 +202,133 @@ pub fn looks_like_game_root(path: &Path) -> bool {
     path.join("Data").is_dir() && path.join("bin").is_dir()
 }
 
+pub fn detect_game_build(game_root: &Path) -> Option<String> {
+    let manifest = game_root
+        .parent()
+        .map(|common| common.with_file_name(format!("appmanifest_{STEAM_APP_ID}.acf")));
+    if let Some(build) = manifest
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|raw| parse_steam_build_id(&raw))
+    {
+        return Some(format!("steam build {build}"));
+    }
+    if let Some(version) = detect_game_version(game_root) {
+        return Some(format!("v{}", metadata::format_version64(version)));
+    }
+    ["bg3.exe", "bg3_dx11.exe"].iter().find_map(|exe| {
+        let modified = fs::metadata(game_root.join("bin").join(exe))
+            .and_then(|meta| meta.modified())
+            .ok()?;
+        let secs = modified
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()?
+            .as_secs();
+        Some(format!("{exe} mtime {secs}"))
+    })
+}
+
+fn parse_steam_build_id(raw: &str) -> Option<String> {
+    raw.lines().find_map(|line| {
+        let parts: Vec<&str> = line.trim().split('"').collect();
+        (parts.len() >= 4 && parts[1] == "buildid").then(|| parts[3].to_string())
+    })
+}
+
+pub fn detect_game_version(game_root: &Path) -> Option<u64> {
+    let version_txt = game_root.join("Data").join("Version.txt");
+    if let Some(version) = fs::read_to_string(version_txt)
+        .ok()
+        .and_then(|raw| raw.lines().next().and_then(metadata::parse_version_value))
+    {
+        return Some(version);
+    }
+    ["bg3.exe", "bg3_dx11.exe"]
+        .iter()
+        .find_map(|exe| read_pe_file_version(&game_root.join("bin").join(exe)))
+}
+
+/// Newest mtime of the files `detect_game_version` reads, so callers can skip
+/// re-parsing the executable when nothing changed.
+pub fn game_version_stamp(game_root: &Path) -> Option<u64> {
+    [
+        game_root.join("Data").join("Version.txt"),
+        game_root.join("bin").join("bg3.exe"),
+        game_root.join("bin").join("bg3_dx11.exe"),
+    ]
+    .iter()
+    .filter_map(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+    .filter_map(|modified| modified.duration_since(std::time::UNIX_EPOCH).ok())
+    .map(|age| age.as_secs())
+    .max()
+}
+
+fn read_pe_file_version(path: &Path) -> Option<u64> {
+    const MAX_RSRC_BYTES: u32 = 64 * 1024 * 1024;
+    const FIXED_FILE_INFO_SIGNATURE: [u8; 4] = [0xBD, 0x04, 0xEF, 0xFE];
+
+    let mut file = fs::File::open(path).ok()?;
+    let mut dos_header = [0u8; 64];
+    file.read_exact(&mut dos_header).ok()?;
+    if &dos_header[0..2] != b"MZ" {
+        return None;
+    }
+    let pe_offset = u32::from_le_bytes(dos_header[0x3C..0x40].try_into().ok()?);
+    file.seek(SeekFrom::Start(pe_offset as u64)).ok()?;
+    let mut coff_header = [0u8; 24];
+    file.read_exact(&mut coff_header).ok()?;
+    if &coff_header[0..4] != b"PE\0\0" {
+        return None;
+    }
+    let section_count = u16::from_le_bytes([coff_header[6], coff_header[7]]) as usize;
+    let optional_size = u16::from_le_bytes([coff_header[20], coff_header[21]]);
+    file.seek(SeekFrom::Current(optional_size as i64)).ok()?;
+    let mut sections = vec![0u8; section_count * 40];
+    file.read_exact(&mut sections).ok()?;
+    let rsrc = sections
+        .chunks_exact(40)
+        .find(|section| section.starts_with(b".rsrc"))?;
+    let size = u32::from_le_bytes(rsrc[16..20].try_into().ok()?).min(MAX_RSRC_BYTES);
+    let offset = u32::from_le_bytes(rsrc[20..24].try_into().ok()?);
+    file.seek(SeekFrom::Start(offset as u64)).ok()?;
+    let mut data = vec![0u8; size as usize];
+    file.read_exact(&mut data).ok()?;
+
+    let start = data
+        .windows(4)
+        .position(|window| window == FIXED_FILE_INFO_SIGNATURE)?;
+    let fixed = data.get(start..start + 16)?;
+    let file_ms = u32::from_le_bytes(fixed[8..12].try_into().ok()?);
+    let file_ls = u32::from_le_bytes(fixed[12..16].try_into().ok()?);
+    Some(metadata::encode_version64(
+        (file_ms >> 16) as u64,
+        (file_ms & 0xFFFF) as u64,
+        (file_ls >> 16) as u64,
+        (file_ls & 0xFFFF) as u64,
+    ))
+}
+
+#[derive(Debug, Clone)]
+pub struct ScriptExtenderInfo {
+    pub loader: PathBuf,
+    pub version: Option<u64>,
+}
+
+pub fn detect_script_extender(game_root: &Path) -> Option<ScriptExtenderInfo> {
+    let loader = fs::read_dir(game_root.join("bin"))
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.eq_ignore_ascii_case("dwrite.dll"))
+        })?;
+    Some(ScriptExtenderInfo {
+        version: read_pe_file_version(&loader),
+        loader,
+    })
+}
+
 pub fn looks_like_larian_dir(path: &Path) -> bool {
     path.join("PlayerProfiles").is_dir()
 }
@@ -1,13 +1,19 @@
 use crate::{
-    app::{App, CliImportOptions, CliVerbosity, DependencyLookup, StartupMode},
+    app::{
+        App, CliDuplicatePolicy, CliExitCode, CliImportOptions, CliVerbosity, DependencyLookup,
+        StartupMode,
+    },
     bg3::GamePaths,
-    game,
+    config, game, importer,
     library::{library_mod_root, InstallTarget, Library, ModEntry, Profile},
     metadata, native_pak, ui,
 };
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use serde::Serialize;
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum OutputFormat {
@@ -50,8 +56,23 @@ enum CliCommand {
     DepsMissing,
     DepsResolved,
     DepsDebug(String),
+    ScanMeta,
+    Rank {
+        apply: bool,
+    },
     Debug(DebugCommand),
     Paths,
+    SupportBundle {
+        path: PathBuf,
+        strip_mod_names: bool,
+    },
+    StageDeploy {
+        path: PathBuf,
+        keep: Vec<String>,
+    },
+    Deploy {
+        keep: Vec<String>,
+    },
     Help,
     Version,
 }
@@ -84,9 +105,16 @@ enum ModSortKey {
     Kind,
 }
 
+pub fn exit_code(err: &anyhow::Error) -> u8 {
+    err.downcast_ref::<CliExitCode>()
+        .map(|code| *code as u8)
+        .unwrap_or(CliExitCode::Failure as u8)
+}
+
 pub fn run() -> Result<()> {
     let args: Vec<String> = std::env::args().skip(1).collect();
-    let action = parse_args(&args)?;
+    let args = take_data_dir_arg(args)?;
+    let action = parse_args(&args).context(CliExitCode::Usage)?;
     match action {
         CliAction::Ui => {
             let mut app = App::initialize(StartupMode::Ui)?;
@@ -117,25 +145,53 @@ pub fn run() -> Result<()> {
     }
 }
 
+fn take_data_dir_arg(args: Vec<String>) -> Result<Vec<String>> {
+    let mut data_dir = None;
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        if let Some(value) = arg.strip_prefix("--data-dir=") {
+            data_dir = Some(value.to_string());
+            continue;
+        }
+        if arg == "--data-dir" {
+            let Some(value) = iter.next() else {
+                bail!("--data-dir requires a path");
+            };
+            data_dir = Some(value);
+            continue;
+        }
+        rest.push(arg);
+    }
+    if let Some(data_dir) = data_dir {
+        config::set_data_dir_override(PathBuf::from(data_dir))?;
+    } else if let Some(data_dir) = std::env::var_os(config::DATA_DIR_ENV).filter(|v| !v.is_empty())
+    {
+        // Pin the env value now so a relative path keeps pointing at the launch dir.
+        config::set_data_dir_override(PathBuf::from(data_dir))?;
+    }
+    Ok(rest)
+}
+
 fn parse_args(args: &[String]) -> Result<CliAction> {
     if args.is_empty() {
         return Ok(CliAction::Ui);
     }
 
-    if matches!(
-        args.first().map(|s| s.as_str()),
-        Some("--help" | "-h" | "help")
-    ) {
+    let flags = args.iter().take_while(|arg| arg.as_str() != "--");
+    let flags: Vec<&str> = flags.map(|arg| arg.as_str()).collect();
+    if matches!(flags.first(), Some(&"help")) || flags.contains(&"--help") || flags.contains(&"-h")
+    {
         return Ok(CliAction::Command {
             command: CliCommand::Help,
             format: OutputFormat::Text,
             profile: None,
         });
     }
-    if matches!(
-        args.first().map(|s| s.as_str()),
-        Some("--version" | "-V" | "version")
-    ) {
+    if matches!(flags.first(), Some(&"version"))
+        || flags.contains(&"--version")
+        || flags.contains(&"-V")
+    {
         return Ok(CliAction::Command {
             command: CliCommand::Version,
             format: OutputFormat::Text,
@@ -148,15 +204,11 @@ fn parse_args(args: &[String]) -> Result<CliAction> {
         return Ok(action);
     }
 
-    if let Some(action) = parse_legacy_import(args) {
+    if let Some(action) = parse_legacy_import(args)? {
         return Ok(action);
     }
 
-    Ok(CliAction::Command {
-        command: CliCommand::Help,
-        format: OutputFormat::Text,
-        profile: None,
-    })
+    bail!("Unrecognized arguments: {} (see --help)", args.join(" "))
 }
 
 fn parse_global_options(args: &[String]) -> (GlobalOptions, Vec<String>) {
@@ -179,6 +231,10 @@ fn parse_global_options(args: &[String]) -> (GlobalOptions, Vec<String>) {
             }
             continue;
         }
+        if arg == "--json" {
+            format = OutputFormat::Json;
+            continue;
+        }
         if let Some(value) = arg.strip_prefix("--profile=") {
             profile = Some(value.to_string());
             continue;
@@ -281,15 +337,98 @@ fn parse_subcommand(tokens: &[String], global: &GlobalOptions) -> Result<Option<
                 profile: global.profile.clone(),
             }))
         }
+        "--scan-meta" | "scan-meta" => Ok(Some(CliAction::Command {
+            command: CliCommand::ScanMeta,
+            format: global.format,
+            profile: global.profile.clone(),
+        })),
+        "--rank" | "rank" => {
+            let mut apply = false;
+            for arg in tokens.get(1..).unwrap_or(&[]) {
+                match arg.as_str() {
+                    "--apply" => apply = true,
+                    value => bail!("Unexpected rank argument: {value}"),
+                }
+            }
+            Ok(Some(CliAction::Command {
+                command: CliCommand::Rank { apply },
+                format: global.format,
+                profile: global.profile.clone(),
+            }))
+        }
         "paths" => Ok(Some(CliAction::Command {
             command: CliCommand::Paths,
             format: global.format,
             profile: global.profile.clone(),
         })),
+        "--support-bundle" | "support-bundle" => {
+            let mut path = None;
+            let mut strip_mod_names = false;
+            for arg in tokens.get(1..).unwrap_or(&[]) {
+                match arg.as_str() {
+                    "--strip-mod-names" => strip_mod_names = true,
+                    value if path.is_none() => path = Some(PathBuf::from(value)),
+                    value => bail!("Unexpected support bundle argument: {value}"),
+                }
+            }
+            let path = path.ok_or_else(|| anyhow::anyhow!("--support-bundle requires a path"))?;
+            Ok(Some(CliAction::Command {
+                command: CliCommand::SupportBundle {
+                    path,
+                    strip_mod_names,
+                },
+                format: global.format,
+                profile: global.profile.clone(),
+            }))
+        }
+        "--deploy" | "deploy"
+            if !tokens
+                .iter()
+                .any(|token| matches!(token.as_str(), "--import" | "-i" | "--import-url")) =>
+        {
+            let mut keep = Vec::new();
+            let mut args = tokens.get(1..).unwrap_or(&[]).iter();
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--keep" => keep.push(parse_keep_value(args.next())?),
+                    value => bail!("Unexpected deploy argument: {value}"),
+                }
+            }
+            Ok(Some(CliAction::Command {
+                command: CliCommand::Deploy { keep },
+                format: global.format,
+                profile: global.profile.clone(),
+            }))
+        }
+        "--stage-deploy" | "stage-deploy" => {
+            let mut path = None;
+            let mut keep = Vec::new();
+            let mut args = tokens.get(1..).unwrap_or(&[]).iter();
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--keep" => keep.push(parse_keep_value(args.next())?),
+                    value if path.is_none() => path = Some(PathBuf::from(value)),
+                    value => bail!("Unexpected stage deploy argument: {value}"),
+                }
+            }
+            let path = path.ok_or_else(|| anyhow::anyhow!("--stage-deploy requires a folder"))?;
+            Ok(Some(CliAction::Command {
+                command: CliCommand::StageDeploy { path, keep },
+                format: global.format,
+                profile: global.profile.clone(),
+            }))
+        }
         _ => Ok(None),
     }
 }
 
+fn parse_keep_value(value: Option<&String>) -> Result<String> {
+    value
+        .filter(|value| !value.starts_with("--"))
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("--keep requires a mod id or name"))
+}
+
 fn parse_mods_list(args: &[String]) -> Result<ModsListOptions> {
     let mut sort = ModSortKey::Order;
     let mut reverse = false;
@@ -342,10 +481,13 @@ fn parse_sort_key(value: &str) -> Result<ModSortKey> {
     }
 }
 
-fn parse_legacy_import(args: &[String]) -> Option<CliAction> {
+fn parse_legacy_import(args: &[String]) -> Result<Option<CliAction>> {
     let mut import_paths = Vec::new();
     let mut deploy = None;
     let mut verbosity = CliVerbosity::Normal;
+    let mut json = false;
+    let mut on_duplicate = None;
+    let mut non_interactive = false;
     let mut stop_parsing = false;
     let mut iter = args.iter().peekable();
 
@@ -372,8 +514,39 @@ fn parse_legacy_import(args: &[String]) -> Option<CliAction> {
                     eprintln!("--import requires one or more paths");
                 }
             }
+            "--import-url" => {
+                let mut pushed = false;
+                while let Some(next) = iter.peek() {
+                    if !importer::is_url(next) {
+                        break;
+                    }
+                    if let Some(url) = iter.next() {
+                        import_paths.push(url.to_string());
+                        pushed = true;
+                    }
+                }
+                if !pushed {
+                    eprintln!("--import-url requires one or more http(s) URLs");
+                }
+            }
             "--deploy" => deploy = Some(true),
             "--no-deploy" => deploy = Some(false),
+            "--json" | "--format=json" => json = true,
+            "--non-interactive" => non_interactive = true,
+            "--on-duplicate" => {
+                let Some(value) = iter.next() else {
+                    bail!("--on-duplicate requires skip | overwrite | newer | prompt");
+                };
+                let Some(policy) = CliDuplicatePolicy::parse(value) else {
+                    bail!("Unknown duplicate action: {value} (skip | overwrite | newer | prompt)");
+                };
+                on_duplicate = Some(policy);
+            }
+            "--format" => {
+                if let Some(value) = iter.next() {
+                    json = matches!(OutputFormat::parse(value), Some(OutputFormat::Json));
+                }
+            }
             "-q" | "--quiet" => verbosity = CliVerbosity::Quiet,
             "--verbose" => verbosity = CliVerbosity::Verbose,
             "--verbosity" => {
@@ -405,16 +578,26 @@ fn parse_legacy_import(args: &[String]) -> Option<CliAction> {
     }
 
     if import_paths.is_empty() {
-        return None;
+        return Ok(None);
     }
+    let on_duplicate = match on_duplicate {
+        Some(CliDuplicatePolicy::Prompt) if non_interactive => {
+            bail!("--non-interactive cannot be combined with --on-duplicate prompt");
+        }
+        Some(policy) => policy,
+        None if non_interactive => CliDuplicatePolicy::Skip,
+        None => CliDuplicatePolicy::Prompt,
+    };
 
-    Some(CliAction::Import {
+    Ok(Some(CliAction::Import {
         paths: import_paths,
         options: CliImportOptions {
             deploy: deploy.unwrap_or(false),
             verbosity,
+            json,
+            on_duplicate,
         },
-    })
+    }))
 }
 
 fn run_command(
@@ -442,6 +625,8 @@ fn run_command(
             list_resolved_dependencies(app, profile, format)
         }
         CliCommand::DepsDebug(query) => debug_dependencies(app, &query),
+        CliCommand::ScanMeta => scan_metadata(app, format),
+        CliCommand::Rank { apply } => rank_profile(app, profile.as_deref(), apply, format),
         CliCommand::Debug(command) => match command {
             DebugCommand::SmartRank => debug_smart_rank(app),
             DebugCommand::SmartRankWarmup => debug_smart_rank_warmup(app),
@@ -455,6 +640,18 @@ fn run_command(
             DebugCommand::SmartRankZipFlow => debug_smart_rank_zip_flow(app),
         },
         CliCommand::Paths => list_paths(app, format),
+        CliCommand::SupportBundle {
+            path,
+            strip_mod_names,
+        } => {
+            let path = app.create_support_bundle(strip_mod_names, Some(path))?;
+            println!("Support bundle written: {}", path.display());
+            Ok(())
+        }
+        CliCommand::StageDeploy { path, keep } => {
+            stage_deploy(app, profile.as_deref(), path, &keep, format)
+        }
+        CliCommand::Deploy { keep } => deploy_profile(app, profile.as_deref(), &keep, format),
         CliCommand::Help | CliCommand::Version => Ok(()),
     }
 }
@@ -556,6 +753,228 @@ fn list_mods(
     Ok(())
 }
 
+#[derive(Serialize)]
+struct RankOutput {
+    profile: String,
+    total: usize,
+    applied: bool,
+    moves: Vec<RankMoveItem>,
+    warnings: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct RankMoveItem {
+    name: String,
+    from: usize,
+    to: usize,
+}
+
+fn deploy_profile(
+    app: &mut App,
+    profile: Option<&str>,
+    keep: &[String],
+    format: OutputFormat,
+) -> Result<()> {
+    let active = app.library.active_profile.clone();
+    let report = app.deploy_profile_cli(profile, keep)?;
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        OutputFormat::Text => {
+            println!(
+                "Deployed \"{}\": {} pak, {} loose ({} files)",
+                report.profile, report.pak_count, report.loose_count, report.file_count
+            );
+            for warning in &report.warnings {
+                eprintln!("Deploy warning: {warning}");
+            }
+        }
+    }
+    if report.profile != active {
+        eprintln!(
+            "Warning: deployed \"{}\" while \"{active}\" stays active; the next deploy from the UI restores \"{active}\"",
+            report.profile
+        );
+    }
+    Ok(())
+}
+
+fn stage_deploy(
+    app: &mut App,
+    profile: Option<&str>,
+    path: PathBuf,
+    keep: &[String],
+    format: OutputFormat,
+) -> Result<()> {
+    let report = app.stage_deploy_cli(profile, path, keep)?;
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        OutputFormat::Text => {
+            let root = report
+                .staging_root
+                .as_ref()
+                .map(|root| root.display().to_string())
+                .unwrap_or_default();
+            println!(
+                "Staged \"{}\" into {root}: {} pak, {} loose ({} files)",
+                report.profile, report.pak_count, report.loose_count, report.file_count
+            );
+            for warning in &report.warnings {
+                println!("Warning: {warning}");
+            }
+        }
+    }
+    Ok(())
+}
+
+fn rank_profile(
+    app: &mut App,
+    profile: Option<&str>,
+    apply: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let report = app.rank_profile_cli(profile, apply)?;
+    let output = RankOutput {
+        profile: report.profile,
+        total: report.total,
+        applied: report.applied,
+        moves: report
+            .moves
+            .into_iter()
+            .map(|item| RankMoveItem {
+                name: item.name,
+                from: item.from + 1,
+                to: item.to + 1,
+            })
+            .collect(),
+        warnings: report.warnings,
+    };
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+        OutputFormat::Text => {
+            println!(
+                "SigiLink ranking for \"{}\": {}/{} mod(s) would move",
+                output.profile,
+                output.moves.len(),
+                output.total
+            );
+            for item in &output.moves {
+                println!("{:>4} -> {:<4} {}", item.from, item.to, item.name);
+            }
+            for warning in &output.warnings {
+                println!("Warning: {warning}");
+            }
+            if output.applied {
+                println!("Applied and saved.");
+            } else if !output.moves.is_empty() {
+                println!("Dry run: pass --apply to save this order.");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct ScanMetaItem {
+    id: String,
+    name: String,
+    author: Option<String>,
+    version: Option<String>,
+    version64: Option<u64>,
+    targets: Vec<ScanMetaTarget>,
+    dependencies: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ScanMetaTarget {
+    kind: String,
+    path: String,
+    uuid: Option<String>,
+}
+
+fn scan_metadata(app: &mut App, format: OutputFormat) -> Result<()> {
+    app.scan_metadata_inline()?;
+
+    let items: Vec<ScanMetaItem> = app
+        .library
+        .mods
+        .iter()
+        .map(|mod_entry| {
+            let version64 = mod_entry.targets.iter().find_map(|target| match target {
+                InstallTarget::Pak { info, .. } if info.version > 0 => Some(info.version),
+                _ => None,
+            });
+            let targets = mod_entry
+                .targets
+                .iter()
+                .map(|target| match target {
+                    InstallTarget::Pak { file, info, .. } => ScanMetaTarget {
+                        kind: "pak".to_string(),
+                        path: file.clone(),
+                        uuid: Some(info.uuid.clone()),
+                    },
+                    InstallTarget::Generated { dir } => ScanMetaTarget {
+                        kind: "generated".to_string(),
+                        path: dir.clone(),
+                        uuid: None,
+                    },
+                    InstallTarget::Data { dir } => ScanMetaTarget {
+                        kind: "data".to_string(),
+                        path: dir.clone(),
+                        uuid: None,
+                    },
+                    InstallTarget::Bin { dir } => ScanMetaTarget {
+                        kind: "bin".to_string(),
+                        path: dir.clone(),
+                        uuid: None,
+                    },
+                })
+                .collect();
+            ScanMetaItem {
+                id: mod_entry.id.clone(),
+                name: mod_entry.display_name(),
+                author: mod_entry.author.clone(),
+                version: mod_entry
+                    .version
+                    .clone()
+                    .or_else(|| version64.map(metadata::format_version64)),
+                version64,
+                targets,
+                dependencies: mod_entry.dependencies.clone(),
+            }
+        })
+        .collect();
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&items)?);
+        }
+        OutputFormat::Text => {
+            for item in items {
+                match item.version {
+                    Some(version) => println!("{} ({}) v{version}", item.name, item.id),
+                    None => println!("{} ({})", item.name, item.id),
+                }
+                for target in item.targets {
+                    println!("  [{}] {}", target.kind, target.path);
+                }
+                for dep in item.dependencies {
+                    println!("  -> {dep}");
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[derive(Serialize)]
 struct ProfileListItem {
     name: String,
@@ -1165,7 +1584,7 @@ fn print_help() {
     println!("SigilSmith v{}", env!("CARGO_PKG_VERSION"));
     println!("Usage:");
     println!("  sigilsmith                     Launch TUI");
-    println!("  sigilsmith mods list            List mods");
+    println!("  sigilsmith mods list [options]  List mods");
     println!("  sigilsmith profiles list        List profiles");
     println!("  sigilsmith deps list            List dependencies for installed mods");
     println!("  sigilsmith deps missing         List missing dependencies");
@@ -1183,22 +1602,66 @@ fn print_help() {
     println!("  sigilsmith debug restart-check  Validate cache hit on restart (debug builds)");
     println!("  sigilsmith debug warmup-flow    Run warmup + edits flow (debug builds)");
     println!("  sigilsmith debug zip-flow       Import real zips in temp dir (debug builds)");
+    println!(
+        "  sigilsmith --scan-meta          Re-scan mod metadata and list versions/targets/deps"
+    );
+    println!("  sigilsmith --rank [--apply]     Preview SigiLink ranking moves (apply to save)");
     println!("  sigilsmith paths                Show detected paths");
+    println!("  sigilsmith --support-bundle <path> [--strip-mod-names]");
+    println!("                                  Write a redacted support bundle zip");
+    println!("  sigilsmith --deploy [--profile <name>] [--keep <mod>...]");
+    println!(
+        "                                  Deploy a profile (default: active) without switching"
+    );
+    println!("  sigilsmith --stage-deploy <dir> Deploy the profile into <dir> instead of the game");
+    println!(
+        "  --keep <mod id or name>         Keep this mod when enabled paks share a UUID (repeatable)"
+    );
     println!("  sigilsmith --import <paths...>  Import mods without the TUI");
+    println!("  sigilsmith --import-url <urls...>  Download and import mods without the TUI");
+    println!();
+    println!("Mods list options:");
+    println!("  --sort <key>                    order | name | created | added | kind");
+    println!("  -r, --reverse                   Reverse the sort order");
+    println!("  --filter <text>                 Only list mods whose name contains <text>");
     println!();
     println!("Global options:");
     println!("  --format <json|text>            Output format for list commands");
-    println!("  --profile <name>                Profile name for list commands");
+    println!("  --json                          Alias for --format json");
+    println!("  --profile <name>                Profile name for list, rank and deploy commands");
+    println!("  --data-dir <path>               Keep all state under <path> (portable mode)");
+    println!(
+        "                                  Also set via {}",
+        config::DATA_DIR_ENV
+    );
     println!("  -h, --help                      Show help");
     println!("  -V, --version                   Show version");
     println!();
     println!("Import options:");
     println!("  --deploy                         Deploy after import");
     println!("  --no-deploy                      Skip deploy after import (default)");
+    println!(
+        "  --non-interactive                Never prompt; skip duplicates unless --on-duplicate"
+    );
+    println!(
+        "  --on-duplicate <action>          skip | overwrite | newer | prompt (default prompt)"
+    );
     println!("  -q, --quiet                      Errors only");
     println!("  -v, -vv, -vvv                    Increase verbosity");
     println!("  --verbosity <level>              quiet | normal | verbose | debug");
     println!("  --verbose                        Alias for --verbosity verbose");
+    println!(
+        "  --json                           Print a JSON summary on stdout (progress on stderr)"
+    );
+    println!();
+    println!("Exit codes:");
+    println!("  0                               Success");
+    println!("  1                               Unexpected error");
+    println!("  2                               Invalid arguments");
+    println!("  3                               Game paths not ready (--deploy)");
+    println!("  4                               One or more imports failed");
+    println!("  5                               Deploy failed (--deploy, --stage-deploy)");
+    println!("  6                               Pak UUID collision; rerun with --keep");
 }
 
 fn format_date_cell(value: Option<i64>) -> String {
@@ -1258,3 +1721,66 @@ fn prefers_ymd(locale: &str) -> bool {
         || locale.contains("TW")
         || locale.contains("HU")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn duplicate_policy(args: &[&str]) -> Result<CliDuplicatePolicy> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        match parse_legacy_import(&args)? {
+            Some(CliAction::Import { options, .. }) => Ok(options.on_duplicate),
+            _ => bail!("not an import"),
+        }
+    }
+
+    #[test]
+    fn non_interactive_never_prompts_in_any_flag_order() {
+        assert_eq!(
+            duplicate_policy(&["--import", "a.zip"]).unwrap(),
+            CliDuplicatePolicy::Prompt
+        );
+        assert_eq!(
+            duplicate_policy(&["--non-interactive", "--import", "a.zip"]).unwrap(),
+            CliDuplicatePolicy::Skip
+        );
+        assert_eq!(
+            duplicate_policy(&[
+                "--on-duplicate",
+                "overwrite",
+                "--non-interactive",
+                "-i",
+                "a.zip"
+            ])
+            .unwrap(),
+            CliDuplicatePolicy::Overwrite
+        );
+        for args in [
+            [
+                "--non-interactive",
+                "--on-duplicate",
+                "prompt",
+                "-i",
+                "a.zip",
+            ],
+            ["--on-duplicate", "ask", "--non-interactive", "-i", "a.zip"],
+        ] {
+            assert!(duplicate_policy(&args).is_err());
+        }
+    }
+
+    #[test]
+    fn unknown_duplicate_action_is_rejected() {
+        assert!(duplicate_policy(&["--on-duplicate", "skp", "-i", "a.zip"]).is_err());
+        assert!(duplicate_policy(&["-i", "a.zip", "--on-duplicate"]).is_err());
+        assert_eq!(
+            duplicate_policy(&["-y", "-i", "a.zip"]).unwrap(),
+            CliDuplicatePolicy::Prompt
+        );
+        let args: Vec<String> = ["--on-duplicate", "skp", "-i", "a.zip"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        assert!(parse_args(&args).is_err());
+    }
+}
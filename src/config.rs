@@ -1,8 +1,28 @@
-use crate::game::{self, GameId};
+use crate::{
+    bg3::{self, LarianDirSource},
+    game::{self, GameId},
+    smart_rank::SigilLinkWeights,
+};
 use anyhow::{Context, Result};
 use directories::{BaseDirs, UserDirs};
-use serde::{Deserialize, Serialize};
-use std::{fs, path::PathBuf};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::{fs, path::PathBuf, sync::OnceLock};
+
+pub const DATA_DIR_ENV: &str = "SIGILSMITH_DATA_DIR";
+const SETTINGS_EXPORT_VERSION: u64 = 1;
+const APP_MACHINE_KEYS: &[&str] = &["downloads_dir", "last_browser_dir"];
+const GAME_MACHINE_KEYS: &[&str] = &["game_root", "larian_dir", "sigillink_cache_dir"];
+const GAME_LOCAL_KEYS: &[&str] = &[
+    "game_id",
+    "game_name",
+    "data_dir",
+    "game_version",
+    "game_version_stamp",
+    "game_update_acknowledged",
+];
+
+static DATA_DIR_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
@@ -13,10 +33,22 @@ pub struct AppConfig {
     pub confirm_mod_delete: bool,
     #[serde(default = "default_true")]
     pub auto_deploy_enabled: bool,
+    #[serde(default = "default_auto_deploy_debounce_ms")]
+    pub auto_deploy_debounce_ms: u64,
+    #[serde(default = "default_smart_rank_debounce_ms")]
+    pub smart_rank_debounce_ms: u64,
     #[serde(default = "default_true")]
     pub enable_mods_after_import: bool,
     #[serde(default = "default_false")]
+    pub enable_import_dependencies: bool,
+    #[serde(default = "default_false")]
     pub delete_mod_files_on_remove: bool,
+    #[serde(default = "default_true")]
+    pub quarantine_removed_mods: bool,
+    #[serde(default = "default_trash_retention_days")]
+    pub trash_retention_days: u64,
+    #[serde(default)]
+    pub evict_disabled_after_days: u64,
     #[serde(default = "default_downloads_dir")]
     pub downloads_dir: PathBuf,
     #[serde(default)]
@@ -38,9 +70,25 @@ pub struct AppConfig {
     #[serde(default = "default_false")]
     pub sigillink_pin_notice_dismissed: bool,
     #[serde(default)]
+    pub sigillink_weights: SigilLinkWeights,
+    #[serde(default)]
     pub last_whats_new_version: Option<String>,
     #[serde(default)]
     pub default_sort_column: Option<String>,
+    #[serde(default = "default_recent_added_hours")]
+    pub recent_added_hours: u64,
+    #[serde(default = "default_false")]
+    pub sort_profiles_alphabetically: bool,
+    #[serde(default = "default_false")]
+    pub show_mod_size_column: bool,
+    #[serde(default = "default_false")]
+    pub background_conflict_scan: bool,
+    #[serde(default = "default_background_conflict_scan_secs")]
+    pub background_conflict_scan_secs: u64,
+    #[serde(default = "default_log_max_bytes")]
+    pub log_max_bytes: u64,
+    #[serde(default = "default_true")]
+    pub setup_completed: bool,
 }
 
 impl AppConfig {
@@ -63,8 +111,14 @@ impl AppConfig {
             confirm_profile_delete: true,
             confirm_mod_delete: true,
             auto_deploy_enabled: true,
+            auto_deploy_debounce_ms: default_auto_deploy_debounce_ms(),
+            smart_rank_debounce_ms: default_smart_rank_debounce_ms(),
             enable_mods_after_import: true,
+            enable_import_dependencies: false,
             delete_mod_files_on_remove: false,
+            quarantine_removed_mods: true,
+            trash_retention_days: default_trash_retention_days(),
+            evict_disabled_after_days: 0,
             downloads_dir: default_downloads_dir(),
             last_browser_dir: None,
             offer_dependency_downloads: true,
@@ -75,8 +129,16 @@ impl AppConfig {
             sigillink_ranking_enabled: false,
             sigillink_auto_preview: true,
             sigillink_pin_notice_dismissed: false,
+            sigillink_weights: SigilLinkWeights::default(),
             last_whats_new_version: None,
             default_sort_column: None,
+            recent_added_hours: default_recent_added_hours(),
+            sort_profiles_alphabetically: false,
+            show_mod_size_column: false,
+            background_conflict_scan: false,
+            background_conflict_scan_secs: default_background_conflict_scan_secs(),
+            log_max_bytes: default_log_max_bytes(),
+            setup_completed: false,
         };
         config.save()?;
         Ok(config)
@@ -103,6 +165,22 @@ pub struct GameConfig {
     pub game_root: PathBuf,
     pub larian_dir: PathBuf,
     pub active_profile: String,
+    #[serde(default)]
+    pub default_profile: Option<String>,
+    #[serde(default)]
+    pub native_sync_ignore: Vec<String>,
+    #[serde(default)]
+    pub conflict_ignore: Vec<String>,
+    #[serde(default)]
+    pub conflict_ignore_identical: bool,
+    #[serde(default)]
+    pub game_version: Option<u64>,
+    #[serde(default)]
+    pub game_version_stamp: Option<u64>,
+    #[serde(default)]
+    pub game_update_acknowledged: Option<String>,
+    #[serde(default)]
+    pub larian_dir_source: Option<LarianDirSource>,
 }
 
 impl GameConfig {
@@ -126,7 +204,7 @@ impl GameConfig {
             Err(_) => (PathBuf::new(), PathBuf::new()),
         };
 
-        let config = GameConfig {
+        let mut config = GameConfig {
             game_id: game,
             game_name: game.display_name().to_string(),
             data_dir,
@@ -134,12 +212,39 @@ impl GameConfig {
             game_root,
             larian_dir,
             active_profile: "Default".to_string(),
+            default_profile: None,
+            native_sync_ignore: Vec::new(),
+            conflict_ignore: Vec::new(),
+            conflict_ignore_identical: false,
+            game_version: None,
+            game_version_stamp: None,
+            game_update_acknowledged: None,
+            larian_dir_source: None,
         };
+        config.set_larian_dir(config.larian_dir.clone());
 
         config.save()?;
         Ok(config)
     }
 
+    pub fn set_larian_dir(&mut self, path: PathBuf) {
+        self.larian_dir_source =
+            (!path.as_os_str().is_empty()).then(|| bg3::classify_larian_dir(&path));
+        self.larian_dir = path;
+    }
+
+    /// Re-reads the installed game version only when the version files changed.
+    pub fn refresh_game_version(&mut self) {
+        let stamp = game::game_version_stamp(self.game_id, &self.game_root);
+        if stamp.is_some() && stamp == self.game_version_stamp && self.game_version.is_some() {
+            return;
+        }
+        if let Some(version) = game::detect_game_version(self.game_id, &self.game_root) {
+            self.game_version = Some(version);
+            self.game_version_stamp = stamp;
+        }
+    }
+
     pub fn save(&self) -> Result<()> {
         let config_path = self.data_dir.join("config.json");
         let raw = serde_json::to_string_pretty(self).context("serialize config")?;
@@ -157,7 +262,6 @@ impl GameConfig {
         self.sigillink_cache_root().join("mods")
     }
 
-    #[allow(dead_code)]
     pub fn sigillink_index_root(&self) -> PathBuf {
         self.sigillink_cache_root().join("sigillink")
     }
@@ -165,6 +269,47 @@ impl GameConfig {
     pub fn sigillink_temp_root(&self) -> PathBuf {
         self.sigillink_cache_root().join("tmp")
     }
+
+    pub fn native_sync_ignored(&self, candidates: &[&str]) -> bool {
+        self.native_sync_ignore.iter().any(|pattern| {
+            let pattern = pattern.trim();
+            !pattern.is_empty()
+                && candidates
+                    .iter()
+                    .any(|candidate| glob_match(pattern, candidate))
+        })
+    }
+
+    pub fn conflict_rule_for(&self, relative_path: &str) -> Option<&str> {
+        let relative_path = relative_path.replace('\\', "/");
+        self.conflict_ignore
+            .iter()
+            .map(|pattern| pattern.trim())
+            .find(|pattern| !pattern.is_empty() && glob_match(pattern, &relative_path))
+    }
+}
+
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let (mut p, mut t) = (0usize, 0usize);
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|ch| *ch == '*')
 }
 
 pub fn data_dir_for_game(game: GameId) -> Result<PathBuf> {
@@ -180,6 +325,30 @@ fn default_false() -> bool {
     false
 }
 
+fn default_recent_added_hours() -> u64 {
+    24
+}
+
+fn default_background_conflict_scan_secs() -> u64 {
+    30
+}
+
+fn default_auto_deploy_debounce_ms() -> u64 {
+    0
+}
+
+fn default_smart_rank_debounce_ms() -> u64 {
+    600
+}
+
+fn default_log_max_bytes() -> u64 {
+    5 * 1024 * 1024
+}
+
+fn default_trash_retention_days() -> u64 {
+    14
+}
+
 fn default_downloads_dir() -> PathBuf {
     if let Some(user_dirs) = UserDirs::new() {
         if let Some(path) = user_dirs.download_dir() {
@@ -191,7 +360,127 @@ fn default_downloads_dir() -> PathBuf {
         .unwrap_or_else(|| PathBuf::from("/"))
 }
 
+pub fn export_settings(
+    app_config: &AppConfig,
+    games: &[&GameConfig],
+    include_paths: bool,
+) -> Result<Value> {
+    let mut app = config_object(app_config)?;
+    let mut game_map = Map::new();
+    for game in games {
+        let mut object = config_object(*game)?;
+        for key in GAME_LOCAL_KEYS {
+            object.remove(*key);
+        }
+        if !include_paths {
+            for key in GAME_MACHINE_KEYS {
+                object.remove(*key);
+            }
+        }
+        game_map.insert(game.game_id.as_str().to_string(), Value::Object(object));
+    }
+    if !include_paths {
+        for key in APP_MACHINE_KEYS {
+            app.remove(*key);
+        }
+    }
+    Ok(serde_json::json!({
+        "sigilsmith_settings": SETTINGS_EXPORT_VERSION,
+        "app": app,
+        "games": game_map,
+    }))
+}
+
+pub fn import_settings(raw: &str, app_config: &mut AppConfig, game: &mut GameConfig) -> Result<()> {
+    let value: Value = serde_json::from_str(raw).context("parse settings file")?;
+    let version = value
+        .get("sigilsmith_settings")
+        .and_then(Value::as_u64)
+        .context("not a SigilSmith settings file")?;
+    if version > SETTINGS_EXPORT_VERSION {
+        anyhow::bail!("settings file version {version} is newer than this SigilSmith");
+    }
+    if let Some(app) = value.get("app").and_then(Value::as_object) {
+        *app_config = overlay_config(app_config, app, &[])?;
+    }
+    if let Some(incoming) = value
+        .get("games")
+        .and_then(|games| games.get(game.game_id.as_str()))
+        .and_then(Value::as_object)
+    {
+        *game = overlay_config(game, incoming, GAME_LOCAL_KEYS)?;
+    }
+    Ok(())
+}
+
+fn config_object<T: Serialize>(config: &T) -> Result<Map<String, Value>> {
+    match serde_json::to_value(config).context("serialize config")? {
+        Value::Object(object) => Ok(object),
+        _ => anyhow::bail!("config is not an object"),
+    }
+}
+
+fn overlay_config<T: Serialize + DeserializeOwned>(
+    current: &T,
+    incoming: &Map<String, Value>,
+    skip: &[&str],
+) -> Result<T> {
+    let mut object = config_object(current)?;
+    for (key, value) in incoming {
+        if skip.contains(&key.as_str()) {
+            continue;
+        }
+        object.insert(key.clone(), value.clone());
+    }
+    serde_json::from_value(Value::Object(object)).context("apply settings")
+}
+
+fn absolute_data_dir(path: PathBuf) -> Result<PathBuf> {
+    if path.is_absolute() {
+        return Ok(path);
+    }
+    Ok(std::env::current_dir()
+        .context("resolve current dir")?
+        .join(path))
+}
+
+pub fn set_data_dir_override(path: PathBuf) -> Result<()> {
+    let path = absolute_data_dir(path)?;
+    DATA_DIR_OVERRIDE
+        .set(path)
+        .map_err(|_| anyhow::anyhow!("data dir override already set"))
+}
+
+pub fn data_dir_override() -> Option<PathBuf> {
+    if let Some(path) = DATA_DIR_OVERRIDE.get() {
+        return Some(path.clone());
+    }
+    std::env::var_os(DATA_DIR_ENV)
+        .filter(|value| !value.is_empty())
+        .and_then(|value| absolute_data_dir(PathBuf::from(value)).ok())
+}
+
 fn base_data_dir() -> Result<PathBuf> {
+    if let Some(path) = data_dir_override() {
+        return Ok(path);
+    }
     let base = BaseDirs::new().context("resolve home dir")?;
     Ok(base.data_local_dir().join("sigilsmith"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_data_dir_resolves_against_current_dir() {
+        let cwd = std::env::current_dir().expect("cwd");
+        let resolved = absolute_data_dir(PathBuf::from("portable-data")).expect("resolve");
+        assert_eq!(resolved, cwd.join("portable-data"));
+        let absolute = cwd.join("already-absolute");
+        assert_eq!(
+            absolute_data_dir(absolute.clone()).expect("resolve"),
+            absolute
+        );
+    }
+}
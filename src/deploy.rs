@@ -2,8 +2,8 @@ use crate::{
     backup,
     bg3::GamePaths,
     config::GameConfig,
-    game,
-    library::{FileOverride, InstallTarget, Library, ModEntry, PakInfo, TargetKind},
+    game, importer,
+    library::{FileOverride, InstallTarget, Library, ModEntry, PakInfo, Profile, TargetKind},
     metadata, sigillink,
 };
 use anyhow::{Context, Result};
@@ -17,23 +17,39 @@ use std::{
     collections::{HashMap, HashSet, VecDeque},
     fs, io,
     path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering as AtomicOrdering},
+        Arc, Mutex, OnceLock,
+    },
+    time::SystemTime,
 };
 use walkdir::WalkDir;
 
+#[derive(Debug, Serialize, Deserialize)]
 pub struct DeployReport {
+    pub profile: String,
+    pub deployed_at: i64,
     pub pak_count: usize,
     pub loose_count: usize,
     pub file_count: usize,
     pub removed_count: usize,
     pub overridden_files: usize,
     pub link_mode_summary: String,
+    pub loose_skipped: bool,
     pub warnings: Vec<String>,
+    #[serde(default)]
+    pub game_build: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub staging_root: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_hash: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct ConflictCandidate {
     pub mod_id: String,
     pub mod_name: String,
+    pub source: PathBuf,
 }
 
 #[derive(Debug, Clone)]
@@ -45,12 +61,17 @@ pub struct ConflictEntry {
     pub winner_name: String,
     pub default_winner_id: String,
     pub overridden: bool,
+    pub ignored: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct DeployOptions {
     pub backup: bool,
     pub reason: Option<String>,
+    pub skip_loose: bool,
+    pub warnings: Vec<String>,
+    pub cancel: Option<Arc<AtomicBool>>,
+    pub target_override: Option<PathBuf>,
 }
 
 impl Default for DeployOptions {
@@ -58,14 +79,85 @@ impl Default for DeployOptions {
         Self {
             backup: true,
             reason: None,
+            skip_loose: false,
+            warnings: Vec::new(),
+            cancel: None,
+            target_override: None,
         }
     }
 }
 
+impl DeployOptions {
+    fn cancelled(&self) -> bool {
+        deploy_cancel_requested(self.cancel.as_ref())
+    }
+}
+
+fn deploy_cancel_requested(cancel: Option<&Arc<AtomicBool>>) -> bool {
+    cancel.is_some_and(|flag| flag.load(AtomicOrdering::Relaxed))
+}
+
+#[derive(Debug, Clone)]
+pub struct UuidCollisionMember {
+    pub mod_id: String,
+    pub mod_name: String,
+    pub pak_file: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct UuidCollision {
+    pub uuid: String,
+    pub members: Vec<UuidCollisionMember>,
+}
+
+#[derive(Debug)]
+pub struct UuidCollisionError {
+    pub collisions: Vec<UuidCollision>,
+}
+
+impl std::fmt::Display for UuidCollisionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let groups: Vec<String> = self
+            .collisions
+            .iter()
+            .map(|collision| {
+                let names: Vec<&str> = collision
+                    .members
+                    .iter()
+                    .map(|member| member.mod_name.as_str())
+                    .collect();
+                format!("{} ({})", collision.uuid, names.join(", "))
+            })
+            .collect();
+        write!(
+            f,
+            "pak UUID collision among enabled mods: {}; keep one mod per UUID",
+            groups.join("; ")
+        )
+    }
+}
+
+impl std::error::Error for UuidCollisionError {}
+
+#[derive(Debug)]
+pub struct DeployCancelled;
+
+impl std::fmt::Display for DeployCancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "deploy cancelled; files linked so far are tracked, modsettings lists only the linked paks, and the next deploy replaces them"
+        )
+    }
+}
+
+impl std::error::Error for DeployCancelled {}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SigilLinkMode {
     Hardlink,
     Symlink,
+    Copy,
 }
 
 impl SigilLinkMode {
@@ -73,6 +165,7 @@ impl SigilLinkMode {
         match self {
             SigilLinkMode::Hardlink => "hardlink",
             SigilLinkMode::Symlink => "symlink",
+            SigilLinkMode::Copy => "copy",
         }
     }
 }
@@ -101,6 +194,7 @@ struct LinkModeCache {
     cache_dev: u64,
     modes: HashMap<PathBuf, SigilLinkMode>,
     used: HashSet<SigilLinkMode>,
+    copy_only: bool,
 }
 
 impl LinkModeCache {
@@ -111,10 +205,23 @@ impl LinkModeCache {
             cache_dev,
             modes: HashMap::new(),
             used: HashSet::new(),
+            copy_only: false,
+        })
+    }
+
+    // Staging dirs get edited by hand; links there would write straight into the cache.
+    fn copying(cache_root: &Path) -> Result<Self> {
+        Ok(Self {
+            copy_only: true,
+            ..Self::new(cache_root)?
         })
     }
 
     fn mode_for(&mut self, target_root: &Path) -> Result<SigilLinkMode> {
+        if self.copy_only {
+            self.used.insert(SigilLinkMode::Copy);
+            return Ok(SigilLinkMode::Copy);
+        }
         if let Some(mode) = self.modes.get(target_root) {
             self.used.insert(*mode);
             return Ok(*mode);
@@ -223,6 +330,9 @@ fn link_with_mode(
             fs::hard_link(source, dest)
                 .with_context(|| format!("hardlink {:?} -> {:?}", source, dest))?;
         }
+        SigilLinkMode::Copy => {
+            fs::copy(source, dest).with_context(|| format!("copy {:?} -> {:?}", source, dest))?;
+        }
         SigilLinkMode::Symlink => match create_symlink(source, dest) {
             Ok(()) => {}
             Err(err) => {
@@ -305,13 +415,25 @@ pub fn deploy_with_options(
     library: &mut Library,
     options: DeployOptions,
 ) -> Result<DeployReport> {
-    let paths = game::detect_paths(
-        config.game_id,
-        Some(&config.game_root),
-        Some(&config.larian_dir),
-    )?;
+    let staging_root = options.target_override.clone();
+    let paths = match &staging_root {
+        Some(root) => staging_paths(root)?,
+        None => game::detect_paths(
+            config.game_id,
+            Some(&config.game_root),
+            Some(&config.larian_dir),
+        )?,
+    };
+    let manifest_dir = staging_root
+        .clone()
+        .unwrap_or_else(|| config.data_dir.clone());
     let cache_root = config.sigillink_cache_root();
 
+    let collisions = find_uuid_collisions(library);
+    if !collisions.is_empty() {
+        return Err(UuidCollisionError { collisions }.into());
+    }
+
     let active_profile = library.active_profile().context("active profile not set")?;
     let mod_map = library.index_by_id();
     let file_overrides = active_profile.file_overrides.clone();
@@ -342,7 +464,7 @@ pub fn deploy_with_options(
                 continue;
             }
             match target {
-                InstallTarget::Pak { info, .. } => enabled_paks.push(info.clone()),
+                InstallTarget::Pak { info, .. } => enabled_paks.push(info.as_ref().clone()),
                 InstallTarget::Generated { .. }
                 | InstallTarget::Data { .. }
                 | InstallTarget::Bin { .. } => has_loose = true,
@@ -353,6 +475,18 @@ pub fn deploy_with_options(
         }
     }
 
+    // A disabled copy of an enabled pak's UUID (e.g. a resolved collision loser) stays out of
+    // the Mods dir, or the game could load it in place of the enabled one.
+    let enabled_ids: HashSet<&str> = ordered_mods.iter().map(|m| m.id.as_str()).collect();
+    let enabled_uuids: HashSet<String> = enabled_paks
+        .iter()
+        .map(|info| info.uuid.to_ascii_lowercase())
+        .collect();
+    let shadowed = |mod_entry: &ModEntry, info: &PakInfo| {
+        !enabled_ids.contains(mod_entry.id.as_str())
+            && enabled_uuids.contains(&info.uuid.to_ascii_lowercase())
+    };
+
     for mod_entry in &all_mods {
         for target in &mod_entry.targets {
             let kind = target.kind();
@@ -360,23 +494,50 @@ pub fn deploy_with_options(
                 continue;
             }
             if let InstallTarget::Pak { info, .. } = target {
-                installed_paks.push(info.clone());
+                if !shadowed(mod_entry, info) {
+                    installed_paks.push(info.as_ref().clone());
+                }
             }
         }
     }
 
-    if options.backup {
+    if options.backup && staging_root.is_none() {
         backup::create_backup(config, library, &paths, options.reason.as_deref())?;
     }
+    if options.cancelled() {
+        return Err(DeployCancelled.into());
+    }
 
-    let mut manifest = load_manifest(&config.data_dir)?;
-    let removed_count = remove_previous_deploy(&paths, &mut manifest)?;
-    let warnings = Vec::new();
-    let mut link_modes = LinkModeCache::new(&cache_root)?;
+    let mut manifest = load_manifest(&manifest_dir)?;
+    let removed_count = remove_previous_deploy(&paths, &mut manifest, !options.skip_loose)?;
+    let mut warnings = options.warnings.clone();
+    warnings.extend(game_version_warnings(config, library));
+    let mut link_modes = if staging_root.is_some() {
+        LinkModeCache::copying(&cache_root)?
+    } else {
+        LinkModeCache::new(&cache_root)?
+    };
 
     let mut pak_files = Vec::new();
+    let mut linked_uuids = HashSet::new();
     for mod_entry in &all_mods {
+        if options.cancelled() {
+            let linked: Vec<PakInfo> = enabled_paks
+                .iter()
+                .filter(|info| linked_uuids.contains(&info.uuid))
+                .cloned()
+                .collect();
+            update_modsettings(&paths, &installed_paks, &linked)?;
+            manifest.pak_files = pak_files;
+            save_manifest(&manifest_dir, &manifest)?;
+            return Err(DeployCancelled.into());
+        }
         if mod_entry.is_native() {
+            for target in &mod_entry.targets {
+                if let InstallTarget::Pak { info, .. } = target {
+                    linked_uuids.insert(info.uuid.clone());
+                }
+            }
             continue;
         }
         for target in &mod_entry.targets {
@@ -384,7 +545,10 @@ pub fn deploy_with_options(
             if !mod_entry.is_target_enabled(kind) {
                 continue;
             }
-            if let InstallTarget::Pak { file, info } = target {
+            if let InstallTarget::Pak { file, info, .. } = target {
+                if shadowed(mod_entry, info) {
+                    continue;
+                }
                 let source = library_mod_path(&cache_root, &mod_entry.id).join(file);
                 let dest = paths.larian_mods_dir.join(format!("{}.pak", info.folder));
                 fs::create_dir_all(&paths.larian_mods_dir).context("create mods dir")?;
@@ -392,37 +556,254 @@ pub fn deploy_with_options(
                 link_with_mode(&source, &dest, &paths.larian_mods_dir, mode)
                     .with_context(|| format!("deploy pak {:?}", source))?;
                 pak_files.push(dest.to_string_lossy().to_string());
+                for (index, part) in metadata::pak_part_paths(&source).iter().enumerate() {
+                    let part_dest =
+                        paths
+                            .larian_mods_dir
+                            .join(format!("{}_{}.pak", info.folder, index + 1));
+                    link_with_mode(part, &part_dest, &paths.larian_mods_dir, mode)
+                        .with_context(|| format!("deploy pak part {:?}", part))?;
+                    pak_files.push(part_dest.to_string_lossy().to_string());
+                }
+                linked_uuids.insert(info.uuid.clone());
             }
         }
     }
 
-    let overridden_files = deploy_loose_files(
-        &paths,
-        &loose_targets,
-        &cache_root,
-        &mut manifest,
-        &file_overrides,
-        &mut link_modes,
-    )?;
+    let overridden_files = if options.skip_loose {
+        0
+    } else {
+        match deploy_loose_files(
+            &paths,
+            &loose_targets,
+            &cache_root,
+            &mut manifest,
+            &file_overrides,
+            &mut link_modes,
+            options.cancel.as_ref(),
+        ) {
+            Ok(overridden_files) => overridden_files,
+            Err(err) if err.is::<DeployCancelled>() => {
+                update_modsettings(&paths, &installed_paks, &enabled_paks)?;
+                manifest.pak_files = pak_files;
+                save_manifest(&manifest_dir, &manifest)?;
+                return Err(err);
+            }
+            Err(err) => return Err(err),
+        }
+    };
     update_modsettings(&paths, &installed_paks, &enabled_paks)?;
 
     manifest.pak_files = pak_files;
-    save_manifest(&config.data_dir, &manifest)?;
+    save_manifest(&manifest_dir, &manifest)?;
 
     let file_count = manifest.files.len() + manifest.pak_files.len();
     let link_mode_summary = link_modes.summary();
-
-    Ok(DeployReport {
+    let deployed_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or_default();
+    let profile = library.active_profile.clone();
+    let state_hash = if staging_root.is_some() {
+        None
+    } else {
+        carried_state_hash(library, &config.data_dir, options.skip_loose)
+    };
+    let report = DeployReport {
+        profile,
+        deployed_at,
         pak_count: installed_paks.len(),
         loose_count: loose_targets.len(),
         file_count,
         removed_count,
         overridden_files,
         link_mode_summary,
+        loose_skipped: options.skip_loose,
         warnings,
+        game_build: game::detect_game_build(config.game_id, &config.game_root),
+        staging_root,
+        state_hash,
+    };
+    if report.staging_root.is_none() {
+        let _ = save_last_report(&config.data_dir, &report);
+    }
+    Ok(report)
+}
+
+fn staging_paths(root: &Path) -> Result<GamePaths> {
+    let game_root = root.join("Game");
+    let data_dir = game_root.join("Data");
+    let larian_dir = root.join("Larian");
+    let larian_mods_dir = larian_dir.join("Mods");
+    let profiles_dir = larian_dir.join("PlayerProfiles");
+    let modsettings_path = profiles_dir.join("Public").join("modsettings.lsx");
+    for dir in [
+        &data_dir,
+        &game_root.join("bin"),
+        &larian_mods_dir,
+        &profiles_dir.join("Public"),
+    ] {
+        fs::create_dir_all(dir).with_context(|| format!("create staging dir {}", dir.display()))?;
+    }
+    Ok(GamePaths {
+        game_root,
+        data_dir,
+        larian_dir,
+        larian_mods_dir,
+        modsettings_path,
+        profiles_dir,
     })
 }
 
+pub fn last_report_path(data_dir: &Path) -> PathBuf {
+    data_dir.join("last_deploy.json")
+}
+
+pub fn profile_state_hash(library: &Library) -> Option<String> {
+    let profile = library.active_profile()?;
+    let mods: HashMap<&str, &ModEntry> = library
+        .mods
+        .iter()
+        .map(|mod_entry| (mod_entry.id.as_str(), mod_entry))
+        .collect();
+    // Content only: the profile name is left out so renaming a profile, or switching to one
+    // with the same mods, does not read as a stale deploy.
+    let mut hasher = blake3::Hasher::new();
+    for entry in profile.order.iter().filter(|entry| entry.enabled) {
+        hasher.update(entry.id.as_bytes());
+        if let Some(mod_entry) = mods.get(entry.id.as_str()) {
+            let state = (
+                &mod_entry.targets,
+                &mod_entry.target_overrides,
+                mod_entry.modified_at,
+            );
+            if let Ok(raw) = serde_json::to_vec(&state) {
+                hasher.update(&raw);
+            }
+        }
+    }
+    if let Ok(raw) = serde_json::to_vec(&profile.file_overrides) {
+        hasher.update(&raw);
+    }
+    Some(hasher.finalize().to_hex().to_string())
+}
+
+/// Hash of the last full deploy. A load-order-only deploy leaves loose files as they were,
+/// so it keeps the previous report's hash instead of claiming or dropping one.
+fn carried_state_hash(library: &Library, data_dir: &Path, skip_loose: bool) -> Option<String> {
+    if skip_loose {
+        load_last_report(data_dir).and_then(|report| report.state_hash)
+    } else {
+        profile_state_hash(library)
+    }
+}
+
+pub fn load_last_report(data_dir: &Path) -> Option<DeployReport> {
+    let raw = fs::read_to_string(last_report_path(data_dir)).ok()?;
+    serde_json::from_str(&raw).ok()
+}
+
+fn save_last_report(data_dir: &Path, report: &DeployReport) -> Result<()> {
+    let raw = serde_json::to_string_pretty(report).context("serialize deploy report")?;
+    fs::write(last_report_path(data_dir), raw).context("write deploy report")
+}
+
+pub fn game_version_warnings(config: &GameConfig, library: &Library) -> Vec<String> {
+    let (Some(installed), Some(active_profile)) = (config.game_version, library.active_profile())
+    else {
+        return Vec::new();
+    };
+    let mod_map = library.index_by_id();
+    active_profile
+        .order
+        .iter()
+        .filter(|entry| entry.enabled)
+        .filter_map(|entry| mod_map.get(&entry.id))
+        .filter_map(|mod_entry| {
+            let required = mod_entry.min_game_version?;
+            if metadata::game_version_supports(installed, required) {
+                return None;
+            }
+            Some(format!(
+                "{} requires game version {} (installed {})",
+                mod_entry.display_name(),
+                metadata::format_version64(required),
+                metadata::format_version64(installed)
+            ))
+        })
+        .collect()
+}
+
+pub fn find_uuid_collisions(library: &Library) -> Vec<UuidCollision> {
+    let Some(active_profile) = library.active_profile() else {
+        return Vec::new();
+    };
+    let mod_map = library.index_by_id();
+    let mut groups: Vec<UuidCollision> = Vec::new();
+    let mut group_index: HashMap<String, usize> = HashMap::new();
+    for entry in &active_profile.order {
+        if !entry.enabled || entry.missing_label.is_some() {
+            continue;
+        }
+        let Some(mod_entry) = mod_map.get(&entry.id) else {
+            continue;
+        };
+        for target in &mod_entry.targets {
+            if !mod_entry.is_target_enabled(target.kind()) {
+                continue;
+            }
+            let InstallTarget::Pak { file, info, .. } = target else {
+                continue;
+            };
+            let key = info.uuid.to_ascii_lowercase();
+            let index = *group_index.entry(key).or_insert_with(|| {
+                groups.push(UuidCollision {
+                    uuid: info.uuid.clone(),
+                    members: Vec::new(),
+                });
+                groups.len() - 1
+            });
+            let group = &mut groups[index];
+            if group
+                .members
+                .iter()
+                .any(|member| member.mod_id == mod_entry.id)
+            {
+                continue;
+            }
+            group.members.push(UuidCollisionMember {
+                mod_id: mod_entry.id.clone(),
+                mod_name: mod_entry.display_name(),
+                pak_file: file.clone(),
+            });
+        }
+    }
+    groups.retain(|group| group.members.len() > 1);
+    groups
+}
+
+/// Keeps `winner_id` for one collision: the other members are disabled in `profile` and
+/// remember the winner so the mod list can explain why. Returns the disabled members.
+pub fn keep_uuid_collision_winner(
+    profile: &mut Profile,
+    collision: &UuidCollision,
+    winner_id: &str,
+) -> Vec<UuidCollisionMember> {
+    let losers: Vec<UuidCollisionMember> = collision
+        .members
+        .iter()
+        .filter(|member| member.mod_id != winner_id)
+        .cloned()
+        .collect();
+    for entry in &mut profile.order {
+        if losers.iter().any(|member| member.mod_id == entry.id) {
+            entry.enabled = false;
+            entry.collision_with = Some(winner_id.to_string());
+        }
+    }
+    losers
+}
+
 pub fn scan_conflicts(config: &GameConfig, library: &Library) -> Result<Vec<ConflictEntry>> {
     let paths = game::detect_paths(
         config.game_id,
@@ -441,15 +822,93 @@ pub fn scan_conflicts(config: &GameConfig, library: &Library) -> Result<Vec<Conf
         .collect();
 
     let file_overrides = active_profile.file_overrides.clone();
-    let (_plans, conflicts, _overridden_files) = build_loose_plan(
+    let (_plans, mut conflicts, _overridden_files) = build_loose_plan(
         &paths,
         &ordered_mods,
         &config.sigillink_cache_root(),
         &file_overrides,
     )?;
+    for conflict in &mut conflicts {
+        conflict.ignored = conflict_ignore_reason(config, conflict);
+    }
     Ok(conflicts)
 }
 
+pub fn conflict_ignore_reason(config: &GameConfig, conflict: &ConflictEntry) -> Option<String> {
+    let relative_path = conflict.relative_path.to_string_lossy();
+    if let Some(pattern) = config.conflict_rule_for(&relative_path) {
+        return Some(format!("rule: {pattern}"));
+    }
+    if config.conflict_ignore_identical && conflict_sources_identical(conflict) {
+        return Some("identical contents".to_string());
+    }
+    None
+}
+
+#[derive(Debug, Clone)]
+struct SourceHashCacheEntry {
+    size: u64,
+    modified: Option<SystemTime>,
+    hash: String,
+}
+
+static SOURCE_HASH_CACHE: OnceLock<Mutex<HashMap<PathBuf, SourceHashCacheEntry>>> = OnceLock::new();
+
+fn conflict_sources_identical(conflict: &ConflictEntry) -> bool {
+    let Some(signatures) = conflict
+        .candidates
+        .iter()
+        .map(|candidate| {
+            let meta = fs::metadata(&candidate.source).ok()?;
+            Some((candidate.source.as_path(), meta.len(), meta.modified().ok()))
+        })
+        .collect::<Option<Vec<_>>>()
+    else {
+        return false;
+    };
+    let Some(((_, first_len, _), rest)) = signatures.split_first() else {
+        return false;
+    };
+    if rest.iter().any(|(_, len, _)| len != first_len) {
+        return false;
+    }
+    let mut first_hash = None;
+    for (path, size, modified) in &signatures {
+        let Some(hash) = cached_source_hash(path, *size, *modified) else {
+            return false;
+        };
+        match &first_hash {
+            None => first_hash = Some(hash),
+            Some(first) if *first != hash => return false,
+            Some(_) => {}
+        }
+    }
+    true
+}
+
+fn cached_source_hash(path: &Path, size: u64, modified: Option<SystemTime>) -> Option<String> {
+    let cache = SOURCE_HASH_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Ok(guard) = cache.lock() {
+        if let Some(entry) = guard.get(path) {
+            if entry.size == size && entry.modified == modified && modified.is_some() {
+                return Some(entry.hash.clone());
+            }
+        }
+    }
+    let hash = importer::hash_file(path).ok()?;
+    if let Ok(mut guard) = cache.lock() {
+        guard.insert(
+            path.to_path_buf(),
+            SourceHashCacheEntry {
+                size,
+                modified,
+                hash: hash.clone(),
+            },
+        );
+    }
+    Some(hash)
+}
+
 pub fn read_modsettings_snapshot(path: &Path) -> Result<ModSettingsSnapshot> {
     let save = read_modsettings(path)?;
     let nodes: VecDeque<ModulesShortDescriptionNode> = save
@@ -567,12 +1026,23 @@ fn update_modsettings(
     write_modsettings(&paths.modsettings_path, &save)
 }
 
+/// Builds modsettings for the given paks on top of `modsettings_path`, or on a clean file
+/// with only the base module when `None` (used to repair a corrupted file).
 pub(crate) fn build_modsettings_export(
-    modsettings_path: &Path,
+    modsettings_path: Option<&Path>,
     installed_paks: &[PakInfo],
     enabled_paks: &[PakInfo],
 ) -> Result<Save> {
-    let save = read_modsettings(modsettings_path)?;
+    let save = match modsettings_path {
+        Some(path) => read_modsettings(path)?,
+        None => {
+            let mut save = default_modsettings();
+            save.get_or_insert_node_mut_by_id("Mods").children = vec![ModulesChildren {
+                node: VecDeque::from([base_module_node()]),
+            }];
+            save
+        }
+    };
     Ok(build_modsettings_save(save, installed_paks, enabled_paks))
 }
 
@@ -679,6 +1149,20 @@ fn module_short_desc_from_info(info: &PakInfo) -> ModulesShortDescriptionNode {
     }
 }
 
+fn base_module_node() -> ModulesShortDescriptionNode {
+    ModulesShortDescriptionNode {
+        id: "ModuleShortDesc".to_string(),
+        attribute: vec![
+            ModuleInfoAttribute::new("Folder", "GustavX", "LSString"),
+            ModuleInfoAttribute::new("MD5", "", "LSString"),
+            ModuleInfoAttribute::new("Name", "GustavX", "LSString"),
+            ModuleInfoAttribute::new("PublishHandle", "0", "uint64"),
+            ModuleInfoAttribute::new("UUID", "cb555efe-2d9e-131f-8195-a89329d218ea", "guid"),
+            ModuleInfoAttribute::new("Version64", "36028797018963968", "int64"),
+        ],
+    }
+}
+
 fn module_order_node(uuid: &str) -> ModulesShortDescriptionNode {
     ModulesShortDescriptionNode {
         id: "Module".to_string(),
@@ -708,7 +1192,7 @@ fn write_modsettings(path: &Path, save: &Save) -> Result<()> {
     Ok(())
 }
 
-fn modsettings_xml(save: &Save) -> Result<String> {
+pub(crate) fn modsettings_xml(save: &Save) -> Result<String> {
     let mut xml = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n".to_string();
     let mut ser = quick_xml::se::Serializer::new(&mut xml);
     ser.indent(' ', 4);
@@ -763,6 +1247,7 @@ fn deploy_loose_files(
     manifest: &mut DeployManifest,
     file_overrides: &[FileOverride],
     link_modes: &mut LinkModeCache,
+    cancel: Option<&Arc<AtomicBool>>,
 ) -> Result<usize> {
     let (plans, _conflicts, overridden_files) =
         build_loose_plan(paths, mods, cache_root, file_overrides)?;
@@ -770,6 +1255,10 @@ fn deploy_loose_files(
     let mut created = Vec::with_capacity(plans.len());
 
     for plan in plans {
+        if deploy_cancel_requested(cancel) {
+            manifest.files = deployed;
+            return Err(DeployCancelled.into());
+        }
         if let Some(parent) = plan.dest.parent() {
             fs::create_dir_all(parent).context("create dir")?;
         }
@@ -891,12 +1380,14 @@ fn build_loose_plan(
                     .map(|candidate| ConflictCandidate {
                         mod_id: candidate.mod_id.clone(),
                         mod_name: candidate.mod_name.clone(),
+                        source: candidate.source.clone(),
                     })
                     .collect(),
                 winner_id: winner.mod_id.clone(),
                 winner_name: winner.mod_name.clone(),
                 default_winner_id: default.mod_id.clone(),
                 overridden: overridden_flag,
+                ignored: None,
             });
         }
 
@@ -933,8 +1424,10 @@ fn collect_target_files(
     order: usize,
     map: &mut HashMap<PathBuf, Vec<LooseFileCandidate>>,
 ) -> Result<()> {
+    // Dev payloads are symlinks into the dev folder (including nested ones like
+    // Generated/Public), so those are followed; cached payloads never contain links.
     for entry in WalkDir::new(source_root)
-        .follow_links(false)
+        .follow_links(mod_entry.dev_path.is_some())
         .into_iter()
         .filter_entry(|entry| !is_ignored_deploy_path(entry.path()))
     {
@@ -1027,10 +1520,19 @@ fn is_ignored_deploy_path(path: &Path) -> bool {
     })
 }
 
-fn remove_previous_deploy(paths: &GamePaths, manifest: &mut DeployManifest) -> Result<usize> {
+fn remove_previous_deploy(
+    paths: &GamePaths,
+    manifest: &mut DeployManifest,
+    include_loose: bool,
+) -> Result<usize> {
     let mut removed = 0;
 
-    for file in &manifest.files {
+    let loose_files = if include_loose {
+        manifest.files.as_slice()
+    } else {
+        &[]
+    };
+    for file in loose_files {
         let path = PathBuf::from(&file.path);
         if !path.exists() {
             continue;
@@ -1079,3 +1581,201 @@ fn save_manifest(data_dir: &Path, manifest: &DeployManifest) -> Result<()> {
 fn library_mod_path(cache_root: &Path, id: &str) -> PathBuf {
     cache_root.join("mods").join(id)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn colliding_library() -> Library {
+        let pak = |file: &str| {
+            serde_json::json!({
+                "kind": "pak",
+                "file": file,
+                "info": {
+                    "uuid": "6f1e-shared",
+                    "name": file,
+                    "folder": file,
+                    "version": 0,
+                    "md5": null,
+                    "publish_handle": null,
+                    "author": null,
+                    "description": null,
+                    "module_type": null,
+                },
+            })
+        };
+        let entry = |id: &str| serde_json::json!({ "id": id, "enabled": true });
+        serde_json::from_value(serde_json::json!({
+            "mods": [
+                { "id": "a", "name": "A", "added_at": 0, "targets": [pak("A.pak")] },
+                { "id": "b", "name": "B", "added_at": 0, "targets": [pak("B.pak")] },
+            ],
+            "profiles": [{ "name": "Default", "order": [entry("a"), entry("b")] }],
+            "active_profile": "Default",
+        }))
+        .unwrap()
+    }
+
+    fn staging_config(root: &Path) -> GameConfig {
+        serde_json::from_value(serde_json::json!({
+            "game_name": "Baldur's Gate 3",
+            "data_dir": root.join("data"),
+            "game_root": root.join("game"),
+            "larian_dir": root.join("larian"),
+            "active_profile": "Default",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn uuid_collision_blocks_deploy_until_resolved() {
+        let root = std::env::temp_dir().join(format!("sigilsmith-uuid-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let config = staging_config(&root);
+        for (id, file) in [("a", "A.pak"), ("b", "B.pak")] {
+            let dir = library_mod_path(&config.sigillink_cache_root(), id);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join(file), id.as_bytes()).unwrap();
+        }
+        let mut library = colliding_library();
+        let stage = root.join("stage");
+        let options = || DeployOptions {
+            backup: false,
+            target_override: Some(stage.clone()),
+            ..DeployOptions::default()
+        };
+
+        let err = deploy_with_options(&config, &mut library, options()).unwrap_err();
+        assert!(err.downcast_ref::<UuidCollisionError>().is_some());
+
+        let collisions = find_uuid_collisions(&library);
+        assert_eq!(collisions.len(), 1);
+        let profile = library.active_profile_mut().unwrap();
+        let losers = keep_uuid_collision_winner(profile, &collisions[0], "a");
+        assert_eq!(losers.len(), 1);
+        assert_eq!(losers[0].mod_id, "b");
+        let loser = &library.active_profile().unwrap().order[1];
+        assert!(!loser.enabled);
+        assert_eq!(loser.collision_with.as_deref(), Some("a"));
+        assert!(find_uuid_collisions(&library).is_empty());
+
+        let result = deploy_with_options(&config, &mut library, options());
+        let mods_dir = staging_paths(&stage).unwrap().larian_mods_dir;
+        let mut staged: Vec<(String, Vec<u8>)> = fs::read_dir(&mods_dir)
+            .unwrap()
+            .map(|entry| {
+                let path = entry.unwrap().path();
+                let name = path.file_name().unwrap().to_string_lossy().to_string();
+                (name, fs::read(&path).unwrap())
+            })
+            .collect();
+        staged.sort();
+        let _ = fs::remove_dir_all(&root);
+        let report = result.unwrap();
+        assert_eq!(report.pak_count, 1);
+        assert_eq!(staged, vec![("A.pak.pak".to_string(), b"a".to_vec())]);
+    }
+
+    #[test]
+    fn source_hash_cache_rehashes_changed_files() {
+        let root =
+            std::env::temp_dir().join(format!("sigilsmith-hash-cache-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let path = root.join("file.txt");
+        let signature = |path: &Path| {
+            let meta = fs::metadata(path).unwrap();
+            (meta.len(), meta.modified().ok())
+        };
+
+        fs::write(&path, b"first").unwrap();
+        let (size, modified) = signature(&path);
+        let first = cached_source_hash(&path, size, modified).unwrap();
+        assert_eq!(
+            cached_source_hash(&path, size, modified),
+            Some(first.clone())
+        );
+
+        fs::write(&path, b"second!").unwrap();
+        let (size, modified) = signature(&path);
+        let second = cached_source_hash(&path, size, modified).unwrap();
+        let _ = fs::remove_dir_all(&root);
+        assert_ne!(first, second);
+        assert_eq!(second, blake3::hash(b"second!").to_hex().to_string());
+    }
+
+    #[test]
+    fn modsettings_repair_ignores_the_existing_file() {
+        let root =
+            std::env::temp_dir().join(format!("sigilsmith-modsettings-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let path = root.join("modsettings.lsx");
+        fs::write(&path, "not xml").unwrap();
+        let pak = |uuid: &str| PakInfo {
+            uuid: uuid.to_string(),
+            name: uuid.to_string(),
+            folder: uuid.to_string(),
+            version: 1,
+            md5: None,
+            publish_handle: None,
+            author: None,
+            description: None,
+            module_type: None,
+        };
+        let enabled = vec![pak("second"), pak("first")];
+
+        assert!(build_modsettings_export(Some(&path), &enabled, &enabled).is_err());
+        let save = build_modsettings_export(None, &enabled, &enabled).unwrap();
+        write_modsettings_export(&path, &save).unwrap();
+        let snapshot = read_modsettings_snapshot(&path).unwrap();
+        let _ = fs::remove_dir_all(&root);
+
+        assert_eq!(snapshot.order, vec!["second", "first"]);
+        assert!(snapshot.enabled.contains("first"));
+    }
+
+    #[test]
+    fn load_order_deploy_keeps_the_last_full_state_hash() {
+        let root =
+            std::env::temp_dir().join(format!("sigilsmith-state-hash-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let library = colliding_library();
+        let full = carried_state_hash(&library, &root, false);
+        assert_eq!(full, profile_state_hash(&library));
+        assert_eq!(carried_state_hash(&library, &root, true), None);
+
+        let report: DeployReport = serde_json::from_value(serde_json::json!({
+            "profile": "Default",
+            "deployed_at": 1,
+            "pak_count": 0,
+            "loose_count": 0,
+            "file_count": 0,
+            "removed_count": 0,
+            "overridden_files": 0,
+            "link_mode_summary": "",
+            "loose_skipped": false,
+            "warnings": [],
+            "state_hash": "previous",
+        }))
+        .unwrap();
+        save_last_report(&root, &report).unwrap();
+        let carried = carried_state_hash(&library, &root, true);
+        let _ = fs::remove_dir_all(&root);
+        assert_eq!(carried.as_deref(), Some("previous"));
+    }
+
+    #[test]
+    fn state_hash_ignores_the_profile_name() {
+        let mut library = colliding_library();
+        let before = profile_state_hash(&library);
+        assert!(before.is_some());
+        library.profiles[0].name = "Renamed".to_string();
+        library.active_profile = "Renamed".to_string();
+        assert_eq!(profile_state_hash(&library), before);
+
+        library.profiles[0].order[1].enabled = false;
+        assert_ne!(profile_state_hash(&library), before);
+    }
+}
@@ -57,6 +57,30 @@ pub fn looks_like_game_root(game: GameId, path: &Path) -> bool {
     }
 }
 
+pub fn detect_game_build(game: GameId, game_root: &Path) -> Option<String> {
+    match game {
+        GameId::Bg3 => bg3::detect_game_build(game_root),
+    }
+}
+
+pub fn detect_game_version(game: GameId, game_root: &Path) -> Option<u64> {
+    match game {
+        GameId::Bg3 => bg3::detect_game_version(game_root),
+    }
+}
+
+pub fn game_version_stamp(game: GameId, game_root: &Path) -> Option<u64> {
+    match game {
+        GameId::Bg3 => bg3::game_version_stamp(game_root),
+    }
+}
+
+pub fn detect_script_extender(game: GameId, game_root: &Path) -> Option<bg3::ScriptExtenderInfo> {
+    match game {
+        GameId::Bg3 => bg3::detect_script_extender(game_root),
+    }
+}
+
 pub fn looks_like_user_dir(game: GameId, path: &Path) -> bool {
     match game {
         GameId::Bg3 => bg3::looks_like_larian_dir(path),
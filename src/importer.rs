@@ -4,6 +4,7 @@ use crate::library::{
 };
 use crate::metadata;
 use crate::sigillink::{SigilLinkEntry, SigilLinkIndex, SIGILLINK_VERSION};
+use crate::trash;
 use anyhow::{Context, Result};
 use blake3::Hasher;
 use filetime::{set_file_mtime, FileTime};
@@ -11,11 +12,13 @@ use larian_formats::lspk;
 use std::{
     collections::HashSet,
     fs, io,
+    io::{BufRead, BufReader, Read, Write},
     path::{Path, PathBuf},
     process::{Command, Stdio},
-    sync::atomic::{AtomicUsize, Ordering},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
     sync::Arc,
-    time::{Instant, SystemTime, UNIX_EPOCH},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 use time::{Date, Month, PrimitiveDateTime, Time as TimeOfDay};
 use walkdir::WalkDir;
@@ -38,6 +41,11 @@ struct DirImportResult {
 }
 
 const NESTED_ARCHIVE_SCAN_DEPTH: usize = 4;
+const EXTRACT_REPORT_INTERVAL_MS: u128 = 150;
+const EXTRACT_BUFFER_SIZE: usize = 256 * 1024;
+const DOWNLOAD_MAX_BYTES: u64 = 4 * 1024 * 1024 * 1024;
+const DOWNLOAD_TIMEOUT_SECS: u64 = 30 * 60;
+const DOWNLOAD_USER_AGENT: &str = concat!("SigilSmith/", env!("CARGO_PKG_VERSION"));
 
 #[derive(Clone, Copy)]
 enum CandidateKind {
@@ -58,6 +66,7 @@ struct ProgressReporter {
     unit_count: usize,
     stage_count: usize,
     callback: Option<ProgressCallback>,
+    cancel: Option<CancelFlag>,
 }
 
 impl ProgressReporter {
@@ -67,13 +76,30 @@ impl ProgressReporter {
         stage_current: usize,
         stage_total: usize,
         detail: Option<String>,
+    ) {
+        self.report_bytes(stage, stage_current, stage_total, 0, 0, detail);
+    }
+
+    fn report_bytes(
+        &self,
+        stage: ImportStage,
+        stage_current: usize,
+        stage_total: usize,
+        bytes_processed: u64,
+        bytes_total: u64,
+        detail: Option<String>,
     ) {
         let Some(callback) = &self.callback else {
             return;
         };
         let stage_total = stage_total.max(1);
         let stage_current = stage_current.min(stage_total);
-        let stage_fraction = (stage_current as f32) / (stage_total as f32);
+        let bytes_processed = bytes_processed.min(bytes_total);
+        let stage_fraction = if bytes_total > 0 {
+            (bytes_processed as f64 / bytes_total as f64) as f32
+        } else {
+            (stage_current as f32) / (stage_total as f32)
+        };
         let stage_index = stage.index() as f32;
         let stage_count = self.stage_count as f32;
         let overall_progress = (stage_index + stage_fraction) / stage_count;
@@ -84,10 +110,81 @@ impl ProgressReporter {
             stage,
             stage_current,
             stage_total,
+            bytes_processed,
+            bytes_total,
             overall_progress: overall_progress.clamp(0.0, 1.0),
             detail,
         });
     }
+
+    fn check_canceled(&self) -> Result<()> {
+        check_canceled(self.cancel.as_ref())
+    }
+}
+
+struct ExtractProgress<'a> {
+    reporter: &'a ProgressReporter,
+    files_done: usize,
+    files_total: usize,
+    bytes_done: u64,
+    bytes_total: u64,
+    last_report: Instant,
+}
+
+impl<'a> ExtractProgress<'a> {
+    fn new(reporter: &'a ProgressReporter) -> Self {
+        Self {
+            reporter,
+            files_done: 0,
+            files_total: 1,
+            bytes_done: 0,
+            bytes_total: 0,
+            last_report: Instant::now(),
+        }
+    }
+
+    fn set_totals(&mut self, files_total: usize, bytes_total: u64) {
+        self.files_total = files_total.max(1);
+        self.bytes_total = bytes_total;
+        self.report(true);
+    }
+
+    fn add_bytes(&mut self, count: u64) {
+        self.bytes_done = self.bytes_done.saturating_add(count);
+        self.report(false);
+    }
+
+    fn set_bytes(&mut self, bytes_done: u64) {
+        self.bytes_done = bytes_done;
+        self.report(false);
+    }
+
+    fn finish_entry(&mut self) -> Result<()> {
+        self.files_done = self.files_done.saturating_add(1);
+        self.report(false);
+        self.reporter.check_canceled()
+    }
+
+    fn finish(&mut self) {
+        self.files_done = self.files_total;
+        self.bytes_done = self.bytes_total;
+        self.report(true);
+    }
+
+    fn report(&mut self, force: bool) {
+        if !force && self.last_report.elapsed().as_millis() < EXTRACT_REPORT_INTERVAL_MS {
+            return;
+        }
+        self.reporter.report_bytes(
+            ImportStage::Extracting,
+            self.files_done,
+            self.files_total,
+            self.bytes_done,
+            self.bytes_total,
+            None,
+        );
+        self.last_report = Instant::now();
+    }
 }
 
 struct CopyProgress<'a> {
@@ -182,6 +279,14 @@ impl Drop for StagingGuard {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StageMode {
+    Copy,
+    Move,
+    /// Live-link the payload in place (dev folders); nothing is copied.
+    Link,
+}
+
 #[derive(Debug, Clone)]
 pub struct ImportSource {
     pub label: String,
@@ -204,6 +309,7 @@ pub struct ImportMod {
     pub entry: ModEntry,
     pub staging_root: Option<PathBuf>,
     pub sigillink: Option<SigilLinkIndex>,
+    pub warnings: Vec<String>,
 }
 
 impl ImportMod {
@@ -211,12 +317,17 @@ impl ImportMod {
         let Some(staging_root) = &self.staging_root else {
             return;
         };
+        // Skipped restores stay in the trash until retention expires.
+        if trash::is_quarantined(staging_root) {
+            return;
+        }
         let _ = fs::remove_dir_all(staging_root);
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ImportStage {
+    Downloading,
     Extracting,
     Indexing,
     Installing,
@@ -227,7 +338,7 @@ pub enum ImportStage {
 impl ImportStage {
     fn index(self) -> usize {
         match self {
-            ImportStage::Extracting => 0,
+            ImportStage::Downloading | ImportStage::Extracting => 0,
             ImportStage::Indexing => 1,
             ImportStage::Installing => 2,
             ImportStage::Linking => 3,
@@ -237,6 +348,7 @@ impl ImportStage {
 
     pub fn label(self) -> &'static str {
         match self {
+            ImportStage::Downloading => "Downloading",
             ImportStage::Extracting => "Extracting",
             ImportStage::Indexing => "Indexing",
             ImportStage::Installing => "Installing",
@@ -254,11 +366,61 @@ pub struct ImportProgress {
     pub stage: ImportStage,
     pub stage_current: usize,
     pub stage_total: usize,
+    pub bytes_processed: u64,
+    pub bytes_total: u64,
     pub overall_progress: f32,
     pub detail: Option<String>,
 }
 
 pub type ProgressCallback = Arc<dyn Fn(ImportProgress) + Send + Sync>;
+pub type CancelFlag = Arc<AtomicBool>;
+
+pub const IMPORT_CANCELED: &str = "import canceled";
+
+pub fn is_canceled(cancel: Option<&CancelFlag>) -> bool {
+    cancel.is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+}
+
+fn check_canceled(cancel: Option<&CancelFlag>) -> Result<()> {
+    if is_canceled(cancel) {
+        return Err(anyhow::anyhow!(IMPORT_CANCELED));
+    }
+    Ok(())
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+pub fn format_eta(eta: Duration) -> String {
+    let secs = eta.as_secs();
+    if secs >= 3600 {
+        format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+    } else if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{secs}s")
+    }
+}
+
+fn discard_batches(batches: &[ImportBatch]) {
+    for batch in batches {
+        for import_mod in &batch.mods {
+            import_mod.cleanup_staging();
+        }
+    }
+}
 
 fn source_times_for(path: &Path) -> SourceTimes {
     let (created_at, modified_at) = path_times(path);
@@ -341,6 +503,7 @@ pub fn import_path_with_progress(
     path: &Path,
     data_dir: &Path,
     progress: Option<ProgressCallback>,
+    cancel: Option<CancelFlag>,
 ) -> Result<ImportResult> {
     if !path.exists() {
         return Ok(ImportResult {
@@ -350,8 +513,8 @@ pub fn import_path_with_progress(
         });
     }
 
-    let result = if path.is_dir() {
-        import_batch_from_dir(path, data_dir, None, false, None, progress)?
+    let mut result = if path.is_dir() {
+        import_batch_from_dir(path, data_dir, None, false, None, progress, cancel)?
     } else {
         let source_label = source_label_for_archive(path);
         match path.extension().and_then(|ext| ext.to_str()).unwrap_or("") {
@@ -365,6 +528,7 @@ pub fn import_path_with_progress(
                     unit_count: 1,
                     stage_count: 5,
                     callback: progress.clone(),
+                    cancel: cancel.clone(),
                 };
                 let mods =
                     import_pak_file(path, data_dir, source_label.as_deref(), Some(&reporter))?;
@@ -377,9 +541,11 @@ pub fn import_path_with_progress(
                     failures: Vec::new(),
                 }
             }
-            "zip" | "ZIP" => import_archive_zip(path, data_dir, source_label.as_deref(), progress)?,
+            "zip" | "ZIP" => {
+                import_archive_zip(path, data_dir, source_label.as_deref(), progress, cancel)?
+            }
             "7z" | "7Z" | "rar" | "RAR" => {
-                import_archive_7z(path, data_dir, source_label.as_deref(), progress)?
+                import_archive_7z(path, data_dir, source_label.as_deref(), progress, cancel)?
             }
             _ => ImportResult {
                 batches: Vec::new(),
@@ -389,14 +555,348 @@ pub fn import_path_with_progress(
         }
     };
 
+    let source_path = fs::canonicalize(path)
+        .unwrap_or_else(|_| path.to_path_buf())
+        .to_string_lossy()
+        .to_string();
+    for import_mod in result
+        .batches
+        .iter_mut()
+        .flat_map(|batch| batch.mods.iter_mut())
+    {
+        import_mod.entry.source_path = Some(source_path.clone());
+    }
+
     Ok(result)
 }
 
+/// Imports a dev folder by linking its payload into the cache instead of copying it, so
+/// edits in the folder are what the next deploy picks up.
+pub fn import_dev_folder(
+    path: &Path,
+    data_dir: &Path,
+    progress: Option<ProgressCallback>,
+    cancel: Option<CancelFlag>,
+) -> Result<ImportResult> {
+    let source = fs::canonicalize(path).context("resolve dev folder")?;
+    let scan = scan_payload(&source)?;
+    let found = scan.pak_files.len() + usize::from(scan.has_loose_targets());
+    match found {
+        0 => return Err(anyhow::anyhow!("no mod found in dev folder")),
+        1 => {}
+        count => {
+            return Err(anyhow::anyhow!(
+                "{count} mods found in dev folder, expected one"
+            ))
+        }
+    }
+    let label = display_path_label(&source);
+    let reporter = ProgressReporter {
+        label: label.clone(),
+        unit_index: 0,
+        unit_count: 1,
+        stage_count: 5,
+        callback: progress,
+        cancel,
+    };
+    reporter.report(
+        ImportStage::Linking,
+        0,
+        1,
+        Some("Linking dev folder".to_string()),
+    );
+    let json_mods = scan
+        .info_json
+        .as_ref()
+        .map(|path| metadata::read_json_mods(path))
+        .unwrap_or_default();
+    let mut import_mod = if let Some(pak_path) = scan.pak_files.first() {
+        import_single_pak(
+            pak_path,
+            data_dir,
+            Some(&label),
+            None,
+            &json_mods,
+            None,
+            StageMode::Link,
+        )?
+    } else {
+        let meta_created = scan
+            .meta_file
+            .as_ref()
+            .and_then(|path| metadata::read_meta_lsx(path))
+            .and_then(|meta| meta.created_at);
+        let mut import_mod = import_loose(
+            &source,
+            data_dir,
+            &scan,
+            Some(&label),
+            StageMode::Link,
+            None,
+            meta_created,
+            0,
+            0,
+            None,
+        )?;
+        import_mod.warnings = scan
+            .meta_file
+            .as_ref()
+            .and_then(|path| fs::read(path).ok())
+            .map(|bytes| metadata::validate_meta_lsx(&bytes))
+            .unwrap_or_default();
+        import_mod
+    };
+    if let Some(info) = sidecar_for_entry(&import_mod.entry, &json_mods, true) {
+        apply_sidecar_info(&mut import_mod.entry, info);
+    }
+    let source_path = source.to_string_lossy().to_string();
+    import_mod.entry.dev_path = Some(source_path.clone());
+    import_mod.entry.source_path = Some(source_path);
+    reporter.report(ImportStage::Finalizing, 1, 1, None);
+    Ok(ImportResult {
+        batches: vec![ImportBatch {
+            source: ImportSource { label },
+            mods: vec![import_mod],
+        }],
+        unrecognized: false,
+        failures: Vec::new(),
+    })
+}
+
+#[cfg(unix)]
+fn link_dev_path(original: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(original, link)
+}
+
+#[cfg(not(unix))]
+fn link_dev_path(_original: &Path, _link: &Path) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Other,
+        "symlink unavailable on this platform",
+    ))
+}
+
+pub fn is_url(value: &str) -> bool {
+    let value = value.trim();
+    let lower = value.to_ascii_lowercase();
+    (lower.starts_with("https://") || lower.starts_with("http://"))
+        && !value.contains(char::is_whitespace)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadKind {
+    ModList,
+    ModSettings,
+    Archive,
+}
+
+#[derive(Debug, Clone)]
+pub struct DownloadedFile {
+    pub url: String,
+    pub path: PathBuf,
+    pub kind: DownloadKind,
+    dir: PathBuf,
+}
+
+impl DownloadedFile {
+    pub fn label(&self) -> String {
+        display_path_label(&self.path)
+    }
+
+    pub fn cleanup(&self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+pub fn download_url(
+    url: &str,
+    data_dir: &Path,
+    progress: Option<ProgressCallback>,
+    cancel: Option<CancelFlag>,
+) -> Result<DownloadedFile> {
+    let url = url.trim();
+    let name = download_file_name(url);
+    let dir = make_temp_dir(data_dir, "download")?;
+    let mut guard = StagingGuard::new(dir.clone());
+    let mut path = dir.join(&name);
+
+    let agent = ureq::AgentBuilder::new()
+        .timeout_connect(Duration::from_secs(10))
+        .timeout_read(Duration::from_secs(60))
+        .timeout(Duration::from_secs(DOWNLOAD_TIMEOUT_SECS))
+        .build();
+    let response = match agent.get(url).set("User-Agent", DOWNLOAD_USER_AGENT).call() {
+        Ok(response) => response,
+        Err(ureq::Error::Status(code, response)) => {
+            anyhow::bail!("HTTP {code} {}", response.status_text());
+        }
+        Err(ureq::Error::Transport(err)) => {
+            anyhow::bail!("download failed: {err}");
+        }
+    };
+    let bytes_total = response
+        .header("Content-Length")
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .unwrap_or(0);
+    if bytes_total > DOWNLOAD_MAX_BYTES {
+        anyhow::bail!(
+            "download too large ({} > {})",
+            format_bytes(bytes_total),
+            format_bytes(DOWNLOAD_MAX_BYTES)
+        );
+    }
+
+    let mut reader = response.into_reader();
+    let mut file = fs::File::create(&path).context("create download file")?;
+    let mut buffer = vec![0u8; EXTRACT_BUFFER_SIZE];
+    let mut bytes_processed = 0u64;
+    let mut last_report = Instant::now();
+    let report = |bytes_processed: u64| {
+        let Some(callback) = &progress else {
+            return;
+        };
+        let overall_progress = if bytes_total > 0 {
+            (bytes_processed as f64 / bytes_total as f64).min(1.0) as f32
+        } else {
+            0.0
+        };
+        callback(ImportProgress {
+            label: name.clone(),
+            unit_index: 1,
+            unit_count: 1,
+            stage: ImportStage::Downloading,
+            stage_current: 0,
+            stage_total: 1,
+            bytes_processed,
+            bytes_total,
+            overall_progress,
+            detail: Some(format!("Downloading {}", format_bytes(bytes_processed))),
+        });
+    };
+    report(0);
+    loop {
+        check_canceled(cancel.as_ref())?;
+        let read = reader.read(&mut buffer).context("read download")?;
+        if read == 0 {
+            break;
+        }
+        bytes_processed += read as u64;
+        if bytes_processed > DOWNLOAD_MAX_BYTES {
+            anyhow::bail!("download exceeds {}", format_bytes(DOWNLOAD_MAX_BYTES));
+        }
+        file.write_all(&buffer[..read])
+            .context("write download file")?;
+        if last_report.elapsed().as_millis() >= EXTRACT_REPORT_INTERVAL_MS {
+            report(bytes_processed);
+            last_report = Instant::now();
+        }
+    }
+    file.flush().context("flush download file")?;
+    drop(file);
+    report(bytes_processed);
+    if bytes_processed == 0 {
+        anyhow::bail!("download was empty");
+    }
+
+    let kind = sniff_download(&path)?;
+    if kind == DownloadKind::Archive && !has_import_extension(&path) {
+        let Some(ext) = archive_extension_from_magic(&path) else {
+            anyhow::bail!("unsupported download content");
+        };
+        let renamed = path.with_file_name(format!("{name}.{ext}"));
+        fs::rename(&path, &renamed).context("rename download file")?;
+        path = renamed;
+    }
+
+    guard.disarm();
+    Ok(DownloadedFile {
+        url: url.to_string(),
+        path,
+        kind,
+        dir,
+    })
+}
+
+fn download_file_name(url: &str) -> String {
+    let without_query = url.split(['?', '#']).next().unwrap_or(url);
+    let after_scheme = without_query
+        .split_once("://")
+        .map(|(_, rest)| rest)
+        .unwrap_or(without_query);
+    let segment = after_scheme
+        .split_once('/')
+        .and_then(|(_, path)| path.rsplit('/').find(|part| !part.is_empty()))
+        .unwrap_or("");
+    let name: String = segment
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.') {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let name = name.trim_matches('.').to_string();
+    if name.is_empty() {
+        "download".to_string()
+    } else {
+        name
+    }
+}
+
+fn sniff_download(path: &Path) -> Result<DownloadKind> {
+    let mut file = fs::File::open(path).context("open download file")?;
+    let mut head = [0u8; 512];
+    let read = file.read(&mut head).context("read download file")?;
+    let head = &head[..read];
+    let text = String::from_utf8_lossy(head.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(head));
+    let text = text.trim_start();
+    if text.starts_with('{') {
+        Ok(DownloadKind::ModList)
+    } else if text.starts_with('<') {
+        Ok(DownloadKind::ModSettings)
+    } else {
+        Ok(DownloadKind::Archive)
+    }
+}
+
+fn has_import_extension(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| {
+            matches!(
+                ext.to_ascii_lowercase().as_str(),
+                "pak" | "zip" | "7z" | "rar"
+            )
+        })
+        .unwrap_or(false)
+}
+
+fn archive_extension_from_magic(path: &Path) -> Option<&'static str> {
+    let mut file = fs::File::open(path).ok()?;
+    let mut magic = [0u8; 6];
+    let read = file.read(&mut magic).ok()?;
+    let magic = &magic[..read];
+    if magic.starts_with(b"PK\x03\x04") {
+        Some("zip")
+    } else if magic.starts_with(b"7z\xBC\xAF\x27\x1C") {
+        Some("7z")
+    } else if magic.starts_with(b"Rar!") {
+        Some("rar")
+    } else if magic.starts_with(b"LSPK") {
+        Some("pak")
+    } else {
+        None
+    }
+}
+
 fn import_archive_zip(
     path: &Path,
     data_dir: &Path,
     source_label: Option<&str>,
     progress: Option<ProgressCallback>,
+    cancel: Option<CancelFlag>,
 ) -> Result<ImportResult> {
     let temp_dir = make_temp_dir(data_dir, "zip")?;
     let source_times = source_times_for(path);
@@ -409,13 +909,15 @@ fn import_archive_zip(
         unit_count: 1,
         stage_count: 5,
         callback: progress.clone(),
+        cancel: cancel.clone(),
     };
-    reporter.report(ImportStage::Extracting, 0, 1, None);
-    if let Err(err) = extract_zip(path, &temp_dir) {
+    let mut extract_progress = ExtractProgress::new(&reporter);
+    extract_progress.report(true);
+    if let Err(err) = extract_zip(path, &temp_dir, &mut extract_progress) {
         let _ = fs::remove_dir_all(&temp_dir);
         return Err(err);
     }
-    reporter.report(ImportStage::Extracting, 1, 1, None);
+    extract_progress.finish();
     let result = import_batch_from_dir(
         &temp_dir,
         data_dir,
@@ -423,6 +925,7 @@ fn import_archive_zip(
         true,
         Some(source_times),
         progress,
+        cancel,
     );
     let _ = fs::remove_dir_all(&temp_dir);
     result
@@ -433,6 +936,7 @@ fn import_archive_7z(
     data_dir: &Path,
     source_label: Option<&str>,
     progress: Option<ProgressCallback>,
+    cancel: Option<CancelFlag>,
 ) -> Result<ImportResult> {
     let temp_dir = make_temp_dir(data_dir, "7z")?;
     let source_times = source_times_for(path);
@@ -445,13 +949,15 @@ fn import_archive_7z(
         unit_count: 1,
         stage_count: 5,
         callback: progress.clone(),
+        cancel: cancel.clone(),
     };
-    reporter.report(ImportStage::Extracting, 0, 1, None);
-    if let Err(err) = extract_7z(path, &temp_dir) {
+    let mut extract_progress = ExtractProgress::new(&reporter);
+    extract_progress.report(true);
+    if let Err(err) = extract_7z(path, &temp_dir, &mut extract_progress) {
         let _ = fs::remove_dir_all(&temp_dir);
         return Err(err);
     }
-    reporter.report(ImportStage::Extracting, 1, 1, None);
+    extract_progress.finish();
     let result = import_batch_from_dir(
         &temp_dir,
         data_dir,
@@ -459,6 +965,7 @@ fn import_archive_7z(
         true,
         Some(source_times),
         progress,
+        cancel,
     );
     let _ = fs::remove_dir_all(&temp_dir);
     result
@@ -471,6 +978,7 @@ fn import_batch_from_dir(
     allow_move: bool,
     source_times: Option<SourceTimes>,
     progress: Option<ProgressCallback>,
+    cancel: Option<CancelFlag>,
 ) -> Result<ImportResult> {
     let mut candidates = collect_import_candidates(path)?;
     if candidates.is_empty() {
@@ -488,6 +996,10 @@ fn import_batch_from_dir(
     let mut unrecognized = false;
 
     for (index, candidate) in candidates.into_iter().enumerate() {
+        if let Err(err) = check_canceled(cancel.as_ref()) {
+            discard_batches(&batches);
+            return Err(err);
+        }
         let candidate_label = candidate.label.clone();
         let display_label = match source_label {
             Some(root_label) if multi => format!("{root_label} -> {candidate_label}"),
@@ -505,6 +1017,7 @@ fn import_batch_from_dir(
             unit_count,
             stage_count: 5,
             callback: progress.clone(),
+            cancel: cancel.clone(),
         };
 
         match candidate.kind {
@@ -555,10 +1068,15 @@ fn import_batch_from_dir(
                         data_dir,
                         source_label,
                         progress.clone(),
+                        cancel.clone(),
+                    ),
+                    "7z" | "7Z" | "rar" | "RAR" => import_archive_7z(
+                        &candidate.path,
+                        data_dir,
+                        source_label,
+                        progress.clone(),
+                        cancel.clone(),
                     ),
-                    "7z" | "7Z" | "rar" | "RAR" => {
-                        import_archive_7z(&candidate.path, data_dir, source_label, progress.clone())
-                    }
                     _ => Ok(ImportResult {
                         batches: Vec::new(),
                         unrecognized: true,
@@ -580,6 +1098,10 @@ fn import_batch_from_dir(
                         failures.append(&mut result.failures);
                         batches.append(&mut result.batches);
                     }
+                    Err(err) if is_canceled(cancel.as_ref()) => {
+                        discard_batches(&batches);
+                        return Err(err);
+                    }
                     Err(err) => {
                         failures.push(ImportFailure {
                             source: ImportSource {
@@ -675,6 +1197,12 @@ fn import_from_dir(
         .as_ref()
         .and_then(|path| metadata::read_meta_lsx(path))
         .and_then(|meta| meta.created_at);
+    let meta_issues = scan
+        .meta_file
+        .as_ref()
+        .and_then(|path| fs::read(path).ok())
+        .map(|bytes| metadata::validate_meta_lsx(&bytes))
+        .unwrap_or_default();
     let json_mods = scan
         .info_json
         .as_ref()
@@ -697,8 +1225,21 @@ fn import_from_dir(
                 label.map(|label| format!("Importing {label}")),
             );
         }
-        match import_single_pak(pak_path, data_dir, label, source_times, &json_mods, None) {
-            Ok(entry) => mods.push(entry),
+        match import_single_pak(
+            pak_path,
+            data_dir,
+            label,
+            source_times,
+            &json_mods,
+            None,
+            StageMode::Copy,
+        ) {
+            Ok(mut entry) => {
+                if let Some(info) = sidecar_for_entry(&entry.entry, &json_mods, pak_total == 1) {
+                    apply_sidecar_info(&mut entry.entry, info);
+                }
+                mods.push(entry);
+            }
             Err(err) => {
                 last_error = Some(err.context(format!("import pak {:?}", pak_path)));
             }
@@ -711,14 +1252,24 @@ fn import_from_dir(
             data_dir,
             &scan,
             source_label,
-            allow_move,
+            if allow_move {
+                StageMode::Move
+            } else {
+                StageMode::Copy
+            },
             source_times,
             meta_created.or_else(|| json_mods.iter().filter_map(|info| info.created_at).min()),
             loose_file_count,
             pak_total,
             reporter,
         ) {
-            Ok(entry) => mods.push(entry),
+            Ok(mut entry) => {
+                if let Some(info) = sidecar_for_entry(&entry.entry, &json_mods, pak_total == 0) {
+                    apply_sidecar_info(&mut entry.entry, info);
+                }
+                entry.warnings = meta_issues;
+                mods.push(entry);
+            }
             Err(err) => {
                 last_error = Some(err.context("import loose files"));
             }
@@ -745,12 +1296,62 @@ fn import_from_dir(
     Ok(DirImportResult { mods, unrecognized })
 }
 
+fn sidecar_for_entry<'a>(
+    entry: &ModEntry,
+    json_mods: &'a [metadata::JsonModInfo],
+    single_mod: bool,
+) -> Option<&'a metadata::JsonModInfo> {
+    let pak_info = entry.targets.iter().find_map(|target| match target {
+        InstallTarget::Pak { info, .. } => Some(info),
+        _ => None,
+    });
+    if let Some(pak_info) = pak_info {
+        let matched = json_mods.iter().find(|info| {
+            info.uuid.as_deref() == Some(pak_info.uuid.as_str())
+                || info.folder.as_deref() == Some(pak_info.folder.as_str())
+        });
+        if matched.is_some() {
+            return matched;
+        }
+    }
+    if !single_mod {
+        return None;
+    }
+    match json_mods {
+        [info] if pak_info.is_none() => Some(info),
+        _ => json_mods.iter().find(|info| info.is_archive_level()),
+    }
+}
+
+// meta.lsx stays authoritative for UUID/folder; the sidecar wins for human-facing fields.
+// Import labels come from the archive or folder name, so a sidecar name replaces them; a
+// user `custom_name` is never touched and still renders on top.
+fn apply_sidecar_info(entry: &mut ModEntry, info: &metadata::JsonModInfo) {
+    if let Some(name) = info.name.as_deref().filter(|name| !name.trim().is_empty()) {
+        entry.source_label = Some(name.trim().to_string());
+    }
+    if info.author.is_some() {
+        entry.author = info.author.clone();
+    }
+    if info.version.is_some() {
+        entry.version = info.version.clone();
+    }
+    if info.website.is_some() {
+        entry.website = info.website.clone();
+    }
+    if info.nexus_id.is_some() {
+        entry.nexus_id = info.nexus_id;
+    }
+}
+
 fn import_pak_file(
     path: &Path,
     data_dir: &Path,
     source_label: Option<&str>,
     reporter: Option<&ProgressReporter>,
 ) -> Result<Vec<ImportMod>> {
+    let base = metadata::pak_part_base(path);
+    let path = base.as_deref().unwrap_or(path);
     if let Some(reporter) = reporter {
         reporter.report(
             ImportStage::Indexing,
@@ -765,7 +1366,15 @@ fn import_pak_file(
             Some(display_path_label(path)),
         );
     }
-    let mod_entry = import_single_pak(path, data_dir, source_label, None, &[], None)?;
+    let mod_entry = import_single_pak(
+        path,
+        data_dir,
+        source_label,
+        None,
+        &[],
+        None,
+        StageMode::Copy,
+    )?;
     if let Some(reporter) = reporter {
         reporter.report(
             ImportStage::Linking,
@@ -785,6 +1394,7 @@ fn import_single_pak(
     source_times: Option<SourceTimes>,
     json_mods: &[metadata::JsonModInfo],
     _reporter: Option<&ProgressReporter>,
+    mode: StageMode,
 ) -> Result<ImportMod> {
     let file = fs::File::open(path).context("open .pak")?;
     let lspk = lspk::Reader::new(file)
@@ -792,8 +1402,10 @@ fn import_single_pak(
         .and_then(|mut reader| reader.read().ok());
     let mut meta_info = None;
     let mut module_info = None;
+    let mut meta_issues = None;
     if let Some(lspk) = lspk {
         if let Ok(meta) = lspk.extract_meta_lsx() {
+            meta_issues = Some(metadata::validate_meta_lsx(&meta.decompressed_bytes));
             meta_info = Some(metadata::parse_meta_lsx(&meta.decompressed_bytes));
             if let Ok(parsed) = meta.deserialize_as_mod_pak() {
                 module_info = Some(parsed.module_info);
@@ -808,7 +1420,20 @@ fn import_single_pak(
         .map(PakInfo::from_module_info)
         .or_else(|| pak_info_from_meta(&meta_info));
     let Some(pak_info) = pak_info else {
-        return import_override_pak(path, data_dir, source_label, source_times);
+        if let Some(issues) = meta_issues.filter(|issues| !issues.is_empty()) {
+            return Err(anyhow::anyhow!(issues.join("; ")));
+        }
+        let mut import = import_override_pak(path, data_dir, source_label, source_times)?;
+        if let Some(reason) = metadata::pak_meta_error(path) {
+            let file_name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("pak");
+            import.warnings.push(format!(
+                "meta.lsx in {file_name} could not be read ({reason}); imported as override pak"
+            ));
+        }
+        return Ok(import);
     };
     let json_matches: Vec<&metadata::JsonModInfo> = json_mods
         .iter()
@@ -847,7 +1472,20 @@ fn import_single_pak(
 
     let filename = format!("{}.pak", pak_info.folder);
     let dest = staging_root.join(&filename);
-    fs::copy(path, &dest).context("copy .pak")?;
+    if mode == StageMode::Link {
+        link_dev_path(path, &dest).context("link .pak")?;
+    } else {
+        fs::copy(path, &dest).context("copy .pak")?;
+    }
+    for (index, part) in metadata::pak_part_paths(path).iter().enumerate() {
+        let part_dest = staging_root.join(format!("{}_{}.pak", pak_info.folder, index + 1));
+        if mode == StageMode::Link {
+            link_dev_path(part, &part_dest).context("link .pak part")?;
+        } else {
+            fs::copy(part, &part_dest).context("copy .pak part")?;
+        }
+    }
+    let hash = hash_file(&dest).ok();
 
     let mut times = source_times_for(path);
     if times.created_at.is_none() && times.modified_at.is_none() {
@@ -858,6 +1496,7 @@ fn import_single_pak(
     let primary_created = json_created.or(meta_info.created_at);
     let (created_at, modified_at) =
         resolve_times(primary_created, times.created_at, times.modified_at);
+    let author = pak_info.author.clone();
     let entry = ModEntry {
         id: mod_id,
         name: pak_info.name.clone(),
@@ -866,18 +1505,32 @@ fn import_single_pak(
         added_at: now_timestamp(),
         targets: vec![InstallTarget::Pak {
             file: filename,
-            info: pak_info,
+            info: Box::new(pak_info),
+            hash,
         }],
         target_overrides: Vec::new(),
         source_label: source_label.map(|label| label.to_string()),
         source: ModSource::Managed,
         dependencies,
+        author,
+        version: None,
+        website: None,
+        nexus_id: None,
+        min_game_version: None,
+        dev_path: None,
+        dev_watch: false,
+        custom_name: None,
+        disk_usage: None,
+        source_path: None,
+        disabled_since: None,
+        cache_evicted: false,
     };
     guard.disarm();
     Ok(ImportMod {
         entry,
         staging_root: Some(staging_root),
         sigillink: None,
+        warnings: meta_issues.unwrap_or_default(),
     })
 }
 
@@ -919,6 +1572,11 @@ fn import_override_pak(
     fs::create_dir_all(&data_root).context("create override pak storage")?;
     let dest = data_root.join(filename);
     fs::copy(path, &dest).context("copy override .pak")?;
+    for part in metadata::pak_part_paths(path) {
+        if let Some(part_name) = part.file_name() {
+            fs::copy(&part, data_root.join(part_name)).context("copy override .pak part")?;
+        }
+    }
 
     let mut times = source_times_for(path);
     if times.created_at.is_none() && times.modified_at.is_none() {
@@ -950,12 +1608,25 @@ fn import_override_pak(
         source_label: source_label.map(|label| label.to_string()),
         source: ModSource::Managed,
         dependencies: Vec::new(),
+        author: None,
+        version: None,
+        website: None,
+        nexus_id: None,
+        min_game_version: None,
+        dev_path: None,
+        dev_watch: false,
+        custom_name: None,
+        disk_usage: None,
+        source_path: None,
+        disabled_since: None,
+        cache_evicted: false,
     };
     guard.disarm();
     Ok(ImportMod {
         entry,
         staging_root: Some(staging_root),
         sigillink: None,
+        warnings: Vec::new(),
     })
 }
 
@@ -980,7 +1651,7 @@ fn import_loose(
     data_dir: &Path,
     scan: &PayloadScan,
     source_label: Option<&str>,
-    allow_move: bool,
+    mode: StageMode,
     source_times: Option<SourceTimes>,
     meta_created: Option<i64>,
     total_files: usize,
@@ -1010,11 +1681,7 @@ fn import_loose(
 
     if let Some(data_dir) = &scan.data_dir {
         let dest = staging_root.join("Data");
-        if allow_move {
-            move_or_copy_dir_with_progress(data_dir, &dest, &mut progress)?;
-        } else {
-            copy_dir_with_progress(data_dir, &dest, &mut progress)?;
-        }
+        stage_dir(data_dir, &dest, mode, &mut progress)?;
         targets.push(InstallTarget::Data {
             dir: "Data".to_string(),
         });
@@ -1022,21 +1689,13 @@ fn import_loose(
 
     if let Some(generated_dir) = &scan.generated_dir {
         let dest = staging_root.join("Generated");
-        if allow_move {
-            move_or_copy_dir_with_progress(generated_dir, &dest, &mut progress)?;
-        } else {
-            copy_dir_with_progress(generated_dir, &dest, &mut progress)?;
-        }
+        stage_dir(generated_dir, &dest, mode, &mut progress)?;
         targets.push(InstallTarget::Generated {
             dir: "Generated".to_string(),
         });
     } else if let Some(public_dir) = &scan.public_dir {
         let dest = staging_root.join("Generated").join("Public");
-        if allow_move {
-            move_or_copy_dir_with_progress(public_dir, &dest, &mut progress)?;
-        } else {
-            copy_dir_with_progress(public_dir, &dest, &mut progress)?;
-        }
+        stage_dir(public_dir, &dest, mode, &mut progress)?;
         targets.push(InstallTarget::Generated {
             dir: "Generated".to_string(),
         });
@@ -1044,19 +1703,25 @@ fn import_loose(
 
     if let Some(bin_dir) = &scan.bin_dir {
         let dest = staging_root.join("bin");
-        if allow_move {
-            move_or_copy_dir_with_progress(bin_dir, &dest, &mut progress)?;
-        } else {
-            copy_dir_with_progress(bin_dir, &dest, &mut progress)?;
-        }
+        stage_dir(bin_dir, &dest, mode, &mut progress)?;
         targets.push(InstallTarget::Bin {
             dir: "bin".to_string(),
         });
     }
     progress.finish();
 
-    persist_payload_metadata(scan, &staging_root);
-    let sigillink = build_sigillink_index(&staging_root, &targets, total_files, reporter)?;
+    persist_payload_metadata(scan, &staging_root, mode);
+    // Linked payloads change under us, so they are walked at deploy time instead of indexed.
+    let sigillink = if mode == StageMode::Link {
+        None
+    } else {
+        Some(build_sigillink_index(
+            &staging_root,
+            &targets,
+            total_files,
+            reporter,
+        )?)
+    };
 
     let raw_label = source_label
         .map(|label| label.to_string())
@@ -1090,23 +1755,43 @@ fn import_loose(
         source_label: source_label.map(|label| label.to_string()),
         source: ModSource::Managed,
         dependencies: Vec::new(),
+        author: None,
+        version: None,
+        website: None,
+        nexus_id: None,
+        min_game_version: None,
+        dev_path: None,
+        dev_watch: false,
+        custom_name: None,
+        disk_usage: None,
+        source_path: None,
+        disabled_since: None,
+        cache_evicted: false,
     };
     guard.disarm();
     Ok(ImportMod {
         entry,
         staging_root: Some(staging_root),
-        sigillink: Some(sigillink),
+        sigillink,
+        warnings: Vec::new(),
     })
 }
 
-fn persist_payload_metadata(scan: &PayloadScan, mod_root: &Path) {
+fn persist_payload_metadata(scan: &PayloadScan, mod_root: &Path, mode: StageMode) {
+    let persist = |source: &Path, dest: &Path| {
+        if mode == StageMode::Link {
+            link_dev_path(source, dest).is_ok()
+        } else {
+            fs::copy(source, dest).is_ok()
+        }
+    };
     let mut copied_any = false;
     if scan.meta_file.is_some() || scan.info_json.is_some() {
         let meta_root = mod_root.join("_meta");
         if fs::create_dir_all(&meta_root).is_ok() {
             if let Some(meta_path) = &scan.meta_file {
                 let dest = meta_root.join("meta.lsx");
-                if fs::copy(meta_path, &dest).is_ok() {
+                if persist(meta_path, &dest) {
                     copied_any = true;
                 }
             }
@@ -1116,7 +1801,7 @@ fn persist_payload_metadata(scan: &PayloadScan, mod_root: &Path) {
                     .map(|name| name.to_os_string())
                     .unwrap_or_else(|| "info.json".into());
                 let dest = meta_root.join(name);
-                if fs::copy(info_path, &dest).is_ok() {
+                if persist(info_path, &dest) {
                     copied_any = true;
                 }
             }
@@ -1190,6 +1875,7 @@ fn collect_sigillink_entries(
             kind,
             relative_path: rel.to_string_lossy().to_string(),
             size,
+            hash: hash_file(entry.path()).ok(),
         });
         progress.bump(None, false);
     }
@@ -1234,6 +1920,7 @@ fn scan_payload(root: &Path) -> Result<PayloadScan> {
                 .and_then(|ext| ext.to_str())
                 .map(|ext| ext.eq_ignore_ascii_case("pak"))
                 .unwrap_or(false)
+                && metadata::pak_part_base(path).is_none()
             {
                 pak_files.push(path.to_path_buf());
             }
@@ -1251,9 +1938,10 @@ fn scan_payload(root: &Path) -> Result<PayloadScan> {
                     "info.json" => 0,
                     "mod.json" => 1,
                     "modinfo.json" => 2,
-                    _ => 3,
+                    "modio.json" => 3,
+                    _ => 4,
                 };
-                if priority < 3 {
+                if priority < 4 {
                     json_candidates.push((path.to_path_buf(), depth, priority));
                 }
             }
@@ -1380,7 +2068,8 @@ fn collect_import_candidates(root: &Path) -> Result<Vec<ImportCandidate>> {
                 Ok(file_type) => file_type,
                 Err(_) => continue,
             };
-            if file_type.is_file() && is_pak_file(&path) {
+            if file_type.is_file() && is_pak_file(&path) && metadata::pak_part_base(&path).is_none()
+            {
                 let label = display_pak_label(&path);
                 push_candidate(
                     &mut candidates,
@@ -1560,8 +2249,8 @@ fn is_ignored_path(path: &Path) -> bool {
     })
 }
 
-fn extract_zip(path: &Path, dest: &Path) -> Result<()> {
-    match extract_with_7z(path, dest) {
+fn extract_zip(path: &Path, dest: &Path, progress: &mut ExtractProgress<'_>) -> Result<()> {
+    match extract_with_7z(path, dest, progress) {
         Ok(Some(())) => return Ok(()),
         Ok(None) => {}
         Err(err) => return Err(err),
@@ -1570,15 +2259,25 @@ fn extract_zip(path: &Path, dest: &Path) -> Result<()> {
     let file = fs::File::open(path).context("open zip")?;
     let mut archive = zip::ZipArchive::new(file).context("read zip")?;
 
+    let mut total_bytes = 0u64;
+    for i in 0..archive.len() {
+        if let Ok(file) = archive.by_index_raw(i) {
+            total_bytes = total_bytes.saturating_add(file.size());
+        }
+    }
+    progress.set_totals(archive.len(), total_bytes);
+
     for i in 0..archive.len() {
         let mut file = archive.by_index(i).context("zip entry")?;
         let Some(out_path) = file.enclosed_name() else {
+            progress.finish_entry()?;
             continue;
         };
 
         let out_path = dest.join(out_path);
         if file.is_dir() {
             fs::create_dir_all(&out_path).context("create zip dir")?;
+            progress.finish_entry()?;
             continue;
         }
 
@@ -1587,18 +2286,40 @@ fn extract_zip(path: &Path, dest: &Path) -> Result<()> {
         }
 
         let mut out_file = fs::File::create(&out_path).context("write zip entry")?;
-        std::io::copy(&mut file, &mut out_file).context("extract zip entry")?;
+        copy_with_progress(&mut file, &mut out_file, progress).context("extract zip entry")?;
         if let Some(dt) = file.last_modified() {
             if let Some(mtime) = zip_time_to_unix(dt) {
                 let mtime = FileTime::from_unix_time(mtime, 0);
                 let _ = set_file_mtime(&out_path, mtime);
             }
         }
+        progress.finish_entry()?;
     }
 
     Ok(())
 }
 
+fn copy_with_progress(
+    reader: &mut dyn Read,
+    writer: &mut dyn Write,
+    progress: &mut ExtractProgress<'_>,
+) -> io::Result<u64> {
+    let mut buffer = vec![0u8; EXTRACT_BUFFER_SIZE];
+    let mut copied = 0u64;
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        writer.write_all(&buffer[..read])?;
+        copied = copied.saturating_add(read as u64);
+        progress.add_bytes(read as u64);
+    }
+    Ok(copied)
+}
+
 fn zip_time_to_unix(dt: zip::DateTime) -> Option<i64> {
     let month = Month::try_from(dt.month()).ok()?;
     let date = Date::from_calendar_date(dt.year() as i32, month, dt.day()).ok()?;
@@ -1607,43 +2328,167 @@ fn zip_time_to_unix(dt: zip::DateTime) -> Option<i64> {
     Some(datetime.unix_timestamp())
 }
 
-fn extract_7z(path: &Path, dest: &Path) -> Result<()> {
-    match extract_with_7z(path, dest) {
+fn extract_7z(path: &Path, dest: &Path, progress: &mut ExtractProgress<'_>) -> Result<()> {
+    match extract_with_7z(path, dest, progress) {
         Ok(Some(())) => Ok(()),
-        Ok(None) => sevenz_rust::decompress_file(path, dest)
-            .with_context(|| format!("extract 7z archive {path:?}")),
+        Ok(None) => extract_7z_native(path, dest, progress),
         Err(err) => Err(err),
     }
 }
 
-fn extract_with_7z(path: &Path, dest: &Path) -> Result<Option<()>> {
+fn extract_7z_native(path: &Path, dest: &Path, progress: &mut ExtractProgress<'_>) -> Result<()> {
+    let archive =
+        sevenz_rust::Archive::open(path).with_context(|| format!("read 7z archive {path:?}"))?;
+    let total_bytes = archive
+        .files
+        .iter()
+        .filter(|entry| entry.has_stream())
+        .map(|entry| entry.size())
+        .sum();
+    progress.set_totals(archive.files.len(), total_bytes);
+    drop(archive);
+
+    let mut canceled = false;
+    let result =
+        sevenz_rust::decompress_file_with_extract_fn(path, dest, |entry, reader, out_path| {
+            if entry.is_directory() {
+                fs::create_dir_all(out_path)?;
+            } else {
+                if let Some(parent) = out_path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                let mut out_file = fs::File::create(out_path)?;
+                if entry.size() > 0 {
+                    copy_with_progress(reader, &mut out_file, progress)?;
+                }
+                if entry.has_last_modified_date {
+                    let modified: SystemTime = entry.last_modified_date().into();
+                    let _ = set_file_mtime(out_path, FileTime::from_system_time(modified));
+                }
+            }
+            if progress.finish_entry().is_err() {
+                canceled = true;
+                return Ok(false);
+            }
+            Ok(true)
+        });
+    if canceled {
+        return Err(anyhow::anyhow!(IMPORT_CANCELED));
+    }
+    result.with_context(|| format!("extract 7z archive {path:?}"))
+}
+
+fn extract_with_7z(
+    path: &Path,
+    dest: &Path,
+    progress: &mut ExtractProgress<'_>,
+) -> Result<Option<()>> {
     let mut command = Command::new("7z");
-    let output = command
+    let child = command
         .arg("x")
         .arg("-y")
         .arg("-mmt=on")
+        .arg("-bso0")
+        .arg("-bsp1")
         .arg(format!("-o{}", dest.display()))
         .arg(path)
-        .stdout(Stdio::null())
+        .stdout(Stdio::piped())
         .stderr(Stdio::piped())
-        .output();
+        .spawn();
 
-    let output = match output {
-        Ok(output) => output,
+    let mut child = match child {
+        Ok(child) => child,
         Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
         Err(err) => {
             return Err(err).context("launch 7z");
         }
     };
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
+    // 7z only reports a percentage, so byte progress is scaled to the archive size.
+    let archive_bytes = fs::metadata(path).map(|meta| meta.len()).unwrap_or(0);
+    progress.set_totals(1, archive_bytes);
+
+    let stderr_reader = child.stderr.take().map(|mut stderr| {
+        thread::spawn(move || {
+            let mut raw = String::new();
+            let _ = stderr.read_to_string(&mut raw);
+            raw
+        })
+    });
+
+    let mut canceled = false;
+    if let Some(stdout) = child.stdout.take() {
+        let mut reader = BufReader::new(stdout);
+        let mut segment = Vec::new();
+        loop {
+            if progress.reporter.check_canceled().is_err() {
+                canceled = true;
+                let _ = child.kill();
+                break;
+            }
+            let buffer = match reader.fill_buf() {
+                Ok([]) => break,
+                Ok(buffer) => buffer,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(_) => break,
+            };
+            let consumed = buffer.len();
+            for &byte in buffer {
+                if matches!(byte, b'\x08' | b'\r' | b'\n') {
+                    if let Some(percent) = parse_7z_percent(&segment) {
+                        progress.set_bytes(archive_bytes.saturating_mul(percent) / 100);
+                    }
+                    segment.clear();
+                } else {
+                    segment.push(byte);
+                }
+            }
+            reader.consume(consumed);
+        }
+    }
+
+    let status = child.wait().context("wait for 7z")?;
+    let stderr = stderr_reader
+        .and_then(|handle| handle.join().ok())
+        .unwrap_or_default();
+    if canceled {
+        return Err(anyhow::anyhow!(IMPORT_CANCELED));
+    }
+    if !status.success() {
         return Err(anyhow::anyhow!("7z extraction failed: {}", stderr.trim()));
     }
 
     Ok(Some(()))
 }
 
+fn parse_7z_percent(segment: &[u8]) -> Option<u64> {
+    let text = std::str::from_utf8(segment).ok()?.trim();
+    let (percent, _) = text.split_once('%')?;
+    percent
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(|value| value.min(100))
+}
+
+fn stage_dir(
+    source: &Path,
+    dest: &Path,
+    mode: StageMode,
+    progress: &mut CopyProgress<'_>,
+) -> Result<()> {
+    match mode {
+        StageMode::Copy => copy_dir_with_progress(source, dest, progress),
+        StageMode::Move => move_or_copy_dir_with_progress(source, dest, progress),
+        StageMode::Link => {
+            if let Some(parent) = dest.parent() {
+                fs::create_dir_all(parent).context("create link parent")?;
+            }
+            link_dev_path(source, dest).with_context(|| format!("link {:?} -> {:?}", dest, source))
+        }
+    }
+}
+
 fn count_copy_files(source: &Path) -> usize {
     WalkDir::new(source)
         .follow_links(false)
@@ -1768,6 +2613,15 @@ fn sanitize_stage_label(value: &str) -> String {
         .collect()
 }
 
+pub fn hash_file(path: &Path) -> Result<String> {
+    let file = fs::File::open(path).with_context(|| format!("open {path:?}"))?;
+    let mut hasher = Hasher::new();
+    hasher
+        .update_reader(BufReader::new(file))
+        .with_context(|| format!("hash {path:?}"))?;
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
 fn hash_path(path: &Path) -> String {
     hash_path_with_prefix(path, "loose")
 }
@@ -1850,3 +2704,120 @@ fn has_parent_named(path: &Path, needle: &str) -> bool {
         .filter_map(|ancestor| ancestor.file_name())
         .any(|name| name.to_string_lossy().eq_ignore_ascii_case(needle))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pak_entry(source_label: Option<&str>) -> ModEntry {
+        serde_json::from_value(serde_json::json!({
+            "id": "mod",
+            "name": "Meta Name",
+            "added_at": 0,
+            "source_label": source_label,
+            "author": "Meta Author",
+            "targets": [{
+                "kind": "pak",
+                "file": "Mod.pak",
+                "info": {
+                    "uuid": "meta-uuid",
+                    "name": "Meta Name",
+                    "folder": "MetaFolder",
+                    "version": 1,
+                    "md5": null,
+                    "publish_handle": null,
+                    "author": null,
+                    "description": null,
+                    "module_type": null,
+                },
+            }],
+        }))
+        .unwrap()
+    }
+
+    fn sidecar(uuid: Option<&str>, name: &str) -> metadata::JsonModInfo {
+        metadata::JsonModInfo {
+            uuid: uuid.map(str::to_string),
+            folder: None,
+            name: Some(name.to_string()),
+            created_at: None,
+            dependencies: Vec::new(),
+            author: Some(format!("{name} Author")),
+            version: Some("2.0".to_string()),
+            website: None,
+            nexus_id: Some(42),
+        }
+    }
+
+    #[test]
+    fn sidecar_matches_the_mod_in_either_order() {
+        let entry = pak_entry(None);
+        let archive = sidecar(None, "Archive");
+        let per_mod = sidecar(Some("meta-uuid"), "Sidecar");
+        for json_mods in [
+            vec![archive.clone(), per_mod.clone()],
+            vec![per_mod.clone(), archive.clone()],
+        ] {
+            let info = sidecar_for_entry(&entry, &json_mods, true).unwrap();
+            assert_eq!(info.name.as_deref(), Some("Sidecar"));
+        }
+    }
+
+    #[test]
+    fn sidecar_name_replaces_the_archive_label_and_keeps_meta_identity() {
+        let archive = sidecar(None, "Archive Sidecar");
+        let per_mod = sidecar(Some("meta-uuid"), "Sidecar");
+        for json_mods in [
+            vec![archive.clone(), per_mod.clone()],
+            vec![per_mod.clone(), archive.clone()],
+        ] {
+            for label in [None, Some("MyMod-1234-2-0-1700000000")] {
+                let mut entry = pak_entry(label);
+                let info = sidecar_for_entry(&entry, &json_mods, true).unwrap();
+                apply_sidecar_info(&mut entry, info);
+                assert_eq!(entry.source_label.as_deref(), Some("Sidecar"));
+                assert_eq!(entry.display_name(), "Sidecar");
+                assert_eq!(entry.author.as_deref(), Some("Sidecar Author"));
+                assert_eq!(entry.version.as_deref(), Some("2.0"));
+                assert_eq!(entry.nexus_id, Some(42));
+                let Some(InstallTarget::Pak { info: pak, .. }) = entry.targets.first() else {
+                    panic!("pak target");
+                };
+                assert_eq!(pak.uuid, "meta-uuid");
+                assert_eq!(pak.folder, "MetaFolder");
+            }
+        }
+    }
+
+    #[test]
+    fn sidecar_name_stays_under_a_custom_name() {
+        let mut entry = pak_entry(Some("MyMod-1234"));
+        entry.custom_name = Some("My Rename".to_string());
+        apply_sidecar_info(&mut entry, &sidecar(Some("meta-uuid"), "Sidecar"));
+        assert_eq!(entry.custom_name.as_deref(), Some("My Rename"));
+        assert_eq!(entry.display_name(), "My Rename");
+        assert_eq!(entry.original_display_name(), "Sidecar");
+
+        let mut blank = pak_entry(Some("MyMod-1234"));
+        apply_sidecar_info(&mut blank, &sidecar(Some("meta-uuid"), "  "));
+        assert_eq!(blank.source_label.as_deref(), Some("MyMod-1234"));
+    }
+
+    #[test]
+    fn parse_7z_percent_reads_progress_segments() {
+        assert_eq!(parse_7z_percent(b"  42% 3 - Mods/Example.pak"), Some(42));
+        assert_eq!(parse_7z_percent(b"100%"), Some(100));
+        assert_eq!(parse_7z_percent(b"250%"), Some(100));
+        assert_eq!(parse_7z_percent(b"Everything is Ok"), None);
+        assert_eq!(parse_7z_percent(b"\xff\xfe 12%"), None);
+    }
+
+    #[test]
+    fn format_bytes_switches_units_at_1024() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1024), "1.0 KB");
+        assert_eq!(format_bytes(1536 * 1024), "1.5 MB");
+        assert_eq!(format_bytes(5 * 1024 * 1024 * 1024), "5.0 GB");
+    }
+}
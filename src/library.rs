@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::{
     collections::{HashMap, HashSet},
     fs,
@@ -9,6 +10,12 @@ use std::{
 
 pub const SIGILLINK_RANKING_PROFILE: &str = "__sigillink_ranking__";
 
+pub type SchemaMigration = fn(Value) -> Result<Value>;
+
+// Index N migrates a document from schema version N to N + 1.
+const LIBRARY_MIGRATIONS: &[SchemaMigration] = &[migrate_library_v0_to_v1];
+pub const LIBRARY_SCHEMA_VERSION: u32 = LIBRARY_MIGRATIONS.len() as u32;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SigilLinkRankMeta {
     #[serde(default)]
@@ -19,6 +26,79 @@ pub struct SigilLinkRankMeta {
     pub last_pins: usize,
     #[serde(default)]
     pub last_inputs_hash: Option<String>,
+    #[serde(default)]
+    pub history: Vec<SigilLinkRankSnapshot>,
+}
+
+pub const SIGILLINK_RANK_HISTORY_LIMIT: usize = 8;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SigilLinkRankSnapshot {
+    pub profile: String,
+    pub ranked_at: i64,
+    pub moves: usize,
+    pub before: Vec<ProfileEntry>,
+    pub after: Vec<ProfileEntry>,
+}
+
+impl SigilLinkRankMeta {
+    pub fn push_history(&mut self, snapshot: SigilLinkRankSnapshot) {
+        self.history.insert(0, snapshot);
+        let mut kept = 0;
+        let profile = self.history[0].profile.clone();
+        self.history.retain(|entry| {
+            if entry.profile != profile {
+                return true;
+            }
+            kept += 1;
+            kept <= SIGILLINK_RANK_HISTORY_LIMIT
+        });
+    }
+}
+
+pub fn schema_version_of(value: &Value) -> u32 {
+    value
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .map(|version| version as u32)
+        .unwrap_or(0)
+}
+
+pub fn run_schema_migrations(
+    mut value: Value,
+    from: u32,
+    migrations: &[SchemaMigration],
+) -> Result<Value> {
+    for (version, migration) in migrations.iter().enumerate().skip(from as usize) {
+        value = migration(value).with_context(|| format!("schema v{version} migration"))?;
+        if let Some(object) = value.as_object_mut() {
+            object.insert(
+                "schema_version".to_string(),
+                Value::from(version as u32 + 1),
+            );
+        }
+    }
+    Ok(value)
+}
+
+// Pre-versioned libraries have no NEW badge watermark; seed it from the newest
+// mod so everything imported before the upgrade is treated as already seen.
+fn migrate_library_v0_to_v1(mut value: Value) -> Result<Value> {
+    let Some(object) = value.as_object_mut() else {
+        return Err(anyhow::anyhow!("library.json is not an object"));
+    };
+    if !object.contains_key("new_mods_seen_at") {
+        let newest = object
+            .get("mods")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|mod_entry| mod_entry.get("added_at").and_then(Value::as_i64))
+            .max()
+            .unwrap_or(0);
+        object.insert("new_mods_seen_at".to_string(), Value::from(newest));
+    }
+    Ok(value)
 }
 
 pub fn is_sigillink_ranking_profile(name: &str) -> bool {
@@ -27,6 +107,8 @@ pub fn is_sigillink_ranking_profile(name: &str) -> bool {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Library {
+    #[serde(default)]
+    pub schema_version: u32,
     pub mods: Vec<ModEntry>,
     pub profiles: Vec<Profile>,
     pub active_profile: String,
@@ -37,9 +119,17 @@ pub struct Library {
     #[serde(default)]
     pub metadata_cache_key: Option<String>,
     #[serde(default)]
+    pub metadata_mod_keys: HashMap<String, String>,
+    #[serde(default)]
     pub modsettings_hash: Option<String>,
     #[serde(default = "default_true")]
     pub modsettings_sync_enabled: bool,
+    #[serde(default)]
+    pub new_mods_seen_at: i64,
+    #[serde(default)]
+    pub sigillink_excluded: HashSet<String>,
+    #[serde(default)]
+    pub starred: HashSet<String>,
 }
 
 impl Library {
@@ -47,7 +137,14 @@ impl Library {
         let library_path = data_dir.join("library.json");
         if library_path.exists() {
             let raw = fs::read_to_string(&library_path).context("read library.json")?;
-            let mut library: Library = serde_json::from_str(&raw).context("parse library.json")?;
+            let (mut library, version) = Library::from_json(&raw)?;
+            if version < LIBRARY_SCHEMA_VERSION {
+                let backup_path = data_dir.join(format!("library.json.v{version}.bak"));
+                if !backup_path.exists() {
+                    fs::write(&backup_path, &raw).context("write library.json backup")?;
+                }
+                library.save(data_dir)?;
+            }
             if library.profiles.is_empty() {
                 library.profiles.push(Profile::new("Default"));
             }
@@ -64,19 +161,38 @@ impl Library {
         }
 
         let library = Library {
+            schema_version: LIBRARY_SCHEMA_VERSION,
             mods: Vec::new(),
             profiles: vec![Profile::new("Default")],
             active_profile: "Default".to_string(),
             dependency_blocks: HashSet::new(),
             metadata_cache_version: 0,
             metadata_cache_key: None,
+            metadata_mod_keys: HashMap::new(),
             modsettings_hash: None,
             modsettings_sync_enabled: true,
+            new_mods_seen_at: 0,
+            sigillink_excluded: HashSet::new(),
+            starred: HashSet::new(),
         };
         library.save(data_dir)?;
         Ok(library)
     }
 
+    pub fn from_json(raw: &str) -> Result<(Self, u32)> {
+        let value: Value = serde_json::from_str(raw).context("parse library.json")?;
+        let version = schema_version_of(&value);
+        if version > LIBRARY_SCHEMA_VERSION {
+            return Err(anyhow::anyhow!(
+                "library.json uses schema v{version}, but this SigilSmith supports up to v{LIBRARY_SCHEMA_VERSION}; update SigilSmith to open this library"
+            ));
+        }
+        let value = run_schema_migrations(value, version, LIBRARY_MIGRATIONS)
+            .context("migrate library.json")?;
+        let library = serde_json::from_value(value).context("parse library.json")?;
+        Ok((library, version))
+    }
+
     pub fn save(&self, data_dir: &Path) -> Result<()> {
         let library_path = data_dir.join("library.json");
         let raw = serde_json::to_string_pretty(self).context("serialize library.json")?;
@@ -96,17 +212,60 @@ impl Library {
             .find(|profile| profile.name == self.active_profile)
     }
 
-    pub fn ensure_mods_in_profiles(&mut self) {
-        let mod_ids: Vec<String> = self.mods.iter().map(|m| m.id.clone()).collect();
-        let mod_set: HashSet<&str> = mod_ids.iter().map(|id| id.as_str()).collect();
+    pub fn ensure_mods_in_profiles(&mut self) -> EnsureModsReport {
+        let mod_ids = self.mod_ids();
+        let mut report = EnsureModsReport::default();
         for profile in &mut self.profiles {
             if is_sigillink_ranking_profile(&profile.name) {
                 continue;
             }
-            profile.ensure_mods(&mod_ids);
+            report += profile.ensure_mods(&mod_ids);
         }
+        self.prune_mod_sets();
+        report
+    }
+
+    pub fn mod_ids(&self) -> Vec<String> {
+        self.mods.iter().map(|m| m.id.clone()).collect()
+    }
+
+    /// Drops removed mods from the library-wide id sets (blocks, exclusions, stars).
+    pub fn prune_mod_sets(&mut self) {
+        let mod_set: HashSet<&str> = self.mods.iter().map(|m| m.id.as_str()).collect();
         self.dependency_blocks
             .retain(|id| mod_set.contains(id.as_str()));
+        self.sigillink_excluded
+            .retain(|id| mod_set.contains(id.as_str()));
+        self.starred.retain(|id| mod_set.contains(id.as_str()));
+    }
+
+    pub fn label_placeholders(&mut self, known_names: &HashMap<String, String>) -> usize {
+        self.profiles
+            .iter_mut()
+            .map(|profile| profile.label_placeholders(known_names))
+            .sum()
+    }
+
+    pub fn dedup_profile_orders(&mut self) -> Vec<(String, usize)> {
+        self.profiles
+            .iter_mut()
+            .filter_map(|profile| {
+                let removed = profile.dedup_order();
+                (removed > 0).then(|| (profile.name.clone(), removed))
+            })
+            .collect()
+    }
+
+    pub fn mark_deployed(&mut self, profile_name: &str, deployed_at: i64) -> bool {
+        let Some(profile) = self
+            .profiles
+            .iter_mut()
+            .find(|profile| profile.name == profile_name)
+        else {
+            return false;
+        };
+        profile.last_deployed_at = Some(deployed_at);
+        true
     }
 
     pub fn index_by_id(&self) -> HashMap<String, ModEntry> {
@@ -128,6 +287,12 @@ pub struct Profile {
     pub sigillink_pins: HashMap<String, usize>,
     #[serde(default)]
     pub sigillink_meta: SigilLinkRankMeta,
+    #[serde(default)]
+    pub last_deployed_at: Option<i64>,
+    #[serde(default)]
+    pub locked: bool,
+    #[serde(default)]
+    pub order_rules: Vec<OrderRule>,
 }
 
 impl Profile {
@@ -138,24 +303,123 @@ impl Profile {
             file_overrides: Vec::new(),
             sigillink_pins: HashMap::new(),
             sigillink_meta: SigilLinkRankMeta::default(),
+            last_deployed_at: None,
+            locked: false,
+            order_rules: Vec::new(),
         }
     }
 
-    pub fn ensure_mods(&mut self, mod_ids: &[String]) {
+    pub fn ensure_mods(&mut self, mod_ids: &[String]) -> EnsureModsReport {
         let mod_set: std::collections::HashSet<&String> = mod_ids.iter().collect();
+        let mut report = EnsureModsReport::default();
+        // Like mod-list placeholders, keep the enabled flag so a reimport restores the slot.
+        for entry in &mut self.order {
+            if entry.missing_label.is_none() && !mod_set.contains(&entry.id) {
+                entry.missing_label = Some(entry.id.clone());
+                report.placeholders += 1;
+            }
+        }
         for id in mod_ids {
             if !self.order.iter().any(|entry| entry.id == *id) {
                 self.order.push(ProfileEntry {
                     id: id.clone(),
                     enabled: false,
                     missing_label: None,
+                    collision_with: None,
                 });
+                report.added += 1;
             }
         }
+        let overrides_before = self.file_overrides.len();
         self.file_overrides
             .retain(|override_entry| mod_set.contains(&override_entry.mod_id));
+        report.overrides_removed = overrides_before - self.file_overrides.len();
+        let pins_before = self.sigillink_pins.len();
         self.sigillink_pins
             .retain(|mod_id, _| mod_set.contains(&mod_id));
+        report.pins_removed = pins_before - self.sigillink_pins.len();
+        report
+    }
+
+    /// Replaces placeholder labels that are still bare mod ids with a last known name.
+    pub fn label_placeholders(&mut self, known_names: &HashMap<String, String>) -> usize {
+        let mut labeled = 0;
+        for entry in &mut self.order {
+            if entry.missing_label.as_deref() != Some(entry.id.as_str()) {
+                continue;
+            }
+            if let Some(name) = known_names.get(&entry.id) {
+                entry.missing_label = Some(name.clone());
+                labeled += 1;
+            }
+        }
+        labeled
+    }
+
+    pub fn dedup_order(&mut self) -> usize {
+        let before = self.order.len();
+        let mut seen = HashSet::new();
+        self.order.retain(|entry| seen.insert(entry.id.clone()));
+        before - self.order.len()
+    }
+
+    pub fn dedup_file_overrides(&mut self) -> usize {
+        let before = self.file_overrides.len();
+        let mut seen = HashSet::new();
+        let mut kept: Vec<FileOverride> = self
+            .file_overrides
+            .drain(..)
+            .rev()
+            .filter(|entry| seen.insert((entry.kind, entry.relative_path.clone())))
+            .collect();
+        kept.reverse();
+        self.file_overrides = kept;
+        before - self.file_overrides.len()
+    }
+
+    /// Clones the profile under `name`, unlocked and undeployed. With `enabled_only`,
+    /// disabled entries are dropped (returned as the count) and come back at the end
+    /// as disabled via [`Profile::ensure_mods`].
+    pub fn duplicate(
+        &self,
+        name: &str,
+        enabled_only: bool,
+        mod_ids: &[String],
+    ) -> (Profile, usize) {
+        let mut copy = self.clone();
+        copy.name = name.to_string();
+        copy.last_deployed_at = None;
+        copy.locked = false;
+        let mut dropped = 0;
+        if enabled_only {
+            let before = copy.order.len();
+            copy.order.retain(|entry| entry.enabled);
+            dropped = before - copy.order.len();
+            let kept: HashSet<String> = copy.order.iter().map(|entry| entry.id.clone()).collect();
+            copy.file_overrides
+                .retain(|override_entry| kept.contains(&override_entry.mod_id));
+            copy.sigillink_pins
+                .retain(|mod_id, _| kept.contains(mod_id));
+            copy.ensure_mods(mod_ids);
+        }
+        (copy, dropped)
+    }
+
+    pub fn reset_order(&mut self, mod_ids: &[String]) -> usize {
+        let rank: HashMap<&str, usize> = mod_ids
+            .iter()
+            .enumerate()
+            .map(|(index, id)| (id.as_str(), index))
+            .collect();
+        let before: Vec<String> = self.order.iter().map(|entry| entry.id.clone()).collect();
+        self.order
+            .sort_by_key(|entry| rank.get(entry.id.as_str()).copied().unwrap_or(usize::MAX));
+        self.sigillink_pins.clear();
+        before
+            .iter()
+            .zip(self.order.iter())
+            .filter(|(id, entry)| **id != entry.id)
+            .count()
     }
 
     pub fn move_up(&mut self, index: usize) {
@@ -173,12 +437,61 @@ impl Profile {
     }
 }
 
+/// What [`Profile::ensure_mods`] changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EnsureModsReport {
+    pub added: usize,
+    pub placeholders: usize,
+    pub overrides_removed: usize,
+    pub pins_removed: usize,
+}
+
+impl std::ops::AddAssign for EnsureModsReport {
+    fn add_assign(&mut self, other: Self) {
+        self.added += other.added;
+        self.placeholders += other.placeholders;
+        self.overrides_removed += other.overrides_removed;
+        self.pins_removed += other.pins_removed;
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ProfileEntry {
     pub id: String,
     pub enabled: bool,
     #[serde(default)]
     pub missing_label: Option<String>,
+    #[serde(default)]
+    pub collision_with: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum OrderRule {
+    LoadAfter { mod_id: String, after_id: String },
+    TagLast { tag: String },
+}
+
+impl OrderRule {
+    pub fn label(&self, mods: &HashMap<String, ModEntry>) -> String {
+        let name = |id: &str| {
+            mods.get(id)
+                .map(|mod_entry| mod_entry.display_name())
+                .unwrap_or_else(|| id.to_string())
+        };
+        match self {
+            OrderRule::LoadAfter { mod_id, after_id } => {
+                format!("{} after {}", name(mod_id), name(after_id))
+            }
+            OrderRule::TagLast { tag } => format!("\"{tag}\" mods last"),
+        }
+    }
+
+    pub fn tag_matches(tag: &str, tags: &[String]) -> bool {
+        let tag = tag.trim();
+        tags.iter()
+            .any(|value| value.trim().eq_ignore_ascii_case(tag))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -206,6 +519,36 @@ pub struct ModEntry {
     pub source: ModSource,
     #[serde(default)]
     pub dependencies: Vec<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub website: Option<String>,
+    #[serde(default)]
+    pub nexus_id: Option<u64>,
+    #[serde(default)]
+    pub min_game_version: Option<u64>,
+    #[serde(default)]
+    pub dev_path: Option<String>,
+    #[serde(default)]
+    pub dev_watch: bool,
+    #[serde(default)]
+    pub custom_name: Option<String>,
+    #[serde(default)]
+    pub disk_usage: Option<ModDiskUsage>,
+    #[serde(default)]
+    pub source_path: Option<String>,
+    #[serde(default)]
+    pub disabled_since: Option<i64>,
+    #[serde(default)]
+    pub cache_evicted: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ModDiskUsage {
+    pub bytes: u64,
+    pub files: u64,
 }
 
 fn default_true() -> bool {
@@ -214,6 +557,16 @@ fn default_true() -> bool {
 
 impl ModEntry {
     pub fn display_name(&self) -> String {
+        if let Some(custom) = &self.custom_name {
+            let trimmed = custom.trim();
+            if !trimmed.is_empty() {
+                return trimmed.to_string();
+            }
+        }
+        self.original_display_name()
+    }
+
+    pub fn original_display_name(&self) -> String {
         if let Some(label) = &self.source_label {
             let cleaned = clean_source_label(label);
             if !cleaned.is_empty() {
@@ -223,10 +576,27 @@ impl ModEntry {
         self.name.clone()
     }
 
+    pub fn nexus_link(&self) -> Option<String> {
+        self.nexus_id
+            .map(|id| format!("https://www.nexusmods.com/baldursgate3/mods/{id}"))
+    }
+
     pub fn source_label(&self) -> Option<&str> {
         self.source_label.as_deref()
     }
 
+    pub fn pak_hashes(&self) -> Vec<&str> {
+        self.targets
+            .iter()
+            .filter_map(|target| match target {
+                InstallTarget::Pak {
+                    hash: Some(hash), ..
+                } => Some(hash.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn is_native(&self) -> bool {
         matches!(self.source, ModSource::Native)
     }
@@ -300,10 +670,21 @@ impl Default for ModSource {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum InstallTarget {
-    Pak { file: String, info: PakInfo },
-    Generated { dir: String },
-    Data { dir: String },
-    Bin { dir: String },
+    Pak {
+        file: String,
+        info: Box<PakInfo>,
+        #[serde(default)]
+        hash: Option<String>,
+    },
+    Generated {
+        dir: String,
+    },
+    Data {
+        dir: String,
+    },
+    Bin {
+        dir: String,
+    },
 }
 
 impl InstallTarget {
@@ -474,3 +855,208 @@ pub fn normalize_label(label: &str) -> String {
     }
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, enabled: bool) -> ProfileEntry {
+        ProfileEntry {
+            id: id.to_string(),
+            enabled,
+            missing_label: None,
+            collision_with: None,
+        }
+    }
+
+    #[test]
+    fn ensure_mods_keeps_stale_entries_as_placeholders() {
+        let mut profile = Profile::new("Default");
+        profile.order = vec![entry("gone", true), entry("kept", false)];
+        profile.sigillink_pins.insert("gone".to_string(), 0);
+        let mod_ids = vec!["kept".to_string(), "new".to_string()];
+
+        let report = profile.ensure_mods(&mod_ids);
+        assert_eq!(
+            report,
+            EnsureModsReport {
+                added: 1,
+                placeholders: 1,
+                overrides_removed: 0,
+                pins_removed: 1,
+            }
+        );
+        assert_eq!(profile.order.len(), 3);
+        let stale = &profile.order[0];
+        assert!(stale.enabled);
+        assert_eq!(stale.missing_label.as_deref(), Some("gone"));
+        assert_eq!(profile.order[2].id, "new");
+        assert!(!profile.order[2].enabled);
+        assert!(profile.sigillink_pins.is_empty());
+
+        assert_eq!(profile.ensure_mods(&mod_ids), EnsureModsReport::default());
+    }
+
+    #[test]
+    fn ensure_mods_leaves_duplicates_to_dedup() {
+        let mut profile = Profile::new("Default");
+        profile.order = vec![entry("a", true), entry("a", false)];
+        assert_eq!(
+            profile.ensure_mods(&["a".to_string()]),
+            EnsureModsReport::default()
+        );
+        assert_eq!(profile.order.len(), 2);
+        assert_eq!(profile.dedup_order(), 1);
+    }
+
+    #[test]
+    fn label_placeholders_uses_known_names() {
+        let mut library: Library = serde_json::from_value(serde_json::json!({
+            "mods": [],
+            "profiles": [{ "name": "Default", "order": [] }],
+            "active_profile": "Default",
+        }))
+        .unwrap();
+        library.profiles[0].order = vec![entry("gone", true), entry("unknown", false)];
+        library.ensure_mods_in_profiles();
+        let names = HashMap::from([("gone".to_string(), "Gone Mod".to_string())]);
+
+        assert_eq!(library.label_placeholders(&names), 1);
+        let order = &library.profiles[0].order;
+        assert_eq!(order[0].missing_label.as_deref(), Some("Gone Mod"));
+        assert_eq!(order[1].missing_label.as_deref(), Some("unknown"));
+    }
+
+    #[test]
+    fn mark_deployed_stamps_only_the_named_profile() {
+        let mut library: Library = serde_json::from_value(serde_json::json!({
+            "mods": [],
+            "profiles": [
+                { "name": "Default", "order": [] },
+                { "name": "Other", "order": [], "last_deployed_at": 10 },
+            ],
+            "active_profile": "Default",
+        }))
+        .unwrap();
+
+        assert!(library.mark_deployed("Default", 42));
+        assert_eq!(library.profiles[0].last_deployed_at, Some(42));
+        assert_eq!(library.profiles[1].last_deployed_at, Some(10));
+        assert!(!library.mark_deployed("Missing", 50));
+
+        let raw = serde_json::to_value(&library).unwrap();
+        let restored: Library = serde_json::from_value(raw).unwrap();
+        assert_eq!(restored.profiles[0].last_deployed_at, Some(42));
+    }
+
+    #[test]
+    fn v0_library_marks_existing_mods_as_seen() {
+        let raw = r#"{
+            "mods": [
+                { "id": "a", "name": "A", "added_at": 100, "targets": [] },
+                { "id": "b", "name": "B", "added_at": 250, "targets": [] }
+            ],
+            "profiles": [{ "name": "Default", "order": [{ "id": "a", "enabled": true }] }],
+            "active_profile": "Default",
+            "dependency_blocks": [],
+            "metadata_cache_version": 3,
+            "modsettings_sync_enabled": true
+        }"#;
+
+        let (library, version) = Library::from_json(raw).unwrap();
+        assert_eq!(version, 0);
+        assert_eq!(library.schema_version, LIBRARY_SCHEMA_VERSION);
+        assert_eq!(library.new_mods_seen_at, 250);
+        assert_eq!(library.profiles[0].order.len(), 1);
+    }
+
+    #[test]
+    fn v0_migration_keeps_existing_watermark() {
+        let value = serde_json::json!({
+            "mods": [{ "id": "a", "added_at": 500 }],
+            "new_mods_seen_at": 42,
+        });
+        let migrated = migrate_library_v0_to_v1(value).unwrap();
+        assert_eq!(migrated["new_mods_seen_at"], 42);
+
+        let empty = migrate_library_v0_to_v1(serde_json::json!({ "mods": [] })).unwrap();
+        assert_eq!(empty["new_mods_seen_at"], 0);
+        assert!(migrate_library_v0_to_v1(serde_json::json!([])).is_err());
+    }
+
+    #[test]
+    fn newer_schema_is_refused() {
+        let raw = serde_json::json!({
+            "schema_version": LIBRARY_SCHEMA_VERSION + 1,
+            "mods": [],
+            "profiles": [],
+            "active_profile": "Default",
+        })
+        .to_string();
+        assert!(Library::from_json(&raw).is_err());
+    }
+
+    #[test]
+    fn duplicated_profile_edits_stay_isolated() {
+        let mut original = Profile::new("Default");
+        original.order = vec![entry("a", true), entry("b", false), entry("c", true)];
+        original.sigillink_pins.insert("a".to_string(), 0);
+        original.last_deployed_at = Some(7);
+        original.locked = true;
+        let mod_ids = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+        let (mut copy, dropped) = original.duplicate("Copy", false, &mod_ids);
+        assert_eq!(dropped, 0);
+        assert_eq!(copy.name, "Copy");
+        assert_eq!(copy.last_deployed_at, None);
+        assert!(!copy.locked);
+
+        copy.move_down(0);
+        copy.order[0].enabled = false;
+        copy.sigillink_pins.insert("c".to_string(), 2);
+        let ids = |profile: &Profile| -> Vec<(String, bool)> {
+            profile
+                .order
+                .iter()
+                .map(|entry| (entry.id.clone(), entry.enabled))
+                .collect()
+        };
+        assert_eq!(
+            ids(&original),
+            vec![
+                ("a".to_string(), true),
+                ("b".to_string(), false),
+                ("c".to_string(), true),
+            ]
+        );
+        assert_eq!(original.sigillink_pins.len(), 1);
+
+        original.move_up(2);
+        original.order[0].enabled = false;
+        assert_eq!(
+            ids(&copy),
+            vec![
+                ("b".to_string(), false),
+                ("a".to_string(), true),
+                ("c".to_string(), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn enabled_only_duplicate_readds_disabled_mods_at_the_end() {
+        let mut original = Profile::new("Default");
+        original.order = vec![entry("a", false), entry("b", true)];
+        original.sigillink_pins.insert("a".to_string(), 0);
+        let mod_ids = vec!["a".to_string(), "b".to_string()];
+
+        let (copy, dropped) = original.duplicate("Lean", true, &mod_ids);
+        assert_eq!(dropped, 1);
+        assert_eq!(copy.order[0].id, "b");
+        assert_eq!(copy.order[1].id, "a");
+        assert!(!copy.order[1].enabled);
+        assert!(copy.sigillink_pins.is_empty());
+        assert_eq!(original.order[0].id, "a");
+        assert_eq!(original.sigillink_pins.len(), 1);
+    }
+}
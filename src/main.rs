@@ -9,13 +9,22 @@ mod importer;
 mod library;
 mod metadata;
 mod native_pak;
+mod palette;
 mod sigillink;
 mod smart_rank;
+mod support;
+mod trash;
 mod ui;
 mod update;
 
-use anyhow::Result;
+use std::process::ExitCode;
 
-fn main() -> Result<()> {
-    cli::run()
+fn main() -> ExitCode {
+    match cli::run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err:?}");
+            ExitCode::from(cli::exit_code(&err))
+        }
+    }
 }
@@ -29,6 +29,7 @@ pub struct ModMeta {
     pub description: Option<String>,
     pub publish_handle: Option<u64>,
     pub module_type: Option<String>,
+    pub min_game_version: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -38,6 +39,16 @@ pub struct JsonModInfo {
     pub name: Option<String>,
     pub created_at: Option<i64>,
     pub dependencies: Vec<String>,
+    pub author: Option<String>,
+    pub version: Option<String>,
+    pub website: Option<String>,
+    pub nexus_id: Option<u64>,
+}
+
+impl JsonModInfo {
+    pub fn is_archive_level(&self) -> bool {
+        self.uuid.is_none() && self.folder.is_none()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -56,6 +67,12 @@ impl PakMetaCache {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn invalidate(&self, path: &Path) {
+        if let Ok(mut cache) = self.inner.lock() {
+            cache.remove(path);
+        }
+    }
 }
 
 fn pak_signature(path: &Path) -> Option<(u64, Option<i64>)> {
@@ -108,6 +125,7 @@ pub fn parse_meta_lsx(bytes: &[u8]) -> ModMeta {
     let mut description = None;
     let mut publish_handle = None;
     let mut module_type = None;
+    let mut min_game_version = None;
     let mut in_dependencies = false;
     let mut in_dependency = false;
     let mut in_module_info = false;
@@ -188,6 +206,13 @@ pub fn parse_meta_lsx(bytes: &[u8]) -> ModMeta {
                                     }
                                 }
                                 "Type" | "ModuleType" => module_type = Some(value.clone()),
+                                "MinGameVersion"
+                                | "RequiredGameVersion"
+                                | "TargetGameVersion"
+                                | "GameVersion" => {
+                                    min_game_version =
+                                        min_game_version.max(parse_version_value(value_str));
+                                }
                                 _ => {}
                             }
                         }
@@ -243,6 +268,7 @@ pub fn parse_meta_lsx(bytes: &[u8]) -> ModMeta {
         description,
         publish_handle,
         module_type,
+        min_game_version,
     }
 }
 
@@ -344,13 +370,100 @@ pub fn is_base_dependency_uuid(uuid: &str) -> bool {
     )
 }
 
+pub fn validate_meta_lsx(bytes: &[u8]) -> Vec<String> {
+    let mut reader = Reader::from_reader(bytes);
+    reader.trim_text(true);
+    let mut buf = Vec::new();
+    let mut node_stack: Vec<String> = Vec::new();
+    let mut saw_module_info = false;
+    let mut fields: HashMap<String, String> = HashMap::new();
+    let mut issues = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) if e.name().as_ref() == b"node" => {
+                let id = attr_value(&e, b"id").unwrap_or_default();
+                if id == "ModuleInfo" {
+                    saw_module_info = true;
+                }
+                node_stack.push(id);
+            }
+            Ok(Event::Empty(e)) => {
+                let in_module_info = node_stack
+                    .last()
+                    .map(|node| node == "ModuleInfo")
+                    .unwrap_or(false);
+                if e.name().as_ref() == b"attribute" && in_module_info {
+                    if let Some(id) = attr_value(&e, b"id") {
+                        let value = attr_value(&e, b"value").unwrap_or_default();
+                        fields.insert(id, value);
+                    }
+                } else if e.name().as_ref() == b"node"
+                    && attr_value(&e, b"id").as_deref() == Some("ModuleInfo")
+                {
+                    saw_module_info = true;
+                }
+            }
+            Ok(Event::End(e)) if e.name().as_ref() == b"node" => {
+                node_stack.pop();
+            }
+            Ok(Event::Eof) => break,
+            Err(err) => {
+                issues.push(format!(
+                    "meta.lsx is not valid XML at byte {}: {err}",
+                    reader.buffer_position()
+                ));
+                return issues;
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    if !saw_module_info {
+        issues.push("meta.lsx has no ModuleInfo node".to_string());
+        return issues;
+    }
+    for key in ["UUID", "Name", "Folder"] {
+        match fields.get(key).map(|value| value.trim()) {
+            None => issues.push(format!("meta.lsx missing {key}")),
+            Some("") => issues.push(format!("meta.lsx has an empty {key}")),
+            Some(_) => {}
+        }
+    }
+    if let Some(uuid) = fields.get("UUID").map(|value| value.trim()) {
+        if !uuid.is_empty() && !is_uuid_like(uuid) {
+            issues.push(format!("meta.lsx UUID \"{uuid}\" is not a valid UUID"));
+        }
+    }
+    match fields.get("Version64").or_else(|| fields.get("Version")) {
+        None => issues.push("meta.lsx missing Version64".to_string()),
+        Some(value) if value.trim().parse::<u64>().is_err() => {
+            issues.push(format!(
+                "meta.lsx Version64 \"{}\" is not a number",
+                value.trim()
+            ));
+        }
+        Some(_) => {}
+    }
+    issues
+}
+
+fn is_uuid_like(value: &str) -> bool {
+    value.len() == 36
+        && value.bytes().enumerate().all(|(idx, byte)| match idx {
+            8 | 13 | 18 | 23 => byte == b'-',
+            _ => byte.is_ascii_hexdigit(),
+        })
+}
+
 pub fn read_meta_lsx(path: &Path) -> Option<ModMeta> {
     let bytes = fs::read(path).ok()?;
     Some(parse_meta_lsx(&bytes))
 }
 
 pub fn read_meta_lsx_from_pak(path: &Path) -> Option<ModMeta> {
-    if let Some(meta) = read_meta_lsx_from_pak_custom(path) {
+    if let Ok(Some(meta)) = read_meta_lsx_from_pak_custom(path) {
         return Some(meta);
     }
     if let Ok(file) = fs::File::open(path) {
@@ -381,7 +494,6 @@ fn read_meta_lsx_from_pak_fuzzy(path: &Path) -> Option<ModMeta> {
     None
 }
 
-
 pub fn find_meta_lsx(root: &Path) -> Option<PathBuf> {
     let mut candidates: Vec<(bool, usize, PathBuf)> = Vec::new();
     for entry in WalkDir::new(root).max_depth(6) {
@@ -422,6 +534,7 @@ pub fn find_info_json(root: &Path) -> Option<PathBuf> {
             "info.json" => 0,
             "mod.json" => 1,
             "modinfo.json" => 2,
+            "modio.json" => 3,
             _ => continue,
         };
         candidates.push((priority, entry.depth(), entry.path().to_path_buf()));
@@ -445,8 +558,14 @@ pub fn read_json_mods(path: &Path) -> Vec<JsonModInfo> {
 fn parse_json_mods(value: &Value) -> Vec<JsonModInfo> {
     let mut out = Vec::new();
     if let Some(mods) = value.get("Mods").and_then(|v| v.as_array()) {
+        let archive = parse_json_mod(value);
         for entry in mods {
-            if let Some(info) = parse_json_mod(entry) {
+            if let Some(mut info) = parse_json_mod(entry) {
+                if let Some(archive) = &archive {
+                    info.author = info.author.or_else(|| archive.author.clone());
+                    info.website = info.website.or_else(|| archive.website.clone());
+                    info.nexus_id = info.nexus_id.or(archive.nexus_id);
+                }
                 out.push(info);
             }
         }
@@ -466,6 +585,20 @@ fn parse_json_mod(value: &Value) -> Option<JsonModInfo> {
         .and_then(|v| v.as_str())
         .and_then(parse_created_at);
     let dependencies = parse_json_dependencies(obj);
+    let website = json_string(
+        obj,
+        &[
+            "Website",
+            "website",
+            "Url",
+            "url",
+            "URL",
+            "Homepage",
+            "homepage",
+            "profile_url",
+        ],
+    );
+    let nexus_id = json_nexus_id(obj).or_else(|| website.as_deref().and_then(nexus_id_from_url));
     Some(JsonModInfo {
         uuid: obj
             .get("UUID")
@@ -475,15 +608,126 @@ fn parse_json_mod(value: &Value) -> Option<JsonModInfo> {
             .get("Folder")
             .and_then(|v| v.as_str())
             .map(|s| s.to_string()),
-        name: obj
-            .get("Name")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
+        name: json_string(obj, &["Name", "name", "DisplayName", "displayName"]),
         created_at,
         dependencies,
+        author: json_string(obj, &["Author", "author", "Authors", "authors"]).or_else(|| {
+            obj.get("submitted_by")
+                .and_then(|v| v.get("username"))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        }),
+        version: json_version(obj).or_else(|| {
+            obj.get("modfile")
+                .and_then(|v| v.as_object())
+                .and_then(json_version)
+        }),
+        website,
+        nexus_id,
     })
 }
 
+fn json_string(obj: &serde_json::Map<String, Value>, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .filter_map(|key| obj.get(*key))
+        .find_map(|value| value.as_str())
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn json_version(obj: &serde_json::Map<String, Value>) -> Option<String> {
+    for key in ["Version", "version", "Version64", "ModVersion"] {
+        match obj.get(key) {
+            Some(Value::String(value)) if !value.trim().is_empty() => {
+                return Some(value.trim().to_string());
+            }
+            Some(Value::Number(value)) => {
+                if let Some(value) = value.as_u64() {
+                    return Some(format_version64(value));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn json_nexus_id(obj: &serde_json::Map<String, Value>) -> Option<u64> {
+    for key in [
+        "NexusId",
+        "nexusId",
+        "nexus_id",
+        "NexusModId",
+        "ModId",
+        "modId",
+    ] {
+        match obj.get(key) {
+            Some(Value::Number(value)) => return value.as_u64(),
+            Some(Value::String(value)) => {
+                if let Ok(id) = value.trim().parse() {
+                    return Some(id);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+pub fn nexus_id_from_url(url: &str) -> Option<u64> {
+    let lower = url.to_ascii_lowercase();
+    let (_, rest) = lower.split_once("nexusmods.com/baldursgate3/mods/")?;
+    let digits: String = rest.chars().take_while(|ch| ch.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+pub fn format_version64(value: u64) -> String {
+    let (major, minor, revision, build) = version64_parts(value);
+    format!("{major}.{minor}.{revision}.{build}")
+}
+
+fn version64_parts(value: u64) -> (u64, u64, u64, u64) {
+    (
+        value >> 55,
+        (value >> 47) & 0xFF,
+        (value >> 31) & 0xFFFF,
+        value & 0x7FFF_FFFF,
+    )
+}
+
+pub fn encode_version64(major: u64, minor: u64, revision: u64, build: u64) -> u64 {
+    (major << 55) | ((minor & 0xFF) << 47) | ((revision & 0xFFFF) << 31) | (build & 0x7FFF_FFFF)
+}
+
+pub fn parse_version_value(value: &str) -> Option<u64> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    if !value.contains('.') {
+        return value.parse().ok();
+    }
+    let mut parts = [0u64; 4];
+    for (index, part) in value.split('.').enumerate() {
+        let slot = parts.get_mut(index)?;
+        *slot = part.trim().parse().ok()?;
+    }
+    Some(encode_version64(parts[0], parts[1], parts[2], parts[3]))
+}
+
+/// Orders two Version64 values by major.minor.revision. The build field is
+/// skipped: PE file versions only carry 16 bits of it, so it never lines up
+/// with the 31-bit build mods declare.
+pub fn version_compare(left: u64, right: u64) -> std::cmp::Ordering {
+    let (left_major, left_minor, left_revision, _) = version64_parts(left);
+    let (right_major, right_minor, right_revision, _) = version64_parts(right);
+    (left_major, left_minor, left_revision).cmp(&(right_major, right_minor, right_revision))
+}
+
+pub fn game_version_supports(installed: u64, required: u64) -> bool {
+    version_compare(installed, required) != std::cmp::Ordering::Less
+}
+
 fn parse_json_dependencies(obj: &serde_json::Map<String, Value>) -> Vec<String> {
     let mut out = Vec::new();
     for key in [
@@ -563,8 +807,9 @@ fn parse_created_at(value: &str) -> Option<i64> {
 struct PakIndexEntry {
     path: String,
     offset: u64,
-    compressed_size: u32,
-    decompressed_size: u32,
+    archive_part: u32,
+    compressed_size: u64,
+    decompressed_size: u64,
     compression: CompressionType,
 }
 
@@ -576,7 +821,38 @@ enum CompressionType {
     Zstd,
 }
 
-fn read_meta_lsx_from_pak_custom(path: &Path) -> Option<ModMeta> {
+impl CompressionType {
+    fn from_flags(flags: u8) -> Self {
+        match flags & 0x0F {
+            0 => CompressionType::None,
+            1 => CompressionType::Zlib,
+            2 => CompressionType::Lz4,
+            _ => CompressionType::Zstd,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            CompressionType::None => "uncompressed",
+            CompressionType::Zlib => "zlib",
+            CompressionType::Lz4 => "LZ4",
+            CompressionType::Zstd => "Zstd",
+        }
+    }
+}
+
+const LZ4_FRAME_MAGIC: [u8; 4] = [0x04, 0x22, 0x4D, 0x18];
+const ZSTD_FRAME_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+pub fn pak_meta_error(path: &Path) -> Option<String> {
+    match read_meta_lsx_from_pak_custom(path) {
+        Ok(_) => None,
+        Err(_) if read_meta_lsx_from_pak(path).is_some() => None,
+        Err(err) => Some(err),
+    }
+}
+
+fn read_meta_lsx_from_pak_custom(path: &Path) -> Result<Option<ModMeta>, String> {
     let entries = read_pak_index_entries(path)?;
     let mut meta_entry = entries.iter().find(|entry| {
         let lower = entry.path.to_ascii_lowercase();
@@ -588,64 +864,191 @@ fn read_meta_lsx_from_pak_custom(path: &Path) -> Option<ModMeta> {
             lower.ends_with("/meta.lsx") || lower == "meta.lsx"
         });
     }
-    let entry = meta_entry?;
+    let Some(entry) = meta_entry else {
+        return Ok(None);
+    };
 
-    let mut file = fs::File::open(path).ok()?;
-    file.seek(SeekFrom::Start(entry.offset)).ok()?;
-    let mut compressed = vec![0u8; entry.compressed_size as usize];
-    file.read_exact(&mut compressed).ok()?;
-    let bytes = match entry.compression {
-        CompressionType::None => compressed,
-        CompressionType::Lz4 => decompress(&compressed, entry.decompressed_size as usize).ok()?,
+    let part_path = pak_part_path(path, entry.archive_part);
+    let mut file =
+        fs::File::open(&part_path).map_err(|err| format!("open {}: {err}", part_path.display()))?;
+    file.seek(SeekFrom::Start(entry.offset))
+        .map_err(|err| format!("seek to meta.lsx: {err}"))?;
+    let compressed_size = usize::try_from(entry.compressed_size)
+        .map_err(|_| "meta.lsx entry size is out of range".to_string())?;
+    let mut compressed = vec![0u8; compressed_size];
+    file.read_exact(&mut compressed)
+        .map_err(|err| format!("read meta.lsx: {err}"))?;
+    let bytes = decompress_pak_data(
+        entry.compression,
+        &compressed,
+        entry.decompressed_size as usize,
+    )
+    .map_err(|err| format!("decompress meta.lsx ({}): {err}", entry.compression.label()))?;
+
+    Ok(Some(parse_meta_lsx(&bytes)))
+}
+
+fn pak_part_path(path: &Path, part: u32) -> PathBuf {
+    if part == 0 {
+        return path.to_path_buf();
+    }
+    find_pak_part(path, part).unwrap_or_else(|| path.with_file_name(pak_part_name(path, part)))
+}
+
+fn pak_part_name(path: &Path, part: u32) -> String {
+    let stem = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default();
+    format!("{stem}_{part}.pak")
+}
+
+fn find_pak_part(path: &Path, part: u32) -> Option<PathBuf> {
+    let name = path.file_name()?.to_str()?;
+    [
+        path.with_file_name(pak_part_name(path, part)),
+        path.with_file_name(format!("{name}_{part}")),
+    ]
+    .into_iter()
+    .find(|candidate| is_pak_part_file(candidate))
+}
+
+fn is_pak_part_file(path: &Path) -> bool {
+    let Ok(mut file) = fs::File::open(path) else {
+        return false;
+    };
+    if !file.metadata().map(|meta| meta.is_file()).unwrap_or(false) {
+        return false;
+    }
+    // Continuation parts are raw data; anything with its own header is a standalone pak.
+    let mut id = [0u8; 4];
+    file.read_exact(&mut id).is_err() || &id != b"LSPK"
+}
+
+pub fn pak_part_paths(path: &Path) -> Vec<PathBuf> {
+    let mut parts = Vec::new();
+    let mut part = 1;
+    while let Some(part_path) = find_pak_part(path, part) {
+        parts.push(part_path);
+        part += 1;
+    }
+    parts
+}
+
+pub fn pak_part_base(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_str()?;
+    let lower = name.to_ascii_lowercase();
+    let (base, digits) = if let Some(index) = lower.rfind(".pak_") {
+        (name[..index + 4].to_string(), &name[index + 5..])
+    } else if lower.ends_with(".pak") {
+        let (stem, ext) = name.split_at(name.len() - 4);
+        let (stem, digits) = stem.rsplit_once('_')?;
+        (format!("{stem}{ext}"), digits)
+    } else {
+        return None;
+    };
+    let part = digits.parse::<u32>().ok().filter(|part| *part > 0)?;
+    if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let base = path.with_file_name(base);
+    if !base.is_file() || !is_pak_part_file(path) {
+        return None;
+    }
+    (find_pak_part(&base, part).as_deref() == Some(path)).then_some(base)
+}
+
+fn decompress_pak_data(
+    compression: CompressionType,
+    data: &[u8],
+    size: usize,
+) -> Result<Vec<u8>, String> {
+    match compression {
+        CompressionType::None => Ok(data.to_vec()),
         CompressionType::Zlib => {
-            let mut decoder = ZlibDecoder::new(compressed.as_slice());
-            let mut out = vec![0u8; entry.decompressed_size as usize];
-            decoder.read_exact(&mut out).ok()?;
-            out
+            let mut decoder = ZlibDecoder::new(data);
+            let mut out = vec![0u8; size];
+            decoder
+                .read_exact(&mut out)
+                .map_err(|err| err.to_string())?;
+            Ok(out)
         }
-        CompressionType::Zstd => {
-            zstd_decompress(&compressed, entry.decompressed_size as usize).ok()?
+        CompressionType::Lz4 => {
+            if data.starts_with(&LZ4_FRAME_MAGIC) {
+                return decompress_lz4_frame(data);
+            }
+            match decompress(data, size) {
+                Ok(out) => Ok(out),
+                Err(err) => decompress_lz4_frame(data).map_err(|_| err.to_string()),
+            }
         }
-    };
+        CompressionType::Zstd => match zstd_decompress(data, size) {
+            Ok(out) => Ok(out),
+            Err(err) => zstd::stream::decode_all(data).map_err(|_| err.to_string()),
+        },
+    }
+}
 
-    Some(parse_meta_lsx(&bytes))
+fn decompress_lz4_frame(data: &[u8]) -> Result<Vec<u8>, String> {
+    let mut decoder = lz4_flex::frame::FrameDecoder::new(data);
+    let mut out = Vec::new();
+    decoder
+        .read_to_end(&mut out)
+        .map_err(|err| err.to_string())?;
+    Ok(out)
 }
 
-fn read_pak_index_entries(path: &Path) -> Option<Vec<PakIndexEntry>> {
-    const ENTRY_LEN: usize = 272;
+fn decompress_pak_table(data: &[u8], size: usize) -> Result<Vec<u8>, String> {
+    let compression = if data.starts_with(&ZSTD_FRAME_MAGIC) {
+        CompressionType::Zstd
+    } else {
+        CompressionType::Lz4
+    };
+    match decompress_pak_data(compression, data, size) {
+        Ok(table) => Ok(table),
+        Err(err) => decompress_pak_data(CompressionType::Zstd, data, size)
+            .map_err(|_| format!("decompress file table: {err}")),
+    }
+}
+
+fn read_pak_index_entries(path: &Path) -> Result<Vec<PakIndexEntry>, String> {
     const PATH_LEN: usize = 256;
-    const MIN_VERSION: u32 = 18;
+    const MIN_VERSION: u32 = 15;
 
-    let mut file = fs::File::open(path).ok()?;
+    let mut file = fs::File::open(path).map_err(|err| format!("open pak: {err}"))?;
     let mut id = [0u8; 4];
-    file.read_exact(&mut id).ok()?;
+    file.read_exact(&mut id)
+        .map_err(|err| format!("read pak header: {err}"))?;
     if &id != b"LSPK" {
-        return None;
+        return Err("not an LSPK archive".to_string());
     }
-    let version = read_u32(&mut file)?;
+    let version = read_u32(&mut file).ok_or("truncated pak header")?;
     if version < MIN_VERSION {
-        return None;
+        return Err(format!("unsupported LSPK version {version}"));
     }
-    let footer_offset = read_u64(&mut file)?;
-    let footer_offset = i64::try_from(footer_offset).ok()?;
-    file.seek(SeekFrom::Start(0)).ok()?;
-    file.seek(SeekFrom::Current(footer_offset)).ok()?;
+    let entry_len: usize = if version >= 18 { 272 } else { 296 };
+    let footer_offset = read_u64(&mut file).ok_or("truncated pak header")?;
+    let footer_size = read_u32(&mut file).ok_or("truncated pak header")? as usize;
+    file.seek(SeekFrom::Start(footer_offset))
+        .map_err(|err| format!("seek to file table: {err}"))?;
 
-    let file_count = read_u32(&mut file)? as usize;
-    let compressed_len = read_u32(&mut file)? as usize;
-    let decompressed_len = file_count.saturating_mul(ENTRY_LEN);
+    let file_count = read_u32(&mut file).ok_or("truncated file table")? as usize;
+    let compressed_len = if version >= 18 {
+        read_u32(&mut file).ok_or("truncated file table")? as usize
+    } else {
+        footer_size.saturating_sub(4)
+    };
+    let decompressed_len = file_count.saturating_mul(entry_len);
 
     let mut compressed = vec![0u8; compressed_len];
-    file.read_exact(&mut compressed).ok()?;
-    let table = match decompress(&compressed, decompressed_len) {
-        Ok(table) => table,
-        Err(_) => zstd_decompress(&compressed, decompressed_len).ok()?,
-    };
+    file.read_exact(&mut compressed)
+        .map_err(|err| format!("read file table: {err}"))?;
+    let table = decompress_pak_table(&compressed, decompressed_len)?;
 
     let mut out = Vec::new();
     for index in 0..file_count {
-        let start = index * ENTRY_LEN;
-        let end = start + ENTRY_LEN;
+        let start = index * entry_len;
+        let end = start + entry_len;
         if end > table.len() {
             break;
         }
@@ -657,30 +1060,43 @@ fn read_pak_index_entries(path: &Path) -> Option<Vec<PakIndexEntry>> {
         let raw_path = String::from_utf8_lossy(&entry[..path_end]);
         let path = normalize_path(&raw_path);
 
-        let offset_upper = u32::from_le_bytes(entry[256..260].try_into().unwrap_or([0; 4]));
-        let offset_lower = u16::from_le_bytes(entry[260..262].try_into().unwrap_or([0; 2]));
-        let offset = u64::from(offset_upper) | (u64::from(offset_lower) << 32);
-        let offset = offset & 0x000f_ffff_ffff_ffff;
-
-        let compression = match entry[263] & 0x0F {
-            0 => CompressionType::None,
-            1 => CompressionType::Zlib,
-            2 => CompressionType::Lz4,
-            _ => CompressionType::Zstd,
-        };
-        let compressed_size = u32::from_le_bytes(entry[264..268].try_into().unwrap_or([0; 4]));
-        let decompressed_size = u32::from_le_bytes(entry[268..272].try_into().unwrap_or([0; 4]));
+        let (offset, archive_part, compression, compressed_size, decompressed_size) =
+            if version >= 18 {
+                let offset_upper = u32::from_le_bytes(entry[256..260].try_into().unwrap_or([0; 4]));
+                let offset_lower = u16::from_le_bytes(entry[260..262].try_into().unwrap_or([0; 2]));
+                let offset = u64::from(offset_upper) | (u64::from(offset_lower) << 32);
+                (
+                    offset & 0x000f_ffff_ffff_ffff,
+                    u32::from(entry[262]),
+                    CompressionType::from_flags(entry[263]),
+                    u64::from(u32::from_le_bytes(
+                        entry[264..268].try_into().unwrap_or([0; 4]),
+                    )),
+                    u64::from(u32::from_le_bytes(
+                        entry[268..272].try_into().unwrap_or([0; 4]),
+                    )),
+                )
+            } else {
+                (
+                    u64::from_le_bytes(entry[256..264].try_into().unwrap_or([0; 8])),
+                    u32::from_le_bytes(entry[280..284].try_into().unwrap_or([0; 4])),
+                    CompressionType::from_flags(entry[284]),
+                    u64::from_le_bytes(entry[264..272].try_into().unwrap_or([0; 8])),
+                    u64::from_le_bytes(entry[272..280].try_into().unwrap_or([0; 8])),
+                )
+            };
 
         out.push(PakIndexEntry {
             path,
             offset,
+            archive_part,
             compressed_size,
             decompressed_size,
             compression,
         });
     }
 
-    Some(out)
+    Ok(out)
 }
 
 fn read_u32(file: &mut fs::File) -> Option<u32> {
@@ -728,3 +1144,48 @@ fn split_tags(value: &str) -> Vec<String> {
         .filter(|s| !s.is_empty())
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn version_compare_orders_by_major_minor_revision() {
+        let base = encode_version64(4, 1, 1, 0);
+        assert_eq!(version_compare(base, base), Ordering::Equal);
+        assert_eq!(
+            version_compare(encode_version64(4, 1, 2, 0), base),
+            Ordering::Greater
+        );
+        assert_eq!(
+            version_compare(encode_version64(4, 0, 9, 0), base),
+            Ordering::Less
+        );
+        assert_eq!(
+            version_compare(encode_version64(3, 9, 9, 0), base),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn version_compare_ignores_build() {
+        let pe_build = encode_version64(4, 1, 1, 0xFFFF);
+        let mod_build = encode_version64(4, 1, 1, 6_072_089);
+        assert_eq!(version_compare(pe_build, mod_build), Ordering::Equal);
+        assert!(game_version_supports(pe_build, mod_build));
+    }
+
+    #[test]
+    fn game_version_supports_rejects_older_installs() {
+        let required = parse_version_value("4.1.1.5022896").unwrap();
+        assert!(game_version_supports(
+            parse_version_value("4.1.1.1").unwrap(),
+            required
+        ));
+        assert!(!game_version_supports(
+            parse_version_value("4.1.0.9999999").unwrap(),
+            required
+        ));
+    }
+}
@@ -106,6 +106,7 @@ pub fn build_native_pak_index(larian_mods_dir: &Path) -> Vec<NativePakEntry> {
             .and_then(|ext| ext.to_str())
             .map(|ext| ext.eq_ignore_ascii_case("pak"))
             != Some(true)
+            || metadata::pak_part_base(&path).is_some()
         {
             continue;
         }
@@ -132,6 +133,7 @@ fn build_native_pak_meta_entries(
             .and_then(|ext| ext.to_str())
             .map(|ext| ext.eq_ignore_ascii_case("pak"))
             != Some(true)
+            || metadata::pak_part_base(&path).is_some()
         {
             continue;
         }
@@ -0,0 +1,300 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaletteAction {
+    SwitchProfile(String),
+    DeployProfile(String),
+    Deploy,
+    DeployLoadOrder,
+    RepairModsettings,
+    StageDeploy,
+    Rollback,
+    Import,
+    AdoptDevFolder,
+    ToggleDevWatch,
+    RenameMod,
+    ShowInstallTargets,
+    ToggleAllTargets,
+    SigilLinkRank,
+    SigilLinkRankScoped,
+    CompatibilityReport,
+    ExportMenu,
+    CopyModsettings,
+    ImportModList,
+    ToggleAutoDeploy,
+    ToggleAutomationPause,
+    ToggleSigilLinkRanking,
+    VerifySigilLinkCache,
+    CleanSigilLinkCache,
+    RestageMissingMods,
+    ClearFileOverrides,
+    ConflictRules,
+    OrderRules,
+    CheckLoadOrder,
+    PreviewToggleImpact,
+    RankHistory,
+    RevertLastRank,
+    ToggleSigilLinkExclusion,
+    ClearFilter,
+    ToggleRecentFilter,
+    ToggleStarredFilter,
+    ToggleStar,
+    RecomputeModSizes,
+    RefreshVisibleMetadata,
+    AdoptNative,
+    RestoreRemovedMods,
+    FreezeSort,
+    ResetProfileOrder,
+    NormalizeProfiles,
+    ShowPaths,
+    OpenDataDir,
+    OpenGameRoot,
+    OpenLarianDir,
+    SetupPaths,
+    Settings,
+    Help,
+    WhatsNew,
+    CheckUpdates,
+}
+
+impl PaletteAction {
+    pub fn id(&self) -> String {
+        match self {
+            PaletteAction::SwitchProfile(name) => format!("profile.switch:{name}"),
+            PaletteAction::DeployProfile(name) => format!("profile.deploy:{name}"),
+            PaletteAction::Deploy => "deploy".to_string(),
+            PaletteAction::DeployLoadOrder => "deploy.load_order".to_string(),
+            PaletteAction::RepairModsettings => "deploy.repair_modsettings".to_string(),
+            PaletteAction::StageDeploy => "deploy.stage".to_string(),
+            PaletteAction::Rollback => "deploy.rollback".to_string(),
+            PaletteAction::Import => "mods.import".to_string(),
+            PaletteAction::AdoptDevFolder => "mods.adopt_dev".to_string(),
+            PaletteAction::ToggleDevWatch => "mods.dev_watch".to_string(),
+            PaletteAction::RenameMod => "mods.rename".to_string(),
+            PaletteAction::ShowInstallTargets => "mods.targets".to_string(),
+            PaletteAction::ToggleAllTargets => "mods.targets.toggle_all".to_string(),
+            PaletteAction::SigilLinkRank => "sigillink.rank".to_string(),
+            PaletteAction::SigilLinkRankScoped => "sigillink.rank.scoped".to_string(),
+            PaletteAction::CompatibilityReport => "sigillink.report".to_string(),
+            PaletteAction::ExportMenu => "profile.export".to_string(),
+            PaletteAction::CopyModsettings => "profile.copy_modsettings".to_string(),
+            PaletteAction::ImportModList => "profile.import".to_string(),
+            PaletteAction::ToggleAutoDeploy => "settings.auto_deploy".to_string(),
+            PaletteAction::ToggleAutomationPause => "settings.pause_automation".to_string(),
+            PaletteAction::ToggleSigilLinkRanking => "settings.sigillink".to_string(),
+            PaletteAction::VerifySigilLinkCache => "sigillink.verify_cache".to_string(),
+            PaletteAction::CleanSigilLinkCache => "sigillink.cache_gc".to_string(),
+            PaletteAction::RestageMissingMods => "sigillink.restage_missing".to_string(),
+            PaletteAction::ClearFileOverrides => "overrides.clear_all".to_string(),
+            PaletteAction::ConflictRules => "overrides.ignore_rules".to_string(),
+            PaletteAction::OrderRules => "sigillink.order_rules".to_string(),
+            PaletteAction::CheckLoadOrder => "order.check".to_string(),
+            PaletteAction::PreviewToggleImpact => "mods.toggle_preview".to_string(),
+            PaletteAction::RankHistory => "sigillink.history".to_string(),
+            PaletteAction::RevertLastRank => "sigillink.history.revert".to_string(),
+            PaletteAction::ToggleSigilLinkExclusion => "sigillink.exclude".to_string(),
+            PaletteAction::ClearFilter => "mods.filter.clear".to_string(),
+            PaletteAction::ToggleRecentFilter => "mods.filter.recent".to_string(),
+            PaletteAction::ToggleStarredFilter => "mods.filter.starred".to_string(),
+            PaletteAction::ToggleStar => "mods.star".to_string(),
+            PaletteAction::RecomputeModSizes => "mods.sizes".to_string(),
+            PaletteAction::RefreshVisibleMetadata => "mods.metadata.refresh".to_string(),
+            PaletteAction::AdoptNative => "mods.adopt_native".to_string(),
+            PaletteAction::RestoreRemovedMods => "mods.trash.restore".to_string(),
+            PaletteAction::FreezeSort => "mods.sort.freeze".to_string(),
+            PaletteAction::ResetProfileOrder => "profile.reset_order".to_string(),
+            PaletteAction::NormalizeProfiles => "profile.normalize_all".to_string(),
+            PaletteAction::ShowPaths => "paths.show".to_string(),
+            PaletteAction::OpenDataDir => "paths.open_data".to_string(),
+            PaletteAction::OpenGameRoot => "paths.open_game_root".to_string(),
+            PaletteAction::OpenLarianDir => "paths.open_larian".to_string(),
+            PaletteAction::SetupPaths => "paths.setup".to_string(),
+            PaletteAction::Settings => "settings.open".to_string(),
+            PaletteAction::Help => "help".to_string(),
+            PaletteAction::WhatsNew => "whats_new".to_string(),
+            PaletteAction::CheckUpdates => "update.check".to_string(),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            PaletteAction::SwitchProfile(name) => format!("Switch To Profile: {name}"),
+            PaletteAction::DeployProfile(name) => {
+                format!("Deploy Profile Without Switching: {name}")
+            }
+            PaletteAction::Deploy => "Deploy Now".to_string(),
+            PaletteAction::DeployLoadOrder => {
+                "Deploy Load Order Only (Skip Loose Files)".to_string()
+            }
+            PaletteAction::RepairModsettings => "Repair modsettings.lsx From Profile".to_string(),
+            PaletteAction::StageDeploy => "Deploy To Staging Folder (Test Output)".to_string(),
+            PaletteAction::Rollback => "Rollback Last Backup".to_string(),
+            PaletteAction::Import => "Import Mod".to_string(),
+            PaletteAction::AdoptDevFolder => "Adopt Dev Folder (Live Link)".to_string(),
+            PaletteAction::ToggleDevWatch => "Toggle Dev Watch (Auto Redeploy)".to_string(),
+            PaletteAction::RenameMod => "Rename Mod".to_string(),
+            PaletteAction::ShowInstallTargets => "Show Install Targets".to_string(),
+            PaletteAction::ToggleAllTargets => "Toggle All Targets Of Selected Mod".to_string(),
+            PaletteAction::SigilLinkRank => "Run SigiLink Ranking".to_string(),
+            PaletteAction::SigilLinkRankScoped => {
+                "Run SigiLink Ranking On Selected/Filtered Mods".to_string()
+            }
+            PaletteAction::CompatibilityReport => "Compatibility Report".to_string(),
+            PaletteAction::ExportMenu => "Export Mod List".to_string(),
+            PaletteAction::CopyModsettings => "Copy modsettings.lsx To Clipboard".to_string(),
+            PaletteAction::ImportModList => "Import Mod List".to_string(),
+            PaletteAction::ToggleAutoDeploy => "Toggle Auto Deploy".to_string(),
+            PaletteAction::ToggleAutomationPause => "Pause/Resume All Automation".to_string(),
+            PaletteAction::ToggleSigilLinkRanking => "Toggle SigiLink Auto Ranking".to_string(),
+            PaletteAction::VerifySigilLinkCache => "Verify SigiLink Cache Integrity".to_string(),
+            PaletteAction::CleanSigilLinkCache => "Clean Up Orphaned SigiLink Cache".to_string(),
+            PaletteAction::RestageMissingMods => "Re-stage Missing Mods From Source".to_string(),
+            PaletteAction::ClearFileOverrides => "Clear All File Overrides".to_string(),
+            PaletteAction::ConflictRules => "Edit Conflict Ignore Rules".to_string(),
+            PaletteAction::OrderRules => "Edit SigiLink Ordering Rules".to_string(),
+            PaletteAction::CheckLoadOrder => "Check Load Order (Validate Placement)".to_string(),
+            PaletteAction::PreviewToggleImpact => {
+                "Preview Toggle Impact (Override Changes)".to_string()
+            }
+            PaletteAction::RankHistory => "SigiLink Ranking History".to_string(),
+            PaletteAction::RevertLastRank => {
+                "Revert To Order Before Last SigiLink Rank".to_string()
+            }
+            PaletteAction::ToggleSigilLinkExclusion => {
+                "Toggle Never Auto-Rank For Selected Mod (All Profiles)".to_string()
+            }
+            PaletteAction::ClearFilter => "Clear Mod Filter".to_string(),
+            PaletteAction::ToggleRecentFilter => "Toggle Recently Added Filter".to_string(),
+            PaletteAction::ToggleStarredFilter => "Toggle Starred Filter".to_string(),
+            PaletteAction::ToggleStar => "Star/Unstar Selected Mod".to_string(),
+            PaletteAction::RecomputeModSizes => "Recompute Mod Sizes".to_string(),
+            PaletteAction::RefreshVisibleMetadata => "Refresh Visible Metadata".to_string(),
+            PaletteAction::AdoptNative => "Adopt Selected Native Mod Into Cache".to_string(),
+            PaletteAction::RestoreRemovedMods => "Restore Recently Removed Mods".to_string(),
+            PaletteAction::FreezeSort => "Freeze Current Sort Into Load Order".to_string(),
+            PaletteAction::ResetProfileOrder => "Reset Profile Order To Default".to_string(),
+            PaletteAction::NormalizeProfiles => "Normalize All Profiles".to_string(),
+            PaletteAction::ShowPaths => "Display SigilSmith Paths".to_string(),
+            PaletteAction::OpenDataDir => "Open Data/Log Folder".to_string(),
+            PaletteAction::OpenGameRoot => "Open Game Root Folder".to_string(),
+            PaletteAction::OpenLarianDir => "Open Larian User Folder".to_string(),
+            PaletteAction::SetupPaths => "Configure Game Paths".to_string(),
+            PaletteAction::Settings => "Open Settings".to_string(),
+            PaletteAction::Help => "Help".to_string(),
+            PaletteAction::WhatsNew => "What's New?".to_string(),
+            PaletteAction::CheckUpdates => "Check For Updates".to_string(),
+        }
+    }
+
+    pub fn blocked_when_busy(&self) -> bool {
+        matches!(
+            self,
+            PaletteAction::SwitchProfile(_)
+                | PaletteAction::DeployProfile(_)
+                | PaletteAction::Deploy
+                | PaletteAction::DeployLoadOrder
+                | PaletteAction::RepairModsettings
+                | PaletteAction::StageDeploy
+                | PaletteAction::Rollback
+                | PaletteAction::SigilLinkRank
+                | PaletteAction::SigilLinkRankScoped
+                | PaletteAction::CompatibilityReport
+                | PaletteAction::ImportModList
+                | PaletteAction::AdoptDevFolder
+                | PaletteAction::ClearFileOverrides
+                | PaletteAction::VerifySigilLinkCache
+                | PaletteAction::CleanSigilLinkCache
+                | PaletteAction::RestageMissingMods
+                | PaletteAction::RefreshVisibleMetadata
+                | PaletteAction::AdoptNative
+                | PaletteAction::RestoreRemovedMods
+                | PaletteAction::FreezeSort
+                | PaletteAction::ResetProfileOrder
+                | PaletteAction::NormalizeProfiles
+                | PaletteAction::RankHistory
+                | PaletteAction::RevertLastRank
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PaletteEntry {
+    pub action: PaletteAction,
+    pub label: String,
+    pub disabled: Option<String>,
+}
+
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let chars: Vec<char> = candidate.chars().collect();
+    let mut score = 0i64;
+    let mut matched = 0usize;
+    let mut last_match: Option<usize> = None;
+    for (index, ch) in chars.iter().enumerate() {
+        if matched == query.len() {
+            break;
+        }
+        if !ch.to_lowercase().eq(std::iter::once(query[matched])) {
+            continue;
+        }
+        score += 1;
+        if last_match.is_some_and(|last| last + 1 == index) {
+            score += 5;
+        }
+        let word_start = index == 0 || !chars[index - 1].is_alphanumeric();
+        if word_start {
+            score += 8;
+        }
+        if matched == 0 {
+            score -= index.min(10) as i64;
+        }
+        last_match = Some(index);
+        matched += 1;
+    }
+
+    (matched == query.len()).then_some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_score_requires_every_query_char_in_order() {
+        assert_eq!(fuzzy_score("", "Deploy"), Some(0));
+        assert_eq!(fuzzy_score("   ", "Deploy"), Some(0));
+        assert!(fuzzy_score("dpl", "Deploy").is_some());
+        assert!(fuzzy_score("DEPLOY", "deploy").is_some());
+        assert!(fuzzy_score("yold", "Deploy").is_none());
+        assert!(fuzzy_score("deployx", "Deploy").is_none());
+    }
+
+    #[test]
+    fn fuzzy_score_prefers_word_starts_and_runs() {
+        let initials = fuzzy_score("ec", "Export Cache").unwrap();
+        let scattered = fuzzy_score("ec", "Select").unwrap();
+        assert!(initials > scattered);
+
+        let run = fuzzy_score("cache", "Verify Cache").unwrap();
+        let split = fuzzy_score("cache", "Clean Archive").unwrap();
+        assert!(run > split);
+
+        let early = fuzzy_score("rank", "Rank History").unwrap();
+        let late = fuzzy_score("rank", "Revert Last Rank").unwrap();
+        assert!(early > late);
+    }
+
+    #[test]
+    fn fuzzy_score_ignores_query_whitespace() {
+        assert_eq!(
+            fuzzy_score("rank hist", "Rank History"),
+            fuzzy_score("rankhist", "Rank History")
+        );
+    }
+}
@@ -21,6 +21,8 @@ pub struct SigilLinkEntry {
     pub kind: TargetKind,
     pub relative_path: String,
     pub size: u64,
+    #[serde(default)]
+    pub hash: Option<String>,
 }
 
 pub fn sigillink_root(cache_root: &Path) -> PathBuf {
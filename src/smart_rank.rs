@@ -1,7 +1,9 @@
 use crate::{
     config::GameConfig,
     game,
-    library::{library_mod_root, InstallTarget, Library, ModEntry, ProfileEntry, TargetKind},
+    library::{
+        library_mod_root, InstallTarget, Library, ModEntry, OrderRule, ProfileEntry, TargetKind,
+    },
     metadata, native_pak,
 };
 use anyhow::{Context, Result};
@@ -67,6 +69,48 @@ pub struct SmartRankResult {
     pub report: SmartRankReport,
     pub warnings: Vec<String>,
     pub explain: SmartRankExplain,
+    #[serde(default)]
+    pub weights: SigilLinkWeights,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SigilLinkWeights {
+    pub conflict: u8,
+    pub data_relevance: u8,
+    pub framework: u8,
+    pub patch: u8,
+}
+
+impl Default for SigilLinkWeights {
+    fn default() -> Self {
+        Self {
+            conflict: 1,
+            data_relevance: 0,
+            framework: 0,
+            patch: 0,
+        }
+    }
+}
+
+impl SigilLinkWeights {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "conflict {}, data relevance {}, framework {}, patch {}",
+            self.conflict, self.data_relevance, self.framework, self.patch
+        )
+    }
+
+    fn score(&self, item: &RankItem) -> i64 {
+        i64::from(self.conflict) * item.conflict_partners as i64
+            + i64::from(self.data_relevance) * item.relevant_conflicts as i64
+            + i64::from(self.framework) * item.dependents as i64
+            - i64::from(self.patch) * i64::from(item.patch_score)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -88,6 +132,8 @@ pub struct SmartRankModCache {
     pub patch_reasons: Vec<String>,
     pub date_hint: i64,
     pub warning: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -102,7 +148,7 @@ pub struct SmartRankComputed {
     pub scanned_mods: usize,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum RankGroup {
     Loose,
     Pak,
@@ -124,13 +170,16 @@ struct RankItem {
     total_bytes: u64,
     conflict_files: usize,
     conflict_partners: usize,
+    relevant_conflicts: usize,
     original_index: usize,
     has_data: bool,
     patch_score: u8,
     patch_reasons: Vec<String>,
     dependencies: Vec<String>,
+    after: Vec<String>,
     dependents: usize,
     date_hint: i64,
+    tags: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -151,7 +200,50 @@ pub enum ExplainLineKind {
     Muted,
 }
 
-const MOD_CACHE_KEY_VERSION: &str = "mod-cache-v3-pak-meta-index";
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum OverlapSeverity {
+    Cosmetic,
+    Data,
+    Logic,
+}
+
+impl OverlapSeverity {
+    pub fn label(self) -> &'static str {
+        match self {
+            OverlapSeverity::Cosmetic => "Cosmetic",
+            OverlapSeverity::Data => "Game Data",
+            OverlapSeverity::Logic => "Stats/Scripts",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompatibilityOverlap {
+    pub other_id: String,
+    pub other_name: String,
+    pub severity: OverlapSeverity,
+    pub wins: bool,
+    pub paths: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompatibilityEntry {
+    pub id: String,
+    pub name: String,
+    pub severity: OverlapSeverity,
+    pub overlap_files: usize,
+    pub overlaps: Vec<CompatibilityOverlap>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompatibilityReport {
+    pub profile: String,
+    pub scanned: usize,
+    pub unscanned: Vec<String>,
+    pub entries: Vec<CompatibilityEntry>,
+}
+
+const MOD_CACHE_KEY_VERSION: &str = "mod-cache-v4-tags";
 
 pub fn mod_cache_key(mod_entry: &ModEntry) -> String {
     let mut hasher = Hasher::new();
@@ -174,7 +266,7 @@ pub fn mod_cache_key(mod_entry: &ModEntry) -> String {
     let mut targets: Vec<String> = Vec::new();
     for target in &mod_entry.targets {
         let key = match target {
-            InstallTarget::Pak { file, info } => {
+            InstallTarget::Pak { file, info, .. } => {
                 format!("pak|{}|{}|{}", file, info.uuid, info.folder)
             }
             InstallTarget::Generated { dir } => format!("gen|{dir}"),
@@ -208,6 +300,7 @@ where
         library,
         None,
         SmartRankRefreshMode::Full,
+        SigilLinkWeights::default(),
         &mut progress,
     )?;
     Ok(computed.result)
@@ -218,6 +311,7 @@ pub fn smart_rank_profile_cached_with_progress<F>(
     library: &Library,
     cache: Option<&SmartRankCacheData>,
     refresh: SmartRankRefreshMode,
+    weights: SigilLinkWeights,
     mut progress: F,
 ) -> Result<SmartRankComputed>
 where
@@ -251,16 +345,7 @@ where
         let Some(mod_entry) = mod_map.get(&entry.id) else {
             continue;
         };
-        let has_loose = mod_entry
-            .targets
-            .iter()
-            .any(|target| !matches!(target, InstallTarget::Pak { .. }));
-        let group = if has_loose {
-            RankGroup::Loose
-        } else {
-            RankGroup::Pak
-        };
-        group_by_id.insert(entry.id.clone(), group);
+        group_by_id.insert(entry.id.clone(), rank_group(mod_entry));
     }
 
     let mut missing_pak_ids = HashSet::new();
@@ -382,6 +467,7 @@ where
         let mut dependencies = Vec::new();
         let mut patch_value = 0u8;
         let mut patch_notes = Vec::new();
+        let mut tags = Vec::new();
         let mut date_hint = mod_entry
             .created_at
             .or(mod_entry.modified_at)
@@ -398,6 +484,7 @@ where
                 patch_value = entry_cache.patch_score;
                 patch_notes = entry_cache.patch_reasons.clone();
                 date_hint = entry_cache.date_hint;
+                tags = entry_cache.tags.clone();
                 warning = warning.or_else(|| entry_cache.warning.clone());
             }
         } else {
@@ -424,6 +511,16 @@ where
             }
         }
 
+        let after = profile
+            .order_rules
+            .iter()
+            .filter_map(|rule| match rule {
+                OrderRule::LoadAfter { mod_id, after_id } if *mod_id == entry.id => {
+                    Some(after_id.clone())
+                }
+                _ => None,
+            })
+            .collect();
         let file_count = file_paths.len();
         items.push(RankItem {
             id: entry.id.clone(),
@@ -434,13 +531,16 @@ where
             total_bytes,
             conflict_files: 0,
             conflict_partners: 0,
+            relevant_conflicts: 0,
             original_index: index,
             has_data,
             patch_score: patch_value,
             patch_reasons: patch_notes,
             dependencies,
+            after,
             dependents: 0,
             date_hint,
+            tags,
         });
     }
 
@@ -494,13 +594,18 @@ where
             if !item.enabled || !item.has_data {
                 item.conflict_files = 0;
                 item.conflict_partners = 0;
+                item.relevant_conflicts = 0;
                 continue;
             }
             let mut conflict_files = 0usize;
+            let mut relevant_conflicts = 0usize;
             let mut partners = HashSet::new();
             for path in &item.file_paths {
                 if path_counts.get(path).copied().unwrap_or(0) > 1 {
                     conflict_files += 1;
+                    if !matches!(overlap_severity(path), OverlapSeverity::Cosmetic) {
+                        relevant_conflicts += 1;
+                    }
                     if let Some(mods) = path_mods.get(path) {
                         for id in mods {
                             if id != &item.id {
@@ -512,11 +617,26 @@ where
             }
             item.conflict_files = conflict_files;
             item.conflict_partners = partners.len();
+            item.relevant_conflicts = relevant_conflicts;
         }
     }
 
-    let loose_order = rank_group_order(&items, RankGroup::Loose, &mod_map, &mut warnings);
-    let pak_order = rank_group_order(&items, RankGroup::Pak, &mod_map, &mut warnings);
+    let loose_order = rank_group_order(
+        &items,
+        RankGroup::Loose,
+        &mod_map,
+        weights,
+        &profile.order_rules,
+        &mut warnings,
+    );
+    let pak_order = rank_group_order(
+        &items,
+        RankGroup::Pak,
+        &mod_map,
+        weights,
+        &profile.order_rules,
+        &mut warnings,
+    );
     let mut new_ids = Vec::new();
     new_ids.extend(loose_order);
     new_ids.extend(pak_order);
@@ -541,7 +661,37 @@ where
         .filter(|(a, b)| a.id != b.id)
         .count();
 
-    let explain = build_explain_lines(&items, &top_paths, &mod_map, profile);
+    let tags_by_id: HashMap<String, Vec<String>> = items
+        .iter()
+        .map(|item| (item.id.clone(), item.tags.clone()))
+        .collect();
+    let violations = order_rule_violations(&profile.order_rules, &new_order, &mod_map, &tags_by_id);
+    for violation in &violations {
+        warnings.push(format!("SigiLink ordering rule not satisfied: {violation}"));
+    }
+    let mut explain = build_explain_lines(&items, &top_paths, &mod_map, profile, weights);
+    if !profile.order_rules.is_empty() {
+        explain.lines.push(SmartRankExplainLine {
+            kind: ExplainLineKind::Header,
+            text: "Ordering rules".to_string(),
+        });
+        for rule in &profile.order_rules {
+            let label = rule.label(&mod_map);
+            let broken = violations.contains(&label);
+            explain.lines.push(SmartRankExplainLine {
+                kind: if broken {
+                    ExplainLineKind::Item
+                } else {
+                    ExplainLineKind::Muted
+                },
+                text: if broken {
+                    format!("{label} — not satisfied")
+                } else {
+                    format!("{label} — ok")
+                },
+            });
+        }
+    }
 
     let result = SmartRankResult {
         order: new_order,
@@ -562,6 +712,7 @@ where
         },
         warnings,
         explain,
+        weights,
     };
 
     Ok(SmartRankComputed {
@@ -571,33 +722,83 @@ where
     })
 }
 
+fn rank_group(mod_entry: &ModEntry) -> RankGroup {
+    let has_loose = mod_entry
+        .targets
+        .iter()
+        .any(|target| !matches!(target, InstallTarget::Pak { .. }));
+    if has_loose {
+        RankGroup::Loose
+    } else {
+        RankGroup::Pak
+    }
+}
+
+fn last_tags(rules: &[OrderRule]) -> Vec<&str> {
+    rules
+        .iter()
+        .filter_map(|rule| match rule {
+            OrderRule::TagLast { tag } => Some(tag.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
 fn rank_group_order(
     items: &[RankItem],
     group: RankGroup,
     mod_map: &HashMap<String, ModEntry>,
+    weights: SigilLinkWeights,
+    rules: &[OrderRule],
     warnings: &mut Vec<String>,
 ) -> Vec<String> {
     let mut group_items: Vec<&RankItem> = items.iter().filter(|item| item.group == group).collect();
     group_items.sort_by_key(|item| item.original_index);
 
+    let last_tags = last_tags(rules);
+    let last_ids: HashSet<String> = group_items
+        .iter()
+        .filter(|item| {
+            last_tags
+                .iter()
+                .any(|tag| OrderRule::tag_matches(tag, &item.tags))
+        })
+        .map(|item| item.id.clone())
+        .collect();
+
     let group_ids: HashSet<String> = group_items.iter().map(|item| item.id.clone()).collect();
     let mut reorder_set: HashSet<String> = group_items
         .iter()
         .filter(|item| {
             (item.enabled && item.has_data && item.conflict_files > 0)
                 || !item.dependencies.is_empty()
+                || !item.after.is_empty()
         })
         .map(|item| item.id.clone())
         .collect();
     for item in &group_items {
-        for dep in &item.dependencies {
+        for dep in item.dependencies.iter().chain(&item.after) {
             if group_ids.contains(dep) {
                 reorder_set.insert(dep.clone());
             }
         }
     }
+    // Tagged mods rank last inside the reorder set, so the set must also own the
+    // group's tail slots for them to land at the end of the group.
+    if !last_ids.is_empty() {
+        reorder_set.extend(last_ids.iter().cloned());
+        let tail = group_items.len().saturating_sub(last_ids.len());
+        reorder_set.extend(group_items[tail..].iter().map(|item| item.id.clone()));
+    }
 
-    let mut ranked = topological_rank(group_items.as_slice(), &reorder_set, mod_map, warnings);
+    let mut ranked = topological_rank(
+        group_items.as_slice(),
+        &reorder_set,
+        &last_ids,
+        mod_map,
+        weights,
+        warnings,
+    );
     let mut ranked_iter = ranked.drain(..);
     let mut out = Vec::new();
     for item in &group_items {
@@ -614,10 +815,58 @@ fn rank_group_order(
     out
 }
 
+pub fn order_rule_violations(
+    rules: &[OrderRule],
+    order: &[ProfileEntry],
+    mod_map: &HashMap<String, ModEntry>,
+    tags_by_id: &HashMap<String, Vec<String>>,
+) -> Vec<String> {
+    let positions: HashMap<&str, usize> = order
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| (entry.id.as_str(), index))
+        .collect();
+    rules
+        .iter()
+        .filter(|rule| match rule {
+            OrderRule::LoadAfter { mod_id, after_id } => {
+                match (
+                    positions.get(mod_id.as_str()),
+                    positions.get(after_id.as_str()),
+                ) {
+                    (Some(mod_index), Some(after_index)) => mod_index < after_index,
+                    _ => false,
+                }
+            }
+            OrderRule::TagLast { tag } => {
+                let mut tagged_seen: HashSet<RankGroup> = HashSet::new();
+                order.iter().any(|entry| {
+                    let Some(mod_entry) = mod_map.get(&entry.id) else {
+                        return false;
+                    };
+                    let group = rank_group(mod_entry);
+                    let tagged = tags_by_id
+                        .get(&entry.id)
+                        .is_some_and(|tags| OrderRule::tag_matches(tag, tags));
+                    if tagged {
+                        tagged_seen.insert(group);
+                        false
+                    } else {
+                        tagged_seen.contains(&group)
+                    }
+                })
+            }
+        })
+        .map(|rule| rule.label(mod_map))
+        .collect()
+}
+
 fn topological_rank<'a>(
     items: &'a [&'a RankItem],
     reorder_set: &HashSet<String>,
+    last_ids: &HashSet<String>,
     mod_map: &HashMap<String, ModEntry>,
+    weights: SigilLinkWeights,
     warnings: &mut Vec<String>,
 ) -> Vec<&'a RankItem> {
     let mut indegree: HashMap<String, usize> = HashMap::new();
@@ -645,6 +894,15 @@ fn topological_rank<'a>(
             edges.entry(dep.clone()).or_default().push(item.id.clone());
             *indegree.entry(item.id.clone()).or_insert(0) += 1;
         }
+        for after in &item.after {
+            if reorder_set.contains(after) {
+                edges
+                    .entry(after.clone())
+                    .or_default()
+                    .push(item.id.clone());
+                *indegree.entry(item.id.clone()).or_insert(0) += 1;
+            }
+        }
     }
 
     let mut available: Vec<&RankItem> = items
@@ -657,7 +915,14 @@ fn topological_rank<'a>(
     let mut result = Vec::new();
     let mut remaining = reorder_set.len();
     while !available.is_empty() {
-        available.sort_by(|a, b| compare_rank_items(a, b));
+        // "<tag> last" rules: a tagged mod is only placed once no untagged mod is
+        // ready, so dependencies still win over the rule instead of forming a cycle.
+        available.sort_by(|a, b| {
+            last_ids
+                .contains(&a.id)
+                .cmp(&last_ids.contains(&b.id))
+                .then_with(|| compare_rank_items(a, b, weights))
+        });
         let next = available.remove(0);
         result.push(next);
         remaining = remaining.saturating_sub(1);
@@ -686,14 +951,23 @@ fn topological_rank<'a>(
             .filter(|item| reorder_set.contains(&item.id))
             .copied()
             .collect();
-        fallback.sort_by(|a, b| compare_rank_items(a, b));
+        fallback.sort_by(|a, b| {
+            last_ids
+                .contains(&a.id)
+                .cmp(&last_ids.contains(&b.id))
+                .then_with(|| compare_rank_items(a, b, weights))
+        });
         return fallback;
     }
 
     result
 }
 
-fn compare_rank_items(a: &RankItem, b: &RankItem) -> std::cmp::Ordering {
+fn compare_rank_items(a: &RankItem, b: &RankItem, weights: SigilLinkWeights) -> std::cmp::Ordering {
+    let score = weights.score(b).cmp(&weights.score(a));
+    if score != std::cmp::Ordering::Equal {
+        return score;
+    }
     let partners = b.conflict_partners.cmp(&a.conflict_partners);
     if partners != std::cmp::Ordering::Equal {
         return partners;
@@ -787,6 +1061,8 @@ fn scan_mod_cache_entry(
     }
 
     let (patch_score, patch_reasons) = patch_score(mod_entry, &tags);
+    tags.sort();
+    tags.dedup();
     let date_hint = mod_entry
         .created_at
         .or(meta_created)
@@ -808,6 +1084,7 @@ fn scan_mod_cache_entry(
         patch_reasons,
         date_hint,
         warning,
+        tags,
     }
 }
 
@@ -909,7 +1186,7 @@ fn collect_pak_paths(
     let mut pak_paths = Vec::new();
 
     for target in &mod_entry.targets {
-        if let InstallTarget::Pak { file, info } = target {
+        if let InstallTarget::Pak { file, info, .. } = target {
             if mod_entry.is_native() {
                 let by_folder = larian_mods_dir.join(format!("{}.pak", info.folder));
                 if by_folder.exists() {
@@ -1003,7 +1280,8 @@ fn scan_pak_index(path: &Path) -> Result<Vec<FileEntry>> {
 fn patch_score(mod_entry: &ModEntry, tags: &[String]) -> (u8, Vec<String>) {
     let mut score = 0u8;
     let mut reasons = Vec::new();
-    let label = mod_entry.display_name().to_ascii_lowercase();
+    // Custom names are display-only and not part of the cache key, so score the real name.
+    let label = mod_entry.original_display_name().to_ascii_lowercase();
     for (keyword, weight) in patch_keywords() {
         if label.contains(keyword) {
             score = score.saturating_add(weight);
@@ -1048,6 +1326,7 @@ fn build_explain_lines(
     paths: &[ConflictPathInfo],
     mod_map: &HashMap<String, ModEntry>,
     profile: &crate::library::Profile,
+    weights: SigilLinkWeights,
 ) -> SmartRankExplain {
     let mut lines = Vec::new();
     let mut ordered_ids = HashMap::new();
@@ -1055,6 +1334,23 @@ fn build_explain_lines(
         ordered_ids.insert(entry.id.clone(), index);
     }
 
+    lines.push(SmartRankExplainLine {
+        kind: ExplainLineKind::Header,
+        text: "Ranking weights".to_string(),
+    });
+    lines.push(SmartRankExplainLine {
+        kind: if weights.is_default() {
+            ExplainLineKind::Muted
+        } else {
+            ExplainLineKind::Item
+        },
+        text: if weights.is_default() {
+            format!("{} (defaults)", weights.summary())
+        } else {
+            weights.summary()
+        },
+    });
+
     lines.push(SmartRankExplainLine {
         kind: ExplainLineKind::Header,
         text: "Top conflicts".to_string(),
@@ -1182,6 +1478,130 @@ fn build_explain_lines(
     SmartRankExplain { lines }
 }
 
+pub fn build_compatibility_report(
+    library: &Library,
+    cache: &SmartRankCacheData,
+) -> Result<CompatibilityReport> {
+    let profile = library.active_profile().context("active profile not set")?;
+    let mod_map = library.index_by_id();
+    let mut scanned: Vec<(usize, &str, &SmartRankModCache)> = Vec::new();
+    let mut unscanned = Vec::new();
+    for (index, entry) in profile.order.iter().enumerate() {
+        if !entry.enabled {
+            continue;
+        }
+        let Some(mod_entry) = mod_map.get(&entry.id) else {
+            continue;
+        };
+        let key = mod_cache_key(mod_entry);
+        match cache.mods.get(&entry.id) {
+            Some(entry_cache) if entry_cache.key == key && entry_cache.has_data => {
+                scanned.push((index, entry.id.as_str(), entry_cache));
+            }
+            _ => unscanned.push(mod_entry.display_name()),
+        }
+    }
+
+    let mut path_mods: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (slot, (_, _, entry_cache)) in scanned.iter().enumerate() {
+        for path in &entry_cache.file_paths {
+            path_mods.entry(path.as_str()).or_default().push(slot);
+        }
+    }
+    let mut pair_paths: HashMap<(usize, usize), Vec<String>> = HashMap::new();
+    for (path, slots) in path_mods.iter().filter(|(_, slots)| slots.len() > 1) {
+        for (pos, a) in slots.iter().enumerate() {
+            for b in &slots[pos + 1..] {
+                let key = ((*a).min(*b), (*a).max(*b));
+                pair_paths.entry(key).or_default().push((*path).to_string());
+            }
+        }
+    }
+
+    let mut overlaps_by_slot: HashMap<usize, Vec<CompatibilityOverlap>> = HashMap::new();
+    for ((a, b), mut paths) in pair_paths {
+        paths.sort();
+        let severity = paths
+            .iter()
+            .map(|path| overlap_severity(path))
+            .max()
+            .unwrap_or(OverlapSeverity::Cosmetic);
+        for (this, other) in [(a, b), (b, a)] {
+            let (this_index, _, _) = scanned[this];
+            let (other_index, other_id, _) = scanned[other];
+            overlaps_by_slot
+                .entry(this)
+                .or_default()
+                .push(CompatibilityOverlap {
+                    other_id: other_id.to_string(),
+                    other_name: display_mod_name(other_id, &mod_map),
+                    severity,
+                    wins: this_index > other_index,
+                    paths: paths.clone(),
+                });
+        }
+    }
+
+    let mut entries = Vec::new();
+    for (slot, mut overlaps) in overlaps_by_slot {
+        let (_, id, _) = scanned[slot];
+        overlaps.sort_by(|a, b| {
+            b.severity
+                .cmp(&a.severity)
+                .then_with(|| b.paths.len().cmp(&a.paths.len()))
+                .then_with(|| a.other_name.cmp(&b.other_name))
+        });
+        let severity = overlaps
+            .iter()
+            .map(|overlap| overlap.severity)
+            .max()
+            .unwrap_or(OverlapSeverity::Cosmetic);
+        let overlap_files = overlaps
+            .iter()
+            .flat_map(|overlap| overlap.paths.iter())
+            .collect::<HashSet<_>>()
+            .len();
+        entries.push(CompatibilityEntry {
+            id: id.to_string(),
+            name: display_mod_name(id, &mod_map),
+            severity,
+            overlap_files,
+            overlaps,
+        });
+    }
+    entries.sort_by(|a, b| {
+        b.severity
+            .cmp(&a.severity)
+            .then_with(|| b.overlap_files.cmp(&a.overlap_files))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    Ok(CompatibilityReport {
+        profile: profile.name.clone(),
+        scanned: scanned.len(),
+        unscanned,
+        entries,
+    })
+}
+
+pub fn overlap_severity(path: &str) -> OverlapSeverity {
+    let path = path.split_once(':').map(|(_, rest)| rest).unwrap_or(path);
+    let logic_dirs = ["stats/", "story/", "scripts/", "goals/", "scriptextender/"];
+    let logic_exts = [".lua", ".khn", ".osi"];
+    if logic_dirs
+        .iter()
+        .any(|dir| path.starts_with(dir) || path.contains(&format!("/{dir}")))
+        || logic_exts.iter().any(|ext| path.ends_with(ext))
+    {
+        return OverlapSeverity::Logic;
+    }
+    let data_exts = [".lsx", ".lsf", ".lsj", ".lsb", ".lsbc", ".lsbs"];
+    if data_exts.iter().any(|ext| path.ends_with(ext)) {
+        return OverlapSeverity::Data;
+    }
+    OverlapSeverity::Cosmetic
+}
+
 fn display_mod_name(id: &str, mod_map: &HashMap<String, ModEntry>) -> String {
     mod_map
         .get(id)
@@ -1227,3 +1647,126 @@ fn target_kind_label(kind: TargetKind) -> &'static str {
         TargetKind::Bin => "bin",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mod_entry(id: &str) -> ModEntry {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": id,
+            "added_at": 0,
+            "targets": [],
+        }))
+        .unwrap()
+    }
+
+    fn rank_item(id: &str, index: usize, tags: &[&str], dependencies: &[&str]) -> RankItem {
+        RankItem {
+            id: id.to_string(),
+            enabled: true,
+            group: RankGroup::Pak,
+            file_paths: HashSet::new(),
+            file_count: 0,
+            total_bytes: 0,
+            conflict_files: 0,
+            conflict_partners: 0,
+            relevant_conflicts: 0,
+            original_index: index,
+            has_data: true,
+            patch_score: 0,
+            patch_reasons: Vec::new(),
+            dependencies: dependencies.iter().map(|dep| dep.to_string()).collect(),
+            after: Vec::new(),
+            dependents: 0,
+            date_hint: 0,
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        }
+    }
+
+    fn rank(items: &[RankItem], rules: &[OrderRule]) -> Vec<String> {
+        let mod_map = items
+            .iter()
+            .map(|item| (item.id.clone(), mod_entry(&item.id)))
+            .collect();
+        let mut warnings = Vec::new();
+        rank_group_order(
+            items,
+            RankGroup::Pak,
+            &mod_map,
+            SigilLinkWeights::default(),
+            rules,
+            &mut warnings,
+        )
+    }
+
+    fn patch_last() -> Vec<OrderRule> {
+        vec![OrderRule::TagLast {
+            tag: "patch".to_string(),
+        }]
+    }
+
+    #[test]
+    fn patch_score_ignores_custom_names() {
+        let mut patch = mod_entry("Armor Patch");
+        patch.custom_name = Some("My Armor".to_string());
+        assert!(patch_score(&patch, &[]).0 > 0);
+
+        let mut plain = mod_entry("Armor");
+        plain.custom_name = Some("Armor patch".to_string());
+        assert_eq!(patch_score(&plain, &[]).0, 0);
+    }
+
+    #[test]
+    fn tag_matches_whole_tags_only() {
+        let tags = vec!["Patch ".to_string(), "Patches".to_string()];
+        assert!(OrderRule::tag_matches("patch", &tags));
+        assert!(!OrderRule::tag_matches("patches-and-fixes", &tags));
+        assert!(!OrderRule::tag_matches("pat", &tags));
+    }
+
+    #[test]
+    fn tag_last_moves_tagged_mods_to_group_end() {
+        let items = vec![
+            rank_item("a", 0, &["Patch"], &[]),
+            rank_item("b", 1, &[], &[]),
+            rank_item("c", 2, &[], &[]),
+        ];
+        // "b" is not in the reorder set, so it keeps its slot.
+        assert_eq!(rank(&items, &patch_last()), vec!["c", "b", "a"]);
+    }
+
+    #[test]
+    fn tag_last_yields_to_dependencies() {
+        let items = vec![
+            rank_item("a", 0, &["patch"], &[]),
+            rank_item("b", 1, &[], &["a"]),
+            rank_item("c", 2, &[], &[]),
+        ];
+        let order = rank(&items, &patch_last());
+        let position = |id: &str| order.iter().position(|entry| entry == id).unwrap();
+        assert!(position("a") < position("b"));
+        assert!(position("c") < position("a"));
+    }
+
+    #[test]
+    fn order_rule_violations_reports_tag_last() {
+        let mod_map: HashMap<String, ModEntry> = ["a", "b"]
+            .iter()
+            .map(|id| (id.to_string(), mod_entry(id)))
+            .collect();
+        let tags = HashMap::from([("a".to_string(), vec!["patch".to_string()])]);
+        let entry = |id: &str| ProfileEntry {
+            id: id.to_string(),
+            enabled: true,
+            missing_label: None,
+            collision_with: None,
+        };
+        let rules = patch_last();
+        let broken = order_rule_violations(&rules, &[entry("a"), entry("b")], &mod_map, &tags);
+        assert_eq!(broken, vec!["\"patch\" mods last".to_string()]);
+        let ok = order_rule_violations(&rules, &[entry("b"), entry("a")], &mod_map, &tags);
+        assert!(ok.is_empty());
+    }
+}
@@ -0,0 +1,371 @@
+use crate::{
+    config::{AppConfig, GameConfig},
+    library::{InstallTarget, Library, TargetKind},
+};
+use anyhow::{Context, Result};
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+pub const LOG_TAIL_MAX_BYTES: usize = 512 * 1024;
+const HOME_PLACEHOLDER: &str = "~";
+
+pub struct SupportBundleInput<'a> {
+    pub app_config: &'a AppConfig,
+    pub game_config: &'a GameConfig,
+    pub library: &'a Library,
+    pub log_text: &'a str,
+    pub last_deploy_report: Option<String>,
+    pub library_detail: LibraryDetail,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LibraryDetail {
+    Full,
+    StripModNames,
+    Summary,
+}
+
+pub struct SupportBundle {
+    pub entries: Vec<(String, String)>,
+}
+
+impl SupportBundle {
+    pub fn collect(input: &SupportBundleInput<'_>, home: Option<&Path>) -> Result<Self> {
+        let mut entries = vec![
+            ("version.txt".to_string(), version_info(input.game_config)),
+            (
+                "sigilsmith.log".to_string(),
+                log_tail(input.log_text, LOG_TAIL_MAX_BYTES).to_string(),
+            ),
+            (
+                "app_config.json".to_string(),
+                serde_json::to_string_pretty(input.app_config).context("serialize app config")?,
+            ),
+            (
+                "game_config.json".to_string(),
+                serde_json::to_string_pretty(input.game_config).context("serialize game config")?,
+            ),
+        ];
+        let (library_name, library_json) = match input.library_detail {
+            LibraryDetail::Full => ("library.json", serde_json::to_string_pretty(input.library)),
+            LibraryDetail::StripModNames => (
+                "library.json",
+                serde_json::to_string_pretty(&strip_mod_names(input.library)),
+            ),
+            LibraryDetail::Summary => (
+                "library_summary.json",
+                serde_json::to_string_pretty(&library_summary(input.library)),
+            ),
+        };
+        let library_json = library_json.context("serialize library")?;
+        entries.push((library_name.to_string(), library_json));
+        if let Some(report) = &input.last_deploy_report {
+            entries.push(("last_deploy.json".to_string(), report.clone()));
+        }
+
+        for (_, text) in &mut entries {
+            *text = redact_home(text, home);
+        }
+        Ok(Self { entries })
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).context("create support bundle dir")?;
+        }
+        let file =
+            fs::File::create(path).with_context(|| format!("create support bundle {:?}", path))?;
+        let mut zip = ZipWriter::new(file);
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        for (name, text) in &self.entries {
+            zip.start_file(name.as_str(), options)
+                .with_context(|| format!("add {name} to support bundle"))?;
+            zip.write_all(text.as_bytes())
+                .with_context(|| format!("write {name} to support bundle"))?;
+        }
+        zip.finish().context("finish support bundle")?;
+        Ok(())
+    }
+}
+
+pub fn bundle_file_name(timestamp: &str) -> PathBuf {
+    PathBuf::from(format!("sigilsmith-support-{timestamp}.zip"))
+}
+
+pub fn diagnostics_file_name(timestamp: &str) -> PathBuf {
+    PathBuf::from(format!("sigilsmith-diagnostics-{timestamp}.zip"))
+}
+
+pub fn redact_home(text: &str, home: Option<&Path>) -> String {
+    let Some(home) = home.map(|home| home.to_string_lossy().to_string()) else {
+        return text.to_string();
+    };
+    let home = home.trim_end_matches('/');
+    if home.is_empty() {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for (start, _) in text.match_indices(home) {
+        let end = start + home.len();
+        let before = text[..start].chars().next_back();
+        let after = text[end..].chars().next();
+        let bounded_before = before.is_none_or(|ch| !continues_path(ch));
+        let bounded_after = after.is_none_or(|ch| !continues_path(ch) || ch == '/' || ch == '\\');
+        if bounded_before && bounded_after {
+            out.push_str(&text[last..start]);
+            out.push_str(HOME_PLACEHOLDER);
+            last = end;
+        }
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+fn continues_path(ch: char) -> bool {
+    ch.is_alphanumeric() || matches!(ch, '.' | '_' | '-' | '/' | '\\')
+}
+
+fn log_tail(text: &str, max_bytes: usize) -> &str {
+    if text.len() <= max_bytes {
+        return text;
+    }
+    let mut start = text.len() - max_bytes;
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    let tail = &text[start..];
+    match tail.find('\n') {
+        Some(newline) => &tail[newline + 1..],
+        None => tail,
+    }
+}
+
+fn version_info(game_config: &GameConfig) -> String {
+    format!(
+        "SigilSmith v{}\nOS: {} ({})\nGame: {}\nProfile: {}\n",
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        game_config.game_id.display_name(),
+        game_config.active_profile
+    )
+}
+
+fn library_summary(library: &Library) -> serde_json::Value {
+    let count_targets = |kind: TargetKind| {
+        library
+            .mods
+            .iter()
+            .filter(|mod_entry| mod_entry.has_target_kind(kind))
+            .count()
+    };
+    let profiles: Vec<serde_json::Value> = library
+        .profiles
+        .iter()
+        .map(|profile| {
+            serde_json::json!({
+                "name": profile.name,
+                "entries": profile.order.len(),
+                "enabled": profile.order.iter().filter(|entry| entry.enabled).count(),
+                "missing": profile
+                    .order
+                    .iter()
+                    .filter(|entry| entry.missing_label.is_some())
+                    .count(),
+                "file_overrides": profile.file_overrides.len(),
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "mods": library.mods.len(),
+        "native_mods": library.mods.iter().filter(|mod_entry| mod_entry.is_native()).count(),
+        "pak_mods": count_targets(TargetKind::Pak),
+        "loose_mods": library
+            .mods
+            .iter()
+            .filter(|mod_entry| {
+                mod_entry
+                    .targets
+                    .iter()
+                    .any(|target| !matches!(target, InstallTarget::Pak { .. }))
+            })
+            .count(),
+        "active_profile": library.active_profile,
+        "profiles": profiles,
+    })
+}
+
+fn strip_mod_names(library: &Library) -> Library {
+    let mut library = library.clone();
+    for (index, mod_entry) in library.mods.iter_mut().enumerate() {
+        let label = format!("mod-{:03}", index + 1);
+        mod_entry.name = label.clone();
+        mod_entry.custom_name = None;
+        mod_entry.source_label = None;
+        mod_entry.website = None;
+        mod_entry.author = None;
+        mod_entry.version = None;
+        mod_entry.nexus_id = None;
+        mod_entry.source_path = None;
+        mod_entry.dev_path = None;
+        for (target_index, target) in mod_entry.targets.iter_mut().enumerate() {
+            if let InstallTarget::Pak { file, info, .. } = target {
+                *file = format!("{label}-{}.pak", target_index + 1);
+                info.name = label.clone();
+                info.folder = label.clone();
+                info.description = None;
+                info.author = None;
+                info.publish_handle = None;
+            }
+        }
+    }
+    for profile in &mut library.profiles {
+        for entry in &mut profile.order {
+            if entry.missing_label.is_some() {
+                entry.missing_label = Some("missing mod".to_string());
+            }
+        }
+    }
+    library
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redact_home_only_replaces_whole_path_components() {
+        let home = Path::new("/home/al");
+        let text = "/home/al/mods /home/alice/mods path=/home/al /srv/home/al";
+        assert_eq!(
+            redact_home(text, Some(home)),
+            "~/mods /home/alice/mods path=~ /srv/home/al"
+        );
+        assert_eq!(redact_home(text, None), text);
+    }
+
+    #[test]
+    fn strip_mod_names_removes_identifying_fields() {
+        let library: Library = serde_json::from_value(serde_json::json!({
+            "mods": [{
+                "id": "m1",
+                "name": "Secret Mod",
+                "added_at": 0,
+                "custom_name": "My Secret",
+                "author": "Someone",
+                "version": "1.2.3",
+                "nexus_id": 1234,
+                "website": "https://example.invalid/mod",
+                "source_label": "Secret.zip",
+                "source_path": "/downloads/Secret.zip",
+                "targets": [{
+                    "kind": "pak",
+                    "file": "Secret.pak",
+                    "info": {
+                        "uuid": "u1",
+                        "name": "Secret Mod",
+                        "folder": "SecretMod",
+                        "version": 1,
+                        "md5": null,
+                        "publish_handle": 99,
+                        "author": "Someone",
+                        "description": "About the secret",
+                        "module_type": null,
+                    },
+                }],
+            }],
+            "profiles": [{ "name": "Default", "order": [] }],
+            "active_profile": "Default",
+        }))
+        .unwrap();
+
+        let raw = serde_json::to_string(&strip_mod_names(&library)).unwrap();
+        for needle in [
+            "Secret",
+            "Someone",
+            "1.2.3",
+            "1234",
+            "example.invalid",
+            "downloads",
+            "99",
+        ] {
+            assert!(!raw.contains(needle), "{needle} leaked: {raw}");
+        }
+        assert!(raw.contains("mod-001"));
+    }
+
+    #[test]
+    fn collected_bundle_redacts_home_from_every_entry() {
+        let home = Path::new("/home/tester");
+        let app_config: AppConfig = serde_json::from_value(serde_json::json!({
+            "active_game": "bg3",
+            "downloads_dir": "/home/tester/Downloads",
+            "last_browser_dir": "/home/tester/mods",
+        }))
+        .unwrap();
+        let game_config: GameConfig = serde_json::from_value(serde_json::json!({
+            "game_name": "Baldur's Gate 3",
+            "data_dir": "/home/tester/.local/share/sigilsmith",
+            "game_root": "/home/tester/games/bg3",
+            "larian_dir": "/home/tester/.local/share/Larian",
+            "active_profile": "Default",
+        }))
+        .unwrap();
+        let library: Library = serde_json::from_value(serde_json::json!({
+            "mods": [{
+                "id": "m1",
+                "name": "Mod",
+                "added_at": 0,
+                "source_path": "/home/tester/Downloads/Mod.zip",
+                "targets": [],
+            }],
+            "profiles": [{ "name": "Default", "order": [] }],
+            "active_profile": "Default",
+        }))
+        .unwrap();
+        let log_text = "[INFO] Imported /home/tester/Downloads/Mod.zip\n";
+        let report = r#"{"staging_root": "/home/tester/stage"}"#.to_string();
+
+        for (detail, library_name) in [
+            (LibraryDetail::Full, "library.json"),
+            (LibraryDetail::StripModNames, "library.json"),
+            (LibraryDetail::Summary, "library_summary.json"),
+        ] {
+            let input = SupportBundleInput {
+                app_config: &app_config,
+                game_config: &game_config,
+                library: &library,
+                log_text,
+                last_deploy_report: Some(report.clone()),
+                library_detail: detail,
+            };
+            let bundle = SupportBundle::collect(&input, Some(home)).unwrap();
+            let names: Vec<&str> = bundle
+                .entries
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect();
+            assert_eq!(
+                names,
+                vec![
+                    "version.txt",
+                    "sigilsmith.log",
+                    "app_config.json",
+                    "game_config.json",
+                    library_name,
+                    "last_deploy.json",
+                ]
+            );
+            for (name, text) in &bundle.entries {
+                assert!(!text.contains("/home/tester"), "{name} leaked home: {text}");
+            }
+            let config = &bundle.entries[2].1;
+            assert!(config.contains("~/Downloads"), "{config}");
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{
+    library::ModEntry,
+    sigillink::{self, SigilLinkIndex},
+};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntry {
+    pub removed_at: u64,
+    pub mod_entry: ModEntry,
+    #[serde(skip)]
+    pub dir: PathBuf,
+}
+
+impl TrashEntry {
+    fn record_path(&self) -> PathBuf {
+        record_path(&self.dir)
+    }
+
+    pub fn load_index(&self) -> Option<SigilLinkIndex> {
+        let raw = fs::read_to_string(index_path(&self.dir)).ok()?;
+        serde_json::from_str(&raw).ok()
+    }
+}
+
+pub fn trash_root(cache_root: &Path) -> PathBuf {
+    cache_root.join("trash")
+}
+
+fn sibling(dir: &Path, suffix: &str) -> PathBuf {
+    let mut name = dir.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    dir.with_file_name(name)
+}
+
+fn record_path(dir: &Path) -> PathBuf {
+    sibling(dir, ".json")
+}
+
+fn index_path(dir: &Path) -> PathBuf {
+    sibling(dir, ".sigillink.json")
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+pub fn quarantine(cache_root: &Path, mod_root: &Path, mod_entry: &ModEntry) -> Result<PathBuf> {
+    let root = trash_root(cache_root);
+    fs::create_dir_all(&root).context("create trash dir")?;
+    let removed_at = now_secs();
+    let dir = root.join(format!("{}-{removed_at}", mod_entry.id));
+    fs::rename(mod_root, &dir).context("move mod files to trash")?;
+
+    let index = sigillink::sigillink_index_path(cache_root, &mod_entry.id);
+    if index.exists() {
+        let _ = fs::rename(&index, index_path(&dir));
+    }
+
+    let entry = TrashEntry {
+        removed_at,
+        mod_entry: mod_entry.clone(),
+        dir: dir.clone(),
+    };
+    let raw = serde_json::to_string_pretty(&entry).context("serialize trash record")?;
+    fs::write(entry.record_path(), raw).context("write trash record")?;
+    Ok(dir)
+}
+
+pub fn list(cache_root: &Path) -> Vec<TrashEntry> {
+    let Ok(read_dir) = fs::read_dir(trash_root(cache_root)) else {
+        return Vec::new();
+    };
+    let mut entries: Vec<TrashEntry> = read_dir
+        .filter_map(|item| item.ok())
+        .map(|item| item.path())
+        .filter(|path| path.is_dir())
+        .filter_map(|dir| {
+            let raw = fs::read_to_string(record_path(&dir)).ok()?;
+            let mut entry: TrashEntry = serde_json::from_str(&raw).ok()?;
+            entry.dir = dir;
+            Some(entry)
+        })
+        .collect();
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.removed_at));
+    entries
+}
+
+pub fn is_quarantined(dir: &Path) -> bool {
+    record_path(dir).is_file()
+}
+
+/// Drops the trash record once its files have been restaged elsewhere.
+pub fn forget(dir: &Path) {
+    let _ = fs::remove_file(record_path(dir));
+    let _ = fs::remove_file(index_path(dir));
+}
+
+/// Trash dirs past retention, plus leftovers without a record (hard deletes that never finished).
+pub fn expired(cache_root: &Path, retention_days: u64) -> Vec<PathBuf> {
+    let Ok(read_dir) = fs::read_dir(trash_root(cache_root)) else {
+        return Vec::new();
+    };
+    let cutoff = now_secs().saturating_sub(retention_days.saturating_mul(SECS_PER_DAY));
+    read_dir
+        .filter_map(|item| item.ok())
+        .map(|item| item.path())
+        .filter(|path| path.is_dir())
+        .filter(|dir| {
+            fs::read_to_string(record_path(dir))
+                .ok()
+                .and_then(|raw| serde_json::from_str::<TrashEntry>(&raw).ok())
+                .map(|entry| entry.removed_at < cutoff)
+                .unwrap_or(true)
+        })
+        .collect()
+}
+
+pub fn purge(dirs: &[PathBuf]) {
+    for dir in dirs {
+        let _ = fs::remove_dir_all(dir);
+        let _ = fs::remove_file(record_path(dir));
+        let _ = fs::remove_file(index_path(dir));
+    }
+}
@@ -1,11 +1,15 @@
 use crate::{
     app::{
         expand_tilde, App, DependencyStatus, DialogChoice, DialogKind, ExplorerItem,
-        ExplorerItemKind, ExportKind, Focus, InputMode, InputPurpose, LogLevel, ModSort,
+        ExplorerItemKind, ExportKind, Focus, InputMode, InputPurpose, LogFilter, LogLevel, ModSort,
         ModSortColumn, PathBrowser, PathBrowserEntryKind, PathBrowserFocus, PathBrowserPurpose,
-        SetupStep, SigilLinkCacheAction, SigilLinkMissingTrigger, ToastLevel, UpdateStatus,
+        SetupStep, SigilLinkCacheAction, SigilLinkMissingTrigger, ToastLevel, ToggleImpactKind,
+        UpdateStatus, SETUP_WIZARD_STEPS,
     },
-    library::{InstallTarget, ModEntry, TargetKind},
+    bg3::LarianDirSource,
+    importer,
+    library::{InstallTarget, ModEntry, Profile, TargetKind},
+    metadata,
 };
 use anyhow::Result;
 use arboard::Clipboard;
@@ -41,6 +45,7 @@ const LOG_MIN_HEIGHT: u16 = 5;
 const CONFLICTS_BAR_HEIGHT: u16 = 0;
 const FILTER_HEIGHT: u16 = 2;
 const TABLE_MIN_HEIGHT: u16 = 6;
+const MOD_SIZE_WIDTH: u16 = 9;
 const SUBPANEL_PAD_X: u16 = 0;
 const SUBPANEL_PAD_TOP: u16 = 0;
 
@@ -164,6 +169,8 @@ fn run_loop(terminal: &mut Terminal<impl Backend>, app: &mut App) -> Result<()>
         app.poll_imports();
         app.poll_metadata_refresh();
         app.poll_missing_pak_scan();
+        app.poll_dev_watch();
+        app.poll_disk_usage();
         app.poll_smart_rank();
         app.poll_updates();
         app.clamp_selection();
@@ -209,6 +216,10 @@ fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
     if app.dependency_queue_active() {
         return handle_dependency_queue(app, key);
     }
+    if app.import_overlay_active() && matches!(key.code, KeyCode::Esc) {
+        app.prompt_cancel_import();
+        return Ok(());
+    }
     if app.paths_overlay_open {
         return handle_paths_overlay(app, key);
     }
@@ -221,6 +232,42 @@ fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
     if app.smart_rank_preview.is_some() {
         return handle_smart_rank_preview(app, key);
     }
+    if app.compatibility_view.is_some() {
+        return handle_compatibility_report(app, key);
+    }
+    if app.command_palette.is_some() {
+        return handle_command_palette(app, key);
+    }
+    if app.uuid_collision_view.is_some() {
+        return handle_uuid_collisions(app, key);
+    }
+    if app.conflict_rules_view.is_some() {
+        return handle_conflict_rules(app, key);
+    }
+    if app.load_order_check_view.is_some() {
+        return handle_load_order_check(app, key);
+    }
+    if app.toggle_impact_view.is_some() {
+        return handle_toggle_impact(app, key);
+    }
+    if app.trash_view.is_some() {
+        return handle_trash_view(app, key);
+    }
+    if app.order_rules_view.is_some() {
+        return handle_order_rules(app, key);
+    }
+    if app.rank_history_view.is_some() {
+        return handle_rank_history(app, key);
+    }
+    if app.targets_view.is_some() {
+        return handle_targets_view(app, key);
+    }
+    if app.setup_wizard.is_some() && matches!(app.input_mode, InputMode::Normal) {
+        return handle_setup_wizard(app, key);
+    }
+    if app.mod_list_picker.is_some() {
+        return handle_mod_list_picker(app, key);
+    }
     if app.mod_list_preview.is_some() {
         return handle_mod_list_preview(app, key);
     }
@@ -230,6 +277,20 @@ fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
     if app.settings_menu.is_some() {
         return handle_settings_menu(app, key);
     }
+    if app.deploy_cancelable()
+        && matches!(app.input_mode, InputMode::Normal)
+        && matches!(key.code, KeyCode::Esc)
+    {
+        app.prompt_cancel_deploy();
+        return Ok(());
+    }
+    if app.metadata_scan_cancelable()
+        && matches!(app.input_mode, InputMode::Normal)
+        && matches!(key.code, KeyCode::Esc)
+    {
+        app.cancel_metadata_refresh();
+        return Ok(());
+    }
 
     let mode = std::mem::replace(&mut app.input_mode, InputMode::Normal);
     match mode {
@@ -336,6 +397,13 @@ fn handle_dialog_mode(app: &mut App, key: KeyEvent) -> Result<()> {
                     DialogKind::DeleteMod { .. } => {
                         app.close_dialog();
                     }
+                    DialogKind::DuplicateProfile { .. } => {
+                        app.close_dialog();
+                    }
+                    DialogKind::PendingMove { .. } => {
+                        app.dialog_set_choice(DialogChoice::Cancel);
+                        app.dialog_confirm();
+                    }
                     DialogKind::DisableDependents { .. } => {
                         app.dialog_set_choice(DialogChoice::Yes);
                         app.dialog_confirm();
@@ -355,7 +423,7 @@ fn handle_dialog_mode(app: &mut App, key: KeyEvent) -> Result<()> {
 fn handle_help_mode(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
         KeyCode::Esc | KeyCode::Char('?') => app.close_help(),
-        KeyCode::Char('q') | KeyCode::Char('Q') => app.should_quit = true,
+        KeyCode::Char('q') | KeyCode::Char('Q') => app.request_quit(),
         KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
             app.help_scroll = app.help_scroll.saturating_sub(1);
         }
@@ -414,6 +482,28 @@ fn handle_paths_overlay(app: &mut App, key: KeyEvent) -> Result<()> {
         KeyCode::Esc | KeyCode::Enter | KeyCode::Char(' ') => {
             app.close_paths_overlay();
         }
+        KeyCode::Char('o') | KeyCode::Char('O') => app.open_data_dir(),
+        KeyCode::Char('g') | KeyCode::Char('G') => app.open_game_root(),
+        KeyCode::Char('u') | KeyCode::Char('U') => app.open_larian_dir(),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.paths_overlay_move(-1),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => app.paths_overlay_move(1),
+        KeyCode::Char('c') | KeyCode::Char('y') => app.copy_selected_path(),
+        KeyCode::Char('C') | KeyCode::Char('a') | KeyCode::Char('A') => app.copy_all_paths(),
+        _ => {}
+    }
+    Ok(())
+}
+
+fn handle_setup_wizard(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Enter => {
+            if let Err(err) = app.setup_wizard_next() {
+                app.status = format!("Setup failed: {err}");
+                app.log_error(format!("Setup failed: {err}"));
+            }
+        }
+        KeyCode::Esc | KeyCode::Backspace => app.setup_wizard_back(),
+        KeyCode::Char('b') | KeyCode::Char('B') => app.setup_wizard_browse(),
         _ => {}
     }
     Ok(())
@@ -457,6 +547,24 @@ fn handle_smart_rank_preview(app: &mut App, key: KeyEvent) -> Result<()> {
     Ok(())
 }
 
+fn handle_compatibility_report(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => {
+            app.close_compatibility_report();
+        }
+        KeyCode::Enter | KeyCode::Char(' ') => app.compatibility_toggle_expanded(),
+        KeyCode::Char('e') | KeyCode::Char('E') => app.export_compatibility_report(),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.compatibility_move(-1),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => app.compatibility_move(1),
+        KeyCode::PageUp => app.compatibility_move(-10),
+        KeyCode::PageDown => app.compatibility_move(10),
+        KeyCode::Home => app.compatibility_move(isize::MIN / 2),
+        KeyCode::End => app.compatibility_move(isize::MAX / 2),
+        _ => {}
+    }
+    Ok(())
+}
+
 fn handle_mod_list_preview(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
         KeyCode::Enter | KeyCode::Char(' ') => {
@@ -474,6 +582,15 @@ fn handle_mod_list_preview(app: &mut App, key: KeyEvent) -> Result<()> {
         KeyCode::Char('m') | KeyCode::Char('M') => {
             app.toggle_mod_list_mode();
         }
+        KeyCode::Char('v') | KeyCode::Char('V') => {
+            app.toggle_mod_list_missing_only();
+        }
+        KeyCode::Char('x') | KeyCode::Char('X') => {
+            app.toggle_mod_list_missing_mode();
+        }
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            app.open_mod_list_picker();
+        }
         KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
             app.mod_list_scroll = app.mod_list_scroll.saturating_sub(1);
         }
@@ -549,6 +666,21 @@ fn handle_sigillink_missing_queue(app: &mut App, key: KeyEvent) -> Result<()> {
     Ok(())
 }
 
+fn handle_mod_list_picker(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.mod_list_picker_move(-1),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => app.mod_list_picker_move(1),
+        KeyCode::PageUp => app.mod_list_picker_move(-10),
+        KeyCode::PageDown => app.mod_list_picker_move(10),
+        KeyCode::Home => app.mod_list_picker_move(isize::MIN / 2),
+        KeyCode::End => app.mod_list_picker_move(isize::MAX / 2),
+        KeyCode::Enter => app.mod_list_picker_select(),
+        KeyCode::Esc => app.mod_list_picker_cancel(),
+        _ => {}
+    }
+    Ok(())
+}
+
 fn handle_override_picker(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
         KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.override_picker_move(-1),
@@ -567,32 +699,56 @@ fn handle_override_picker(app: &mut App, key: KeyEvent) -> Result<()> {
 #[derive(Debug, Clone, Copy)]
 enum SettingsItemKind {
     ActionSetupPaths,
+    ActionSetupWizard,
     ActionShowPaths,
     ActionMoveSigilLinkCache,
     ActionClearFrameworkCaches,
     ActionClearSigilLinkCaches,
+    ActionVerifySigilLinkCache,
+    ActionCleanSigilLinkCache,
+    ActionRestageMissingMods,
     ActionCopyLogTail,
     ActionCopyLogAll,
     ActionExportLogFile,
+    ActionSupportBundle,
+    ActionDiagnosticsBundle,
     ProfilesHeader,
     ActionExportModList,
     ActionImportModList,
+    ActionRepairModsettings,
+    ActionNormalizeProfiles,
+    ActionExportSettings,
+    ActionImportSettings,
     SigilLinkHeader,
     SigilLinkDebugHeader,
     SigilLinkToggle,
     SigilLinkAutoPreview,
+    SigilLinkDebounce,
     SigilLinkInfo,
     ActionSigilLinkSoloRank,
+    ActionSigilLinkScopedRank,
+    ActionCompatibilityReport,
+    ActionOrderRules,
+    ActionRankHistory,
     ActionClearSigilLinkPins,
     ToggleModDelete,
     ToggleProfileDelete,
+    ToggleSortProfiles,
+    ToggleModSizeColumn,
+    ToggleBackgroundConflictScan,
     ToggleAutoDeploy,
+    AutoDeployDebounce,
     ToggleEnableModsAfterImport,
+    ToggleEnableImportDependencies,
     ToggleDeleteModFilesOnRemove,
+    ToggleQuarantineRemovedMods,
+    ActionRestoreRemovedMods,
     ToggleDependencyDownloads,
     ToggleDependencyWarnings,
     ToggleStartupDependencyNotice,
     DefaultSortColumn,
+    RecentAddedWindow,
+    EvictDisabledAfter,
     ActionCheckUpdates,
     ActionWhatsNew,
 }
@@ -602,6 +758,9 @@ enum ExportMenuItemKind {
     ExportModList,
     ExportModListClipboard,
     ExportModsettings,
+    ExportModsettingsClipboard,
+    ExportUuidList,
+    ExportUuidListClipboard,
 }
 
 #[derive(Debug, Clone)]
@@ -632,6 +791,12 @@ fn settings_items(app: &App) -> Vec<SettingsItem> {
             checked: None,
             selectable: true,
         },
+        SettingsItem {
+            label: "Run Setup Wizard".to_string(),
+            kind: SettingsItemKind::ActionSetupWizard,
+            checked: None,
+            selectable: true,
+        },
         SettingsItem {
             label: "Display SigilSmith Paths".to_string(),
             kind: SettingsItemKind::ActionShowPaths,
@@ -650,6 +815,12 @@ fn settings_items(app: &App) -> Vec<SettingsItem> {
             checked: Some(app.app_config.auto_deploy_enabled),
             selectable: true,
         },
+        SettingsItem {
+            label: "Auto Deploy Debounce".to_string(),
+            kind: SettingsItemKind::AutoDeployDebounce,
+            checked: None,
+            selectable: true,
+        },
         SettingsItem {
             label: "Confirm Mod Delete".to_string(),
             kind: SettingsItemKind::ToggleModDelete,
@@ -662,6 +833,24 @@ fn settings_items(app: &App) -> Vec<SettingsItem> {
             checked: Some(app.app_config.confirm_profile_delete),
             selectable: true,
         },
+        SettingsItem {
+            label: "Sort Profiles A-Z".to_string(),
+            kind: SettingsItemKind::ToggleSortProfiles,
+            checked: Some(app.app_config.sort_profiles_alphabetically),
+            selectable: true,
+        },
+        SettingsItem {
+            label: "Show Mod Size Column".to_string(),
+            kind: SettingsItemKind::ToggleModSizeColumn,
+            checked: Some(app.app_config.show_mod_size_column),
+            selectable: true,
+        },
+        SettingsItem {
+            label: "Background Override Scan".to_string(),
+            kind: SettingsItemKind::ToggleBackgroundConflictScan,
+            checked: Some(app.app_config.background_conflict_scan),
+            selectable: true,
+        },
         SettingsItem {
             label: "Auto Dependency Downloads".to_string(),
             kind: SettingsItemKind::ToggleDependencyDownloads,
@@ -686,18 +875,48 @@ fn settings_items(app: &App) -> Vec<SettingsItem> {
             checked: Some(app.app_config.enable_mods_after_import),
             selectable: true,
         },
+        SettingsItem {
+            label: "Enable Import Dependencies".to_string(),
+            kind: SettingsItemKind::ToggleEnableImportDependencies,
+            checked: Some(app.app_config.enable_import_dependencies),
+            selectable: true,
+        },
         SettingsItem {
             label: "Delete Mod Files on Remove".to_string(),
             kind: SettingsItemKind::ToggleDeleteModFilesOnRemove,
             checked: Some(app.app_config.delete_mod_files_on_remove),
             selectable: true,
         },
+        SettingsItem {
+            label: "Quarantine Removed Mod Files".to_string(),
+            kind: SettingsItemKind::ToggleQuarantineRemovedMods,
+            checked: Some(app.app_config.quarantine_removed_mods),
+            selectable: true,
+        },
+        SettingsItem {
+            label: "Restore Removed Mods".to_string(),
+            kind: SettingsItemKind::ActionRestoreRemovedMods,
+            checked: None,
+            selectable: true,
+        },
         SettingsItem {
             label: "Default Sort Column".to_string(),
             kind: SettingsItemKind::DefaultSortColumn,
             checked: None,
             selectable: true,
         },
+        SettingsItem {
+            label: "Recently Added Window".to_string(),
+            kind: SettingsItemKind::RecentAddedWindow,
+            checked: None,
+            selectable: true,
+        },
+        SettingsItem {
+            label: "Evict Disabled From Cache".to_string(),
+            kind: SettingsItemKind::EvictDisabledAfter,
+            checked: None,
+            selectable: true,
+        },
         SettingsItem {
             label: update_menu_label(app),
             kind: SettingsItemKind::ActionCheckUpdates,
@@ -743,12 +962,42 @@ fn settings_items(app: &App) -> Vec<SettingsItem> {
             checked: Some(app.app_config.sigillink_auto_preview),
             selectable: true,
         },
+        SettingsItem {
+            label: "Refresh Debounce".to_string(),
+            kind: SettingsItemKind::SigilLinkDebounce,
+            checked: None,
+            selectable: true,
+        },
         SettingsItem {
             label: "SigiLink Ranking Solo Run".to_string(),
             kind: SettingsItemKind::ActionSigilLinkSoloRank,
             checked: None,
             selectable: true,
         },
+        SettingsItem {
+            label: "SigiLink Rank Selected/Filtered Mods".to_string(),
+            kind: SettingsItemKind::ActionSigilLinkScopedRank,
+            checked: None,
+            selectable: true,
+        },
+        SettingsItem {
+            label: "Compatibility Report".to_string(),
+            kind: SettingsItemKind::ActionCompatibilityReport,
+            checked: None,
+            selectable: true,
+        },
+        SettingsItem {
+            label: "SigiLink Ordering Rules".to_string(),
+            kind: SettingsItemKind::ActionOrderRules,
+            checked: None,
+            selectable: true,
+        },
+        SettingsItem {
+            label: "SigiLink Ranking History".to_string(),
+            kind: SettingsItemKind::ActionRankHistory,
+            checked: None,
+            selectable: true,
+        },
         SettingsItem {
             label: "Reset All SigiLink Pins".to_string(),
             kind: SettingsItemKind::ActionClearSigilLinkPins,
@@ -762,7 +1011,25 @@ fn settings_items(app: &App) -> Vec<SettingsItem> {
             selectable: true,
         },
         SettingsItem {
-            label: "Move SigiLink Cache".to_string(),
+            label: "Verify SigiLink Cache".to_string(),
+            kind: SettingsItemKind::ActionVerifySigilLinkCache,
+            checked: None,
+            selectable: true,
+        },
+        SettingsItem {
+            label: "Clean Up Orphaned Cache".to_string(),
+            kind: SettingsItemKind::ActionCleanSigilLinkCache,
+            checked: None,
+            selectable: true,
+        },
+        SettingsItem {
+            label: "Re-stage Missing From Source".to_string(),
+            kind: SettingsItemKind::ActionRestageMissingMods,
+            checked: None,
+            selectable: true,
+        },
+        SettingsItem {
+            label: format!("Move SigiLink Cache ({})", app.cache_size_label()),
             kind: SettingsItemKind::ActionMoveSigilLinkCache,
             checked: None,
             selectable: true,
@@ -785,6 +1052,30 @@ fn settings_items(app: &App) -> Vec<SettingsItem> {
             checked: None,
             selectable: true,
         },
+        SettingsItem {
+            label: "Repair modsettings.lsx".to_string(),
+            kind: SettingsItemKind::ActionRepairModsettings,
+            checked: None,
+            selectable: true,
+        },
+        SettingsItem {
+            label: "Normalize All Profiles".to_string(),
+            kind: SettingsItemKind::ActionNormalizeProfiles,
+            checked: None,
+            selectable: true,
+        },
+        SettingsItem {
+            label: "Export Settings".to_string(),
+            kind: SettingsItemKind::ActionExportSettings,
+            checked: None,
+            selectable: true,
+        },
+        SettingsItem {
+            label: "Import Settings".to_string(),
+            kind: SettingsItemKind::ActionImportSettings,
+            checked: None,
+            selectable: true,
+        },
         SettingsItem {
             label: "Debug".to_string(),
             kind: SettingsItemKind::SigilLinkDebugHeader,
@@ -809,6 +1100,18 @@ fn settings_items(app: &App) -> Vec<SettingsItem> {
             checked: None,
             selectable: true,
         },
+        SettingsItem {
+            label: "Create Support Bundle".to_string(),
+            kind: SettingsItemKind::ActionSupportBundle,
+            checked: None,
+            selectable: true,
+        },
+        SettingsItem {
+            label: "Copy Diagnostics Bundle".to_string(),
+            kind: SettingsItemKind::ActionDiagnosticsBundle,
+            checked: None,
+            selectable: true,
+        },
         SettingsItem {
             label: "What's New?".to_string(),
             kind: SettingsItemKind::ActionWhatsNew,
@@ -834,6 +1137,18 @@ fn export_menu_items() -> Vec<ExportMenuItem> {
             label: "Export modsettings.lsx (Interop)".to_string(),
             kind: ExportMenuItemKind::ExportModsettings,
         },
+        ExportMenuItem {
+            label: "Copy modsettings.lsx (Clipboard)".to_string(),
+            kind: ExportMenuItemKind::ExportModsettingsClipboard,
+        },
+        ExportMenuItem {
+            label: "Export Enabled UUID List (Text)".to_string(),
+            kind: ExportMenuItemKind::ExportUuidList,
+        },
+        ExportMenuItem {
+            label: "Copy Enabled UUID List (Clipboard)".to_string(),
+            kind: ExportMenuItemKind::ExportUuidListClipboard,
+        },
     ]
 }
 
@@ -892,6 +1207,19 @@ fn handle_export_menu(app: &mut App, key: KeyEvent) -> Result<()> {
                         app.close_export_menu();
                         app.open_export_path_browser(&profile, ExportKind::Modsettings);
                     }
+                    ExportMenuItemKind::ExportModsettingsClipboard => {
+                        if let Err(err) = app.export_modsettings_clipboard(&profile) {
+                            app.status = format!("Export failed: {err}");
+                            app.log_error(format!("Export failed: {err}"));
+                        }
+                    }
+                    ExportMenuItemKind::ExportUuidList => {
+                        app.close_export_menu();
+                        app.open_export_path_browser(&profile, ExportKind::UuidList);
+                    }
+                    ExportMenuItemKind::ExportUuidListClipboard => {
+                        app.export_uuid_list_clipboard(&profile);
+                    }
                 }
             }
         }
@@ -948,6 +1276,11 @@ fn handle_settings_menu(app: &mut App, key: KeyEvent) -> Result<()> {
                         app.close_settings_menu();
                         app.enter_setup_game_root();
                     }
+                    SettingsItemKind::ActionSetupWizard => {
+                        app.request_settings_menu_return();
+                        app.close_settings_menu();
+                        app.open_setup_wizard();
+                    }
                     SettingsItemKind::ActionShowPaths => {
                         app.request_settings_menu_return();
                         app.close_settings_menu();
@@ -959,6 +1292,24 @@ fn handle_settings_menu(app: &mut App, key: KeyEvent) -> Result<()> {
                             app.log_error(format!("Settings update failed: {err}"));
                         }
                     }
+                    SettingsItemKind::ToggleSortProfiles => {
+                        if let Err(err) = app.toggle_sort_profiles_alphabetically() {
+                            app.status = format!("Settings update failed: {err}");
+                            app.log_error(format!("Settings update failed: {err}"));
+                        }
+                    }
+                    SettingsItemKind::ToggleModSizeColumn => {
+                        if let Err(err) = app.toggle_show_mod_size_column() {
+                            app.status = format!("Settings update failed: {err}");
+                            app.log_error(format!("Settings update failed: {err}"));
+                        }
+                    }
+                    SettingsItemKind::ToggleBackgroundConflictScan => {
+                        if let Err(err) = app.toggle_background_conflict_scan() {
+                            app.status = format!("Settings update failed: {err}");
+                            app.log_error(format!("Settings update failed: {err}"));
+                        }
+                    }
                     SettingsItemKind::ToggleModDelete => {
                         if let Err(err) = app.toggle_confirm_mod_delete() {
                             app.status = format!("Settings update failed: {err}");
@@ -977,18 +1328,59 @@ fn handle_settings_menu(app: &mut App, key: KeyEvent) -> Result<()> {
                             app.log_error(format!("Settings update failed: {err}"));
                         }
                     }
+                    SettingsItemKind::ToggleEnableImportDependencies => {
+                        if let Err(err) = app.toggle_enable_import_dependencies() {
+                            app.status = format!("Settings update failed: {err}");
+                            app.log_error(format!("Settings update failed: {err}"));
+                        }
+                    }
                     SettingsItemKind::ToggleDeleteModFilesOnRemove => {
                         if let Err(err) = app.toggle_delete_mod_files_on_remove() {
                             app.status = format!("Settings update failed: {err}");
                             app.log_error(format!("Settings update failed: {err}"));
                         }
                     }
+                    SettingsItemKind::ToggleQuarantineRemovedMods => {
+                        if let Err(err) = app.toggle_quarantine_removed_mods() {
+                            app.status = format!("Settings update failed: {err}");
+                            app.log_error(format!("Settings update failed: {err}"));
+                        }
+                    }
+                    SettingsItemKind::ActionRestoreRemovedMods => {
+                        app.request_settings_menu_return();
+                        app.close_settings_menu();
+                        app.open_trash_view();
+                    }
                     SettingsItemKind::DefaultSortColumn => {
                         if let Err(err) = app.cycle_default_sort_column() {
                             app.status = format!("Settings update failed: {err}");
                             app.log_error(format!("Settings update failed: {err}"));
                         }
                     }
+                    SettingsItemKind::RecentAddedWindow => {
+                        if let Err(err) = app.cycle_recent_added_hours() {
+                            app.status = format!("Settings update failed: {err}");
+                            app.log_error(format!("Settings update failed: {err}"));
+                        }
+                    }
+                    SettingsItemKind::EvictDisabledAfter => {
+                        if let Err(err) = app.cycle_evict_disabled_after() {
+                            app.status = format!("Settings update failed: {err}");
+                            app.log_error(format!("Settings update failed: {err}"));
+                        }
+                    }
+                    SettingsItemKind::AutoDeployDebounce => {
+                        if let Err(err) = app.cycle_auto_deploy_debounce() {
+                            app.status = format!("Settings update failed: {err}");
+                            app.log_error(format!("Settings update failed: {err}"));
+                        }
+                    }
+                    SettingsItemKind::SigilLinkDebounce => {
+                        if let Err(err) = app.cycle_smart_rank_debounce() {
+                            app.status = format!("Settings update failed: {err}");
+                            app.log_error(format!("Settings update failed: {err}"));
+                        }
+                    }
                     SettingsItemKind::SigilLinkToggle => {
                         if let Err(err) = app.toggle_sigillink_ranking() {
                             app.status = format!("Settings update failed: {err}");
@@ -1030,6 +1422,17 @@ fn handle_settings_menu(app: &mut App, key: KeyEvent) -> Result<()> {
                     SettingsItemKind::ActionClearSigilLinkCaches => {
                         app.clear_sigillink_caches();
                     }
+                    SettingsItemKind::ActionVerifySigilLinkCache => {
+                        app.verify_sigillink_cache();
+                    }
+                    SettingsItemKind::ActionRestageMissingMods => {
+                        app.restage_missing_mods();
+                    }
+                    SettingsItemKind::ActionCleanSigilLinkCache => {
+                        app.request_settings_menu_return();
+                        app.close_settings_menu();
+                        app.prompt_sigillink_cache_gc();
+                    }
                     SettingsItemKind::ActionClearSigilLinkPins => {
                         app.request_settings_menu_return();
                         app.close_settings_menu();
@@ -1046,11 +1449,49 @@ fn handle_settings_menu(app: &mut App, key: KeyEvent) -> Result<()> {
                         app.close_settings_menu();
                         app.enter_import_profile();
                     }
+                    SettingsItemKind::ActionRepairModsettings => {
+                        app.request_settings_menu_return();
+                        app.close_settings_menu();
+                        app.prompt_repair_modsettings();
+                    }
+                    SettingsItemKind::ActionNormalizeProfiles => {
+                        app.normalize_all_profiles();
+                    }
+                    SettingsItemKind::ActionExportSettings => {
+                        app.request_settings_menu_return();
+                        app.close_settings_menu();
+                        app.prompt_export_settings();
+                    }
+                    SettingsItemKind::ActionImportSettings => {
+                        app.request_settings_menu_return();
+                        app.close_settings_menu();
+                        app.enter_import_settings();
+                    }
                     SettingsItemKind::ActionSigilLinkSoloRank => {
                         app.request_settings_menu_return();
                         app.close_settings_menu();
                         app.run_sigillink_ranking_solo();
                     }
+                    SettingsItemKind::ActionSigilLinkScopedRank => {
+                        app.request_settings_menu_return();
+                        app.close_settings_menu();
+                        app.run_sigillink_ranking_scoped();
+                    }
+                    SettingsItemKind::ActionCompatibilityReport => {
+                        app.request_settings_menu_return();
+                        app.close_settings_menu();
+                        app.open_compatibility_report();
+                    }
+                    SettingsItemKind::ActionOrderRules => {
+                        app.request_settings_menu_return();
+                        app.close_settings_menu();
+                        app.open_order_rules();
+                    }
+                    SettingsItemKind::ActionRankHistory => {
+                        app.request_settings_menu_return();
+                        app.close_settings_menu();
+                        app.open_rank_history();
+                    }
                     SettingsItemKind::ActionCopyLogTail => {
                         app.copy_log_tail_to_clipboard(200);
                     }
@@ -1062,6 +1503,16 @@ fn handle_settings_menu(app: &mut App, key: KeyEvent) -> Result<()> {
                         app.close_settings_menu();
                         app.open_log_export();
                     }
+                    SettingsItemKind::ActionSupportBundle => {
+                        app.request_settings_menu_return();
+                        app.close_settings_menu();
+                        app.prompt_support_bundle();
+                    }
+                    SettingsItemKind::ActionDiagnosticsBundle => {
+                        app.request_settings_menu_return();
+                        app.close_settings_menu();
+                        app.open_diagnostics_export();
+                    }
                     SettingsItemKind::ActionWhatsNew => {
                         app.request_settings_menu_return();
                         app.close_settings_menu();
@@ -1088,34 +1539,223 @@ fn handle_settings_menu(app: &mut App, key: KeyEvent) -> Result<()> {
     Ok(())
 }
 
-fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<()> {
-    match (key.code, key.modifiers) {
-        (KeyCode::Char('f'), mods) | (KeyCode::Char('F'), mods)
-            if mods.contains(KeyModifiers::CONTROL) =>
-        {
-            app.focus_mods();
-            app.enter_mod_filter();
-            return Ok(());
-        }
-        (KeyCode::Char('e'), mods) | (KeyCode::Char('E'), mods)
-            if mods.contains(KeyModifiers::CONTROL) =>
-        {
-            let active = app.library.active_profile.clone();
-            app.enter_export_profile(&active);
-            return Ok(());
-        }
-        (KeyCode::Char('p'), mods) | (KeyCode::Char('P'), mods)
-            if mods.contains(KeyModifiers::CONTROL) =>
-        {
-            app.enter_import_profile();
-            return Ok(());
+fn handle_command_palette(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Esc => app.close_command_palette(),
+        KeyCode::Enter => {
+            if let Err(err) = app.command_palette_execute() {
+                app.status = format!("Command failed: {err}");
+                app.log_error(format!("Command failed: {err}"));
+            }
         }
-        (KeyCode::Char('/'), _) => {
-            app.focus_mods();
-            app.enter_mod_filter();
-            return Ok(());
+        KeyCode::Up => app.command_palette_move(-1),
+        KeyCode::Down => app.command_palette_move(1),
+        KeyCode::PageUp => app.command_palette_move(-10),
+        KeyCode::PageDown => app.command_palette_move(10),
+        KeyCode::Backspace => app.command_palette_backspace(),
+        KeyCode::Char(ch) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.command_palette_input(ch);
         }
-        (KeyCode::Left, mods) if mods.contains(KeyModifiers::CONTROL) => {
+        _ => {}
+    }
+    Ok(())
+}
+
+fn handle_uuid_collisions(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Esc => app.uuid_collision_cancel(),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.uuid_collision_move(-1),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => app.uuid_collision_move(1),
+        KeyCode::Enter => app.uuid_collision_keep_selected(),
+        _ => {}
+    }
+    Ok(())
+}
+
+fn handle_conflict_rules(app: &mut App, key: KeyEvent) -> Result<()> {
+    let adding = app
+        .conflict_rules_view
+        .as_ref()
+        .is_some_and(|view| view.adding.is_some());
+    if adding {
+        match key.code {
+            KeyCode::Esc => app.conflict_rules_cancel_add(),
+            KeyCode::Enter => app.conflict_rules_submit(),
+            KeyCode::Backspace => app.conflict_rules_backspace(),
+            KeyCode::Char(ch) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.conflict_rules_input(ch);
+            }
+            _ => {}
+        }
+        return Ok(());
+    }
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => app.close_conflict_rules(),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.conflict_rules_move(-1),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => app.conflict_rules_move(1),
+        KeyCode::Char('a') | KeyCode::Char('A') => app.conflict_rules_begin_add(),
+        KeyCode::Delete | KeyCode::Backspace | KeyCode::Char('d') | KeyCode::Char('D') => {
+            app.conflict_rules_remove();
+        }
+        KeyCode::Char('t') | KeyCode::Char('T') => app.conflict_rules_test(),
+        KeyCode::Char('i') | KeyCode::Char('I') => app.conflict_rules_toggle_identical(),
+        _ => {}
+    }
+    Ok(())
+}
+
+fn handle_order_rules(app: &mut App, key: KeyEvent) -> Result<()> {
+    let adding = app
+        .order_rules_view
+        .as_ref()
+        .is_some_and(|view| view.adding.is_some());
+    if adding {
+        match key.code {
+            KeyCode::Esc => app.order_rules_cancel_add(),
+            KeyCode::Enter => app.order_rules_submit(),
+            KeyCode::Backspace => app.order_rules_backspace(),
+            KeyCode::Char(ch) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.order_rules_input(ch);
+            }
+            _ => {}
+        }
+        return Ok(());
+    }
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => app.close_order_rules(),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.order_rules_move(-1),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => app.order_rules_move(1),
+        KeyCode::Char('a') | KeyCode::Char('A') => app.order_rules_begin_add(),
+        KeyCode::Delete | KeyCode::Backspace | KeyCode::Char('d') | KeyCode::Char('D') => {
+            app.order_rules_remove();
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn handle_rank_history(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => app.close_rank_history(),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.rank_history_move(-1),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => app.rank_history_move(1),
+        KeyCode::Enter | KeyCode::Char('r') | KeyCode::Char('R') => {
+            app.rank_history_restore(true);
+        }
+        KeyCode::Char('b') | KeyCode::Char('B') | KeyCode::Char('u') | KeyCode::Char('U') => {
+            app.rank_history_restore(false);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn handle_targets_view(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Esc
+        | KeyCode::Enter
+        | KeyCode::Char('q')
+        | KeyCode::Char('Q')
+        | KeyCode::Char('t')
+        | KeyCode::Char('T') => app.close_targets_view(),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.targets_view_scroll(-1),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => app.targets_view_scroll(1),
+        _ => {}
+    }
+    Ok(())
+}
+
+fn handle_load_order_check(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => app.close_load_order_check(),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.load_order_check_move(-1),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => app.load_order_check_move(1),
+        KeyCode::PageUp => app.load_order_check_move(-10),
+        KeyCode::PageDown => app.load_order_check_move(10),
+        KeyCode::Enter => app.load_order_check_jump(),
+        _ => {}
+    }
+    Ok(())
+}
+
+fn handle_toggle_impact(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => app.close_toggle_impact(),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.toggle_impact_move(-1),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => app.toggle_impact_move(1),
+        KeyCode::PageUp => app.toggle_impact_move(-10),
+        KeyCode::PageDown => app.toggle_impact_move(10),
+        KeyCode::Enter => app.toggle_impact_apply(),
+        _ => {}
+    }
+    Ok(())
+}
+
+fn handle_trash_view(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => app.close_trash_view(),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.trash_view_move(-1),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => app.trash_view_move(1),
+        KeyCode::Enter | KeyCode::Char('r') | KeyCode::Char('R') => app.restore_selected_trash(),
+        _ => {}
+    }
+    Ok(())
+}
+
+fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<()> {
+    match (key.code, key.modifiers) {
+        (KeyCode::Char('f'), mods) | (KeyCode::Char('F'), mods)
+            if mods.contains(KeyModifiers::CONTROL) =>
+        {
+            app.focus_mods();
+            app.enter_mod_filter();
+            return Ok(());
+        }
+        (KeyCode::Char('e'), mods) | (KeyCode::Char('E'), mods)
+            if mods.contains(KeyModifiers::CONTROL) =>
+        {
+            let active = app.library.active_profile.clone();
+            app.enter_export_profile(&active);
+            return Ok(());
+        }
+        (KeyCode::Char('p'), mods) | (KeyCode::Char('P'), mods)
+            if mods.contains(KeyModifiers::CONTROL) =>
+        {
+            app.enter_import_profile();
+            return Ok(());
+        }
+        (KeyCode::Char('o'), mods) | (KeyCode::Char('O'), mods)
+            if mods.contains(KeyModifiers::CONTROL) =>
+        {
+            app.open_data_dir();
+            return Ok(());
+        }
+        (KeyCode::Char('g'), mods) | (KeyCode::Char('G'), mods)
+            if mods.contains(KeyModifiers::CONTROL) =>
+        {
+            app.open_game_root();
+            return Ok(());
+        }
+        (KeyCode::Char('u'), mods) | (KeyCode::Char('U'), mods)
+            if mods.contains(KeyModifiers::CONTROL) =>
+        {
+            app.open_larian_dir();
+            return Ok(());
+        }
+        (KeyCode::Char('/'), _) if app.focus == Focus::Log => {
+            app.enter_log_search();
+            return Ok(());
+        }
+        (KeyCode::Char('/'), _) => {
+            app.focus_mods();
+            app.enter_mod_filter();
+            return Ok(());
+        }
+        (KeyCode::Char(':'), _) => {
+            app.open_command_palette();
+            return Ok(());
+        }
+        (KeyCode::Left, mods) if mods.contains(KeyModifiers::CONTROL) => {
             app.focus_mods();
             app.cycle_mod_sort_column(-1);
             return Ok(());
@@ -1135,8 +1775,14 @@ fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<()> {
             app.toggle_mod_sort_direction();
             return Ok(());
         }
-        (KeyCode::Char('q'), _) | (KeyCode::Char('Q'), _) => app.should_quit = true,
+        (KeyCode::Char('q'), _) | (KeyCode::Char('Q'), _) => app.request_quit(),
         (KeyCode::Char('i'), _) | (KeyCode::Char('I'), _) => app.enter_import_mode(),
+        (KeyCode::Char('d'), mods) if mods.contains(KeyModifiers::CONTROL) => {
+            if let Err(err) = app.deploy_load_order_only() {
+                app.status = format!("Deploy failed: {err}");
+                app.log_error(format!("Deploy failed: {err}"));
+            }
+        }
         (KeyCode::Char('d'), _) | (KeyCode::Char('D'), _) => {
             if let Err(err) = app.deploy() {
                 app.status = format!("Deploy failed: {err}");
@@ -1167,9 +1813,31 @@ fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<()> {
 }
 
 fn handle_explorer_mode(app: &mut App, key: KeyEvent) -> Result<()> {
+    let move_up = matches!(key.code, KeyCode::Char('u') | KeyCode::Char('U'))
+        || (key.code == KeyCode::Up && key.modifiers.contains(KeyModifiers::SHIFT));
+    let move_down = matches!(key.code, KeyCode::Char('n') | KeyCode::Char('N'))
+        || (key.code == KeyCode::Down && key.modifiers.contains(KeyModifiers::SHIFT));
+    if move_up || move_down {
+        if let Some(ExplorerItem {
+            kind: ExplorerItemKind::Profile { name, .. },
+            disabled: false,
+            ..
+        }) = app.explorer_selected_item()
+        {
+            if let Err(err) = app.move_profile(&name, move_up) {
+                app.status = format!("Profile move failed: {err}");
+                app.log_error(format!("Profile move failed: {err}"));
+            }
+        }
+        return Ok(());
+    }
     match key.code {
         KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.explorer_move_up(),
         KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => app.explorer_move_down(),
+        KeyCode::PageUp => app.explorer_jump(-(app.page_step() as isize)),
+        KeyCode::PageDown => app.explorer_jump(app.page_step() as isize),
+        KeyCode::Home => app.explorer_jump(isize::MIN),
+        KeyCode::End => app.explorer_jump(isize::MAX),
         KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('H') => app.explorer_toggle_collapse(),
         KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('L') => app.explorer_toggle_expand(),
         KeyCode::Enter | KeyCode::Char(' ') => {
@@ -1231,6 +1899,32 @@ fn handle_explorer_mode(app: &mut App, key: KeyEvent) -> Result<()> {
             }
         }
         KeyCode::Char('p') | KeyCode::Char('P') => app.enter_import_profile(),
+        KeyCode::Char('x') | KeyCode::Char('X') => {
+            if let Some(ExplorerItem {
+                kind: ExplorerItemKind::Profile { name, .. },
+                disabled: false,
+                ..
+            }) = app.explorer_selected_item()
+            {
+                if let Err(err) = app.toggle_profile_lock(&name) {
+                    app.status = format!("Profile lock failed: {err}");
+                    app.log_error(format!("Profile lock failed: {err}"));
+                }
+            }
+        }
+        KeyCode::Char('s') | KeyCode::Char('S') => {
+            if let Some(ExplorerItem {
+                kind: ExplorerItemKind::Profile { name, .. },
+                disabled: false,
+                ..
+            }) = app.explorer_selected_item()
+            {
+                if let Err(err) = app.toggle_default_profile(&name) {
+                    app.status = format!("Default profile failed: {err}");
+                    app.log_error(format!("Default profile failed: {err}"));
+                }
+            }
+        }
         _ => {}
     }
 
@@ -1264,6 +1958,13 @@ fn handle_mods_mode(app: &mut App, key: KeyEvent) -> Result<()> {
             KeyCode::Enter | KeyCode::Char(' ') => app.toggle_move_mode(),
             KeyCode::Esc => app.cancel_move_mode(),
             KeyCode::Char('m') | KeyCode::Char('M') => app.toggle_move_mode(),
+            KeyCode::Char('g') | KeyCode::Char('G') => {
+                if app.mod_filter_active() || !app.mod_sort.is_order_default() {
+                    app.prompt_move_blocked(true);
+                } else {
+                    app.enter_move_to_slot();
+                }
+            }
             KeyCode::Char('k')
             | KeyCode::Char('K')
             | KeyCode::Up
@@ -1299,9 +2000,27 @@ fn handle_mods_mode(app: &mut App, key: KeyEvent) -> Result<()> {
         (KeyCode::F(12), _) => {
             app.prompt_clear_sigillink_pins();
         }
+        (KeyCode::F(5), mods) if mods.contains(KeyModifiers::SHIFT) => {
+            app.refresh_visible_metadata();
+        }
+        (KeyCode::F(5), _) => app.refresh_selected_metadata(),
         (KeyCode::Char('r'), mods) if mods.contains(KeyModifiers::CONTROL) => {
             app.restore_sigillink_rank_for_selected();
         }
+        (KeyCode::Char('n'), mods) if mods.contains(KeyModifiers::CONTROL) => {
+            app.acknowledge_new_mods();
+        }
+        (KeyCode::Char('x'), mods) if mods.contains(KeyModifiers::CONTROL) => {
+            app.toggle_sigillink_exclusion_selected();
+        }
+        (KeyCode::Char('a'), mods) if mods.contains(KeyModifiers::CONTROL) => {
+            app.prompt_adopt_native_mod();
+        }
+        (KeyCode::Char('r'), _) => app.toggle_recent_filter(),
+        (KeyCode::Char('*'), _) => app.toggle_star_selected(),
+        (KeyCode::Char('f'), _) => app.toggle_starred_filter(),
+        (KeyCode::F(2), _) => app.enter_rename_mod(),
+        (KeyCode::Char('t'), _) => app.open_targets_view(),
         (KeyCode::Char('/'), _) => app.enter_mod_filter(),
         (KeyCode::Char('l'), mods) | (KeyCode::Char('L'), mods)
             if mods.contains(KeyModifiers::CONTROL) =>
@@ -1377,6 +2096,7 @@ fn handle_mods_mode(app: &mut App, key: KeyEvent) -> Result<()> {
                 app.move_selected_down();
             }
         }
+        (KeyCode::Char('0'), _) => app.toggle_all_targets_selected(),
         (KeyCode::Char('1'), _) => app.select_target_override(None),
         (KeyCode::Char('2'), _) => app.select_target_override(Some(TargetKind::Pak)),
         (KeyCode::Char('3'), _) => app.select_target_override(Some(TargetKind::Generated)),
@@ -1384,6 +2104,8 @@ fn handle_mods_mode(app: &mut App, key: KeyEvent) -> Result<()> {
         (KeyCode::Char('5'), _) => app.select_target_override(Some(TargetKind::Bin)),
         (KeyCode::PageUp, _) => app.page_mods_up(),
         (KeyCode::PageDown, _) => app.page_mods_down(),
+        (KeyCode::Home, _) => app.mods_home(),
+        (KeyCode::End, _) => app.mods_end(),
         _ => {}
     }
 
@@ -1394,6 +2116,10 @@ fn handle_conflicts_mode(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
         KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.conflict_move_up(),
         KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => app.conflict_move_down(),
+        KeyCode::PageUp => app.conflict_jump(-(app.page_step() as isize)),
+        KeyCode::PageDown => app.conflict_jump(app.page_step() as isize),
+        KeyCode::Home => app.conflict_jump(isize::MIN),
+        KeyCode::End => app.conflict_jump(isize::MAX),
         KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('H') => app.cycle_conflict_winner(-1),
         KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('L') => app.cycle_conflict_winner(1),
         KeyCode::Char('1') => app.select_conflict_candidate(0),
@@ -1411,6 +2137,9 @@ fn handle_conflicts_mode(app: &mut App, key: KeyEvent) -> Result<()> {
                 app.clear_conflict_override();
             }
         }
+        KeyCode::Char('x') | KeyCode::Char('X') => app.prompt_clear_all_file_overrides(),
+        KeyCode::Char('r') | KeyCode::Char('R') => app.open_conflict_rules(),
+        KeyCode::Char('v') | KeyCode::Char('V') => app.toggle_show_ignored_conflicts(),
         KeyCode::Enter => app.apply_pending_override(),
         KeyCode::Backspace | KeyCode::Delete => app.clear_conflict_override(),
         _ => {}
@@ -1423,8 +2152,16 @@ fn handle_log_mode(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
         KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => app.scroll_log_up(1),
         KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => app.scroll_log_down(1),
-        KeyCode::PageUp => app.scroll_log_up(3),
-        KeyCode::PageDown => app.scroll_log_down(3),
+        KeyCode::PageUp => app.scroll_log_up(app.page_step()),
+        KeyCode::PageDown => app.scroll_log_down(app.page_step()),
+        KeyCode::Home => app.scroll_log_up(usize::MAX),
+        KeyCode::End => app.scroll_log_down(usize::MAX),
+        KeyCode::Char('a') | KeyCode::Char('A') => app.set_log_filter(LogFilter::All),
+        KeyCode::Char('w') | KeyCode::Char('W') => app.set_log_filter(LogFilter::Warnings),
+        KeyCode::Char('e') | KeyCode::Char('E') => app.set_log_filter(LogFilter::Errors),
+        KeyCode::Char('f') | KeyCode::Char('F') => app.cycle_log_filter(),
+        KeyCode::Char('n') => app.log_search_step(true),
+        KeyCode::Char('N') => app.log_search_step(false),
         _ => {}
     }
     Ok(())
@@ -1440,8 +2177,11 @@ fn handle_browser_mode(app: &mut App, key: KeyEvent, browser: &mut PathBrowser)
         }
         PathBrowserPurpose::Setup(SetupStep::DownloadsDir) => "Not a folder.",
         PathBrowserPurpose::ImportProfile => "Select a file to import.",
+        PathBrowserPurpose::ImportSettings => "Select a settings file to import.",
         PathBrowserPurpose::ExportProfile { .. } => "Enter a file name to export.",
         PathBrowserPurpose::ExportLog => "Select a folder to export the log.",
+        PathBrowserPurpose::ExportDiagnostics => "Select a folder for the diagnostics bundle.",
+        PathBrowserPurpose::StageDeploy => "Select a folder outside the game to deploy into.",
         PathBrowserPurpose::SigilLinkCache { require_dev, .. } => {
             if require_dev.is_some() {
                 "Select a directory on the same drive as BG3 to use SigiLink without symlinks."
@@ -1642,7 +2382,7 @@ fn sync_path_input_for_browser(app: &App, browser: &mut PathBrowser) {
                 None => browser.current.display().to_string(),
             }
         }
-        PathBrowserPurpose::ImportProfile => {
+        PathBrowserPurpose::ImportProfile | PathBrowserPurpose::ImportSettings => {
             let trimmed = browser.path_input.trim();
             let keep_name = if trimmed.is_empty() {
                 None
@@ -1756,7 +2496,8 @@ fn handle_input_mode(
                 InputPurpose::RenameProfile { original } => {
                     format!("Rename cancelled: {original}")
                 }
-                InputPurpose::DuplicateProfile { source } => {
+                InputPurpose::RenameMod { .. } => "Rename mod cancelled".to_string(),
+                InputPurpose::DuplicateProfile { source, .. } => {
                     format!("Duplicate cancelled: {source}")
                 }
                 InputPurpose::ExportProfile { profile, .. } => {
@@ -1765,7 +2506,10 @@ fn handle_input_mode(
                 InputPurpose::ImportProfile | InputPurpose::ImportPath => {
                     "Import cancelled".to_string()
                 }
+                InputPurpose::AdoptDevFolder => "Adopt dev folder cancelled".to_string(),
                 InputPurpose::FilterMods => "Search cancelled".to_string(),
+                InputPurpose::SearchLog => "Log search cancelled".to_string(),
+                InputPurpose::MoveToSlot => "Move to slot cancelled".to_string(),
             };
             app.set_toast(&cancel_message, ToastLevel::Warn, Duration::from_secs(2));
             if matches!(purpose, InputPurpose::FilterMods) {
@@ -1777,7 +2521,8 @@ fn handle_input_mode(
             let value = buffer.trim().to_string();
             app.input_mode = InputMode::Normal;
             keep_editing = false;
-            let should_submit = !value.is_empty() || matches!(purpose, InputPurpose::FilterMods);
+            let should_submit = !value.is_empty()
+                || matches!(purpose, InputPurpose::FilterMods | InputPurpose::SearchLog);
             if should_submit {
                 if let Err(err) = app.handle_submit(purpose.clone(), value) {
                     app.status = format!("Action failed: {err}");
@@ -2058,13 +2803,12 @@ fn draw(frame: &mut Frame<'_>, app: &mut App) {
         }
     }
     let status_color = status_color_text(&status_text, &theme);
-    let overrides_total = app.conflicts.len();
-    let overrides_manual = app
+    let overrides_auto = app
         .conflicts
         .iter()
-        .filter(|entry| entry.overridden)
+        .filter(|entry| !entry.overridden)
         .count();
-    let overrides_auto = overrides_total.saturating_sub(overrides_manual);
+    let overrides_manual = app.file_override_count();
     let total_mods = counts.total;
     let enabled_mods = counts.enabled;
     let disabled_mods = total_mods.saturating_sub(enabled_mods);
@@ -2080,6 +2824,10 @@ fn draw(frame: &mut Frame<'_>, app: &mut App) {
     if !app.paths_ready() {
         context_labels.push("Setup");
     }
+    let active_tasks = app.active_task_labels();
+    if !active_tasks.is_empty() {
+        context_labels.push("Tasks");
+    }
     let legend_rows = legend_rows(app);
     let hotkey_rows = hotkey_rows(app);
     let base_context_height = context_labels.len().saturating_add(1);
@@ -2320,7 +3068,12 @@ fn draw(frame: &mut Frame<'_>, app: &mut App) {
         let mod_gap_width = 4u16;
         let created_gap_width = 2u16;
         let added_gap_width = 2u16;
-        let fixed_without_mod_target = 4
+        let show_size = app.app_config.show_mod_size_column;
+        let size_width = if show_size { MOD_SIZE_WIDTH } else { 0 };
+        let size_gap_width = if show_size { 2u16 } else { 0 };
+        let fixed_without_mod_target = size_width
+            + size_gap_width
+            + 4
             + 3
             + 3
             + 6
@@ -2351,7 +3104,7 @@ fn draw(frame: &mut Frame<'_>, app: &mut App) {
         if target_col == 0 {
             target_col = 1;
         }
-        let header = Row::new(vec![
+        let mut header_cells = vec![
             mod_header_cell("On", ModSortColumn::Enabled, app.mod_sort, &theme),
             mod_header_cell(" # ", ModSortColumn::Order, app.mod_sort, &theme),
             mod_header_cell(" N ", ModSortColumn::Native, app.mod_sort, &theme),
@@ -2365,38 +3118,46 @@ fn draw(frame: &mut Frame<'_>, app: &mut App) {
             mod_header_cell("Added", ModSortColumn::Added, app.mod_sort, &theme),
             mod_header_cell_static(" ", &theme),
             mod_header_cell("Target", ModSortColumn::Target, app.mod_sort, &theme),
-        ])
-        .style(Style::default().bg(theme.header_bg));
-        let table = Table::new(
-            rows,
-            [
-                Constraint::Length(4),
-                Constraint::Length(3),
-                Constraint::Length(3),
-                Constraint::Length(6),
-                Constraint::Length(dep_width),
-                Constraint::Length(link_width),
-                Constraint::Length(mod_col),
-                Constraint::Length(mod_gap_width),
-                Constraint::Length(date_width),
-                Constraint::Length(created_gap_width),
-                Constraint::Length(date_width),
-                Constraint::Length(added_gap_width),
-                Constraint::Length(target_col),
-            ],
-        )
-        .style(Style::default().bg(theme.mod_bg).fg(theme.text))
-        .header(header)
-        .column_spacing(spacing)
-        .highlight_style(if app.focus == Focus::Mods {
-            Style::default()
-                .bg(theme.accent_soft)
-                .fg(Color::Black)
-                .add_modifier(Modifier::BOLD)
-        } else {
-            Style::default().bg(theme.header_bg).fg(theme.text)
-        })
-        .highlight_symbol("");
+        ];
+        let mut widths = vec![
+            Constraint::Length(4),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(6),
+            Constraint::Length(dep_width),
+            Constraint::Length(link_width),
+            Constraint::Length(mod_col),
+            Constraint::Length(mod_gap_width),
+            Constraint::Length(date_width),
+            Constraint::Length(created_gap_width),
+            Constraint::Length(date_width),
+            Constraint::Length(added_gap_width),
+            Constraint::Length(target_col),
+        ];
+        if show_size {
+            let target_index = header_cells.len() - 1;
+            header_cells.insert(
+                target_index,
+                mod_header_cell("Size", ModSortColumn::Size, app.mod_sort, &theme),
+            );
+            header_cells.insert(target_index + 1, mod_header_cell_static(" ", &theme));
+            widths.insert(target_index, Constraint::Length(size_width));
+            widths.insert(target_index + 1, Constraint::Length(size_gap_width));
+        }
+        let header = Row::new(header_cells).style(Style::default().bg(theme.header_bg));
+        let table = Table::new(rows, widths)
+            .style(Style::default().bg(theme.mod_bg).fg(theme.text))
+            .header(header)
+            .column_spacing(spacing)
+            .highlight_style(if app.focus == Focus::Mods {
+                Style::default()
+                    .bg(theme.accent_soft)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().bg(theme.header_bg).fg(theme.text)
+            })
+            .highlight_symbol("");
 
         let mut state = TableState::default();
         state.select(Some(app.selected));
@@ -2449,6 +3210,13 @@ fn draw(frame: &mut Frame<'_>, app: &mut App) {
             }
             frame.render_stateful_widget(scrollbar, scroll_area, &mut scroll_state);
         }
+        let position = app.selected.min(row_count.saturating_sub(1)) + 1;
+        render_position_label(
+            frame,
+            mod_stack_area,
+            &format!("{position}/{row_count}"),
+            &theme,
+        );
     }
 
     let details_focus = app.focus == Focus::Conflicts;
@@ -2500,6 +3268,9 @@ fn draw(frame: &mut Frame<'_>, app: &mut App) {
         if conflict_status_label(app).is_some() {
             footer_len += 1;
         }
+        if app.conflicts[selected].ignored.is_some() {
+            footer_len += 1;
+        }
         let metrics = conflict_list_metrics(total, selected, details_content_height, footer_len);
         if metrics.show_scroll && details_content_width > 1 {
             details_content_width = details_content_width.saturating_sub(1);
@@ -2673,13 +3444,17 @@ fn draw(frame: &mut Frame<'_>, app: &mut App) {
     let auto_deploy_enabled = app.app_config.auto_deploy_enabled;
     let auto_row = KvRow {
         label: "Auto-Deploy".to_string(),
-        value: if auto_deploy_enabled {
+        value: if app.automation_paused() {
+            "PAUSED".to_string()
+        } else if auto_deploy_enabled {
             "ON".to_string()
         } else {
             "OFF".to_string()
         },
         label_style,
-        value_style: Style::default().fg(if auto_deploy_enabled {
+        value_style: Style::default().fg(if app.automation_paused() {
+            theme.warning
+        } else if auto_deploy_enabled {
             theme.success
         } else {
             theme.muted
@@ -2742,6 +3517,19 @@ fn draw(frame: &mut Frame<'_>, app: &mut App) {
             context_label_width,
         ));
     }
+    if !active_tasks.is_empty() {
+        let tasks_row = KvRow {
+            label: "Tasks".to_string(),
+            value: active_tasks.join(" · "),
+            label_style,
+            value_style: Style::default().fg(theme.warning),
+        };
+        context_lines.push(format_kv_line_aligned(
+            &tasks_row,
+            context_width,
+            context_label_width,
+        ));
+    }
     let context_widget =
         Paragraph::new(context_lines).style(Style::default().fg(theme.text).bg(theme.subpanel_bg));
     frame.render_widget(context_widget, context_chunks[0]);
@@ -2769,7 +3557,7 @@ fn draw(frame: &mut Frame<'_>, app: &mut App) {
     let overrides_focused = app.focus == Focus::Conflicts;
     let log_bg = theme.log_bg;
     let log_block = theme
-        .panel("Log")
+        .panel(app.log_filter.title())
         .border_style(Style::default().fg(if app.focus == Focus::Log {
             theme.accent
         } else {
@@ -2799,7 +3587,7 @@ fn draw(frame: &mut Frame<'_>, app: &mut App) {
         .direction(Direction::Horizontal)
         .constraints([Constraint::Min(10), Constraint::Length(1)])
         .split(log_content);
-    let log_total = app.logs.len();
+    let log_total = app.visible_logs().len();
     let log_view = log_chunks[0].height.max(1) as usize;
     let max_scroll = log_total.saturating_sub(log_view);
     if app.log_scroll > max_scroll {
@@ -2825,6 +3613,13 @@ fn draw(frame: &mut Frame<'_>, app: &mut App) {
             .track_style(Style::default().fg(theme.border))
             .thumb_style(Style::default().fg(theme.accent));
         frame.render_stateful_widget(scrollbar, log_chunks[1], &mut scroll_state);
+        let newest_visible = (log_start + log_view).min(log_total);
+        render_position_label(
+            frame,
+            log_area,
+            &format!("{newest_visible}/{log_total}"),
+            &theme,
+        );
     }
 
     if status_area.width > 0 && status_area.height > 0 {
@@ -2904,15 +3699,51 @@ fn draw(frame: &mut Frame<'_>, app: &mut App) {
     if app.dialog.is_some() {
         draw_dialog(frame, app, &theme);
     }
+    if app.setup_wizard.is_some() {
+        draw_setup_wizard(frame, app, &theme);
+    }
     if let InputMode::Browsing(browser) = &app.input_mode {
         draw_path_browser(frame, app, &theme, browser);
     }
     if app.smart_rank_preview.is_some() {
         draw_smart_rank_preview(frame, app, &theme);
     }
+    if app.compatibility_view.is_some() {
+        draw_compatibility_report(frame, app, &theme);
+    }
+    if app.command_palette.is_some() {
+        draw_command_palette(frame, app, &theme);
+    }
+    if app.conflict_rules_view.is_some() {
+        draw_conflict_rules(frame, app, &theme);
+    }
+    if app.load_order_check_view.is_some() {
+        draw_load_order_check(frame, app, &theme);
+    }
+    if app.toggle_impact_view.is_some() {
+        draw_toggle_impact(frame, app, &theme);
+    }
+    if app.trash_view.is_some() {
+        draw_trash_view(frame, app, &theme);
+    }
+    if app.order_rules_view.is_some() {
+        draw_order_rules(frame, app, &theme);
+    }
+    if app.rank_history_view.is_some() {
+        draw_rank_history(frame, app, &theme);
+    }
+    if app.targets_view.is_some() {
+        draw_targets_view(frame, app, &theme);
+    }
+    if app.uuid_collision_view.is_some() {
+        draw_uuid_collisions(frame, app, &theme);
+    }
     if app.mod_list_preview.is_some() {
         draw_mod_list_preview(frame, app, &theme);
     }
+    if app.mod_list_picker.is_some() {
+        draw_mod_list_picker(frame, app, &theme);
+    }
     if app.export_menu.is_some() {
         draw_export_menu(frame, app, &theme);
     }
@@ -2944,6 +3775,24 @@ fn current_filter_value(app: &App) -> (String, bool) {
     }
 }
 
+fn render_position_label(frame: &mut Frame<'_>, panel: Rect, label: &str, theme: &Theme) {
+    let label = format!(" {label} ");
+    let width = (display_width(&label) as u16).min(panel.width.saturating_sub(4));
+    if width == 0 || panel.height == 0 {
+        return;
+    }
+    let area = Rect {
+        x: panel.x + panel.width.saturating_sub(width + 2),
+        y: panel.y,
+        width,
+        height: 1,
+    };
+    frame.render_widget(
+        Paragraph::new(Span::styled(label, Style::default().fg(theme.muted))),
+        area,
+    );
+}
+
 fn render_filter_bar(
     frame: &mut Frame<'_>,
     app: &App,
@@ -3016,7 +3865,7 @@ fn render_filter_bar(
             Constraint::Length(search_right_width),
         ])
         .split(search_area);
-    let left_line = Line::from(vec![
+    let mut left_spans = vec![
         Span::styled(
             " Search",
             Style::default()
@@ -3027,7 +3876,26 @@ fn render_filter_bar(
         Span::styled(value_text.to_string(), value_style),
         Span::styled(" | ", Style::default().fg(theme.border)),
         Span::styled(search_hint, hint_style),
-    ]);
+    ];
+    if app.recent_filter {
+        left_spans.push(Span::styled(" | ", Style::default().fg(theme.border)));
+        left_spans.push(Span::styled(
+            format!("Added ≤ {}", app.recent_added_label()),
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if app.starred_filter {
+        left_spans.push(Span::styled(" | ", Style::default().fg(theme.border)));
+        left_spans.push(Span::styled(
+            "★ Starred",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    let left_line = Line::from(left_spans);
     let left = Paragraph::new(left_line)
         .style(Style::default().bg(theme.header_bg))
         .alignment(Alignment::Left);
@@ -3038,8 +3906,50 @@ fn render_filter_bar(
         .alignment(Alignment::Right);
     frame.render_widget(right, search_chunks[1]);
 
+    if bar_chunks[1].height > 0 {
+        if let Some(profile) = app.library.active_profile() {
+            let info = format!(
+                "Profile: {} | Deployed: {} ",
+                app.active_profile_label(),
+                format_rank_timestamp(profile.last_deployed_at)
+            );
+            let reserved = if show_clear { 21 } else { 0 };
+            let info_area = Rect {
+                x: bar_chunks[1].x + reserved.min(bar_chunks[1].width),
+                width: bar_chunks[1].width.saturating_sub(reserved),
+                ..bar_chunks[1]
+            };
+            let badge = if app.deploy_out_of_date {
+                "⚠ Deploy out of date | "
+            } else {
+                ""
+            };
+            let badge_width = badge.chars().count();
+            let info = Paragraph::new(Line::from(vec![
+                Span::styled(
+                    truncate_text(badge, info_area.width as usize),
+                    Style::default()
+                        .fg(theme.warning)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    truncate_text(
+                        &info,
+                        (info_area.width as usize).saturating_sub(badge_width),
+                    ),
+                    Style::default().fg(theme.muted),
+                ),
+            ]))
+            .style(Style::default().bg(theme.header_bg))
+            .alignment(Alignment::Right);
+            frame.render_widget(info, info_area);
+        }
+    }
     if bar_chunks[1].height > 0 && show_clear {
-        let meta_area = bar_chunks[1];
+        let meta_area = Rect {
+            width: bar_chunks[1].width.min(20),
+            ..bar_chunks[1]
+        };
         let meta_left = Paragraph::new(Line::from(Span::styled(
             "Clear search: Ctrl+L",
             Style::default()
@@ -3140,7 +4050,7 @@ fn status_badge_area(area: Rect, status_text: &str) -> Rect {
 
 fn draw_status_panel(
     frame: &mut Frame<'_>,
-    _app: &App,
+    app: &App,
     theme: &Theme,
     area: Rect,
     status_text: &str,
@@ -3173,6 +4083,18 @@ fn draw_status_panel(
         width: status_inner.width,
         height: 1,
     };
+    if let Some(ratio) = app.metadata_progress() {
+        let gauge = Gauge::default()
+            .ratio(ratio)
+            .use_unicode(true)
+            .gauge_style(Style::default().fg(theme.overlay_bar).bg(status_bg))
+            .label(Span::styled(
+                status_text,
+                Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+            ));
+        frame.render_widget(gauge, text_area);
+        return;
+    }
     let status_widget = Paragraph::new(Line::from(Span::styled(
         status_text,
         Style::default().fg(status_color).bg(status_bg),
@@ -3186,40 +4108,95 @@ fn build_log_lines(app: &App, theme: &Theme, height: usize) -> Vec<Line<'static>
         return Vec::new();
     }
 
-    if app.logs.is_empty() {
+    let logs = app.visible_logs();
+    if logs.is_empty() {
+        let message = if app.logs.is_empty() {
+            "No recent events.".to_string()
+        } else {
+            format!("No events match filter: {}", app.log_filter.label())
+        };
         return vec![Line::from(Span::styled(
-            "No recent events.",
+            message,
             Style::default().fg(theme.muted),
         ))];
     }
 
-    let total = app.logs.len();
+    let total = logs.len();
     let view = height.max(1);
     let max_scroll = total.saturating_sub(view);
     let scroll = app.log_scroll.min(max_scroll);
     let start = total.saturating_sub(view + scroll);
     let end = (start + view).min(total);
 
-    app.logs[start..end]
+    let needle = app.log_search.to_ascii_lowercase();
+    logs[start..end]
         .iter()
-        .map(|entry| {
+        .enumerate()
+        .map(|(offset, entry)| {
             let (label, color) = match entry.level {
                 LogLevel::Info => ("[i]", theme.accent),
                 LogLevel::Warn => ("[!]", theme.warning),
                 LogLevel::Error => ("[x]", theme.error),
             };
-            Line::from(vec![
+            let current = app.log_search_match == Some(start + offset);
+            let mut spans = vec![
+                Span::styled(
+                    format!("{} ", entry.time_label()),
+                    Style::default().fg(theme.muted),
+                ),
                 Span::styled(
                     label,
                     Style::default().fg(color).add_modifier(Modifier::BOLD),
                 ),
                 Span::raw(" "),
-                Span::styled(entry.message.clone(), Style::default().fg(theme.text)),
-            ])
-        })
+            ];
+            spans.extend(highlight_log_matches(
+                &entry.message,
+                &needle,
+                current,
+                theme,
+            ));
+            Line::from(spans)
+        })
         .collect()
 }
 
+fn highlight_log_matches(
+    message: &str,
+    needle: &str,
+    current: bool,
+    theme: &Theme,
+) -> Vec<Span<'static>> {
+    let text_style = Style::default().fg(theme.text);
+    if needle.is_empty() {
+        return vec![Span::styled(message.to_string(), text_style)];
+    }
+    let match_style = if current {
+        Style::default()
+            .fg(theme.header_bg)
+            .bg(theme.accent)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.text).bg(theme.accent_soft)
+    };
+    let haystack = message.to_ascii_lowercase();
+    let mut spans = Vec::new();
+    let mut cursor = 0usize;
+    while let Some(found) = haystack[cursor..].find(needle) {
+        let begin = cursor + found;
+        let end = begin + needle.len();
+        if begin > cursor {
+            spans.push(Span::styled(message[cursor..begin].to_string(), text_style));
+        }
+        spans.push(Span::styled(message[begin..end].to_string(), match_style));
+        cursor = end;
+    }
+    if cursor < message.len() {
+        spans.push(Span::styled(message[cursor..].to_string(), text_style));
+    }
+    spans
+}
+
 fn extend_table_stripes(
     frame: &mut Frame<'_>,
     theme: &Theme,
@@ -3514,7 +4491,10 @@ fn draw_dialog(frame: &mut Frame<'_>, app: &mut App, theme: &Theme) {
     let area = frame.size();
     let message_lines = build_dialog_message_lines(dialog, theme);
 
-    let has_cancel = matches!(dialog.kind, DialogKind::DeleteMod { .. });
+    let has_cancel = matches!(
+        dialog.kind,
+        DialogKind::DeleteMod { .. } | DialogKind::PendingMove { .. }
+    );
     let yes_selected = matches!(dialog.choice, DialogChoice::Yes);
     let no_selected = if has_cancel {
         matches!(dialog.choice, DialogChoice::No)
@@ -3941,38 +4921,93 @@ fn draw_settings_menu(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
 
 fn draw_paths_overlay(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
     let area = frame.size();
-    let root = if app.config.game_root.as_os_str().is_empty() {
-        "<not set>".to_string()
-    } else {
-        app.config.game_root.display().to_string()
-    };
-    let user_dir = if app.config.larian_dir.as_os_str().is_empty() {
-        "<not set>".to_string()
-    } else {
-        app.config.larian_dir.display().to_string()
-    };
-    let config_path = app.config.data_dir.join("config.json");
     let label_style = Style::default().fg(theme.muted);
     let value_style = Style::default().fg(theme.text);
-    let lines = vec![
-        Line::from(vec![
-            Span::styled("Root: ", label_style),
-            Span::styled(root, value_style),
-        ]),
-        Line::from(vec![
-            Span::styled("User: ", label_style),
-            Span::styled(user_dir, value_style),
-        ]),
-        Line::from(vec![
-            Span::styled("Config: ", label_style),
-            Span::styled(config_path.display().to_string(), value_style),
-        ]),
+    let selected_style = Style::default()
+        .fg(theme.accent)
+        .add_modifier(Modifier::BOLD);
+    let mut lines: Vec<Line> = app
+        .paths_overlay_entries()
+        .into_iter()
+        .enumerate()
+        .map(|(index, (label, path))| {
+            let selected = index == app.paths_overlay_selected;
+            let value = path
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|| "<not set>".to_string());
+            Line::from(vec![
+                Span::styled(if selected { "> " } else { "  " }, selected_style),
+                Span::styled(format!("{label}: "), label_style),
+                Span::styled(
+                    value,
+                    if selected {
+                        selected_style
+                    } else {
+                        value_style
+                    },
+                ),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(vec![
+        Span::styled("  SigiLink Cache Size: ", label_style),
+        Span::styled(app.cache_size_label(), value_style),
+    ]));
+    let split = app.cache_filesystem_split();
+    if !split.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  Cache is on a different drive than the {}: deploys symlink instead of hardlink",
+                split.join(" and ")
+            ),
+            Style::default().fg(theme.warning),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  Script Extender: ", label_style),
+        Span::styled(
+            app.script_extender_status(),
+            if app.script_extender.is_some() {
+                Style::default().fg(theme.success)
+            } else {
+                Style::default().fg(theme.muted)
+            },
+        ),
+    ]));
+    if app.script_extender.is_some()
+        && app.config.larian_dir_source == Some(LarianDirSource::Proton)
+    {
+        lines.push(Line::from(Span::styled(
+            "  Proton launch option: WINEDLLOVERRIDES=\"DWrite=n,b\" %command%",
+            label_style,
+        )));
+    }
+    let required = app.mods_requiring_script_extender();
+    if app.script_extender.is_none() && !required.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  {} enabled mod(s) use Script Extender; install BG3SE into bin/",
+                required.len()
+            ),
+            Style::default().fg(theme.warning),
+        )));
+    }
+    lines.extend([
         Line::from(""),
+        Line::from(Span::styled(
+            "↑/↓: select | c: copy path | a: copy all",
+            Style::default().fg(theme.muted),
+        )),
+        Line::from(Span::styled(
+            "o: open data dir | g: open root | u: open user dir",
+            Style::default().fg(theme.muted),
+        )),
         Line::from(Span::styled(
             "Enter/Esc: close",
             Style::default().fg(theme.muted),
         )),
-    ];
+    ]);
 
     let mut max_line = 0usize;
     for line in &lines {
@@ -4730,6 +5765,101 @@ fn draw_override_picker(frame: &mut Frame<'_>, app: &mut App, theme: &Theme) {
     frame.render_widget(footer_widget, chunks[2]);
 }
 
+fn draw_mod_list_picker(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
+    let Some(picker) = &app.mod_list_picker else {
+        return;
+    };
+
+    let area = frame.size();
+    let max_width = area.width.saturating_sub(6).max(1);
+    let width = max_width.clamp(52, 96);
+    let max_height = area.height.saturating_sub(6).max(1);
+    let height = max_height.clamp(10, 20);
+    let (outer_area, modal) = padded_modal(area, width, height, 2, 1);
+
+    render_modal_backdrop(frame, outer_area, theme);
+    let panel_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.overlay_border))
+        .style(Style::default().bg(theme.overlay_panel_bg))
+        .title(Span::styled(
+            "Ambiguous match",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let inner = panel_block.inner(modal);
+    frame.render_widget(panel_block, modal);
+
+    let header_lines = vec![
+        Line::from(Span::styled(
+            truncate_text(&format!("Entry: {}", picker.label), inner.width as usize),
+            Style::default().fg(theme.text),
+        )),
+        Line::from(Span::styled(
+            "Select the library mod this entry refers to.",
+            Style::default().fg(theme.muted),
+        )),
+    ];
+    let header_height = header_lines.len() as u16 + 1;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(header_height),
+            Constraint::Min(3),
+            Constraint::Length(2),
+        ])
+        .split(inner);
+    frame.render_widget(
+        Paragraph::new(header_lines).style(Style::default().bg(theme.overlay_panel_bg)),
+        chunks[0],
+    );
+
+    let list_width = chunks[1].width as usize;
+    let list_items: Vec<ListItem<'_>> = picker
+        .items
+        .iter()
+        .map(|item| {
+            ListItem::new(Line::from(Span::styled(
+                truncate_text(&item.name, list_width),
+                Style::default().fg(theme.text),
+            )))
+        })
+        .collect();
+    let list = List::new(list_items)
+        .style(Style::default().bg(theme.overlay_panel_bg))
+        .highlight_style(
+            Style::default()
+                .bg(theme.accent_soft)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("");
+    let mut state = ListState::default();
+    if !picker.items.is_empty() {
+        state.select(Some(picker.selected));
+    }
+    frame.render_stateful_widget(list, chunks[1], &mut state);
+
+    let key_style = Style::default()
+        .fg(theme.accent)
+        .add_modifier(Modifier::BOLD);
+    let text_style = Style::default().fg(theme.muted);
+    let footer = Line::from(vec![
+        Span::styled("↑/↓", key_style),
+        Span::styled(" Move  ", text_style),
+        Span::styled("[Enter]", key_style),
+        Span::styled(" Select  ", text_style),
+        Span::styled("[Esc]", key_style),
+        Span::styled(" Back", text_style),
+    ]);
+    frame.render_widget(
+        Paragraph::new(vec![footer]).style(Style::default().bg(theme.overlay_panel_bg)),
+        chunks[2],
+    );
+}
+
 fn draw_sigillink_missing_queue(frame: &mut Frame<'_>, app: &mut App, theme: &Theme) {
     let (total, trigger) = {
         let Some(queue) = app.sigillink_missing_queue() else {
@@ -4930,8 +6060,12 @@ fn draw_path_browser(frame: &mut Frame<'_>, app: &App, theme: &Theme, browser: &
         PathBrowserPurpose::ExportProfile { kind, .. } => match kind {
             ExportKind::ModList => "Export mod list",
             ExportKind::Modsettings => "Export modsettings.lsx",
+            ExportKind::UuidList => "Export enabled UUID list",
         },
         PathBrowserPurpose::ExportLog => "Export Log File",
+        PathBrowserPurpose::ExportDiagnostics => "Copy Diagnostics Bundle",
+        PathBrowserPurpose::StageDeploy => "Deploy To Staging Folder",
+        PathBrowserPurpose::ImportSettings => "Import Settings",
         PathBrowserPurpose::SigilLinkCache { action, .. } => match action {
             SigilLinkCacheAction::Move => "Move SigiLink Cache",
             SigilLinkCacheAction::Relocate { .. } => "Select SigiLink Cache Folder",
@@ -5013,9 +6147,14 @@ fn draw_path_browser(frame: &mut Frame<'_>, app: &App, theme: &Theme, browser: &
             "Not a Larian data dir (needs PlayerProfiles/)",
         ),
         PathBrowserPurpose::Setup(SetupStep::DownloadsDir) => (" Folder valid ", "Not a folder."),
-        PathBrowserPurpose::ImportProfile => (" File selected ", "Select a file to import."),
+        PathBrowserPurpose::ImportProfile | PathBrowserPurpose::ImportSettings => {
+            (" File selected ", "Select a file to import.")
+        }
         PathBrowserPurpose::ExportProfile { .. } => (" Export path valid ", "Enter a file name."),
-        PathBrowserPurpose::ExportLog => (" Folder selected ", "Select a folder to export."),
+        PathBrowserPurpose::ExportLog | PathBrowserPurpose::ExportDiagnostics => {
+            (" Folder selected ", "Select a folder to export.")
+        }
+        PathBrowserPurpose::StageDeploy => (" Folder selected ", "Select a staging folder."),
         PathBrowserPurpose::SigilLinkCache { require_dev, .. } => {
             if require_dev.is_some() {
                 (
@@ -5052,6 +6191,8 @@ fn draw_path_browser(frame: &mut Frame<'_>, app: &App, theme: &Theme, browser: &
         browser.purpose,
         PathBrowserPurpose::Setup(_)
             | PathBrowserPurpose::ExportLog
+            | PathBrowserPurpose::ExportDiagnostics
+            | PathBrowserPurpose::StageDeploy
             | PathBrowserPurpose::SigilLinkCache { .. }
     );
     let hide_select = !show_select;
@@ -5187,86 +6328,1422 @@ struct SmartRankScroll {
     header_lines: usize,
 }
 
-struct ModListPreviewRender {
-    lines: Vec<Line<'static>>,
-    scroll: usize,
-    max_scroll: usize,
+struct ModListPreviewRender {
+    lines: Vec<Line<'static>>,
+    scroll: usize,
+    max_scroll: usize,
+}
+
+fn draw_smart_rank_preview(frame: &mut Frame<'_>, app: &mut App, theme: &Theme) {
+    let Some(preview) = &app.smart_rank_preview else {
+        return;
+    };
+
+    let area = frame.size();
+    let max_width = area.width.saturating_sub(2).max(1);
+    let width = max_width.min(120).max(60).min(max_width);
+    let max_height = area.height.saturating_sub(2).max(1);
+    let height = max_height.min(22).max(10);
+    let (outer_area, preview_area) = padded_modal(area, width, height, 2, 1);
+
+    let inner_width = preview_area.width.saturating_sub(3) as usize;
+    let inner_height = preview_area.height.saturating_sub(2) as usize;
+    let notice = app
+        .sigillink_preview_notice()
+        .map(|value| value.to_string());
+    let render = build_smart_rank_preview_render(
+        preview,
+        theme,
+        inner_width,
+        inner_height,
+        app.smart_rank_scroll,
+        app.smart_rank_view,
+        notice,
+    );
+
+    render_modal_backdrop(frame, outer_area, theme);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.accent_soft))
+        .style(Style::default().bg(theme.header_bg))
+        .title(Span::styled(
+            "SigiLink Intelligent Ranking",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(preview_area);
+    let widget = Paragraph::new(render.lines)
+        .block(block)
+        .style(Style::default().fg(theme.text))
+        .alignment(Alignment::Left);
+    frame.render_widget(widget, preview_area);
+
+    let scroll_meta = render.scroll;
+    if let Some(scroll) = scroll_meta {
+        app.smart_rank_scroll = scroll.position;
+        if scroll.total > scroll.view && inner.width > 0 && inner.height > 0 {
+            let body_height = scroll.view.min(inner.height as usize) as u16;
+            if body_height > 0 {
+                let scroll_area = Rect {
+                    x: inner.x + inner.width.saturating_sub(1),
+                    y: inner.y.saturating_add(scroll.header_lines as u16),
+                    width: 1,
+                    height: body_height,
+                };
+                let scroll_len = scroll.total.saturating_sub(scroll.view).saturating_add(1);
+                let mut scroll_state = ScrollbarState::new(scroll_len)
+                    .position(scroll.position)
+                    .viewport_content_length(scroll.view);
+                let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                    .track_symbol(Some("░"))
+                    .thumb_symbol("▓")
+                    .begin_symbol(None)
+                    .end_symbol(None)
+                    .track_style(Style::default().fg(theme.border))
+                    .thumb_style(Style::default().fg(theme.accent));
+                frame.render_stateful_widget(scrollbar, scroll_area, &mut scroll_state);
+            }
+        }
+    } else {
+        app.smart_rank_scroll = 0;
+    }
+}
+
+fn draw_compatibility_report(frame: &mut Frame<'_>, app: &mut App, theme: &Theme) {
+    let Some(view) = &app.compatibility_view else {
+        return;
+    };
+
+    let area = frame.size();
+    let max_width = area.width.saturating_sub(2).max(1);
+    let width = max_width.min(120);
+    let max_height = area.height.saturating_sub(2).max(1);
+    let height = max_height.min(28);
+    let (outer_area, report_area) = padded_modal(area, width, height, 2, 1);
+    let inner_width = report_area.width.saturating_sub(3) as usize;
+    let inner_height = report_area.height.saturating_sub(2) as usize;
+    let render = build_compatibility_report_render(view, theme, inner_width, inner_height);
+    let header_lines = render.header_lines;
+
+    render_modal_backdrop(frame, outer_area, theme);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.accent_soft))
+        .style(Style::default().bg(theme.header_bg))
+        .title(Span::styled(
+            "Compatibility Report",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(report_area);
+    let widget = Paragraph::new(render.lines)
+        .block(block)
+        .style(Style::default().fg(theme.text))
+        .alignment(Alignment::Left);
+    frame.render_widget(widget, report_area);
+    if let Some(view) = &mut app.compatibility_view {
+        view.scroll = render.scroll;
+    }
+
+    if render.max_scroll > 0 && inner.width > 0 && inner.height > 0 {
+        let body_height = inner.height.saturating_sub(header_lines as u16 + 1);
+        if body_height > 0 {
+            let scroll_area = Rect {
+                x: inner.x + inner.width.saturating_sub(1),
+                y: inner.y + header_lines as u16,
+                width: 1,
+                height: body_height,
+            };
+            let scroll_len = render.max_scroll.saturating_add(1);
+            let mut scroll_state = ScrollbarState::new(scroll_len)
+                .position(render.scroll)
+                .viewport_content_length(body_height as usize);
+            let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .track_symbol(Some("░"))
+                .thumb_symbol("▓")
+                .begin_symbol(None)
+                .end_symbol(None)
+                .track_style(Style::default().fg(theme.border))
+                .thumb_style(Style::default().fg(theme.accent));
+            frame.render_stateful_widget(scrollbar, scroll_area, &mut scroll_state);
+        }
+    }
+}
+
+fn draw_command_palette(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
+    let Some(palette) = &app.command_palette else {
+        return;
+    };
+    let entries = app.command_palette_entries();
+
+    let area = frame.size();
+    let max_width = area.width.saturating_sub(2).max(1);
+    let width = max_width.min(72);
+    let max_height = area.height.saturating_sub(2).max(1);
+    let height = max_height.min(20);
+    let (outer_area, palette_area) = padded_modal(area, width, height, 2, 1);
+
+    render_modal_backdrop(frame, outer_area, theme);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.accent_soft))
+        .style(Style::default().bg(theme.header_bg))
+        .title(Span::styled(
+            "Command Palette",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(palette_area);
+    frame.render_widget(block, palette_area);
+    if inner.width == 0 || inner.height < 3 {
+        return;
+    }
+    let width = inner.width as usize;
+
+    let mut lines = Vec::new();
+    lines.push(Line::from(vec![
+        Span::styled(": ", Style::default().fg(theme.accent)),
+        Span::styled(palette.query.clone(), Style::default().fg(theme.text)),
+        Span::styled("_", Style::default().fg(theme.accent)),
+    ]));
+    lines.push(Line::from(Span::styled(
+        truncate_text("Enter: run | Esc: close | Up/Down: select", width),
+        Style::default().fg(theme.muted),
+    )));
+
+    let view = inner.height.saturating_sub(2) as usize;
+    let selected = palette.selected.min(entries.len().saturating_sub(1));
+    let start = (selected + 1).saturating_sub(view);
+    if entries.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No matching actions.",
+            Style::default().fg(theme.muted),
+        )));
+    }
+    for (index, entry) in entries.iter().enumerate().skip(start).take(view) {
+        let is_selected = index == selected;
+        let prefix = if is_selected { "> " } else { "  " };
+        let mut style = match entry.disabled {
+            Some(_) => Style::default().fg(theme.muted),
+            None => Style::default().fg(theme.text),
+        };
+        if is_selected {
+            style = style.bg(theme.accent_soft).add_modifier(Modifier::BOLD);
+        }
+        let text = match &entry.disabled {
+            Some(reason) => format!("{prefix}{} ({reason})", entry.label),
+            None => format!("{prefix}{}", entry.label),
+        };
+        lines.push(Line::from(Span::styled(truncate_text(&text, width), style)));
+    }
+
+    frame.render_widget(
+        Paragraph::new(lines).style(Style::default().fg(theme.text)),
+        inner,
+    );
+}
+
+fn draw_setup_wizard(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
+    let Some(wizard) = &app.setup_wizard else {
+        return;
+    };
+
+    let area = frame.size();
+    let max_width = area.width.saturating_sub(2).max(1);
+    let width = max_width.min(84);
+    let max_height = area.height.saturating_sub(2).max(1);
+    let height = max_height.min(18);
+    let (outer_area, wizard_area) = padded_modal(area, width, height, 2, 1);
+
+    render_modal_backdrop(frame, outer_area, theme);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.accent_soft))
+        .style(Style::default().bg(theme.header_bg))
+        .title(Span::styled(
+            "SigilSmith Setup",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(wizard_area);
+    frame.render_widget(block, wizard_area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+    let width = inner.width as usize;
+    let muted = Style::default().fg(theme.muted);
+    let step_label = |step: SetupStep| match step {
+        SetupStep::GameRoot => "BG3 install root",
+        SetupStep::LarianDir => "Larian data dir",
+        SetupStep::DownloadsDir => "Downloads folder",
+    };
+    let path_text = |path: &PathBuf| {
+        if path.as_os_str().is_empty() {
+            "<not set>".to_string()
+        } else {
+            path.display().to_string()
+        }
+    };
+
+    let total = SETUP_WIZARD_STEPS.len() + 1;
+    let mut lines = vec![
+        Line::from(Span::styled(
+            truncate_text(
+                &format!("Step {} of {total}", (wizard.step + 1).min(total)),
+                width,
+            ),
+            muted,
+        )),
+        Line::from(""),
+    ];
+    match wizard.current_step() {
+        Some(step) => {
+            let (help, invalid) = match step {
+                SetupStep::GameRoot => (
+                    "Folder that contains Data/ and bin/ (Steam: steamapps/common/Baldurs Gate 3).",
+                    "Not a BG3 install root.",
+                ),
+                SetupStep::LarianDir => (
+                    "Larian user folder that contains PlayerProfiles/.",
+                    "PlayerProfiles/ not found here.",
+                ),
+                SetupStep::DownloadsDir => (
+                    "Where the import browser starts when you add mods.",
+                    "Folder does not exist.",
+                ),
+            };
+            lines.push(Line::from(Span::styled(
+                step_label(step),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(Span::styled(truncate_text(help, width), muted)));
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                truncate_text(&path_text(wizard.path(step)), width),
+                Style::default().fg(theme.text),
+            )));
+            let (status, style) = if app.setup_wizard_valid(step) {
+                ("Looks good.", Style::default().fg(theme.success))
+            } else {
+                (invalid, Style::default().fg(theme.warning))
+            };
+            lines.push(Line::from(Span::styled(status, style)));
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                truncate_text("Enter: next | b: browse | Esc: back", width),
+                muted,
+            )));
+        }
+        None => {
+            lines.push(Line::from(Span::styled(
+                "Summary",
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(""));
+            for step in SETUP_WIZARD_STEPS {
+                let (mark, style) = if app.setup_wizard_valid(step) {
+                    ("✓", Style::default().fg(theme.success))
+                } else {
+                    ("✗", Style::default().fg(theme.warning))
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{mark} "), style),
+                    Span::styled(format!("{}: ", step_label(step)), muted),
+                    Span::styled(
+                        truncate_text(
+                            &path_text(wizard.path(step)),
+                            width.saturating_sub(step_label(step).len() + 4),
+                        ),
+                        Style::default().fg(theme.text),
+                    ),
+                ]));
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                truncate_text("Enter: save and finish | Esc: back", width),
+                muted,
+            )));
+        }
+    }
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_conflict_rules(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
+    let Some(view) = &app.conflict_rules_view else {
+        return;
+    };
+
+    let area = frame.size();
+    let max_width = area.width.saturating_sub(2).max(1);
+    let width = max_width.min(76);
+    let max_height = area.height.saturating_sub(2).max(1);
+    let height = max_height.min(22);
+    let (outer_area, rules_area) = padded_modal(area, width, height, 2, 1);
+
+    render_modal_backdrop(frame, outer_area, theme);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.accent_soft))
+        .style(Style::default().bg(theme.header_bg))
+        .title(Span::styled(
+            "Conflict Ignore Rules",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(rules_area);
+    frame.render_widget(block, rules_area);
+    if inner.width == 0 || inner.height < 4 {
+        return;
+    }
+    let width = inner.width as usize;
+    let muted = Style::default().fg(theme.muted);
+
+    let mut lines = Vec::new();
+    let identical = if app.config.conflict_ignore_identical {
+        "[x]"
+    } else {
+        "[ ]"
+    };
+    lines.push(Line::from(Span::styled(
+        truncate_text(
+            &format!("{identical} Ignore when file contents are identical (i)"),
+            width,
+        ),
+        Style::default().fg(theme.text),
+    )));
+    let mut summary = format!("Ignored now: {}", app.ignored_conflict_count());
+    if app.conflicts_scanning() || app.conflicts_pending() {
+        summary.push_str(" (rescanning...)");
+    }
+    lines.push(Line::from(Span::styled(
+        truncate_text(&summary, width),
+        muted,
+    )));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        truncate_text(
+            "Path globs (* and ?), matched against relative paths:",
+            width,
+        ),
+        muted,
+    )));
+
+    let footer_len = 3usize;
+    let view_rows = (inner.height as usize)
+        .saturating_sub(lines.len() + footer_len)
+        .max(1);
+    let rules = &app.config.conflict_ignore;
+    if rules.is_empty() && view.adding.is_none() {
+        lines.push(Line::from(Span::styled(
+            truncate_text("No rules yet. Press a to add one, e.g. *.dds", width),
+            muted,
+        )));
+    }
+    let selected = view.selected.min(rules.len().saturating_sub(1));
+    let start = (selected + 1).saturating_sub(view_rows);
+    for (index, pattern) in rules.iter().enumerate().skip(start).take(view_rows) {
+        let is_selected = index == selected && view.adding.is_none();
+        let prefix = if is_selected { "> " } else { "  " };
+        let matches = app.conflict_rule_count(index);
+        let mut style = Style::default().fg(theme.text);
+        if is_selected {
+            style = style.bg(theme.accent_soft).add_modifier(Modifier::BOLD);
+        }
+        lines.push(Line::from(Span::styled(
+            truncate_text(&format!("{prefix}{pattern}  ({matches} match)"), width),
+            style,
+        )));
+    }
+    if let Some(buffer) = &view.adding {
+        let matches = view.adding_matches;
+        let total = app.conflict_total();
+        lines.push(Line::from(vec![
+            Span::styled("+ ", Style::default().fg(theme.accent)),
+            Span::styled(buffer.clone(), Style::default().fg(theme.text)),
+            Span::styled("_", Style::default().fg(theme.accent)),
+            Span::styled(format!("  ({matches} of {total})"), muted),
+        ]));
+    }
+
+    let mut footer = Vec::new();
+    footer.push(Line::from(""));
+    if let Some(result) = &view.test_result {
+        footer.push(Line::from(Span::styled(
+            truncate_text(result, width),
+            Style::default().fg(theme.accent),
+        )));
+    }
+    let hint = if view.adding.is_some() {
+        "Enter: add rule | Esc: cancel"
+    } else {
+        "a: add | d/Del: remove | t: test | i: identical | Esc: close"
+    };
+    footer.push(Line::from(Span::styled(truncate_text(hint, width), muted)));
+    let body_rows = (inner.height as usize).saturating_sub(footer.len());
+    lines.truncate(body_rows);
+    while lines.len() < body_rows {
+        lines.push(Line::from(""));
+    }
+    lines.extend(footer);
+
+    frame.render_widget(
+        Paragraph::new(lines).style(Style::default().fg(theme.text)),
+        inner,
+    );
+}
+
+fn draw_order_rules(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
+    let Some(view) = &app.order_rules_view else {
+        return;
+    };
+
+    let area = frame.size();
+    let max_width = area.width.saturating_sub(2).max(1);
+    let width = max_width.min(76);
+    let max_height = area.height.saturating_sub(2).max(1);
+    let height = max_height.min(20);
+    let (outer_area, rules_area) = padded_modal(area, width, height, 2, 1);
+
+    render_modal_backdrop(frame, outer_area, theme);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.accent_soft))
+        .style(Style::default().bg(theme.header_bg))
+        .title(Span::styled(
+            "SigiLink Ordering Rules",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(rules_area);
+    frame.render_widget(block, rules_area);
+    if inner.width == 0 || inner.height < 4 {
+        return;
+    }
+    let width = inner.width as usize;
+    let muted = Style::default().fg(theme.muted);
+
+    let mut lines = Vec::new();
+    lines.push(Line::from(Span::styled(
+        truncate_text(
+            &format!(
+                "Profile \"{}\": hard constraints for SigiLink ranking (pins still win).",
+                app.library.active_profile
+            ),
+            width,
+        ),
+        muted,
+    )));
+    lines.push(Line::from(Span::styled(
+        truncate_text(
+            "Forms: A after B | A before B | <tag> last (tag or name keyword)",
+            width,
+        ),
+        muted,
+    )));
+    lines.push(Line::from(""));
+
+    let footer_len = 3usize;
+    let view_rows = (inner.height as usize)
+        .saturating_sub(lines.len() + footer_len)
+        .max(1);
+    let rules = app.active_order_rules();
+    if rules.is_empty() && view.adding.is_none() {
+        lines.push(Line::from(Span::styled(
+            truncate_text("No rules yet. Press a to add one, e.g. patch last", width),
+            muted,
+        )));
+    }
+    let mod_map = app.library.index_by_id();
+    let selected = view.selected.min(rules.len().saturating_sub(1));
+    let start = (selected + 1).saturating_sub(view_rows);
+    for (index, rule) in rules.iter().enumerate().skip(start).take(view_rows) {
+        let is_selected = index == selected && view.adding.is_none();
+        let prefix = if is_selected { "> " } else { "  " };
+        let mut style = Style::default().fg(theme.text);
+        if is_selected {
+            style = style.bg(theme.accent_soft).add_modifier(Modifier::BOLD);
+        }
+        lines.push(Line::from(Span::styled(
+            truncate_text(&format!("{prefix}{}", rule.label(&mod_map)), width),
+            style,
+        )));
+    }
+    if let Some(buffer) = &view.adding {
+        lines.push(Line::from(vec![
+            Span::styled("+ ", Style::default().fg(theme.accent)),
+            Span::styled(buffer.clone(), Style::default().fg(theme.text)),
+            Span::styled("_", Style::default().fg(theme.accent)),
+        ]));
+    }
+
+    let mut footer = Vec::new();
+    footer.push(Line::from(""));
+    if let Some(error) = &view.error {
+        footer.push(Line::from(Span::styled(
+            truncate_text(error, width),
+            Style::default().fg(theme.warning),
+        )));
+    }
+    let hint = if view.adding.is_some() {
+        "Enter: add rule | Esc: cancel"
+    } else {
+        "a: add | d/Del: remove | Esc: close"
+    };
+    footer.push(Line::from(Span::styled(truncate_text(hint, width), muted)));
+    let body_rows = (inner.height as usize).saturating_sub(footer.len());
+    lines.truncate(body_rows);
+    while lines.len() < body_rows {
+        lines.push(Line::from(""));
+    }
+    lines.extend(footer);
+
+    frame.render_widget(
+        Paragraph::new(lines).style(Style::default().fg(theme.text)),
+        inner,
+    );
+}
+
+fn draw_rank_history(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
+    let Some(view) = &app.rank_history_view else {
+        return;
+    };
+
+    let area = frame.size();
+    let max_width = area.width.saturating_sub(2).max(1);
+    let width = max_width.min(76);
+    let max_height = area.height.saturating_sub(2).max(1);
+    let height = max_height.min(20);
+    let (outer_area, history_area) = padded_modal(area, width, height, 2, 1);
+
+    render_modal_backdrop(frame, outer_area, theme);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.accent_soft))
+        .style(Style::default().bg(theme.header_bg))
+        .title(Span::styled(
+            "SigiLink Ranking History",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(history_area);
+    frame.render_widget(block, history_area);
+    if inner.width == 0 || inner.height < 4 {
+        return;
+    }
+    let width = inner.width as usize;
+    let muted = Style::default().fg(theme.muted);
+
+    let history = app.sigillink_rank_history();
+    let current: Vec<&str> = app
+        .library
+        .active_profile()
+        .map(|profile| {
+            profile
+                .order
+                .iter()
+                .map(|entry| entry.id.as_str())
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut lines = Vec::new();
+    lines.push(Line::from(Span::styled(
+        truncate_text(
+            &format!(
+                "Profile \"{}\": last {} applied ranking(s), newest first.",
+                app.library.active_profile,
+                history.len()
+            ),
+            width,
+        ),
+        muted,
+    )));
+    lines.push(Line::from(""));
+
+    let footer_len = 2usize;
+    let view_rows = (inner.height as usize)
+        .saturating_sub(lines.len() + footer_len)
+        .max(1);
+    let selected = view.selected.min(history.len().saturating_sub(1));
+    let start = (selected + 1).saturating_sub(view_rows);
+    for (index, snapshot) in history.iter().enumerate().skip(start).take(view_rows) {
+        let is_selected = index == selected;
+        let prefix = if is_selected { "> " } else { "  " };
+        let matches_ranked = snapshot
+            .after
+            .iter()
+            .map(|entry| entry.id.as_str())
+            .eq(current.iter().copied());
+        let matches_before = snapshot
+            .before
+            .iter()
+            .map(|entry| entry.id.as_str())
+            .eq(current.iter().copied());
+        let state = if matches_ranked {
+            "  [current = ranked]"
+        } else if matches_before {
+            "  [current = before]"
+        } else {
+            ""
+        };
+        let mut style = Style::default().fg(theme.text);
+        if is_selected {
+            style = style.bg(theme.accent_soft).add_modifier(Modifier::BOLD);
+        }
+        lines.push(Line::from(Span::styled(
+            truncate_text(
+                &format!(
+                    "{prefix}{}  {} move(s), {} mod(s){state}",
+                    format_rank_timestamp(Some(snapshot.ranked_at)),
+                    snapshot.moves,
+                    snapshot.after.len()
+                ),
+                width,
+            ),
+            style,
+        )));
+    }
+
+    let footer = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            truncate_text(
+                "Enter/r: restore ranked order | b/u: restore order before | Esc: close",
+                width,
+            ),
+            muted,
+        )),
+    ];
+    let body_rows = (inner.height as usize).saturating_sub(footer.len());
+    lines.truncate(body_rows);
+    while lines.len() < body_rows {
+        lines.push(Line::from(""));
+    }
+    lines.extend(footer);
+
+    frame.render_widget(
+        Paragraph::new(lines).style(Style::default().fg(theme.text)),
+        inner,
+    );
+}
+
+fn draw_targets_view(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
+    let Some(view) = &app.targets_view else {
+        return;
+    };
+
+    let area = frame.size();
+    let max_width = area.width.saturating_sub(2).max(1);
+    let width = max_width.min(96);
+    let max_height = area.height.saturating_sub(2).max(1);
+    let height = max_height.min(24);
+    let (outer_area, targets_area) = padded_modal(area, width, height, 2, 1);
+
+    render_modal_backdrop(frame, outer_area, theme);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.accent_soft))
+        .style(Style::default().bg(theme.header_bg))
+        .title(Span::styled(
+            "Install Targets",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(targets_area);
+    frame.render_widget(block, targets_area);
+    if inner.width == 0 || inner.height < 4 {
+        return;
+    }
+    let width = inner.width as usize;
+    let muted = Style::default().fg(theme.muted);
+
+    let mod_entry = app
+        .library
+        .mods
+        .iter()
+        .find(|mod_entry| mod_entry.id == view.mod_id);
+    let name = mod_entry
+        .map(|mod_entry| mod_entry.display_name())
+        .unwrap_or_else(|| view.mod_id.clone());
+    let profile_enabled = app
+        .library
+        .active_profile()
+        .and_then(|profile| {
+            profile
+                .order
+                .iter()
+                .find(|entry| entry.id == view.mod_id)
+                .map(|entry| entry.enabled)
+        })
+        .unwrap_or(false);
+    let details = app.mod_target_details(&view.mod_id);
+
+    let mut header = vec![
+        Line::from(Span::styled(
+            truncate_text(&name, width),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            truncate_text(
+                &format!(
+                    "{} target(s) | {} in profile \"{}\"",
+                    details.len(),
+                    if profile_enabled {
+                        "enabled"
+                    } else {
+                        "disabled"
+                    },
+                    app.library.active_profile
+                ),
+                width,
+            ),
+            muted,
+        )),
+        Line::from(""),
+    ];
+
+    let mut body = Vec::new();
+    if details.is_empty() {
+        body.push(Line::from(Span::styled(
+            "No install targets recorded.",
+            muted,
+        )));
+    }
+    for detail in &details {
+        let (state, state_style) = if detail.enabled {
+            ("ON ", Style::default().fg(theme.success))
+        } else {
+            ("OFF", Style::default().fg(theme.warning))
+        };
+        let mut spans = vec![
+            Span::styled(format!("[{state}] "), state_style),
+            Span::styled(
+                format!("{:<10}", target_kind_label(detail.kind)),
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(detail.label.clone(), Style::default().fg(theme.text)),
+        ];
+        if detail.overridden {
+            spans.push(Span::styled("  (override)", muted));
+        }
+        body.push(Line::from(spans));
+        let source = format!(
+            "    from: {}{}",
+            detail.source.display(),
+            if detail.source_exists {
+                ""
+            } else {
+                " (missing)"
+            }
+        );
+        let source_style = if detail.source_exists {
+            muted
+        } else {
+            Style::default().fg(theme.warning)
+        };
+        body.push(Line::from(Span::styled(
+            truncate_text(&source, width),
+            source_style,
+        )));
+        let dest = match &detail.dest {
+            Some(dest) => format!("    to:   {}", dest.display()),
+            None if detail.kind == TargetKind::Pak => "    to:   (native, in place)".to_string(),
+            None => "    to:   (game paths not detected)".to_string(),
+        };
+        body.push(Line::from(Span::styled(truncate_text(&dest, width), muted)));
+    }
+
+    let footer = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            truncate_text(
+                "0: all targets on/off | 1-5: target override (mods list) | ↑/↓: scroll | Esc: close",
+                width,
+            ),
+            muted,
+        )),
+    ];
+    let body_rows = (inner.height as usize)
+        .saturating_sub(header.len() + footer.len())
+        .max(1);
+    let max_scroll = body.len().saturating_sub(body_rows);
+    let scroll = view.scroll.min(max_scroll);
+    let mut lines = std::mem::take(&mut header);
+    lines.extend(body.into_iter().skip(scroll).take(body_rows));
+    let total_rows = (inner.height as usize).saturating_sub(footer.len());
+    lines.truncate(total_rows);
+    while lines.len() < total_rows {
+        lines.push(Line::from(""));
+    }
+    lines.extend(footer);
+
+    frame.render_widget(
+        Paragraph::new(lines).style(Style::default().fg(theme.text)),
+        inner,
+    );
+}
+
+fn draw_load_order_check(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
+    let Some(view) = &app.load_order_check_view else {
+        return;
+    };
+
+    let area = frame.size();
+    let max_width = area.width.saturating_sub(2).max(1);
+    let width = max_width.min(96);
+    let max_height = area.height.saturating_sub(2).max(1);
+    let height = max_height.min(24);
+    let (outer_area, check_area) = padded_modal(area, width, height, 2, 1);
+
+    render_modal_backdrop(frame, outer_area, theme);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.accent_soft))
+        .style(Style::default().bg(theme.header_bg))
+        .title(Span::styled(
+            "Load Order Check",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(check_area);
+    frame.render_widget(block, check_area);
+    if inner.width == 0 || inner.height < 4 {
+        return;
+    }
+    let width = inner.width as usize;
+    let muted = Style::default().fg(theme.muted);
+
+    let summary = if view.issues.is_empty() {
+        format!(
+            "No likely problems in {} enabled mod(s). Heuristic only; not a guarantee.",
+            view.checked
+        )
+    } else {
+        format!(
+            "{} warning(s) in {} enabled mod(s). Heuristic only; review before moving.",
+            view.issues.len(),
+            view.checked
+        )
+    };
+    let mut lines = vec![
+        Line::from(Span::styled(truncate_text(&summary, width), muted)),
+        Line::from(""),
+    ];
+    let footer = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            truncate_text("Enter: select mod | ↑/↓: move | Esc: close", width),
+            muted,
+        )),
+    ];
+    let view_rows = (inner.height as usize)
+        .saturating_sub(lines.len() + footer.len())
+        .max(1);
+    let selected = view.selected.min(view.issues.len().saturating_sub(1));
+    let start = (selected + 1).saturating_sub(view_rows);
+    for (index, issue) in view.issues.iter().enumerate().skip(start).take(view_rows) {
+        let is_selected = index == selected;
+        let prefix = if is_selected { "> " } else { "  " };
+        let mut style = Style::default().fg(theme.text);
+        if is_selected {
+            style = style.bg(theme.accent_soft).add_modifier(Modifier::BOLD);
+        }
+        let tag = format!("[{}] ", issue.kind.label());
+        lines.push(Line::from(vec![
+            Span::styled(prefix, style),
+            Span::styled(tag.clone(), style.fg(theme.warning)),
+            Span::styled(
+                truncate_text(
+                    &issue.message,
+                    width.saturating_sub(prefix.len() + tag.chars().count()),
+                ),
+                style,
+            ),
+        ]));
+    }
+    let body_rows = (inner.height as usize).saturating_sub(footer.len());
+    lines.truncate(body_rows);
+    while lines.len() < body_rows {
+        lines.push(Line::from(""));
+    }
+    lines.extend(footer);
+
+    frame.render_widget(
+        Paragraph::new(lines).style(Style::default().fg(theme.text)),
+        inner,
+    );
+}
+
+fn draw_toggle_impact(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
+    let Some(view) = &app.toggle_impact_view else {
+        return;
+    };
+
+    let area = frame.size();
+    let max_width = area.width.saturating_sub(2).max(1);
+    let width = max_width.min(96);
+    let max_height = area.height.saturating_sub(2).max(1);
+    let height = max_height.min(24);
+    let (outer_area, impact_area) = padded_modal(area, width, height, 2, 1);
+
+    render_modal_backdrop(frame, outer_area, theme);
+    let title = if view.enable {
+        "Enable Preview"
+    } else {
+        "Disable Preview"
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.accent_soft))
+        .style(Style::default().bg(theme.header_bg))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(impact_area);
+    frame.render_widget(block, impact_area);
+    if inner.width == 0 || inner.height < 4 {
+        return;
+    }
+    let width = inner.width as usize;
+    let muted = Style::default().fg(theme.muted);
+
+    let verb = if view.enable { "Enabling" } else { "Disabling" };
+    let summary = if view.loading {
+        format!("{verb} {}: scanning overrides...", view.mod_name)
+    } else if let Some(error) = &view.error {
+        format!("{verb} {}: scan failed ({error})", view.mod_name)
+    } else if view.changes.is_empty() {
+        format!("{verb} {} changes no overrides.", view.mod_name)
+    } else {
+        format!(
+            "{verb} {} changes {} override(s).",
+            view.mod_name,
+            view.changes.len()
+        )
+    };
+    let mut lines = vec![
+        Line::from(Span::styled(truncate_text(&summary, width), muted)),
+        Line::from(""),
+    ];
+    let action = if view.enable { "enable" } else { "disable" };
+    let footer = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            truncate_text(&format!("Enter: {action} | ↑/↓: move | Esc: cancel"), width),
+            muted,
+        )),
+    ];
+    let view_rows = (inner.height as usize)
+        .saturating_sub(lines.len() + footer.len())
+        .max(1);
+    let selected = view.selected.min(view.changes.len().saturating_sub(1));
+    let start = (selected + 1).saturating_sub(view_rows);
+    for (index, change) in view.changes.iter().enumerate().skip(start).take(view_rows) {
+        let is_selected = index == selected;
+        let prefix = if is_selected { "> " } else { "  " };
+        let mut style = Style::default().fg(theme.text);
+        if is_selected {
+            style = style.bg(theme.accent_soft).add_modifier(Modifier::BOLD);
+        }
+        let tag_color = match change.kind {
+            ToggleImpactKind::Introduced => theme.warning,
+            ToggleImpactKind::Resolved => theme.success,
+            ToggleImpactKind::WinnerChanged => theme.accent,
+        };
+        let tag = format!("[{}] ", change.kind.label());
+        let text = format!(
+            "{} {} ({})",
+            target_kind_label(change.target),
+            change.path,
+            change.detail
+        );
+        lines.push(Line::from(vec![
+            Span::styled(prefix, style),
+            Span::styled(tag.clone(), style.fg(tag_color)),
+            Span::styled(
+                truncate_text(
+                    &text,
+                    width.saturating_sub(prefix.len() + tag.chars().count()),
+                ),
+                style,
+            ),
+        ]));
+    }
+    let body_rows = (inner.height as usize).saturating_sub(footer.len());
+    lines.truncate(body_rows);
+    while lines.len() < body_rows {
+        lines.push(Line::from(""));
+    }
+    lines.extend(footer);
+
+    frame.render_widget(
+        Paragraph::new(lines).style(Style::default().fg(theme.text)),
+        inner,
+    );
+}
+
+fn draw_trash_view(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
+    let Some(view) = &app.trash_view else {
+        return;
+    };
+
+    let area = frame.size();
+    let max_width = area.width.saturating_sub(2).max(1);
+    let width = max_width.min(76);
+    let max_height = area.height.saturating_sub(2).max(1);
+    let height = max_height.min(20);
+    let (outer_area, trash_area) = padded_modal(area, width, height, 2, 1);
+
+    render_modal_backdrop(frame, outer_area, theme);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.accent_soft))
+        .style(Style::default().bg(theme.header_bg))
+        .title(Span::styled(
+            "Recently Removed Mods",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let inner = block.inner(trash_area);
+    frame.render_widget(block, trash_area);
+    if inner.width == 0 || inner.height < 4 {
+        return;
+    }
+    let width = inner.width as usize;
+    let muted = Style::default().fg(theme.muted);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            truncate_text(
+                &format!(
+                    "Removed mod files are kept for {} day(s).",
+                    app.app_config.trash_retention_days
+                ),
+                width,
+            ),
+            muted,
+        )),
+        Line::from(""),
+    ];
+    let footer = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            truncate_text("Enter/r: restore into library | Esc: close", width),
+            muted,
+        )),
+    ];
+    let view_rows = (inner.height as usize)
+        .saturating_sub(lines.len() + footer.len())
+        .max(1);
+    let selected = view.selected.min(view.entries.len().saturating_sub(1));
+    let start = (selected + 1).saturating_sub(view_rows);
+    for (index, entry) in view.entries.iter().enumerate().skip(start).take(view_rows) {
+        let is_selected = index == selected;
+        let prefix = if is_selected { "> " } else { "  " };
+        let mut style = Style::default().fg(theme.text);
+        if is_selected {
+            style = style.bg(theme.accent_soft).add_modifier(Modifier::BOLD);
+        }
+        let age = format_age(entry.removed_at as i64);
+        let name = entry.mod_entry.display_name();
+        lines.push(Line::from(Span::styled(
+            truncate_text(&format!("{prefix}{name}  (removed {age})"), width),
+            style,
+        )));
+    }
+    let body_rows = (inner.height as usize).saturating_sub(footer.len());
+    lines.truncate(body_rows);
+    while lines.len() < body_rows {
+        lines.push(Line::from(""));
+    }
+    lines.extend(footer);
+
+    frame.render_widget(
+        Paragraph::new(lines).style(Style::default().fg(theme.text)),
+        inner,
+    );
 }
 
-fn draw_smart_rank_preview(frame: &mut Frame<'_>, app: &mut App, theme: &Theme) {
-    let Some(preview) = &app.smart_rank_preview else {
+fn draw_uuid_collisions(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
+    let Some(view) = &app.uuid_collision_view else {
         return;
     };
 
     let area = frame.size();
     let max_width = area.width.saturating_sub(2).max(1);
-    let width = max_width.min(120).max(60).min(max_width);
+    let width = max_width.min(84);
     let max_height = area.height.saturating_sub(2).max(1);
-    let height = max_height.min(22).max(10);
-    let (outer_area, preview_area) = padded_modal(area, width, height, 2, 1);
-
-    let inner_width = preview_area.width.saturating_sub(3) as usize;
-    let inner_height = preview_area.height.saturating_sub(2) as usize;
-    let notice = app
-        .sigillink_preview_notice()
-        .map(|value| value.to_string());
-    let render = build_smart_rank_preview_render(
-        preview,
-        theme,
-        inner_width,
-        inner_height,
-        app.smart_rank_scroll,
-        app.smart_rank_view,
-        notice,
-    );
+    let height = max_height.min(24);
+    let (outer_area, collision_area) = padded_modal(area, width, height, 2, 1);
 
     render_modal_backdrop(frame, outer_area, theme);
     let block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(theme.accent_soft))
+        .border_style(Style::default().fg(theme.warning))
         .style(Style::default().bg(theme.header_bg))
         .title(Span::styled(
-            "SigiLink Intelligent Ranking",
+            "Pak UUID Collision",
             Style::default()
-                .fg(theme.accent)
+                .fg(theme.warning)
                 .add_modifier(Modifier::BOLD),
         ));
-    let inner = block.inner(preview_area);
-    let widget = Paragraph::new(render.lines)
-        .block(block)
-        .style(Style::default().fg(theme.text))
-        .alignment(Alignment::Left);
-    frame.render_widget(widget, preview_area);
+    let inner = block.inner(collision_area);
+    frame.render_widget(block, collision_area);
+    if inner.width == 0 || inner.height < 4 {
+        return;
+    }
+    let width = inner.width as usize;
+    let muted = Style::default().fg(theme.muted);
 
-    let scroll_meta = render.scroll;
-    if let Some(scroll) = scroll_meta {
-        app.smart_rank_scroll = scroll.position;
-        if scroll.total > scroll.view && inner.width > 0 && inner.height > 0 {
-            let body_height = scroll.view.min(inner.height as usize) as u16;
-            if body_height > 0 {
-                let scroll_area = Rect {
-                    x: inner.x + inner.width.saturating_sub(1),
-                    y: inner.y.saturating_add(scroll.header_lines as u16),
-                    width: 1,
-                    height: body_height,
-                };
-                let scroll_len = scroll.total.saturating_sub(scroll.view).saturating_add(1);
-                let mut scroll_state = ScrollbarState::new(scroll_len)
-                    .position(scroll.position)
-                    .viewport_content_length(scroll.view);
-                let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                    .track_symbol(Some("░"))
-                    .thumb_symbol("▓")
-                    .begin_symbol(None)
-                    .end_symbol(None)
-                    .track_style(Style::default().fg(theme.border))
-                    .thumb_style(Style::default().fg(theme.accent));
-                frame.render_stateful_widget(scrollbar, scroll_area, &mut scroll_state);
+    let mut lines = vec![
+        Line::from(Span::styled(
+            truncate_text(
+                "BG3 loads only one pak per UUID. Keep one mod per group; the rest are disabled.",
+                width,
+            ),
+            Style::default().fg(theme.text),
+        )),
+        Line::from(""),
+    ];
+    for (group_index, collision) in view.collisions.iter().enumerate() {
+        let active = group_index == 0;
+        let header_style = if active {
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            muted
+        };
+        lines.push(Line::from(Span::styled(
+            truncate_text(&format!("UUID {}", collision.uuid), width),
+            header_style,
+        )));
+        for (index, member) in collision.members.iter().enumerate() {
+            let selected = active && index == view.selected;
+            let prefix = if selected { "> " } else { "  " };
+            let mut style = if active {
+                Style::default().fg(theme.text)
+            } else {
+                muted
+            };
+            if selected {
+                style = style.bg(theme.accent_soft).add_modifier(Modifier::BOLD);
+            }
+            lines.push(Line::from(Span::styled(
+                truncate_text(
+                    &format!("{prefix}{}  ({})", member.mod_name, member.pak_file),
+                    width,
+                ),
+                style,
+            )));
+        }
+    }
+
+    let footer = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            truncate_text(
+                "↑/↓: choose | Enter: keep selected | Esc: cancel deploy",
+                width,
+            ),
+            muted,
+        )),
+    ];
+    let body_rows = (inner.height as usize).saturating_sub(footer.len());
+    lines.truncate(body_rows);
+    while lines.len() < body_rows {
+        lines.push(Line::from(""));
+    }
+    lines.extend(footer);
+
+    frame.render_widget(
+        Paragraph::new(lines).style(Style::default().fg(theme.text)),
+        inner,
+    );
+}
+
+struct CompatibilityReportRender {
+    lines: Vec<Line<'static>>,
+    header_lines: usize,
+    scroll: usize,
+    max_scroll: usize,
+}
+
+const COMPATIBILITY_PATHS_SHOWN: usize = 12;
+
+fn build_compatibility_report_render(
+    view: &crate::app::CompatibilityView,
+    theme: &Theme,
+    width: usize,
+    height: usize,
+) -> CompatibilityReportRender {
+    let report = &view.report;
+    let mut lines = Vec::new();
+    lines.push(Line::from(Span::styled(
+        truncate_text(
+            &format!(
+                "Profile: {} | Scanned: {} | Overlapping: {}",
+                report.profile,
+                report.scanned,
+                report.entries.len()
+            ),
+            width,
+        ),
+        Style::default().fg(theme.text),
+    )));
+    if !report.unscanned.is_empty() {
+        lines.push(Line::from(Span::styled(
+            truncate_text(
+                &format!(
+                    "Not scanned ({}): {}",
+                    report.unscanned.len(),
+                    report.unscanned.join(", ")
+                ),
+                width,
+            ),
+            Style::default().fg(theme.warning),
+        )));
+    }
+    lines.push(Line::from(""));
+    let header_lines = lines.len();
+
+    let severity_color = |severity: crate::smart_rank::OverlapSeverity| match severity {
+        crate::smart_rank::OverlapSeverity::Logic => theme.error,
+        crate::smart_rank::OverlapSeverity::Data => theme.warning,
+        crate::smart_rank::OverlapSeverity::Cosmetic => theme.muted,
+    };
+    let mut body: Vec<Line<'static>> = Vec::new();
+    let mut selected_line = 0usize;
+    if report.entries.is_empty() {
+        body.push(Line::from(Span::styled(
+            "No overlapping files between enabled mods.",
+            Style::default().fg(theme.muted),
+        )));
+    }
+    for (index, entry) in report.entries.iter().enumerate() {
+        let selected = index == view.selected;
+        let expanded = view.expanded.contains(&index);
+        if selected {
+            selected_line = body.len();
+        }
+        let marker = if expanded { "▾ " } else { "▸ " };
+        let severity = format!("[{}] ", entry.severity.label());
+        let summary = format!(
+            "{} — {} file(s) with {} mod(s)",
+            entry.name,
+            entry.overlap_files,
+            entry.overlaps.len()
+        );
+        let name_width = width.saturating_sub(marker.chars().count() + severity.chars().count());
+        let row_style = if selected {
+            Style::default().bg(theme.accent_soft)
+        } else {
+            Style::default()
+        };
+        body.push(
+            Line::from(vec![
+                Span::styled(marker.to_string(), Style::default().fg(theme.accent)),
+                Span::styled(
+                    severity,
+                    Style::default().fg(severity_color(entry.severity)),
+                ),
+                Span::styled(
+                    truncate_text(&summary, name_width),
+                    Style::default().fg(theme.text),
+                ),
+            ])
+            .style(row_style),
+        );
+        if !expanded {
+            continue;
+        }
+        for overlap in &entry.overlaps {
+            let outcome = if overlap.wins { "wins" } else { "loses" };
+            body.push(Line::from(vec![
+                Span::raw("    vs "),
+                Span::styled(
+                    truncate_text(&overlap.other_name, width.saturating_sub(40).max(10)),
+                    Style::default().fg(theme.text),
+                ),
+                Span::styled(
+                    format!(
+                        " · {} · {} file(s) · {outcome}",
+                        overlap.severity.label(),
+                        overlap.paths.len()
+                    ),
+                    Style::default().fg(severity_color(overlap.severity)),
+                ),
+            ]));
+            for path in overlap.paths.iter().take(COMPATIBILITY_PATHS_SHOWN) {
+                body.push(Line::from(Span::styled(
+                    truncate_text(&format!("      {path}"), width),
+                    Style::default().fg(theme.muted),
+                )));
+            }
+            if overlap.paths.len() > COMPATIBILITY_PATHS_SHOWN {
+                body.push(Line::from(Span::styled(
+                    format!(
+                        "      … {} more (export for the full list)",
+                        overlap.paths.len() - COMPATIBILITY_PATHS_SHOWN
+                    ),
+                    Style::default().fg(theme.muted),
+                )));
             }
         }
-    } else {
-        app.smart_rank_scroll = 0;
+    }
+
+    let view_height = height.saturating_sub(header_lines + 1).max(1);
+    let max_scroll = body.len().saturating_sub(view_height);
+    let mut scroll = view.scroll.min(max_scroll);
+    if selected_line < scroll {
+        scroll = selected_line;
+    } else if selected_line >= scroll + view_height {
+        scroll = selected_line + 1 - view_height;
+    }
+    lines.extend(body.into_iter().skip(scroll).take(view_height));
+    while lines.len() < header_lines + view_height {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(Span::styled(
+        "↑/↓ Select · Enter Details · E Export (txt + json) · Esc Close",
+        Style::default().fg(theme.muted),
+    )));
+
+    CompatibilityReportRender {
+        lines,
+        header_lines,
+        scroll,
+        max_scroll,
     }
 }
 
@@ -5720,7 +8197,11 @@ fn build_mod_list_preview_render(
             }
             crate::app::ModListMatchOutcome::Missing => missing.push(label),
             crate::app::ModListMatchOutcome::Ambiguous { candidates, .. } => {
-                ambiguous.push((label, candidates.clone()));
+                let names: Vec<String> = candidates
+                    .iter()
+                    .map(|candidate| candidate.name.clone())
+                    .collect();
+                ambiguous.push((label, names));
             }
         }
     }
@@ -5750,6 +8231,10 @@ fn build_mod_list_preview_render(
         crate::app::ModListOverrideMode::Merge => "Merge",
         crate::app::ModListOverrideMode::Replace => "Replace",
     };
+    let missing_label = match preview.missing_mode {
+        crate::app::ModListMissingMode::Placeholder => "Placeholders",
+        crate::app::ModListMissingMode::Block => "Block",
+    };
     let (order_changes, enable_changes, new_entries) =
         if let Some(profile) = app.library.active_profile() {
             let mut order_changes = 0usize;
@@ -5810,6 +8295,9 @@ fn build_mod_list_preview_render(
         Span::styled(mode_label, Style::default().fg(theme.text)),
         Span::styled("  [M]  Overrides: ", Style::default().fg(theme.muted)),
         Span::styled(override_label, Style::default().fg(theme.text)),
+        Span::styled("  Missing: ", Style::default().fg(theme.muted)),
+        Span::styled(missing_label, Style::default().fg(theme.text)),
+        Span::styled("  [X]", Style::default().fg(theme.muted)),
     ]));
     header_lines.push(Line::from(vec![
         Span::styled("Entries: ", Style::default().fg(theme.muted)),
@@ -5845,7 +8333,39 @@ fn build_mod_list_preview_render(
     ]));
     if ambiguous_count > 0 {
         header_lines.push(Line::from(Span::styled(
-            "Ambiguous matches block apply.",
+            "Ambiguous matches block apply; press R to pick the intended mods.",
+            Style::default().fg(theme.warning),
+        )));
+    }
+    if missing_count > 0 && matches!(preview.missing_mode, crate::app::ModListMissingMode::Block) {
+        header_lines.push(Line::from(Span::styled(
+            truncate_text(
+                "Missing mods block apply; install them first or press X for placeholders.",
+                width,
+            ),
+            Style::default().fg(theme.warning),
+        )));
+    }
+    if preview.missing_confirm {
+        let percent = missing_count * 100 / total.max(1);
+        header_lines.push(Line::from(""));
+        header_lines.push(Line::from(Span::styled(
+            truncate_text(
+                &format!(
+                    "⚠ {missing_count} of {total} entries ({percent}%) are not in your library \
+                     and will be added as missing placeholders."
+                ),
+                width,
+            ),
+            Style::default()
+                .fg(theme.warning)
+                .add_modifier(Modifier::BOLD),
+        )));
+        header_lines.push(Line::from(Span::styled(
+            truncate_text(
+                "Enter imports anyway; V lists only the missing mods.",
+                width,
+            ),
             Style::default().fg(theme.warning),
         )));
     }
@@ -5888,7 +8408,7 @@ fn build_mod_list_preview_render(
         }
     }
 
-    if !ambiguous.is_empty() {
+    if !ambiguous.is_empty() && !preview.missing_only {
         body_lines.push(Line::from(""));
         body_lines.push(Line::from(Span::styled(
             "Ambiguous:",
@@ -5957,16 +8477,35 @@ fn build_mod_list_preview_render(
         lines.extend(body_lines[scroll..end].iter().cloned());
     }
 
-    let mut footer_parts = vec![
-        ("[Enter]".to_string(), key_style),
-        (" apply  ".to_string(), text_style),
-        ("[Esc]".to_string(), key_style),
-        (" cancel  ".to_string(), text_style),
-        ("[D]".to_string(), key_style),
-        (" destination  ".to_string(), text_style),
-        ("[M]".to_string(), key_style),
-        (" mode".to_string(), text_style),
-    ];
+    let mut footer_parts = if preview.missing_confirm {
+        vec![
+            ("[Enter]".to_string(), key_style),
+            (" import anyway  ".to_string(), text_style),
+            ("[V]".to_string(), key_style),
+            (
+                if preview.missing_only {
+                    " all entries  "
+                } else {
+                    " missing list  "
+                }
+                .to_string(),
+                text_style,
+            ),
+            ("[Esc]".to_string(), key_style),
+            (" cancel".to_string(), text_style),
+        ]
+    } else {
+        vec![
+            ("[Enter]".to_string(), key_style),
+            (" apply  ".to_string(), text_style),
+            ("[Esc]".to_string(), key_style),
+            (" cancel  ".to_string(), text_style),
+            ("[D]".to_string(), key_style),
+            (" destination  ".to_string(), text_style),
+            ("[M]".to_string(), key_style),
+            (" mode".to_string(), text_style),
+        ]
+    };
     if total_body > available {
         footer_parts.push((
             format!("  ↑/↓ scroll {}/{}", scroll + 1, max_scroll + 1),
@@ -6104,10 +8643,16 @@ fn build_settings_menu_lines(
                 matches!(
                     item.kind,
                     SettingsItemKind::ToggleEnableModsAfterImport
+                        | SettingsItemKind::ToggleEnableImportDependencies
                         | SettingsItemKind::ToggleDeleteModFilesOnRemove
+                        | SettingsItemKind::ToggleQuarantineRemovedMods
                         | SettingsItemKind::ToggleProfileDelete
+                        | SettingsItemKind::ToggleSortProfiles
+                        | SettingsItemKind::ToggleModSizeColumn
+                        | SettingsItemKind::ToggleBackgroundConflictScan
                         | SettingsItemKind::ToggleModDelete
                         | SettingsItemKind::ToggleAutoDeploy
+                        | SettingsItemKind::AutoDeployDebounce
                         | SettingsItemKind::ToggleDependencyDownloads
                         | SettingsItemKind::ToggleDependencyWarnings
                         | SettingsItemKind::ToggleStartupDependencyNotice
@@ -6117,7 +8662,12 @@ fn build_settings_menu_lines(
             .max()
             .unwrap_or(0),
     );
-    let default_sort_key_w = clamp_key(display_width("Default Sort Column").max(general_key_w));
+    let default_sort_key_w = clamp_key(
+        display_width("Default Sort Column")
+            .max(display_width("Recently Added Window"))
+            .max(display_width("Evict Disabled From Cache"))
+            .max(general_key_w),
+    );
     let sigilink_key_w = clamp_key(
         items
             .iter()
@@ -6127,6 +8677,7 @@ fn build_settings_menu_lines(
                     SettingsItemKind::SigilLinkToggle
                         | SettingsItemKind::SigilLinkInfo
                         | SettingsItemKind::SigilLinkAutoPreview
+                        | SettingsItemKind::SigilLinkDebounce
                 )
             })
             .map(|item| {
@@ -6211,17 +8762,32 @@ fn build_settings_menu_lines(
         };
         match item.kind {
             SettingsItemKind::ActionSetupPaths
+            | SettingsItemKind::ActionSetupWizard
             | SettingsItemKind::ActionShowPaths
             | SettingsItemKind::ActionMoveSigilLinkCache
             | SettingsItemKind::ActionClearFrameworkCaches
             | SettingsItemKind::ActionClearSigilLinkCaches
+            | SettingsItemKind::ActionVerifySigilLinkCache
+            | SettingsItemKind::ActionCleanSigilLinkCache
+            | SettingsItemKind::ActionRestageMissingMods
             | SettingsItemKind::ActionClearSigilLinkPins
             | SettingsItemKind::ActionSigilLinkSoloRank
+            | SettingsItemKind::ActionSigilLinkScopedRank
+            | SettingsItemKind::ActionCompatibilityReport
+            | SettingsItemKind::ActionOrderRules
+            | SettingsItemKind::ActionRankHistory
             | SettingsItemKind::ActionExportModList
             | SettingsItemKind::ActionImportModList
+            | SettingsItemKind::ActionRepairModsettings
+            | SettingsItemKind::ActionNormalizeProfiles
+            | SettingsItemKind::ActionRestoreRemovedMods
+            | SettingsItemKind::ActionExportSettings
+            | SettingsItemKind::ActionImportSettings
             | SettingsItemKind::ActionCopyLogTail
             | SettingsItemKind::ActionCopyLogAll
             | SettingsItemKind::ActionExportLogFile
+            | SettingsItemKind::ActionSupportBundle
+            | SettingsItemKind::ActionDiagnosticsBundle
             | SettingsItemKind::ActionCheckUpdates
             | SettingsItemKind::ActionWhatsNew => {
                 lines.push(menu_row(
@@ -6236,16 +8802,61 @@ fn build_settings_menu_lines(
                 lines.push(kv_row(
                     MenuRowKind::None,
                     &item.label,
-                    default_sort_key_w,
+                    default_sort_key_w,
+                    style,
+                    vec![Span::styled(value, Style::default().fg(theme.text))],
+                ));
+            }
+            SettingsItemKind::RecentAddedWindow => {
+                let value = app.recent_added_label();
+                lines.push(kv_row(
+                    MenuRowKind::None,
+                    &item.label,
+                    default_sort_key_w,
+                    style,
+                    vec![Span::styled(value, Style::default().fg(theme.text))],
+                ));
+            }
+            SettingsItemKind::EvictDisabledAfter => {
+                let value = app.evict_disabled_label();
+                lines.push(kv_row(
+                    MenuRowKind::None,
+                    &item.label,
+                    default_sort_key_w,
+                    style,
+                    vec![Span::styled(value, Style::default().fg(theme.text))],
+                ));
+            }
+            SettingsItemKind::AutoDeployDebounce => {
+                let value = app.auto_deploy_debounce_label();
+                lines.push(kv_row(
+                    MenuRowKind::None,
+                    &item.label,
+                    general_key_w,
+                    style,
+                    vec![Span::styled(value, Style::default().fg(theme.text))],
+                ));
+            }
+            SettingsItemKind::SigilLinkDebounce => {
+                let value = app.smart_rank_debounce_label();
+                lines.push(kv_row(
+                    MenuRowKind::None,
+                    &item.label,
+                    sigilink_key_w,
                     style,
                     vec![Span::styled(value, Style::default().fg(theme.text))],
                 ));
             }
             SettingsItemKind::ToggleEnableModsAfterImport
+            | SettingsItemKind::ToggleEnableImportDependencies
             | SettingsItemKind::ToggleDeleteModFilesOnRemove
+            | SettingsItemKind::ToggleQuarantineRemovedMods
             | SettingsItemKind::SigilLinkToggle
             | SettingsItemKind::SigilLinkAutoPreview
             | SettingsItemKind::ToggleProfileDelete
+            | SettingsItemKind::ToggleSortProfiles
+            | SettingsItemKind::ToggleModSizeColumn
+            | SettingsItemKind::ToggleBackgroundConflictScan
             | SettingsItemKind::ToggleModDelete
             | SettingsItemKind::ToggleAutoDeploy
             | SettingsItemKind::ToggleDependencyDownloads
@@ -6405,6 +9016,12 @@ fn build_export_menu_lines(theme: &Theme, menu: &crate::app::ExportMenu) -> Vec<
             ExportMenuItemKind::ExportModsettings => {
                 "Interop for BG3MM/Vortex; disabled state may be lost."
             }
+            ExportMenuItemKind::ExportModsettingsClipboard => {
+                "modsettings.lsx text for forum posts or other tools."
+            }
+            ExportMenuItemKind::ExportUuidList | ExportMenuItemKind::ExportUuidListClipboard => {
+                "Enabled pak UUIDs in load order, one per line."
+            }
         };
         lines.push(Line::from(vec![
             Span::raw("  "),
@@ -6506,7 +9123,11 @@ fn mode_toast(app: &App) -> Option<(String, ToastLevel)> {
                     let name = value("<new name>");
                     format!("Renaming \"{original}\" -> \"{name}\" | {hint}")
                 }
-                InputPurpose::DuplicateProfile { source } => {
+                InputPurpose::RenameMod { .. } => {
+                    let name = value("<original name>");
+                    format!("Rename mod: \"{name}\" | {hint}")
+                }
+                InputPurpose::DuplicateProfile { source, .. } => {
                     let name = value("<new name>");
                     format!("Duplicate \"{source}\" -> \"{name}\" | {hint}")
                 }
@@ -6515,17 +9136,34 @@ fn mode_toast(app: &App) -> Option<(String, ToastLevel)> {
                     format!("Export \"{profile}\": {path} | {hint}")
                 }
                 InputPurpose::ImportProfile => {
-                    let path = value("<path>");
+                    let path = value("<path or url>");
                     format!("Import mod list: {path} | {hint}")
                 }
                 InputPurpose::ImportPath => {
-                    let path = value("<path>");
+                    let path = value("<path or url>");
                     format!("Import mod: {path} | {hint}")
                 }
+                InputPurpose::AdoptDevFolder => {
+                    let path = value("<folder>");
+                    format!("Adopt dev folder (live link): {path} | {hint}")
+                }
                 InputPurpose::FilterMods => {
                     let filter = value("<all>");
                     format!("Search mods: {filter} | {hint}")
                 }
+                InputPurpose::SearchLog => {
+                    let query = value("<clear>");
+                    format!("Search log: {query} | Enter find | Esc cancel")
+                }
+                InputPurpose::MoveToSlot => {
+                    let total = app
+                        .library
+                        .active_profile()
+                        .map(|profile| profile.order.len())
+                        .unwrap_or(0);
+                    let slot = value("<slot>");
+                    format!("Move to slot (1-{total}): {slot} | {hint}")
+                }
             };
             Some((message, ToastLevel::Info))
         }
@@ -6533,7 +9171,8 @@ fn mode_toast(app: &App) -> Option<(String, ToastLevel)> {
         InputMode::Normal => {
             if app.move_mode {
                 Some((
-                    "Move mode: arrows reorder | Enter/Space/M confirm | Esc cancel".to_string(),
+                    "Move mode: arrows reorder | G go to slot | Enter/Space/M confirm | Esc cancel"
+                        .to_string(),
                     ToastLevel::Info,
                 ))
             } else {
@@ -6649,6 +9288,23 @@ fn draw_import_overlay(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
             stage_line,
             Style::default().fg(theme.text),
         )));
+        if progress.bytes_total > 0 {
+            let mut bytes_line = format!(
+                "{} / {}",
+                importer::format_bytes(progress.bytes_processed),
+                importer::format_bytes(progress.bytes_total)
+            );
+            if let Some((rate, eta)) = app.import_rate() {
+                bytes_line.push_str(&format!(" · {}/s", importer::format_bytes(rate as u64)));
+                if let Some(eta) = eta {
+                    bytes_line.push_str(&format!(" · ETA {}", importer::format_eta(eta)));
+                }
+            }
+            lines.push(Line::from(Span::styled(
+                bytes_line,
+                Style::default().fg(theme.muted),
+            )));
+        }
         if let Some(detail) = &progress.detail {
             lines.push(Line::from(Span::styled(
                 detail.clone(),
@@ -6662,6 +9318,10 @@ fn draw_import_overlay(frame: &mut Frame<'_>, app: &App, theme: &Theme) {
             Style::default().fg(theme.muted),
         )));
     }
+    lines.push(Line::from(Span::styled(
+        "Esc: cancel import",
+        Style::default().fg(theme.muted),
+    )));
 
     let text_height = lines.len().max(1) as u16;
     let width = area.width.saturating_sub(10).clamp(42, 78);
@@ -6758,10 +9418,44 @@ fn build_explorer_items(app: &App, theme: &Theme) -> Vec<ListItem<'static>> {
     items
         .iter()
         .enumerate()
-        .map(|(index, item)| ListItem::new(explorer_line(item, theme, &items, index)))
+        .map(|(index, item)| {
+            let mut line = explorer_line(item, theme, &items, index);
+            if let ExplorerItemKind::Profile { name } = &item.kind {
+                if let Some(suffix) = app
+                    .library
+                    .profiles
+                    .iter()
+                    .find(|profile| profile.name == *name)
+                    .and_then(profile_activity_suffix)
+                {
+                    line.spans.push(Span::styled(
+                        format!(" {suffix}"),
+                        Style::default().fg(theme.muted),
+                    ));
+                }
+            }
+            ListItem::new(line)
+        })
         .collect()
 }
 
+fn profile_activity_suffix(profile: &Profile) -> Option<String> {
+    profile
+        .last_deployed_at
+        .map(|deployed| format!("deployed {}", format_age(deployed)))
+}
+
+fn format_age(timestamp: i64) -> String {
+    let now = time::OffsetDateTime::now_utc().unix_timestamp();
+    let secs = now.saturating_sub(timestamp).max(0);
+    match secs {
+        0..=59 => "now".to_string(),
+        60..=3599 => format!("{}m ago", secs / 60),
+        3600..=86_399 => format!("{}h ago", secs / 3600),
+        _ => format!("{}d ago", secs / 86_400),
+    }
+}
+
 fn has_next_at_depth(items: &[ExplorerItem], index: usize, depth: usize) -> bool {
     for item in items.iter().skip(index + 1) {
         if item.depth < depth {
@@ -6905,7 +9599,11 @@ fn build_rows(app: &App, theme: &Theme) -> (Vec<Row<'static>>, ModCounts, usize,
             mod_width = mod_width.max(display.chars().count());
             continue;
         };
-        mod_width = mod_width.max(mod_entry.display_name().chars().count());
+        let mut name_len = mod_entry.display_name().chars().count();
+        if let Some(note) = app.uuid_collision_note(&entry.id) {
+            name_len += note.chars().count() + 3;
+        }
+        mod_width = mod_width.max(name_len);
     }
 
     for (row_index, (order_index, entry)) in profile_entries.iter().enumerate() {
@@ -7177,7 +9875,7 @@ fn lerp_u64(min: u64, max: u64, t: f32) -> u64 {
 }
 
 fn row_for_missing_entry(
-    _app: &App,
+    app: &App,
     row_index: usize,
     order_index: usize,
     entry: &crate::library::ProfileEntry,
@@ -7199,7 +9897,7 @@ fn row_for_missing_entry(
         Span::styled("  ", muted),
         Span::styled(" ", muted),
     ]));
-    let mut row = Row::new(vec![
+    let mut cells = vec![
         Cell::from(enabled_text.to_string()).style(muted),
         Cell::from(order_text).style(muted),
         Cell::from(" ".to_string()).style(muted),
@@ -7213,7 +9911,13 @@ fn row_for_missing_entry(
         Cell::from(" ".to_string()).style(muted),
         Cell::from(" ".to_string()).style(muted),
         Cell::from(" ".to_string()).style(muted),
-    ]);
+    ];
+    if app.app_config.show_mod_size_column {
+        let target_index = cells.len() - 1;
+        cells.insert(target_index, Cell::from(" ".to_string()).style(muted));
+        cells.insert(target_index, Cell::from(" ".to_string()).style(muted));
+    }
+    let mut row = Row::new(cells);
     if row_index % 2 == 1 {
         row = row.style(Style::default().bg(theme.row_alt_bg));
     }
@@ -7257,6 +9961,10 @@ fn row_for_entry(
         cells.push(Cell::from(" ").style(loading_style));
         push_loading(&mut cells, &mut loading_index); // Added
         cells.push(Cell::from(" ").style(loading_style));
+        if app.app_config.show_mod_size_column {
+            push_loading(&mut cells, &mut loading_index); // Size
+            cells.push(Cell::from(" ").style(loading_style));
+        }
         push_loading(&mut cells, &mut loading_index); // Target
         Row::new(cells)
     } else {
@@ -7314,7 +10022,7 @@ fn row_for_entry(
         let link_cell = sigillink_link_cell(app, &mod_entry.id, theme);
         let order_text = format_order_cell(order_index);
         let name_cell = mod_name_cell(app, mod_entry, theme);
-        Row::new(vec![
+        let mut cells = vec![
             Cell::from(enabled_text.to_string()).style(enabled_style),
             Cell::from(order_text).style(order_style),
             Cell::from(native_marker.to_string()).style(native_style),
@@ -7328,7 +10036,24 @@ fn row_for_entry(
             Cell::from(added_text).style(Style::default().fg(theme.muted)),
             Cell::from(" "),
             Cell::from(state_label).style(state_style),
-        ])
+        ];
+        if app.app_config.show_mod_size_column {
+            let size_text = mod_entry
+                .disk_usage
+                .map(|usage| importer::format_bytes(usage.bytes))
+                .unwrap_or_else(|| "-".to_string());
+            let target_index = cells.len() - 1;
+            cells.insert(
+                target_index,
+                Cell::from(format!(
+                    "{size_text:>width$}",
+                    width = MOD_SIZE_WIDTH as usize
+                ))
+                .style(Style::default().fg(theme.muted)),
+            );
+            cells.insert(target_index + 1, Cell::from(" "));
+        }
+        Row::new(cells)
     };
     if row_index % 2 == 1 {
         row = row.style(Style::default().bg(theme.row_alt_bg));
@@ -7343,7 +10068,9 @@ fn sigillink_link_cell(app: &App, mod_id: &str, theme: &Theme) -> Cell<'static>
     if !app.sigillink_ranking_enabled() {
         return Cell::from(" ".to_string()).style(Style::default().fg(theme.muted));
     }
-    let (glyph, style) = if app.sigillink_is_pinned(mod_id) {
+    let (glyph, style) = if app.sigillink_is_excluded(mod_id) {
+        ("⊘", Style::default().fg(theme.muted))
+    } else if app.sigillink_is_pinned(mod_id) {
         ("⛕", Style::default().fg(theme.warning))
     } else {
         ("⛓", Style::default().fg(theme.success))
@@ -7352,17 +10079,48 @@ fn sigillink_link_cell(app: &App, mod_id: &str, theme: &Theme) -> Cell<'static>
 }
 
 fn mod_name_cell(app: &App, mod_entry: &ModEntry, theme: &Theme) -> Cell<'static> {
-    if app.sigillink_missing_pak(&mod_entry.id) {
-        let name_style = Style::default()
+    let mut spans = Vec::new();
+    if app.mod_is_starred(&mod_entry.id) {
+        spans.push(Span::styled("★ ", Style::default().fg(theme.accent)));
+    }
+    if app.mod_is_new(mod_entry) {
+        let badge_style = Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD);
+        spans.push(Span::styled("NEW ", badge_style));
+    }
+    if mod_entry.dev_path.is_some() {
+        let badge_style = Style::default()
+            .fg(theme.warning)
+            .add_modifier(Modifier::BOLD);
+        let badge = if mod_entry.dev_watch {
+            "DEV↻ "
+        } else {
+            "DEV "
+        };
+        spans.push(Span::styled(badge, badge_style));
+    }
+    let name_style = if app.sigillink_missing_pak(&mod_entry.id) {
+        Style::default()
             .fg(theme.text)
-            .add_modifier(Modifier::CROSSED_OUT);
-        Cell::from(Line::from(Span::styled(
-            mod_entry.display_name(),
-            name_style,
-        )))
+            .add_modifier(Modifier::CROSSED_OUT)
     } else {
-        Cell::from(mod_entry.display_name())
+        Style::default()
+    };
+    spans.push(Span::styled(mod_entry.display_name(), name_style));
+    if let Some(note) = app.uuid_collision_note(&mod_entry.id) {
+        spans.push(Span::styled(
+            format!(" ({note})"),
+            Style::default().fg(theme.warning),
+        ));
+    }
+    if let Some(required) = app.game_version_requirement(mod_entry) {
+        spans.push(Span::styled(
+            format!(" (needs game {})", metadata::format_version64(required)),
+            Style::default().fg(theme.warning),
+        ));
     }
+    Cell::from(Line::from(spans))
 }
 
 fn format_order_cell(order_index: usize) -> String {
@@ -7681,6 +10439,33 @@ fn build_details(app: &App, theme: &Theme, width: usize, height: usize) -> Vec<L
             value_style: Style::default().fg(theme.accent),
         });
     }
+    if let Some(dev_path) = &mod_entry.dev_path {
+        rows.push(KvRow {
+            label: "Live Link".to_string(),
+            value: dev_path.clone(),
+            label_style,
+            value_style: Style::default().fg(theme.warning),
+        });
+        let watch_value = if mod_entry.dev_watch {
+            "ON (redeploys on change)"
+        } else {
+            "OFF"
+        };
+        rows.push(KvRow {
+            label: "Dev Watch".to_string(),
+            value: watch_value.to_string(),
+            label_style,
+            value_style,
+        });
+    }
+    if mod_entry.custom_name.is_some() {
+        rows.push(KvRow {
+            label: "Renamed From".to_string(),
+            value: mod_entry.original_display_name(),
+            label_style,
+            value_style,
+        });
+    }
     if display_name != mod_entry.name {
         rows.push(KvRow {
             label: "Internal".to_string(),
@@ -7719,6 +10504,34 @@ fn build_details(app: &App, theme: &Theme, width: usize, height: usize) -> Vec<L
         label_style,
         value_style,
     });
+    if mod_entry.cache_evicted {
+        rows.push(KvRow {
+            label: "Cache".to_string(),
+            value: "Evicted (restaged from source when enabled)".to_string(),
+            label_style,
+            value_style: Style::default().fg(theme.muted),
+        });
+    } else if let Some(usage) = mod_entry.disk_usage {
+        rows.push(KvRow {
+            label: "Size".to_string(),
+            value: format!(
+                "{} ({} file{})",
+                importer::format_bytes(usage.bytes),
+                usage.files,
+                if usage.files == 1 { "" } else { "s" }
+            ),
+            label_style,
+            value_style,
+        });
+    }
+    if app.sigillink_is_excluded(&entry.id) {
+        rows.push(KvRow {
+            label: "SigiLink Excluded".to_string(),
+            value: "ON, all profiles (Ctrl+X to rank again)".to_string(),
+            label_style,
+            value_style: Style::default().fg(theme.muted),
+        });
+    }
     if app.sigillink_ranking_enabled() && app.sigillink_is_pinned(&entry.id) {
         rows.push(KvRow {
             label: "SigiLink Unlinked".to_string(),
@@ -7772,13 +10585,39 @@ fn build_details(app: &App, theme: &Theme, width: usize, height: usize) -> Vec<L
             label_style,
             value_style,
         });
-        let version_label = info.version.to_string();
+        let version_label = mod_entry
+            .version
+            .clone()
+            .unwrap_or_else(|| info.version.to_string());
         rows.push(KvRow {
             label: "Version".to_string(),
             value: version_label,
             label_style,
             value_style,
         });
+    } else if let Some(version) = mod_entry.version.as_ref() {
+        rows.push(KvRow {
+            label: "Version".to_string(),
+            value: version.clone(),
+            label_style,
+            value_style,
+        });
+    }
+    if let Some(author) = mod_entry.author.as_ref() {
+        rows.push(KvRow {
+            label: "Author".to_string(),
+            value: author.clone(),
+            label_style,
+            value_style,
+        });
+    }
+    if let Some(link) = mod_entry.website.clone().or_else(|| mod_entry.nexus_link()) {
+        rows.push(KvRow {
+            label: "Website".to_string(),
+            value: link,
+            label_style,
+            value_style,
+        });
     }
 
     format_kv_lines(&rows, width)
@@ -7899,6 +10738,23 @@ fn build_explorer_details(app: &App, theme: &Theme, width: usize) -> Vec<Line<'s
                     label_style,
                     value_style: enabled_style,
                 });
+                let overrides = profile.file_overrides.len();
+                rows.push(KvRow {
+                    label: "Overrides".to_string(),
+                    value: overrides.to_string(),
+                    label_style,
+                    value_style: if overrides > 0 {
+                        Style::default().fg(theme.warning)
+                    } else {
+                        value_style
+                    },
+                });
+                rows.push(KvRow {
+                    label: "Deployed".to_string(),
+                    value: format_rank_timestamp(profile.last_deployed_at),
+                    label_style,
+                    value_style,
+                });
             }
             let mut lines = format_kv_lines(&rows, width);
             if app.is_renaming_profile(&name) {
@@ -8016,24 +10872,34 @@ fn build_conflict_details(
             Style::default().fg(theme.muted),
         )];
     }
+    let ignored = app.ignored_conflict_count();
     if app.conflicts.is_empty() {
-        return vec![center_line(
-            "No Overrides Available",
-            Style::default().fg(theme.muted),
-        )];
+        let message = if ignored > 0 {
+            format!("No Overrides Available ({ignored} ignored, V to show)")
+        } else {
+            "No Overrides Available".to_string()
+        };
+        return vec![center_line(&message, Style::default().fg(theme.muted))];
     }
 
     let total = app.conflicts.len();
     let selected = app.conflict_selected.min(total.saturating_sub(1));
     let conflict = &app.conflicts[selected];
 
-    let header = format!(
+    let mut header = format!(
         "Overrides — Target: {}   ({} files)   ({}/{})",
         target_kind_label(conflict.target),
         total,
         selected + 1,
         total
     );
+    let manual = app.file_override_count();
+    if manual > 0 {
+        header.push_str(&format!("   Manual: {manual}"));
+    }
+    if ignored > 0 {
+        header.push_str(&format!("   Ignored: {ignored}"));
+    }
     let mut lines = Vec::new();
     lines.push(Line::from(Span::styled(
         truncate_text(&header, width),
@@ -8070,13 +10936,21 @@ fn build_conflict_details(
         .find(|candidate| candidate.mod_id == winner_id)
         .map(|candidate| candidate.mod_name.clone())
         .unwrap_or_else(|| conflict.winner_name.clone());
-    footer_lines.push(info_line(
-        &format!("Winner: {winner_name}"),
-        Style::default().fg(theme.text),
-    ));
+    let winner_label = if conflict.overridden || pending_winner.is_some() {
+        format!("Winner: {winner_name} (manual override)")
+    } else {
+        format!("Winner: {winner_name}")
+    };
+    footer_lines.push(info_line(&winner_label, Style::default().fg(theme.text)));
+    if let Some(reason) = &conflict.ignored {
+        footer_lines.push(info_line(
+            &format!("Ignored: {reason}"),
+            Style::default().fg(theme.warning),
+        ));
+    }
 
     footer_lines.push(info_line(
-        "←/→ cycle  1-9 pick  Auto apply 5s  C clear  P pick",
+        "←/→ cycle  1-9 pick  Auto apply 5s  C clear  P pick  R rules  V ignored",
         Style::default().fg(theme.muted),
     ));
 
@@ -8211,12 +11085,25 @@ fn build_conflict_details(
             .and_then(|name| name.to_str())
             .map(|name| name.to_string())
             .unwrap_or_else(|| entry.relative_path.to_string_lossy().to_string());
-        let left_text = truncate_text(&file_name, left_width);
+        let manual = entry.overridden || pending_winner.is_some();
+        let file_label = if manual {
+            format!("* {file_name}")
+        } else {
+            file_name
+        };
+        let left_text = truncate_text(&file_label, left_width);
         let left_pad = left_width.saturating_sub(display_width(&left_text));
+        let left_color = if entry.ignored.is_some() {
+            theme.muted
+        } else if manual {
+            theme.warning
+        } else {
+            theme.text
+        };
         let mut spans = Vec::new();
         spans.push(Span::styled(
             left_text,
-            apply_bg(Style::default().fg(theme.text)),
+            apply_bg(Style::default().fg(left_color)),
         ));
         if left_pad > 0 {
             spans.push(Span::styled(
@@ -8526,10 +11413,22 @@ fn hotkey_rows_for_focus(focus: Focus) -> HotkeyRows {
                     key: "r/F2".to_string(),
                     action: "Rename Profile".to_string(),
                 },
+                LegendRow {
+                    key: "Shift+↑/↓ or u/n".to_string(),
+                    action: "Move Profile".to_string(),
+                },
                 LegendRow {
                     key: "c".to_string(),
                     action: "Duplicate Profile".to_string(),
                 },
+                LegendRow {
+                    key: "x".to_string(),
+                    action: "Lock/Unlock Profile".to_string(),
+                },
+                LegendRow {
+                    key: "s".to_string(),
+                    action: "Set/Clear Default Profile".to_string(),
+                },
                 LegendRow {
                     key: "Del".to_string(),
                     action: "Delete Profile".to_string(),
@@ -8554,6 +11453,14 @@ fn hotkey_rows_for_focus(focus: Focus) -> HotkeyRows {
                     key: "↑/↓".to_string(),
                     action: "Choose Winner".to_string(),
                 },
+                LegendRow {
+                    key: "PgUp/PgDn".to_string(),
+                    action: "Page Overrides".to_string(),
+                },
+                LegendRow {
+                    key: "Home/End".to_string(),
+                    action: "First/Last Override".to_string(),
+                },
                 LegendRow {
                     key: "Enter".to_string(),
                     action: "Cycle Winner".to_string(),
@@ -8562,6 +11469,18 @@ fn hotkey_rows_for_focus(focus: Focus) -> HotkeyRows {
                     key: "Backspace".to_string(),
                     action: "Clear Override".to_string(),
                 },
+                LegendRow {
+                    key: "x".to_string(),
+                    action: "Clear All Overrides".to_string(),
+                },
+                LegendRow {
+                    key: "r".to_string(),
+                    action: "Conflict Ignore Rules".to_string(),
+                },
+                LegendRow {
+                    key: "v".to_string(),
+                    action: "Show/Hide Ignored".to_string(),
+                },
             ]);
         }
         Focus::Mods => {
@@ -8582,6 +11501,10 @@ fn hotkey_rows_for_focus(focus: Focus) -> HotkeyRows {
                     key: "u/n".to_string(),
                     action: "Move Order".to_string(),
                 },
+                LegendRow {
+                    key: "g".to_string(),
+                    action: "Go To Slot (Move Mode)".to_string(),
+                },
                 LegendRow {
                     key: "Enter/Esc".to_string(),
                     action: "Exit Move Mode".to_string(),
@@ -8602,14 +11525,34 @@ fn hotkey_rows_for_focus(focus: Focus) -> HotkeyRows {
                     key: "PgUp/PgDn".to_string(),
                     action: "Page Scroll".to_string(),
                 },
+                LegendRow {
+                    key: "Home/End".to_string(),
+                    action: "Jump To First/Last".to_string(),
+                },
                 LegendRow {
                     key: "Del".to_string(),
                     action: "Remove Mod".to_string(),
                 },
+                LegendRow {
+                    key: "F2".to_string(),
+                    action: "Rename Mod".to_string(),
+                },
+                LegendRow {
+                    key: "t".to_string(),
+                    action: "Install Targets".to_string(),
+                },
+                LegendRow {
+                    key: "* / f".to_string(),
+                    action: "Star Mod / Starred Filter".to_string(),
+                },
                 LegendRow {
                     key: "Target [1-5]".to_string(),
                     action: "Auto/Mods/Gen/Data/Bin".to_string(),
                 },
+                LegendRow {
+                    key: "0".to_string(),
+                    action: "All Targets On/Off".to_string(),
+                },
                 LegendRow {
                     key: "A/S/X".to_string(),
                     action: "All On/Off/Invert".to_string(),
@@ -8618,6 +11561,10 @@ fn hotkey_rows_for_focus(focus: Focus) -> HotkeyRows {
                     key: "Ctrl+R".to_string(),
                     action: "Reset SigiLink Pin".to_string(),
                 },
+                LegendRow {
+                    key: "Ctrl+X".to_string(),
+                    action: "Never Auto-Rank (All Profiles)".to_string(),
+                },
                 LegendRow {
                     key: "F12".to_string(),
                     action: "Reset All SigiLink Pins".to_string(),
@@ -8634,6 +11581,26 @@ fn hotkey_rows_for_focus(focus: Focus) -> HotkeyRows {
                     key: "PgUp/PgDn".to_string(),
                     action: "Page Scroll".to_string(),
                 },
+                LegendRow {
+                    key: "Home/End".to_string(),
+                    action: "Jump To First/Last".to_string(),
+                },
+                LegendRow {
+                    key: "a/w/e".to_string(),
+                    action: "Show All/Warn+/Errors".to_string(),
+                },
+                LegendRow {
+                    key: "f".to_string(),
+                    action: "Cycle Log Filter".to_string(),
+                },
+                LegendRow {
+                    key: "/".to_string(),
+                    action: "Search Log".to_string(),
+                },
+                LegendRow {
+                    key: "n/N".to_string(),
+                    action: "Older/Newer Match".to_string(),
+                },
             ]);
         }
     }
@@ -8895,6 +11862,22 @@ fn help_sections() -> Vec<HelpSection> {
                     key: "d".to_string(),
                     action: "Deploy".to_string(),
                 },
+                LegendRow {
+                    key: "Ctrl+D".to_string(),
+                    action: "Deploy Load Order Only".to_string(),
+                },
+                LegendRow {
+                    key: "Esc".to_string(),
+                    action: "Cancel Manual Deploy (confirm)".to_string(),
+                },
+                LegendRow {
+                    key: "Ctrl+O".to_string(),
+                    action: "Open Data/Log Dir".to_string(),
+                },
+                LegendRow {
+                    key: "Ctrl+G/Ctrl+U".to_string(),
+                    action: "Open Game Root/User Dir".to_string(),
+                },
                 LegendRow {
                     key: "b".to_string(),
                     action: "Rollback Last Backup".to_string(),
@@ -8912,6 +11895,14 @@ fn help_sections() -> Vec<HelpSection> {
                     key: "↑/↓ or j/k".to_string(),
                     action: "Move Selection".to_string(),
                 },
+                LegendRow {
+                    key: "PgUp/PgDn".to_string(),
+                    action: "Page Scroll".to_string(),
+                },
+                LegendRow {
+                    key: "Home/End".to_string(),
+                    action: "Jump To First/Last".to_string(),
+                },
                 LegendRow {
                     key: "←/→ or h/l".to_string(),
                     action: "Collapse/Expand".to_string(),
@@ -8928,10 +11919,22 @@ fn help_sections() -> Vec<HelpSection> {
                     key: "r/F2".to_string(),
                     action: "Rename Profile".to_string(),
                 },
+                LegendRow {
+                    key: "Shift+↑/↓ or u/n".to_string(),
+                    action: "Move Profile".to_string(),
+                },
                 LegendRow {
                     key: "c".to_string(),
                     action: "Duplicate Profile".to_string(),
                 },
+                LegendRow {
+                    key: "x".to_string(),
+                    action: "Lock/Unlock Profile".to_string(),
+                },
+                LegendRow {
+                    key: "s".to_string(),
+                    action: "Set/Clear Default Profile".to_string(),
+                },
                 LegendRow {
                     key: "e".to_string(),
                     action: "Export Mod List".to_string(),
@@ -8957,6 +11960,10 @@ fn help_sections() -> Vec<HelpSection> {
                     key: "PgUp/PgDn".to_string(),
                     action: "Page Scroll".to_string(),
                 },
+                LegendRow {
+                    key: "Home/End".to_string(),
+                    action: "Jump To First/Last".to_string(),
+                },
                 LegendRow {
                     key: "Shift+↑/↓".to_string(),
                     action: "Jump 10".to_string(),
@@ -8973,6 +11980,10 @@ fn help_sections() -> Vec<HelpSection> {
                     key: "u/n".to_string(),
                     action: "Move Order".to_string(),
                 },
+                LegendRow {
+                    key: "g".to_string(),
+                    action: "Go To Slot (Move Mode)".to_string(),
+                },
                 LegendRow {
                     key: "Enter/Esc".to_string(),
                     action: "Exit Move Mode".to_string(),
@@ -8981,6 +11992,10 @@ fn help_sections() -> Vec<HelpSection> {
                     key: "1-5".to_string(),
                     action: "Target Override (Auto/Mods/Gen/Data/Bin)".to_string(),
                 },
+                LegendRow {
+                    key: "0".to_string(),
+                    action: "Toggle All Targets Of Mod".to_string(),
+                },
                 LegendRow {
                     key: "A/S/X".to_string(),
                     action: "Enable/Disable/Invert Visible".to_string(),
@@ -9005,6 +12020,50 @@ fn help_sections() -> Vec<HelpSection> {
                     key: "Ctrl+L".to_string(),
                     action: "Clear Search".to_string(),
                 },
+                LegendRow {
+                    key: "r".to_string(),
+                    action: "Recently Added Filter".to_string(),
+                },
+                LegendRow {
+                    key: "*".to_string(),
+                    action: "Star/Unstar Mod (★)".to_string(),
+                },
+                LegendRow {
+                    key: "f".to_string(),
+                    action: "Starred Filter".to_string(),
+                },
+                LegendRow {
+                    key: "Ctrl+N".to_string(),
+                    action: "Acknowledge New Mods".to_string(),
+                },
+                LegendRow {
+                    key: "F5".to_string(),
+                    action: "Refresh Selected Metadata".to_string(),
+                },
+                LegendRow {
+                    key: "Shift+F5".to_string(),
+                    action: "Refresh Visible Metadata".to_string(),
+                },
+                LegendRow {
+                    key: "Esc".to_string(),
+                    action: "Cancel Metadata Scan (while running)".to_string(),
+                },
+                LegendRow {
+                    key: ":".to_string(),
+                    action: "Command Palette".to_string(),
+                },
+                LegendRow {
+                    key: "Ctrl+A".to_string(),
+                    action: "Adopt Native Mod Into Cache".to_string(),
+                },
+                LegendRow {
+                    key: "F2".to_string(),
+                    action: "Rename Mod (Display Name)".to_string(),
+                },
+                LegendRow {
+                    key: "t".to_string(),
+                    action: "Show Install Targets (Paths + On/Off)".to_string(),
+                },
                 LegendRow {
                     key: "Del".to_string(),
                     action: "Remove Mod".to_string(),
@@ -9022,6 +12081,14 @@ fn help_sections() -> Vec<HelpSection> {
                     key: "↑/↓".to_string(),
                     action: "Choose Winner".to_string(),
                 },
+                LegendRow {
+                    key: "PgUp/PgDn".to_string(),
+                    action: "Page Overrides".to_string(),
+                },
+                LegendRow {
+                    key: "Home/End".to_string(),
+                    action: "First/Last Override".to_string(),
+                },
                 LegendRow {
                     key: "Enter".to_string(),
                     action: "Cycle Winner".to_string(),
@@ -9030,6 +12097,18 @@ fn help_sections() -> Vec<HelpSection> {
                     key: "Backspace/Del".to_string(),
                     action: "Clear Override".to_string(),
                 },
+                LegendRow {
+                    key: "x".to_string(),
+                    action: "Clear All Overrides (Profile)".to_string(),
+                },
+                LegendRow {
+                    key: "r".to_string(),
+                    action: "Conflict Ignore Rules".to_string(),
+                },
+                LegendRow {
+                    key: "v".to_string(),
+                    action: "Show/Hide Ignored".to_string(),
+                },
             ],
         },
         HelpSection {
@@ -9043,6 +12122,26 @@ fn help_sections() -> Vec<HelpSection> {
                     key: "PgUp/PgDn".to_string(),
                     action: "Page Scroll".to_string(),
                 },
+                LegendRow {
+                    key: "Home/End".to_string(),
+                    action: "Jump To First/Last".to_string(),
+                },
+                LegendRow {
+                    key: "a/w/e".to_string(),
+                    action: "Show All/Warn+Error/Errors".to_string(),
+                },
+                LegendRow {
+                    key: "f".to_string(),
+                    action: "Cycle Log Filter".to_string(),
+                },
+                LegendRow {
+                    key: "/".to_string(),
+                    action: "Search Log".to_string(),
+                },
+                LegendRow {
+                    key: "n/N".to_string(),
+                    action: "Older/Newer Match".to_string(),
+                },
             ],
         },
         HelpSection {
@@ -9102,6 +12201,10 @@ fn help_sections() -> Vec<HelpSection> {
                     key: "Ctrl+R".to_string(),
                     action: "Reset SigiLink Pin For Selected Mod.".to_string(),
                 },
+                LegendRow {
+                    key: "Ctrl+X".to_string(),
+                    action: "Exclude Selected Mod From Ranking In All Profiles (⊘).".to_string(),
+                },
                 LegendRow {
                     key: "F12".to_string(),
                     action: "Reset All SigiLink Pins (Confirm).".to_string(),
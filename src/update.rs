@@ -1,3 +1,4 @@
+use crate::config;
 use anyhow::{Context, Result};
 use directories::BaseDirs;
 use serde::Deserialize;
@@ -353,8 +354,13 @@ fn rpm_installed() -> bool {
 }
 
 fn update_cache_dir() -> Result<PathBuf> {
-    let base = BaseDirs::new().context("resolve cache dir")?;
-    let dir = base.cache_dir().join("sigilsmith").join("updates");
+    let dir = match config::data_dir_override() {
+        Some(data_dir) => data_dir.join("cache").join("updates"),
+        None => {
+            let base = BaseDirs::new().context("resolve cache dir")?;
+            base.cache_dir().join("sigilsmith").join("updates")
+        }
+    };
     fs::create_dir_all(&dir).context("create update cache dir")?;
     Ok(dir)
 }